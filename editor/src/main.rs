@@ -1,7 +1,12 @@
 // use engine::vulkan_init::initialize_vulkan;
 use engine::render;
+use engine::render_headless;
 
 fn main() {
-    // headersgen::generate_headers_bindings();
+    if std::env::args().any(|arg| arg == "--headless")
+    {
+        render_headless();
+        return;
+    }
     render();
 }
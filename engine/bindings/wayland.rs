@@ -0,0 +1,1536 @@
+// Generated by headersgen from the Vulkan-Headers, regenerate instead of editing
+pub type __gnuc_va_list = __builtin_va_list;
+pub type va_list = __gnuc_va_list;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_object {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_message {
+    pub name: *const ::std::os::raw::c_char,
+    pub signature: *const ::std::os::raw::c_char,
+    pub types: *mut *const wl_interface,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_interface {
+    pub name: *const ::std::os::raw::c_char,
+    pub version: ::std::os::raw::c_int,
+    pub method_count: ::std::os::raw::c_int,
+    pub methods: *const wl_message,
+    pub event_count: ::std::os::raw::c_int,
+    pub events: *const wl_message,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_list {
+    pub prev: *mut wl_list,
+    pub next: *mut wl_list,
+}
+extern "C" {
+    pub fn wl_list_init(list: *mut wl_list);
+}
+extern "C" {
+    pub fn wl_list_insert(list: *mut wl_list, elm: *mut wl_list);
+}
+extern "C" {
+    pub fn wl_list_remove(elm: *mut wl_list);
+}
+extern "C" {
+    pub fn wl_list_length(list: *const wl_list) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_list_empty(list: *const wl_list) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_list_insert_list(list: *mut wl_list, other: *mut wl_list);
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_array {
+    pub size: usize,
+    pub alloc: usize,
+    pub data: *mut ::std::os::raw::c_void,
+}
+extern "C" {
+    pub fn wl_array_init(array: *mut wl_array);
+}
+extern "C" {
+    pub fn wl_array_release(array: *mut wl_array);
+}
+extern "C" {
+    pub fn wl_array_add(array: *mut wl_array, size: usize) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn wl_array_copy(array: *mut wl_array, source: *mut wl_array) -> ::std::os::raw::c_int;
+}
+pub type wl_fixed_t = i32;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union wl_argument {
+    pub i: i32,
+    pub u: u32,
+    pub f: wl_fixed_t,
+    pub s: *const ::std::os::raw::c_char,
+    pub o: *mut wl_object,
+    pub n: u32,
+    pub a: *mut wl_array,
+    pub h: i32,
+}
+pub type wl_dispatcher_func_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        user_data: *const ::std::os::raw::c_void,
+        target: *mut ::std::os::raw::c_void,
+        opcode: u32,
+        msg: *const wl_message,
+        args: *mut wl_argument,
+    ) -> ::std::os::raw::c_int,
+>;
+pub type wl_log_func_t = ::std::option::Option<
+    unsafe extern "C" fn(fmt: *const ::std::os::raw::c_char, args: *mut __va_list_tag),
+>;
+pub const wl_iterator_result_WL_ITERATOR_STOP: wl_iterator_result = 0;
+pub const wl_iterator_result_WL_ITERATOR_CONTINUE: wl_iterator_result = 1;
+pub type wl_iterator_result = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_proxy {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_display {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_event_queue {
+    _unused: [u8; 0],
+}
+pub const WL_MARSHAL_FLAG_DESTROY: u32 = 1;
+extern "C" {
+    pub fn wl_event_queue_destroy(queue: *mut wl_event_queue);
+}
+extern "C" {
+    pub fn wl_proxy_marshal_flags(
+        proxy: *mut wl_proxy,
+        opcode: u32,
+        interface: *const wl_interface,
+        version: u32,
+        flags: u32,
+        ...
+    ) -> *mut wl_proxy;
+}
+extern "C" {
+    pub fn wl_proxy_marshal_array_flags(
+        proxy: *mut wl_proxy,
+        opcode: u32,
+        interface: *const wl_interface,
+        version: u32,
+        flags: u32,
+        args: *mut wl_argument,
+    ) -> *mut wl_proxy;
+}
+extern "C" {
+    pub fn wl_proxy_marshal(p: *mut wl_proxy, opcode: u32, ...);
+}
+extern "C" {
+    pub fn wl_proxy_marshal_array(p: *mut wl_proxy, opcode: u32, args: *mut wl_argument);
+}
+extern "C" {
+    pub fn wl_proxy_create(factory: *mut wl_proxy, interface: *const wl_interface)
+        -> *mut wl_proxy;
+}
+extern "C" {
+    pub fn wl_proxy_create_wrapper(
+        proxy: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn wl_proxy_wrapper_destroy(proxy_wrapper: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn wl_proxy_marshal_constructor(
+        proxy: *mut wl_proxy,
+        opcode: u32,
+        interface: *const wl_interface,
+        ...
+    ) -> *mut wl_proxy;
+}
+extern "C" {
+    pub fn wl_proxy_marshal_constructor_versioned(
+        proxy: *mut wl_proxy,
+        opcode: u32,
+        interface: *const wl_interface,
+        version: u32,
+        ...
+    ) -> *mut wl_proxy;
+}
+extern "C" {
+    pub fn wl_proxy_marshal_array_constructor(
+        proxy: *mut wl_proxy,
+        opcode: u32,
+        args: *mut wl_argument,
+        interface: *const wl_interface,
+    ) -> *mut wl_proxy;
+}
+extern "C" {
+    pub fn wl_proxy_marshal_array_constructor_versioned(
+        proxy: *mut wl_proxy,
+        opcode: u32,
+        args: *mut wl_argument,
+        interface: *const wl_interface,
+        version: u32,
+    ) -> *mut wl_proxy;
+}
+extern "C" {
+    pub fn wl_proxy_destroy(proxy: *mut wl_proxy);
+}
+extern "C" {
+    pub fn wl_proxy_add_listener(
+        proxy: *mut wl_proxy,
+        implementation: *mut ::std::option::Option<unsafe extern "C" fn()>,
+        data: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_proxy_get_listener(proxy: *mut wl_proxy) -> *const ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn wl_proxy_add_dispatcher(
+        proxy: *mut wl_proxy,
+        dispatcher_func: wl_dispatcher_func_t,
+        dispatcher_data: *const ::std::os::raw::c_void,
+        data: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_proxy_set_user_data(proxy: *mut wl_proxy, user_data: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn wl_proxy_get_user_data(proxy: *mut wl_proxy) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn wl_proxy_get_version(proxy: *mut wl_proxy) -> u32;
+}
+extern "C" {
+    pub fn wl_proxy_get_id(proxy: *mut wl_proxy) -> u32;
+}
+extern "C" {
+    pub fn wl_proxy_set_tag(proxy: *mut wl_proxy, tag: *const *const ::std::os::raw::c_char);
+}
+extern "C" {
+    pub fn wl_proxy_get_tag(proxy: *mut wl_proxy) -> *const *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn wl_proxy_get_class(proxy: *mut wl_proxy) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn wl_proxy_get_display(proxy: *mut wl_proxy) -> *mut wl_display;
+}
+extern "C" {
+    pub fn wl_proxy_set_queue(proxy: *mut wl_proxy, queue: *mut wl_event_queue);
+}
+extern "C" {
+    pub fn wl_display_connect(name: *const ::std::os::raw::c_char) -> *mut wl_display;
+}
+extern "C" {
+    pub fn wl_display_connect_to_fd(fd: ::std::os::raw::c_int) -> *mut wl_display;
+}
+extern "C" {
+    pub fn wl_display_disconnect(display: *mut wl_display);
+}
+extern "C" {
+    pub fn wl_display_get_fd(display: *mut wl_display) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_display_dispatch(display: *mut wl_display) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_display_dispatch_queue(
+        display: *mut wl_display,
+        queue: *mut wl_event_queue,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_display_dispatch_queue_pending(
+        display: *mut wl_display,
+        queue: *mut wl_event_queue,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_display_dispatch_pending(display: *mut wl_display) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_display_get_error(display: *mut wl_display) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_display_get_protocol_error(
+        display: *mut wl_display,
+        interface: *mut *const wl_interface,
+        id: *mut u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn wl_display_flush(display: *mut wl_display) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_display_roundtrip_queue(
+        display: *mut wl_display,
+        queue: *mut wl_event_queue,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_display_roundtrip(display: *mut wl_display) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_display_create_queue(display: *mut wl_display) -> *mut wl_event_queue;
+}
+extern "C" {
+    pub fn wl_display_prepare_read_queue(
+        display: *mut wl_display,
+        queue: *mut wl_event_queue,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_display_prepare_read(display: *mut wl_display) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_display_cancel_read(display: *mut wl_display);
+}
+extern "C" {
+    pub fn wl_display_read_events(display: *mut wl_display) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn wl_log_set_handler_client(handler: wl_log_func_t);
+}
+extern "C" {
+    pub fn wl_display_set_max_buffer_size(display: *mut wl_display, max_buffer_size: usize);
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_buffer {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_callback {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_compositor {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_data_device {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_data_device_manager {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_data_offer {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_data_source {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_fixes {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_keyboard {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_output {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_pointer {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_region {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_registry {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_seat {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_shell {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_shell_surface {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_shm {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_shm_pool {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_subcompositor {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_subsurface {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_surface {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_touch {
+    _unused: [u8; 0],
+}
+extern "C" {
+    pub static wl_display_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_registry_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_callback_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_compositor_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_shm_pool_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_shm_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_buffer_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_data_offer_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_data_source_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_data_device_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_data_device_manager_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_shell_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_shell_surface_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_surface_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_seat_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_pointer_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_keyboard_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_touch_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_output_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_region_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_subcompositor_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_subsurface_interface: wl_interface;
+}
+extern "C" {
+    pub static wl_fixes_interface: wl_interface;
+}
+pub const wl_display_error_WL_DISPLAY_ERROR_INVALID_OBJECT: wl_display_error = 0;
+pub const wl_display_error_WL_DISPLAY_ERROR_INVALID_METHOD: wl_display_error = 1;
+pub const wl_display_error_WL_DISPLAY_ERROR_NO_MEMORY: wl_display_error = 2;
+pub const wl_display_error_WL_DISPLAY_ERROR_IMPLEMENTATION: wl_display_error = 3;
+pub type wl_display_error = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_display_listener {
+    pub error: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_display: *mut wl_display,
+            object_id: *mut ::std::os::raw::c_void,
+            code: u32,
+            message: *const ::std::os::raw::c_char,
+        ),
+    >,
+    pub delete_id: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_display: *mut wl_display,
+            id: u32,
+        ),
+    >,
+}
+pub const WL_DISPLAY_SYNC: u32 = 0;
+pub const WL_DISPLAY_GET_REGISTRY: u32 = 1;
+pub const WL_DISPLAY_ERROR_SINCE_VERSION: u32 = 1;
+pub const WL_DISPLAY_DELETE_ID_SINCE_VERSION: u32 = 1;
+pub const WL_DISPLAY_SYNC_SINCE_VERSION: u32 = 1;
+pub const WL_DISPLAY_GET_REGISTRY_SINCE_VERSION: u32 = 1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_registry_listener {
+    pub global: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_registry: *mut wl_registry,
+            name: u32,
+            interface: *const ::std::os::raw::c_char,
+            version: u32,
+        ),
+    >,
+    pub global_remove: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_registry: *mut wl_registry,
+            name: u32,
+        ),
+    >,
+}
+pub const WL_REGISTRY_BIND: u32 = 0;
+pub const WL_REGISTRY_GLOBAL_SINCE_VERSION: u32 = 1;
+pub const WL_REGISTRY_GLOBAL_REMOVE_SINCE_VERSION: u32 = 1;
+pub const WL_REGISTRY_BIND_SINCE_VERSION: u32 = 1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_callback_listener {
+    pub done: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_callback: *mut wl_callback,
+            callback_data: u32,
+        ),
+    >,
+}
+pub const WL_CALLBACK_DONE_SINCE_VERSION: u32 = 1;
+pub const WL_COMPOSITOR_CREATE_SURFACE: u32 = 0;
+pub const WL_COMPOSITOR_CREATE_REGION: u32 = 1;
+pub const WL_COMPOSITOR_RELEASE: u32 = 2;
+pub const WL_COMPOSITOR_CREATE_SURFACE_SINCE_VERSION: u32 = 1;
+pub const WL_COMPOSITOR_CREATE_REGION_SINCE_VERSION: u32 = 1;
+pub const WL_COMPOSITOR_RELEASE_SINCE_VERSION: u32 = 7;
+pub const wl_shm_pool_error_WL_SHM_POOL_ERROR_INVALID_FORMAT: wl_shm_pool_error = 0;
+pub const wl_shm_pool_error_WL_SHM_POOL_ERROR_INVALID_STRIDE: wl_shm_pool_error = 1;
+pub type wl_shm_pool_error = ::std::os::raw::c_uint;
+pub const WL_SHM_POOL_CREATE_BUFFER: u32 = 0;
+pub const WL_SHM_POOL_DESTROY: u32 = 1;
+pub const WL_SHM_POOL_RESIZE: u32 = 2;
+pub const WL_SHM_POOL_CREATE_BUFFER_SINCE_VERSION: u32 = 1;
+pub const WL_SHM_POOL_DESTROY_SINCE_VERSION: u32 = 1;
+pub const WL_SHM_POOL_RESIZE_SINCE_VERSION: u32 = 1;
+pub const wl_shm_error_WL_SHM_ERROR_INVALID_FORMAT: wl_shm_error = 0;
+pub const wl_shm_error_WL_SHM_ERROR_INVALID_STRIDE: wl_shm_error = 1;
+pub const wl_shm_error_WL_SHM_ERROR_INVALID_FD: wl_shm_error = 2;
+pub type wl_shm_error = ::std::os::raw::c_uint;
+pub const wl_shm_format_WL_SHM_FORMAT_ARGB8888: wl_shm_format = 0;
+pub const wl_shm_format_WL_SHM_FORMAT_XRGB8888: wl_shm_format = 1;
+pub const wl_shm_format_WL_SHM_FORMAT_C8: wl_shm_format = 538982467;
+pub const wl_shm_format_WL_SHM_FORMAT_RGB332: wl_shm_format = 943867730;
+pub const wl_shm_format_WL_SHM_FORMAT_BGR233: wl_shm_format = 944916290;
+pub const wl_shm_format_WL_SHM_FORMAT_XRGB4444: wl_shm_format = 842093144;
+pub const wl_shm_format_WL_SHM_FORMAT_XBGR4444: wl_shm_format = 842089048;
+pub const wl_shm_format_WL_SHM_FORMAT_RGBX4444: wl_shm_format = 842094674;
+pub const wl_shm_format_WL_SHM_FORMAT_BGRX4444: wl_shm_format = 842094658;
+pub const wl_shm_format_WL_SHM_FORMAT_ARGB4444: wl_shm_format = 842093121;
+pub const wl_shm_format_WL_SHM_FORMAT_ABGR4444: wl_shm_format = 842089025;
+pub const wl_shm_format_WL_SHM_FORMAT_RGBA4444: wl_shm_format = 842088786;
+pub const wl_shm_format_WL_SHM_FORMAT_BGRA4444: wl_shm_format = 842088770;
+pub const wl_shm_format_WL_SHM_FORMAT_XRGB1555: wl_shm_format = 892424792;
+pub const wl_shm_format_WL_SHM_FORMAT_XBGR1555: wl_shm_format = 892420696;
+pub const wl_shm_format_WL_SHM_FORMAT_RGBX5551: wl_shm_format = 892426322;
+pub const wl_shm_format_WL_SHM_FORMAT_BGRX5551: wl_shm_format = 892426306;
+pub const wl_shm_format_WL_SHM_FORMAT_ARGB1555: wl_shm_format = 892424769;
+pub const wl_shm_format_WL_SHM_FORMAT_ABGR1555: wl_shm_format = 892420673;
+pub const wl_shm_format_WL_SHM_FORMAT_RGBA5551: wl_shm_format = 892420434;
+pub const wl_shm_format_WL_SHM_FORMAT_BGRA5551: wl_shm_format = 892420418;
+pub const wl_shm_format_WL_SHM_FORMAT_RGB565: wl_shm_format = 909199186;
+pub const wl_shm_format_WL_SHM_FORMAT_BGR565: wl_shm_format = 909199170;
+pub const wl_shm_format_WL_SHM_FORMAT_RGB888: wl_shm_format = 875710290;
+pub const wl_shm_format_WL_SHM_FORMAT_BGR888: wl_shm_format = 875710274;
+pub const wl_shm_format_WL_SHM_FORMAT_XBGR8888: wl_shm_format = 875709016;
+pub const wl_shm_format_WL_SHM_FORMAT_RGBX8888: wl_shm_format = 875714642;
+pub const wl_shm_format_WL_SHM_FORMAT_BGRX8888: wl_shm_format = 875714626;
+pub const wl_shm_format_WL_SHM_FORMAT_ABGR8888: wl_shm_format = 875708993;
+pub const wl_shm_format_WL_SHM_FORMAT_RGBA8888: wl_shm_format = 875708754;
+pub const wl_shm_format_WL_SHM_FORMAT_BGRA8888: wl_shm_format = 875708738;
+pub const wl_shm_format_WL_SHM_FORMAT_XRGB2101010: wl_shm_format = 808669784;
+pub const wl_shm_format_WL_SHM_FORMAT_XBGR2101010: wl_shm_format = 808665688;
+pub const wl_shm_format_WL_SHM_FORMAT_RGBX1010102: wl_shm_format = 808671314;
+pub const wl_shm_format_WL_SHM_FORMAT_BGRX1010102: wl_shm_format = 808671298;
+pub const wl_shm_format_WL_SHM_FORMAT_ARGB2101010: wl_shm_format = 808669761;
+pub const wl_shm_format_WL_SHM_FORMAT_ABGR2101010: wl_shm_format = 808665665;
+pub const wl_shm_format_WL_SHM_FORMAT_RGBA1010102: wl_shm_format = 808665426;
+pub const wl_shm_format_WL_SHM_FORMAT_BGRA1010102: wl_shm_format = 808665410;
+pub const wl_shm_format_WL_SHM_FORMAT_YUYV: wl_shm_format = 1448695129;
+pub const wl_shm_format_WL_SHM_FORMAT_YVYU: wl_shm_format = 1431918169;
+pub const wl_shm_format_WL_SHM_FORMAT_UYVY: wl_shm_format = 1498831189;
+pub const wl_shm_format_WL_SHM_FORMAT_VYUY: wl_shm_format = 1498765654;
+pub const wl_shm_format_WL_SHM_FORMAT_AYUV: wl_shm_format = 1448433985;
+pub const wl_shm_format_WL_SHM_FORMAT_NV12: wl_shm_format = 842094158;
+pub const wl_shm_format_WL_SHM_FORMAT_NV21: wl_shm_format = 825382478;
+pub const wl_shm_format_WL_SHM_FORMAT_NV16: wl_shm_format = 909203022;
+pub const wl_shm_format_WL_SHM_FORMAT_NV61: wl_shm_format = 825644622;
+pub const wl_shm_format_WL_SHM_FORMAT_YUV410: wl_shm_format = 961959257;
+pub const wl_shm_format_WL_SHM_FORMAT_YVU410: wl_shm_format = 961893977;
+pub const wl_shm_format_WL_SHM_FORMAT_YUV411: wl_shm_format = 825316697;
+pub const wl_shm_format_WL_SHM_FORMAT_YVU411: wl_shm_format = 825316953;
+pub const wl_shm_format_WL_SHM_FORMAT_YUV420: wl_shm_format = 842093913;
+pub const wl_shm_format_WL_SHM_FORMAT_YVU420: wl_shm_format = 842094169;
+pub const wl_shm_format_WL_SHM_FORMAT_YUV422: wl_shm_format = 909202777;
+pub const wl_shm_format_WL_SHM_FORMAT_YVU422: wl_shm_format = 909203033;
+pub const wl_shm_format_WL_SHM_FORMAT_YUV444: wl_shm_format = 875713881;
+pub const wl_shm_format_WL_SHM_FORMAT_YVU444: wl_shm_format = 875714137;
+pub const wl_shm_format_WL_SHM_FORMAT_R8: wl_shm_format = 538982482;
+pub const wl_shm_format_WL_SHM_FORMAT_R16: wl_shm_format = 540422482;
+pub const wl_shm_format_WL_SHM_FORMAT_RG88: wl_shm_format = 943212370;
+pub const wl_shm_format_WL_SHM_FORMAT_GR88: wl_shm_format = 943215175;
+pub const wl_shm_format_WL_SHM_FORMAT_RG1616: wl_shm_format = 842221394;
+pub const wl_shm_format_WL_SHM_FORMAT_GR1616: wl_shm_format = 842224199;
+pub const wl_shm_format_WL_SHM_FORMAT_XRGB16161616F: wl_shm_format = 1211388504;
+pub const wl_shm_format_WL_SHM_FORMAT_XBGR16161616F: wl_shm_format = 1211384408;
+pub const wl_shm_format_WL_SHM_FORMAT_ARGB16161616F: wl_shm_format = 1211388481;
+pub const wl_shm_format_WL_SHM_FORMAT_ABGR16161616F: wl_shm_format = 1211384385;
+pub const wl_shm_format_WL_SHM_FORMAT_XYUV8888: wl_shm_format = 1448434008;
+pub const wl_shm_format_WL_SHM_FORMAT_VUY888: wl_shm_format = 875713878;
+pub const wl_shm_format_WL_SHM_FORMAT_VUY101010: wl_shm_format = 808670550;
+pub const wl_shm_format_WL_SHM_FORMAT_Y210: wl_shm_format = 808530521;
+pub const wl_shm_format_WL_SHM_FORMAT_Y212: wl_shm_format = 842084953;
+pub const wl_shm_format_WL_SHM_FORMAT_Y216: wl_shm_format = 909193817;
+pub const wl_shm_format_WL_SHM_FORMAT_Y410: wl_shm_format = 808531033;
+pub const wl_shm_format_WL_SHM_FORMAT_Y412: wl_shm_format = 842085465;
+pub const wl_shm_format_WL_SHM_FORMAT_Y416: wl_shm_format = 909194329;
+pub const wl_shm_format_WL_SHM_FORMAT_XVYU2101010: wl_shm_format = 808670808;
+pub const wl_shm_format_WL_SHM_FORMAT_XVYU12_16161616: wl_shm_format = 909334104;
+pub const wl_shm_format_WL_SHM_FORMAT_XVYU16161616: wl_shm_format = 942954072;
+pub const wl_shm_format_WL_SHM_FORMAT_Y0L0: wl_shm_format = 810299481;
+pub const wl_shm_format_WL_SHM_FORMAT_X0L0: wl_shm_format = 810299480;
+pub const wl_shm_format_WL_SHM_FORMAT_Y0L2: wl_shm_format = 843853913;
+pub const wl_shm_format_WL_SHM_FORMAT_X0L2: wl_shm_format = 843853912;
+pub const wl_shm_format_WL_SHM_FORMAT_YUV420_8BIT: wl_shm_format = 942691673;
+pub const wl_shm_format_WL_SHM_FORMAT_YUV420_10BIT: wl_shm_format = 808539481;
+pub const wl_shm_format_WL_SHM_FORMAT_XRGB8888_A8: wl_shm_format = 943805016;
+pub const wl_shm_format_WL_SHM_FORMAT_XBGR8888_A8: wl_shm_format = 943800920;
+pub const wl_shm_format_WL_SHM_FORMAT_RGBX8888_A8: wl_shm_format = 943806546;
+pub const wl_shm_format_WL_SHM_FORMAT_BGRX8888_A8: wl_shm_format = 943806530;
+pub const wl_shm_format_WL_SHM_FORMAT_RGB888_A8: wl_shm_format = 943798354;
+pub const wl_shm_format_WL_SHM_FORMAT_BGR888_A8: wl_shm_format = 943798338;
+pub const wl_shm_format_WL_SHM_FORMAT_RGB565_A8: wl_shm_format = 943797586;
+pub const wl_shm_format_WL_SHM_FORMAT_BGR565_A8: wl_shm_format = 943797570;
+pub const wl_shm_format_WL_SHM_FORMAT_NV24: wl_shm_format = 875714126;
+pub const wl_shm_format_WL_SHM_FORMAT_NV42: wl_shm_format = 842290766;
+pub const wl_shm_format_WL_SHM_FORMAT_P210: wl_shm_format = 808530512;
+pub const wl_shm_format_WL_SHM_FORMAT_P010: wl_shm_format = 808530000;
+pub const wl_shm_format_WL_SHM_FORMAT_P012: wl_shm_format = 842084432;
+pub const wl_shm_format_WL_SHM_FORMAT_P016: wl_shm_format = 909193296;
+pub const wl_shm_format_WL_SHM_FORMAT_AXBXGXRX106106106106: wl_shm_format = 808534593;
+pub const wl_shm_format_WL_SHM_FORMAT_NV15: wl_shm_format = 892425806;
+pub const wl_shm_format_WL_SHM_FORMAT_Q410: wl_shm_format = 808531025;
+pub const wl_shm_format_WL_SHM_FORMAT_Q401: wl_shm_format = 825242705;
+pub const wl_shm_format_WL_SHM_FORMAT_XRGB16161616: wl_shm_format = 942953048;
+pub const wl_shm_format_WL_SHM_FORMAT_XBGR16161616: wl_shm_format = 942948952;
+pub const wl_shm_format_WL_SHM_FORMAT_ARGB16161616: wl_shm_format = 942953025;
+pub const wl_shm_format_WL_SHM_FORMAT_ABGR16161616: wl_shm_format = 942948929;
+pub const wl_shm_format_WL_SHM_FORMAT_C1: wl_shm_format = 538980675;
+pub const wl_shm_format_WL_SHM_FORMAT_C2: wl_shm_format = 538980931;
+pub const wl_shm_format_WL_SHM_FORMAT_C4: wl_shm_format = 538981443;
+pub const wl_shm_format_WL_SHM_FORMAT_D1: wl_shm_format = 538980676;
+pub const wl_shm_format_WL_SHM_FORMAT_D2: wl_shm_format = 538980932;
+pub const wl_shm_format_WL_SHM_FORMAT_D4: wl_shm_format = 538981444;
+pub const wl_shm_format_WL_SHM_FORMAT_D8: wl_shm_format = 538982468;
+pub const wl_shm_format_WL_SHM_FORMAT_R1: wl_shm_format = 538980690;
+pub const wl_shm_format_WL_SHM_FORMAT_R2: wl_shm_format = 538980946;
+pub const wl_shm_format_WL_SHM_FORMAT_R4: wl_shm_format = 538981458;
+pub const wl_shm_format_WL_SHM_FORMAT_R10: wl_shm_format = 540029266;
+pub const wl_shm_format_WL_SHM_FORMAT_R12: wl_shm_format = 540160338;
+pub const wl_shm_format_WL_SHM_FORMAT_AVUY8888: wl_shm_format = 1498764865;
+pub const wl_shm_format_WL_SHM_FORMAT_XVUY8888: wl_shm_format = 1498764888;
+pub const wl_shm_format_WL_SHM_FORMAT_P030: wl_shm_format = 808661072;
+pub const wl_shm_format_WL_SHM_FORMAT_RGB161616: wl_shm_format = 942950226;
+pub const wl_shm_format_WL_SHM_FORMAT_BGR161616: wl_shm_format = 942950210;
+pub const wl_shm_format_WL_SHM_FORMAT_R16F: wl_shm_format = 1210064978;
+pub const wl_shm_format_WL_SHM_FORMAT_GR1616F: wl_shm_format = 1210077767;
+pub const wl_shm_format_WL_SHM_FORMAT_BGR161616F: wl_shm_format = 1213351746;
+pub const wl_shm_format_WL_SHM_FORMAT_R32F: wl_shm_format = 1176510546;
+pub const wl_shm_format_WL_SHM_FORMAT_GR3232F: wl_shm_format = 1176523335;
+pub const wl_shm_format_WL_SHM_FORMAT_BGR323232F: wl_shm_format = 1179797314;
+pub const wl_shm_format_WL_SHM_FORMAT_ABGR32323232F: wl_shm_format = 1178092097;
+pub const wl_shm_format_WL_SHM_FORMAT_NV20: wl_shm_format = 808605262;
+pub const wl_shm_format_WL_SHM_FORMAT_NV30: wl_shm_format = 808670798;
+pub const wl_shm_format_WL_SHM_FORMAT_S010: wl_shm_format = 808530003;
+pub const wl_shm_format_WL_SHM_FORMAT_S210: wl_shm_format = 808530515;
+pub const wl_shm_format_WL_SHM_FORMAT_S410: wl_shm_format = 808531027;
+pub const wl_shm_format_WL_SHM_FORMAT_S012: wl_shm_format = 842084435;
+pub const wl_shm_format_WL_SHM_FORMAT_S212: wl_shm_format = 842084947;
+pub const wl_shm_format_WL_SHM_FORMAT_S412: wl_shm_format = 842085459;
+pub const wl_shm_format_WL_SHM_FORMAT_S016: wl_shm_format = 909193299;
+pub const wl_shm_format_WL_SHM_FORMAT_S216: wl_shm_format = 909193811;
+pub const wl_shm_format_WL_SHM_FORMAT_S416: wl_shm_format = 909194323;
+pub const wl_shm_format_WL_SHM_FORMAT_XVUY2101010: wl_shm_format = 808671576;
+pub const wl_shm_format_WL_SHM_FORMAT_P230: wl_shm_format = 808661584;
+pub const wl_shm_format_WL_SHM_FORMAT_T430: wl_shm_format = 808662100;
+pub const wl_shm_format_WL_SHM_FORMAT_Y8: wl_shm_format = 1497715271;
+pub const wl_shm_format_WL_SHM_FORMAT_XYYY2101010: wl_shm_format = 876695641;
+pub type wl_shm_format = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_shm_listener {
+    pub format: ::std::option::Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, wl_shm: *mut wl_shm, format: u32),
+    >,
+}
+pub const WL_SHM_CREATE_POOL: u32 = 0;
+pub const WL_SHM_RELEASE: u32 = 1;
+pub const WL_SHM_FORMAT_SINCE_VERSION: u32 = 1;
+pub const WL_SHM_CREATE_POOL_SINCE_VERSION: u32 = 1;
+pub const WL_SHM_RELEASE_SINCE_VERSION: u32 = 2;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_buffer_listener {
+    pub release: ::std::option::Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, wl_buffer: *mut wl_buffer),
+    >,
+}
+pub const WL_BUFFER_DESTROY: u32 = 0;
+pub const WL_BUFFER_RELEASE_SINCE_VERSION: u32 = 1;
+pub const WL_BUFFER_DESTROY_SINCE_VERSION: u32 = 1;
+pub const wl_data_offer_error_WL_DATA_OFFER_ERROR_INVALID_FINISH: wl_data_offer_error = 0;
+pub const wl_data_offer_error_WL_DATA_OFFER_ERROR_INVALID_ACTION_MASK: wl_data_offer_error = 1;
+pub const wl_data_offer_error_WL_DATA_OFFER_ERROR_INVALID_ACTION: wl_data_offer_error = 2;
+pub const wl_data_offer_error_WL_DATA_OFFER_ERROR_INVALID_OFFER: wl_data_offer_error = 3;
+pub type wl_data_offer_error = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_data_offer_listener {
+    pub offer: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_offer: *mut wl_data_offer,
+            mime_type: *const ::std::os::raw::c_char,
+        ),
+    >,
+    pub source_actions: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_offer: *mut wl_data_offer,
+            source_actions: u32,
+        ),
+    >,
+    pub action: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_offer: *mut wl_data_offer,
+            dnd_action: u32,
+        ),
+    >,
+}
+pub const WL_DATA_OFFER_ACCEPT: u32 = 0;
+pub const WL_DATA_OFFER_RECEIVE: u32 = 1;
+pub const WL_DATA_OFFER_DESTROY: u32 = 2;
+pub const WL_DATA_OFFER_FINISH: u32 = 3;
+pub const WL_DATA_OFFER_SET_ACTIONS: u32 = 4;
+pub const WL_DATA_OFFER_OFFER_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_OFFER_SOURCE_ACTIONS_SINCE_VERSION: u32 = 3;
+pub const WL_DATA_OFFER_ACTION_SINCE_VERSION: u32 = 3;
+pub const WL_DATA_OFFER_ACCEPT_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_OFFER_RECEIVE_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_OFFER_DESTROY_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_OFFER_FINISH_SINCE_VERSION: u32 = 3;
+pub const WL_DATA_OFFER_SET_ACTIONS_SINCE_VERSION: u32 = 3;
+pub const wl_data_source_error_WL_DATA_SOURCE_ERROR_INVALID_ACTION_MASK: wl_data_source_error = 0;
+pub const wl_data_source_error_WL_DATA_SOURCE_ERROR_INVALID_SOURCE: wl_data_source_error = 1;
+pub type wl_data_source_error = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_data_source_listener {
+    pub target: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_source: *mut wl_data_source,
+            mime_type: *const ::std::os::raw::c_char,
+        ),
+    >,
+    pub send: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_source: *mut wl_data_source,
+            mime_type: *const ::std::os::raw::c_char,
+            fd: i32,
+        ),
+    >,
+    pub cancelled: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_source: *mut wl_data_source,
+        ),
+    >,
+    pub dnd_drop_performed: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_source: *mut wl_data_source,
+        ),
+    >,
+    pub dnd_finished: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_source: *mut wl_data_source,
+        ),
+    >,
+    pub action: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_source: *mut wl_data_source,
+            dnd_action: u32,
+        ),
+    >,
+}
+pub const WL_DATA_SOURCE_OFFER: u32 = 0;
+pub const WL_DATA_SOURCE_DESTROY: u32 = 1;
+pub const WL_DATA_SOURCE_SET_ACTIONS: u32 = 2;
+pub const WL_DATA_SOURCE_TARGET_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_SOURCE_SEND_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_SOURCE_CANCELLED_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_SOURCE_DND_DROP_PERFORMED_SINCE_VERSION: u32 = 3;
+pub const WL_DATA_SOURCE_DND_FINISHED_SINCE_VERSION: u32 = 3;
+pub const WL_DATA_SOURCE_ACTION_SINCE_VERSION: u32 = 3;
+pub const WL_DATA_SOURCE_OFFER_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_SOURCE_DESTROY_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_SOURCE_SET_ACTIONS_SINCE_VERSION: u32 = 3;
+pub const wl_data_device_error_WL_DATA_DEVICE_ERROR_ROLE: wl_data_device_error = 0;
+pub const wl_data_device_error_WL_DATA_DEVICE_ERROR_USED_SOURCE: wl_data_device_error = 1;
+pub type wl_data_device_error = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_data_device_listener {
+    pub data_offer: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_device: *mut wl_data_device,
+            id: *mut wl_data_offer,
+        ),
+    >,
+    pub enter: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_device: *mut wl_data_device,
+            serial: u32,
+            surface: *mut wl_surface,
+            x: wl_fixed_t,
+            y: wl_fixed_t,
+            id: *mut wl_data_offer,
+        ),
+    >,
+    pub leave: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_device: *mut wl_data_device,
+        ),
+    >,
+    pub motion: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_device: *mut wl_data_device,
+            time: u32,
+            x: wl_fixed_t,
+            y: wl_fixed_t,
+        ),
+    >,
+    pub drop: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_device: *mut wl_data_device,
+        ),
+    >,
+    pub selection: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_data_device: *mut wl_data_device,
+            id: *mut wl_data_offer,
+        ),
+    >,
+}
+pub const WL_DATA_DEVICE_START_DRAG: u32 = 0;
+pub const WL_DATA_DEVICE_SET_SELECTION: u32 = 1;
+pub const WL_DATA_DEVICE_RELEASE: u32 = 2;
+pub const WL_DATA_DEVICE_DATA_OFFER_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_DEVICE_ENTER_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_DEVICE_LEAVE_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_DEVICE_MOTION_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_DEVICE_DROP_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_DEVICE_SELECTION_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_DEVICE_START_DRAG_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_DEVICE_SET_SELECTION_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_DEVICE_RELEASE_SINCE_VERSION: u32 = 2;
+pub const wl_data_device_manager_dnd_action_WL_DATA_DEVICE_MANAGER_DND_ACTION_NONE:
+    wl_data_device_manager_dnd_action = 0;
+pub const wl_data_device_manager_dnd_action_WL_DATA_DEVICE_MANAGER_DND_ACTION_COPY:
+    wl_data_device_manager_dnd_action = 1;
+pub const wl_data_device_manager_dnd_action_WL_DATA_DEVICE_MANAGER_DND_ACTION_MOVE:
+    wl_data_device_manager_dnd_action = 2;
+pub const wl_data_device_manager_dnd_action_WL_DATA_DEVICE_MANAGER_DND_ACTION_ASK:
+    wl_data_device_manager_dnd_action = 4;
+pub type wl_data_device_manager_dnd_action = ::std::os::raw::c_uint;
+pub const WL_DATA_DEVICE_MANAGER_CREATE_DATA_SOURCE: u32 = 0;
+pub const WL_DATA_DEVICE_MANAGER_GET_DATA_DEVICE: u32 = 1;
+pub const WL_DATA_DEVICE_MANAGER_RELEASE: u32 = 2;
+pub const WL_DATA_DEVICE_MANAGER_CREATE_DATA_SOURCE_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_DEVICE_MANAGER_GET_DATA_DEVICE_SINCE_VERSION: u32 = 1;
+pub const WL_DATA_DEVICE_MANAGER_RELEASE_SINCE_VERSION: u32 = 4;
+pub const wl_shell_error_WL_SHELL_ERROR_ROLE: wl_shell_error = 0;
+pub type wl_shell_error = ::std::os::raw::c_uint;
+pub const WL_SHELL_GET_SHELL_SURFACE: u32 = 0;
+pub const WL_SHELL_GET_SHELL_SURFACE_SINCE_VERSION: u32 = 1;
+pub const wl_shell_surface_resize_WL_SHELL_SURFACE_RESIZE_NONE: wl_shell_surface_resize = 0;
+pub const wl_shell_surface_resize_WL_SHELL_SURFACE_RESIZE_TOP: wl_shell_surface_resize = 1;
+pub const wl_shell_surface_resize_WL_SHELL_SURFACE_RESIZE_BOTTOM: wl_shell_surface_resize = 2;
+pub const wl_shell_surface_resize_WL_SHELL_SURFACE_RESIZE_LEFT: wl_shell_surface_resize = 4;
+pub const wl_shell_surface_resize_WL_SHELL_SURFACE_RESIZE_TOP_LEFT: wl_shell_surface_resize = 5;
+pub const wl_shell_surface_resize_WL_SHELL_SURFACE_RESIZE_BOTTOM_LEFT: wl_shell_surface_resize = 6;
+pub const wl_shell_surface_resize_WL_SHELL_SURFACE_RESIZE_RIGHT: wl_shell_surface_resize = 8;
+pub const wl_shell_surface_resize_WL_SHELL_SURFACE_RESIZE_TOP_RIGHT: wl_shell_surface_resize = 9;
+pub const wl_shell_surface_resize_WL_SHELL_SURFACE_RESIZE_BOTTOM_RIGHT: wl_shell_surface_resize =
+    10;
+pub type wl_shell_surface_resize = ::std::os::raw::c_uint;
+pub const wl_shell_surface_transient_WL_SHELL_SURFACE_TRANSIENT_INACTIVE:
+    wl_shell_surface_transient = 1;
+pub type wl_shell_surface_transient = ::std::os::raw::c_uint;
+pub const wl_shell_surface_fullscreen_method_WL_SHELL_SURFACE_FULLSCREEN_METHOD_DEFAULT:
+    wl_shell_surface_fullscreen_method = 0;
+pub const wl_shell_surface_fullscreen_method_WL_SHELL_SURFACE_FULLSCREEN_METHOD_SCALE:
+    wl_shell_surface_fullscreen_method = 1;
+pub const wl_shell_surface_fullscreen_method_WL_SHELL_SURFACE_FULLSCREEN_METHOD_DRIVER:
+    wl_shell_surface_fullscreen_method = 2;
+pub const wl_shell_surface_fullscreen_method_WL_SHELL_SURFACE_FULLSCREEN_METHOD_FILL:
+    wl_shell_surface_fullscreen_method = 3;
+pub type wl_shell_surface_fullscreen_method = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_shell_surface_listener {
+    pub ping: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_shell_surface: *mut wl_shell_surface,
+            serial: u32,
+        ),
+    >,
+    pub configure: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_shell_surface: *mut wl_shell_surface,
+            edges: u32,
+            width: i32,
+            height: i32,
+        ),
+    >,
+    pub popup_done: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_shell_surface: *mut wl_shell_surface,
+        ),
+    >,
+}
+pub const WL_SHELL_SURFACE_PONG: u32 = 0;
+pub const WL_SHELL_SURFACE_MOVE: u32 = 1;
+pub const WL_SHELL_SURFACE_RESIZE: u32 = 2;
+pub const WL_SHELL_SURFACE_SET_TOPLEVEL: u32 = 3;
+pub const WL_SHELL_SURFACE_SET_TRANSIENT: u32 = 4;
+pub const WL_SHELL_SURFACE_SET_FULLSCREEN: u32 = 5;
+pub const WL_SHELL_SURFACE_SET_POPUP: u32 = 6;
+pub const WL_SHELL_SURFACE_SET_MAXIMIZED: u32 = 7;
+pub const WL_SHELL_SURFACE_SET_TITLE: u32 = 8;
+pub const WL_SHELL_SURFACE_SET_CLASS: u32 = 9;
+pub const WL_SHELL_SURFACE_PING_SINCE_VERSION: u32 = 1;
+pub const WL_SHELL_SURFACE_CONFIGURE_SINCE_VERSION: u32 = 1;
+pub const WL_SHELL_SURFACE_POPUP_DONE_SINCE_VERSION: u32 = 1;
+pub const WL_SHELL_SURFACE_PONG_SINCE_VERSION: u32 = 1;
+pub const WL_SHELL_SURFACE_MOVE_SINCE_VERSION: u32 = 1;
+pub const WL_SHELL_SURFACE_RESIZE_SINCE_VERSION: u32 = 1;
+pub const WL_SHELL_SURFACE_SET_TOPLEVEL_SINCE_VERSION: u32 = 1;
+pub const WL_SHELL_SURFACE_SET_TRANSIENT_SINCE_VERSION: u32 = 1;
+pub const WL_SHELL_SURFACE_SET_FULLSCREEN_SINCE_VERSION: u32 = 1;
+pub const WL_SHELL_SURFACE_SET_POPUP_SINCE_VERSION: u32 = 1;
+pub const WL_SHELL_SURFACE_SET_MAXIMIZED_SINCE_VERSION: u32 = 1;
+pub const WL_SHELL_SURFACE_SET_TITLE_SINCE_VERSION: u32 = 1;
+pub const WL_SHELL_SURFACE_SET_CLASS_SINCE_VERSION: u32 = 1;
+pub const wl_surface_error_WL_SURFACE_ERROR_INVALID_SCALE: wl_surface_error = 0;
+pub const wl_surface_error_WL_SURFACE_ERROR_INVALID_TRANSFORM: wl_surface_error = 1;
+pub const wl_surface_error_WL_SURFACE_ERROR_INVALID_SIZE: wl_surface_error = 2;
+pub const wl_surface_error_WL_SURFACE_ERROR_INVALID_OFFSET: wl_surface_error = 3;
+pub const wl_surface_error_WL_SURFACE_ERROR_DEFUNCT_ROLE_OBJECT: wl_surface_error = 4;
+pub const wl_surface_error_WL_SURFACE_ERROR_NO_BUFFER: wl_surface_error = 5;
+pub type wl_surface_error = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_surface_listener {
+    pub enter: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_surface: *mut wl_surface,
+            output: *mut wl_output,
+        ),
+    >,
+    pub leave: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_surface: *mut wl_surface,
+            output: *mut wl_output,
+        ),
+    >,
+    pub preferred_buffer_scale: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_surface: *mut wl_surface,
+            factor: i32,
+        ),
+    >,
+    pub preferred_buffer_transform: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_surface: *mut wl_surface,
+            transform: u32,
+        ),
+    >,
+}
+pub const WL_SURFACE_DESTROY: u32 = 0;
+pub const WL_SURFACE_ATTACH: u32 = 1;
+pub const WL_SURFACE_DAMAGE: u32 = 2;
+pub const WL_SURFACE_FRAME: u32 = 3;
+pub const WL_SURFACE_SET_OPAQUE_REGION: u32 = 4;
+pub const WL_SURFACE_SET_INPUT_REGION: u32 = 5;
+pub const WL_SURFACE_COMMIT: u32 = 6;
+pub const WL_SURFACE_SET_BUFFER_TRANSFORM: u32 = 7;
+pub const WL_SURFACE_SET_BUFFER_SCALE: u32 = 8;
+pub const WL_SURFACE_DAMAGE_BUFFER: u32 = 9;
+pub const WL_SURFACE_OFFSET: u32 = 10;
+pub const WL_SURFACE_GET_RELEASE: u32 = 11;
+pub const WL_SURFACE_ENTER_SINCE_VERSION: u32 = 1;
+pub const WL_SURFACE_LEAVE_SINCE_VERSION: u32 = 1;
+pub const WL_SURFACE_PREFERRED_BUFFER_SCALE_SINCE_VERSION: u32 = 6;
+pub const WL_SURFACE_PREFERRED_BUFFER_TRANSFORM_SINCE_VERSION: u32 = 6;
+pub const WL_SURFACE_DESTROY_SINCE_VERSION: u32 = 1;
+pub const WL_SURFACE_ATTACH_SINCE_VERSION: u32 = 1;
+pub const WL_SURFACE_DAMAGE_SINCE_VERSION: u32 = 1;
+pub const WL_SURFACE_FRAME_SINCE_VERSION: u32 = 1;
+pub const WL_SURFACE_SET_OPAQUE_REGION_SINCE_VERSION: u32 = 1;
+pub const WL_SURFACE_SET_INPUT_REGION_SINCE_VERSION: u32 = 1;
+pub const WL_SURFACE_COMMIT_SINCE_VERSION: u32 = 1;
+pub const WL_SURFACE_SET_BUFFER_TRANSFORM_SINCE_VERSION: u32 = 2;
+pub const WL_SURFACE_SET_BUFFER_SCALE_SINCE_VERSION: u32 = 3;
+pub const WL_SURFACE_DAMAGE_BUFFER_SINCE_VERSION: u32 = 4;
+pub const WL_SURFACE_OFFSET_SINCE_VERSION: u32 = 5;
+pub const WL_SURFACE_GET_RELEASE_SINCE_VERSION: u32 = 7;
+pub const wl_seat_capability_WL_SEAT_CAPABILITY_POINTER: wl_seat_capability = 1;
+pub const wl_seat_capability_WL_SEAT_CAPABILITY_KEYBOARD: wl_seat_capability = 2;
+pub const wl_seat_capability_WL_SEAT_CAPABILITY_TOUCH: wl_seat_capability = 4;
+pub type wl_seat_capability = ::std::os::raw::c_uint;
+pub const wl_seat_error_WL_SEAT_ERROR_MISSING_CAPABILITY: wl_seat_error = 0;
+pub type wl_seat_error = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_seat_listener {
+    pub capabilities: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_seat: *mut wl_seat,
+            capabilities: u32,
+        ),
+    >,
+    pub name: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_seat: *mut wl_seat,
+            name: *const ::std::os::raw::c_char,
+        ),
+    >,
+}
+pub const WL_SEAT_GET_POINTER: u32 = 0;
+pub const WL_SEAT_GET_KEYBOARD: u32 = 1;
+pub const WL_SEAT_GET_TOUCH: u32 = 2;
+pub const WL_SEAT_RELEASE: u32 = 3;
+pub const WL_SEAT_CAPABILITIES_SINCE_VERSION: u32 = 1;
+pub const WL_SEAT_NAME_SINCE_VERSION: u32 = 2;
+pub const WL_SEAT_GET_POINTER_SINCE_VERSION: u32 = 1;
+pub const WL_SEAT_GET_KEYBOARD_SINCE_VERSION: u32 = 1;
+pub const WL_SEAT_GET_TOUCH_SINCE_VERSION: u32 = 1;
+pub const WL_SEAT_RELEASE_SINCE_VERSION: u32 = 5;
+pub const wl_pointer_error_WL_POINTER_ERROR_ROLE: wl_pointer_error = 0;
+pub type wl_pointer_error = ::std::os::raw::c_uint;
+pub const wl_pointer_button_state_WL_POINTER_BUTTON_STATE_RELEASED: wl_pointer_button_state = 0;
+pub const wl_pointer_button_state_WL_POINTER_BUTTON_STATE_PRESSED: wl_pointer_button_state = 1;
+pub type wl_pointer_button_state = ::std::os::raw::c_uint;
+pub const wl_pointer_axis_WL_POINTER_AXIS_VERTICAL_SCROLL: wl_pointer_axis = 0;
+pub const wl_pointer_axis_WL_POINTER_AXIS_HORIZONTAL_SCROLL: wl_pointer_axis = 1;
+pub type wl_pointer_axis = ::std::os::raw::c_uint;
+pub const wl_pointer_axis_source_WL_POINTER_AXIS_SOURCE_WHEEL: wl_pointer_axis_source = 0;
+pub const wl_pointer_axis_source_WL_POINTER_AXIS_SOURCE_FINGER: wl_pointer_axis_source = 1;
+pub const wl_pointer_axis_source_WL_POINTER_AXIS_SOURCE_CONTINUOUS: wl_pointer_axis_source = 2;
+pub const wl_pointer_axis_source_WL_POINTER_AXIS_SOURCE_WHEEL_TILT: wl_pointer_axis_source = 3;
+pub type wl_pointer_axis_source = ::std::os::raw::c_uint;
+pub const WL_POINTER_AXIS_SOURCE_WHEEL_TILT_SINCE_VERSION: u32 = 6;
+pub const wl_pointer_axis_relative_direction_WL_POINTER_AXIS_RELATIVE_DIRECTION_IDENTICAL:
+    wl_pointer_axis_relative_direction = 0;
+pub const wl_pointer_axis_relative_direction_WL_POINTER_AXIS_RELATIVE_DIRECTION_INVERTED:
+    wl_pointer_axis_relative_direction = 1;
+pub type wl_pointer_axis_relative_direction = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_pointer_listener {
+    pub enter: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_pointer: *mut wl_pointer,
+            serial: u32,
+            surface: *mut wl_surface,
+            surface_x: wl_fixed_t,
+            surface_y: wl_fixed_t,
+        ),
+    >,
+    pub leave: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_pointer: *mut wl_pointer,
+            serial: u32,
+            surface: *mut wl_surface,
+        ),
+    >,
+    pub motion: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_pointer: *mut wl_pointer,
+            time: u32,
+            surface_x: wl_fixed_t,
+            surface_y: wl_fixed_t,
+        ),
+    >,
+    pub button: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_pointer: *mut wl_pointer,
+            serial: u32,
+            time: u32,
+            button: u32,
+            state: u32,
+        ),
+    >,
+    pub axis: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_pointer: *mut wl_pointer,
+            time: u32,
+            axis: u32,
+            value: wl_fixed_t,
+        ),
+    >,
+    pub frame: ::std::option::Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, wl_pointer: *mut wl_pointer),
+    >,
+    pub axis_source: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_pointer: *mut wl_pointer,
+            axis_source: u32,
+        ),
+    >,
+    pub axis_stop: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_pointer: *mut wl_pointer,
+            time: u32,
+            axis: u32,
+        ),
+    >,
+    pub axis_discrete: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_pointer: *mut wl_pointer,
+            axis: u32,
+            discrete: i32,
+        ),
+    >,
+    pub axis_value120: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_pointer: *mut wl_pointer,
+            axis: u32,
+            value120: i32,
+        ),
+    >,
+    pub axis_relative_direction: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_pointer: *mut wl_pointer,
+            axis: u32,
+            direction: u32,
+        ),
+    >,
+    pub warp: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_pointer: *mut wl_pointer,
+            surface_x: wl_fixed_t,
+            surface_y: wl_fixed_t,
+        ),
+    >,
+}
+pub const WL_POINTER_SET_CURSOR: u32 = 0;
+pub const WL_POINTER_RELEASE: u32 = 1;
+pub const WL_POINTER_ENTER_SINCE_VERSION: u32 = 1;
+pub const WL_POINTER_LEAVE_SINCE_VERSION: u32 = 1;
+pub const WL_POINTER_MOTION_SINCE_VERSION: u32 = 1;
+pub const WL_POINTER_BUTTON_SINCE_VERSION: u32 = 1;
+pub const WL_POINTER_AXIS_SINCE_VERSION: u32 = 1;
+pub const WL_POINTER_FRAME_SINCE_VERSION: u32 = 5;
+pub const WL_POINTER_AXIS_SOURCE_SINCE_VERSION: u32 = 5;
+pub const WL_POINTER_AXIS_STOP_SINCE_VERSION: u32 = 5;
+pub const WL_POINTER_AXIS_DISCRETE_SINCE_VERSION: u32 = 5;
+pub const WL_POINTER_AXIS_VALUE120_SINCE_VERSION: u32 = 8;
+pub const WL_POINTER_AXIS_RELATIVE_DIRECTION_SINCE_VERSION: u32 = 9;
+pub const WL_POINTER_WARP_SINCE_VERSION: u32 = 11;
+pub const WL_POINTER_SET_CURSOR_SINCE_VERSION: u32 = 1;
+pub const WL_POINTER_RELEASE_SINCE_VERSION: u32 = 3;
+pub const wl_keyboard_keymap_format_WL_KEYBOARD_KEYMAP_FORMAT_NO_KEYMAP: wl_keyboard_keymap_format =
+    0;
+pub const wl_keyboard_keymap_format_WL_KEYBOARD_KEYMAP_FORMAT_XKB_V1: wl_keyboard_keymap_format = 1;
+pub type wl_keyboard_keymap_format = ::std::os::raw::c_uint;
+pub const wl_keyboard_key_state_WL_KEYBOARD_KEY_STATE_RELEASED: wl_keyboard_key_state = 0;
+pub const wl_keyboard_key_state_WL_KEYBOARD_KEY_STATE_PRESSED: wl_keyboard_key_state = 1;
+pub const wl_keyboard_key_state_WL_KEYBOARD_KEY_STATE_REPEATED: wl_keyboard_key_state = 2;
+pub type wl_keyboard_key_state = ::std::os::raw::c_uint;
+pub const WL_KEYBOARD_KEY_STATE_REPEATED_SINCE_VERSION: u32 = 10;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_keyboard_listener {
+    pub keymap: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_keyboard: *mut wl_keyboard,
+            format: u32,
+            fd: i32,
+            size: u32,
+        ),
+    >,
+    pub enter: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_keyboard: *mut wl_keyboard,
+            serial: u32,
+            surface: *mut wl_surface,
+            keys: *mut wl_array,
+        ),
+    >,
+    pub leave: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_keyboard: *mut wl_keyboard,
+            serial: u32,
+            surface: *mut wl_surface,
+        ),
+    >,
+    pub key: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_keyboard: *mut wl_keyboard,
+            serial: u32,
+            time: u32,
+            key: u32,
+            state: u32,
+        ),
+    >,
+    pub modifiers: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_keyboard: *mut wl_keyboard,
+            serial: u32,
+            mods_depressed: u32,
+            mods_latched: u32,
+            mods_locked: u32,
+            group: u32,
+        ),
+    >,
+    pub repeat_info: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_keyboard: *mut wl_keyboard,
+            rate: i32,
+            delay: i32,
+        ),
+    >,
+}
+pub const WL_KEYBOARD_RELEASE: u32 = 0;
+pub const WL_KEYBOARD_KEYMAP_SINCE_VERSION: u32 = 1;
+pub const WL_KEYBOARD_ENTER_SINCE_VERSION: u32 = 1;
+pub const WL_KEYBOARD_LEAVE_SINCE_VERSION: u32 = 1;
+pub const WL_KEYBOARD_KEY_SINCE_VERSION: u32 = 1;
+pub const WL_KEYBOARD_MODIFIERS_SINCE_VERSION: u32 = 1;
+pub const WL_KEYBOARD_REPEAT_INFO_SINCE_VERSION: u32 = 4;
+pub const WL_KEYBOARD_RELEASE_SINCE_VERSION: u32 = 3;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_touch_listener {
+    pub down: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_touch: *mut wl_touch,
+            serial: u32,
+            time: u32,
+            surface: *mut wl_surface,
+            id: i32,
+            x: wl_fixed_t,
+            y: wl_fixed_t,
+        ),
+    >,
+    pub up: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_touch: *mut wl_touch,
+            serial: u32,
+            time: u32,
+            id: i32,
+        ),
+    >,
+    pub motion: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_touch: *mut wl_touch,
+            time: u32,
+            id: i32,
+            x: wl_fixed_t,
+            y: wl_fixed_t,
+        ),
+    >,
+    pub frame: ::std::option::Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, wl_touch: *mut wl_touch),
+    >,
+    pub cancel: ::std::option::Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, wl_touch: *mut wl_touch),
+    >,
+    pub shape: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_touch: *mut wl_touch,
+            id: i32,
+            major: wl_fixed_t,
+            minor: wl_fixed_t,
+        ),
+    >,
+    pub orientation: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_touch: *mut wl_touch,
+            id: i32,
+            orientation: wl_fixed_t,
+        ),
+    >,
+}
+pub const WL_TOUCH_RELEASE: u32 = 0;
+pub const WL_TOUCH_DOWN_SINCE_VERSION: u32 = 1;
+pub const WL_TOUCH_UP_SINCE_VERSION: u32 = 1;
+pub const WL_TOUCH_MOTION_SINCE_VERSION: u32 = 1;
+pub const WL_TOUCH_FRAME_SINCE_VERSION: u32 = 1;
+pub const WL_TOUCH_CANCEL_SINCE_VERSION: u32 = 1;
+pub const WL_TOUCH_SHAPE_SINCE_VERSION: u32 = 6;
+pub const WL_TOUCH_ORIENTATION_SINCE_VERSION: u32 = 6;
+pub const WL_TOUCH_RELEASE_SINCE_VERSION: u32 = 3;
+pub const wl_output_subpixel_WL_OUTPUT_SUBPIXEL_UNKNOWN: wl_output_subpixel = 0;
+pub const wl_output_subpixel_WL_OUTPUT_SUBPIXEL_NONE: wl_output_subpixel = 1;
+pub const wl_output_subpixel_WL_OUTPUT_SUBPIXEL_HORIZONTAL_RGB: wl_output_subpixel = 2;
+pub const wl_output_subpixel_WL_OUTPUT_SUBPIXEL_HORIZONTAL_BGR: wl_output_subpixel = 3;
+pub const wl_output_subpixel_WL_OUTPUT_SUBPIXEL_VERTICAL_RGB: wl_output_subpixel = 4;
+pub const wl_output_subpixel_WL_OUTPUT_SUBPIXEL_VERTICAL_BGR: wl_output_subpixel = 5;
+pub type wl_output_subpixel = ::std::os::raw::c_uint;
+pub const wl_output_transform_WL_OUTPUT_TRANSFORM_NORMAL: wl_output_transform = 0;
+pub const wl_output_transform_WL_OUTPUT_TRANSFORM_90: wl_output_transform = 1;
+pub const wl_output_transform_WL_OUTPUT_TRANSFORM_180: wl_output_transform = 2;
+pub const wl_output_transform_WL_OUTPUT_TRANSFORM_270: wl_output_transform = 3;
+pub const wl_output_transform_WL_OUTPUT_TRANSFORM_FLIPPED: wl_output_transform = 4;
+pub const wl_output_transform_WL_OUTPUT_TRANSFORM_FLIPPED_90: wl_output_transform = 5;
+pub const wl_output_transform_WL_OUTPUT_TRANSFORM_FLIPPED_180: wl_output_transform = 6;
+pub const wl_output_transform_WL_OUTPUT_TRANSFORM_FLIPPED_270: wl_output_transform = 7;
+pub type wl_output_transform = ::std::os::raw::c_uint;
+pub const wl_output_mode_WL_OUTPUT_MODE_CURRENT: wl_output_mode = 1;
+pub const wl_output_mode_WL_OUTPUT_MODE_PREFERRED: wl_output_mode = 2;
+pub type wl_output_mode = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wl_output_listener {
+    pub geometry: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_output: *mut wl_output,
+            x: i32,
+            y: i32,
+            physical_width: i32,
+            physical_height: i32,
+            subpixel: i32,
+            make: *const ::std::os::raw::c_char,
+            model: *const ::std::os::raw::c_char,
+            transform: i32,
+        ),
+    >,
+    pub mode: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_output: *mut wl_output,
+            flags: u32,
+            width: i32,
+            height: i32,
+            refresh: i32,
+        ),
+    >,
+    pub done: ::std::option::Option<
+        unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, wl_output: *mut wl_output),
+    >,
+    pub scale: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_output: *mut wl_output,
+            factor: i32,
+        ),
+    >,
+    pub name: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_output: *mut wl_output,
+            name: *const ::std::os::raw::c_char,
+        ),
+    >,
+    pub description: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            wl_output: *mut wl_output,
+            description: *const ::std::os::raw::c_char,
+        ),
+    >,
+}
+pub const WL_OUTPUT_RELEASE: u32 = 0;
+pub const WL_OUTPUT_GEOMETRY_SINCE_VERSION: u32 = 1;
+pub const WL_OUTPUT_MODE_SINCE_VERSION: u32 = 1;
+pub const WL_OUTPUT_DONE_SINCE_VERSION: u32 = 2;
+pub const WL_OUTPUT_SCALE_SINCE_VERSION: u32 = 2;
+pub const WL_OUTPUT_NAME_SINCE_VERSION: u32 = 4;
+pub const WL_OUTPUT_DESCRIPTION_SINCE_VERSION: u32 = 4;
+pub const WL_OUTPUT_RELEASE_SINCE_VERSION: u32 = 3;
+pub const WL_REGION_DESTROY: u32 = 0;
+pub const WL_REGION_ADD: u32 = 1;
+pub const WL_REGION_SUBTRACT: u32 = 2;
+pub const WL_REGION_DESTROY_SINCE_VERSION: u32 = 1;
+pub const WL_REGION_ADD_SINCE_VERSION: u32 = 1;
+pub const WL_REGION_SUBTRACT_SINCE_VERSION: u32 = 1;
+pub const wl_subcompositor_error_WL_SUBCOMPOSITOR_ERROR_BAD_SURFACE: wl_subcompositor_error = 0;
+pub const wl_subcompositor_error_WL_SUBCOMPOSITOR_ERROR_BAD_PARENT: wl_subcompositor_error = 1;
+pub type wl_subcompositor_error = ::std::os::raw::c_uint;
+pub const WL_SUBCOMPOSITOR_DESTROY: u32 = 0;
+pub const WL_SUBCOMPOSITOR_GET_SUBSURFACE: u32 = 1;
+pub const WL_SUBCOMPOSITOR_DESTROY_SINCE_VERSION: u32 = 1;
+pub const WL_SUBCOMPOSITOR_GET_SUBSURFACE_SINCE_VERSION: u32 = 1;
+pub const wl_subsurface_error_WL_SUBSURFACE_ERROR_BAD_SURFACE: wl_subsurface_error = 0;
+pub type wl_subsurface_error = ::std::os::raw::c_uint;
+pub const WL_SUBSURFACE_DESTROY: u32 = 0;
+pub const WL_SUBSURFACE_SET_POSITION: u32 = 1;
+pub const WL_SUBSURFACE_PLACE_ABOVE: u32 = 2;
+pub const WL_SUBSURFACE_PLACE_BELOW: u32 = 3;
+pub const WL_SUBSURFACE_SET_SYNC: u32 = 4;
+pub const WL_SUBSURFACE_SET_DESYNC: u32 = 5;
+pub const WL_SUBSURFACE_DESTROY_SINCE_VERSION: u32 = 1;
+pub const WL_SUBSURFACE_SET_POSITION_SINCE_VERSION: u32 = 1;
+pub const WL_SUBSURFACE_PLACE_ABOVE_SINCE_VERSION: u32 = 1;
+pub const WL_SUBSURFACE_PLACE_BELOW_SINCE_VERSION: u32 = 1;
+pub const WL_SUBSURFACE_SET_SYNC_SINCE_VERSION: u32 = 1;
+pub const WL_SUBSURFACE_SET_DESYNC_SINCE_VERSION: u32 = 1;
+pub const wl_fixes_error_WL_FIXES_ERROR_INVALID_ACK_REMOVE: wl_fixes_error = 0;
+pub type wl_fixes_error = ::std::os::raw::c_uint;
+pub const WL_FIXES_DESTROY: u32 = 0;
+pub const WL_FIXES_DESTROY_REGISTRY: u32 = 1;
+pub const WL_FIXES_ACK_GLOBAL_REMOVE: u32 = 2;
+pub const WL_FIXES_DESTROY_SINCE_VERSION: u32 = 1;
+pub const WL_FIXES_DESTROY_REGISTRY_SINCE_VERSION: u32 = 1;
+pub const WL_FIXES_ACK_GLOBAL_REMOVE_SINCE_VERSION: u32 = 2;
+pub const VULKAN_WAYLAND_H_: u32 = 1;
+pub const VK_KHR_wayland_surface: u32 = 1;
+pub const VK_KHR_WAYLAND_SURFACE_SPEC_VERSION: u32 = 6;
+pub const VK_KHR_WAYLAND_SURFACE_EXTENSION_NAME: &[u8; 23] = b"VK_KHR_wayland_surface\0";
+pub type VkWaylandSurfaceCreateFlagsKHR = VkFlags;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkWaylandSurfaceCreateInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: VkWaylandSurfaceCreateFlagsKHR,
+    pub display: *mut wl_display,
+    pub surface: *mut wl_surface,
+}
+pub type PFN_vkCreateWaylandSurfaceKHR = ::std::option::Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pCreateInfo: *const VkWaylandSurfaceCreateInfoKHR,
+        pAllocator: *const VkAllocationCallbacks,
+        pSurface: *mut VkSurfaceKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceWaylandPresentationSupportKHR = ::std::option::Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        queueFamilyIndex: u32,
+        display: *mut wl_display,
+    ) -> VkBool32,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __va_list_tag {
+    pub gp_offset: ::std::os::raw::c_uint,
+    pub fp_offset: ::std::os::raw::c_uint,
+    pub overflow_arg_area: *mut ::std::os::raw::c_void,
+    pub reg_save_area: *mut ::std::os::raw::c_void,
+}
+pub type __builtin_va_list = [__va_list_tag; 1usize];
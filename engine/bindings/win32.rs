@@ -0,0 +1,1221 @@
+// Generated by headersgen from the Vulkan-Headers, regenerate instead of editing
+pub type wchar_t = ::std::os::raw::c_ushort;
+pub type HANDLE = *mut ::std::os::raw::c_void;
+pub type LONG = ::std::os::raw::c_long;
+pub type WCHAR = wchar_t;
+pub type LPCWSTR = *const WCHAR;
+pub type LONG_PTR = ::std::os::raw::c_longlong;
+pub type UINT_PTR = ::std::os::raw::c_ulonglong;
+pub type DWORD = ::std::os::raw::c_ulong;
+pub type BOOL = ::std::os::raw::c_int;
+pub type WORD = ::std::os::raw::c_ushort;
+pub type UINT = ::std::os::raw::c_uint;
+pub type LPVOID = *mut ::std::os::raw::c_void;
+pub type WPARAM = UINT_PTR;
+pub type LPARAM = LONG_PTR;
+pub type LRESULT = LONG_PTR;
+pub type ATOM = WORD;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct HINSTANCE__ {
+    pub unused: ::std::os::raw::c_int,
+}
+pub type HINSTANCE = *mut HINSTANCE__;
+pub type HMODULE = HINSTANCE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct HWND__ {
+    pub unused: ::std::os::raw::c_int,
+}
+pub type HWND = *mut HWND__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct HICON__ {
+    pub unused: ::std::os::raw::c_int,
+}
+pub type HICON = *mut HICON__;
+pub type HCURSOR = HICON;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct HMENU__ {
+    pub unused: ::std::os::raw::c_int,
+}
+pub type HMENU = *mut HMENU__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct HBRUSH__ {
+    pub unused: ::std::os::raw::c_int,
+}
+pub type HBRUSH = *mut HBRUSH__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct HDC__ {
+    pub unused: ::std::os::raw::c_int,
+}
+pub type HDC = *mut HDC__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct HMONITOR__ {
+    pub unused: ::std::os::raw::c_int,
+}
+pub type HMONITOR = *mut HMONITOR__;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tagRECT {
+    pub left: LONG,
+    pub top: LONG,
+    pub right: LONG,
+    pub bottom: LONG,
+}
+pub type RECT = tagRECT;
+pub type LPRECT = *mut tagRECT;
+pub type LPCRECT = *const RECT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tagPOINT {
+    pub x: LONG,
+    pub y: LONG,
+}
+pub type POINT = tagPOINT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _POINTL {
+    pub x: LONG,
+    pub y: LONG,
+}
+pub type POINTL = _POINTL;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SECURITY_ATTRIBUTES {
+    pub nLength: DWORD,
+    pub lpSecurityDescriptor: LPVOID,
+    pub bInheritHandle: BOOL,
+}
+pub type SECURITY_ATTRIBUTES = _SECURITY_ATTRIBUTES;
+extern "C" {
+    pub fn GetModuleHandleW(lpModuleName: LPCWSTR) -> HMODULE;
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _devicemodeW {
+    pub dmDeviceName: [WCHAR; 32usize],
+    pub dmSpecVersion: WORD,
+    pub dmDriverVersion: WORD,
+    pub dmSize: WORD,
+    pub dmDriverExtra: WORD,
+    pub dmFields: DWORD,
+    pub __bindgen_anon_1: _devicemodeW__bindgen_ty_1,
+    pub dmColor: ::std::os::raw::c_short,
+    pub dmDuplex: ::std::os::raw::c_short,
+    pub dmYResolution: ::std::os::raw::c_short,
+    pub dmTTOption: ::std::os::raw::c_short,
+    pub dmCollate: ::std::os::raw::c_short,
+    pub dmFormName: [WCHAR; 32usize],
+    pub dmLogPixels: WORD,
+    pub dmBitsPerPel: DWORD,
+    pub dmPelsWidth: DWORD,
+    pub dmPelsHeight: DWORD,
+    pub __bindgen_anon_2: _devicemodeW__bindgen_ty_2,
+    pub dmDisplayFrequency: DWORD,
+    pub dmICMMethod: DWORD,
+    pub dmICMIntent: DWORD,
+    pub dmMediaType: DWORD,
+    pub dmDitherType: DWORD,
+    pub dmReserved1: DWORD,
+    pub dmReserved2: DWORD,
+    pub dmPanningWidth: DWORD,
+    pub dmPanningHeight: DWORD,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _devicemodeW__bindgen_ty_1 {
+    pub __bindgen_anon_1: _devicemodeW__bindgen_ty_1__bindgen_ty_1,
+    pub __bindgen_anon_2: _devicemodeW__bindgen_ty_1__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _devicemodeW__bindgen_ty_1__bindgen_ty_1 {
+    pub dmOrientation: ::std::os::raw::c_short,
+    pub dmPaperSize: ::std::os::raw::c_short,
+    pub dmPaperLength: ::std::os::raw::c_short,
+    pub dmPaperWidth: ::std::os::raw::c_short,
+    pub dmScale: ::std::os::raw::c_short,
+    pub dmCopies: ::std::os::raw::c_short,
+    pub dmDefaultSource: ::std::os::raw::c_short,
+    pub dmPrintQuality: ::std::os::raw::c_short,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _devicemodeW__bindgen_ty_1__bindgen_ty_2 {
+    pub dmPosition: POINTL,
+    pub dmDisplayOrientation: DWORD,
+    pub dmDisplayFixedOutput: DWORD,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _devicemodeW__bindgen_ty_2 {
+    pub dmDisplayFlags: DWORD,
+    pub dmNup: DWORD,
+}
+pub type DEVMODEW = _devicemodeW;
+pub const CDS_FULLSCREEN: u32 = 4;
+pub const DISP_CHANGE_SUCCESSFUL: u32 = 0;
+pub const DM_PELSHEIGHT: u32 = 1048576;
+pub const DM_PELSWIDTH: u32 = 524288;
+pub type WNDPROC = ::std::option::Option<
+    unsafe extern "C" fn(arg1: HWND, arg2: UINT, arg3: WPARAM, arg4: LPARAM) -> LRESULT,
+>;
+pub type MONITORENUMPROC = ::std::option::Option<
+    unsafe extern "C" fn(arg1: HMONITOR, arg2: HDC, arg3: LPRECT, arg4: LPARAM) -> BOOL,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tagWNDCLASSW {
+    pub style: UINT,
+    pub lpfnWndProc: WNDPROC,
+    pub cbClsExtra: ::std::os::raw::c_int,
+    pub cbWndExtra: ::std::os::raw::c_int,
+    pub hInstance: HINSTANCE,
+    pub hIcon: HICON,
+    pub hCursor: HCURSOR,
+    pub hbrBackground: HBRUSH,
+    pub lpszMenuName: LPCWSTR,
+    pub lpszClassName: LPCWSTR,
+}
+pub type WNDCLASSW = tagWNDCLASSW;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tagMSG {
+    pub hwnd: HWND,
+    pub message: UINT,
+    pub wParam: WPARAM,
+    pub lParam: LPARAM,
+    pub time: DWORD,
+    pub pt: POINT,
+}
+pub type MSG = tagMSG;
+pub type LPMSG = *mut tagMSG;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tagMONITORINFO {
+    pub cbSize: DWORD,
+    pub rcMonitor: RECT,
+    pub rcWork: RECT,
+    pub dwFlags: DWORD,
+}
+pub type MONITORINFO = tagMONITORINFO;
+pub type LPMONITORINFO = *mut tagMONITORINFO;
+extern "C" {
+    pub fn RegisterClassW(lpWndClass: *const WNDCLASSW) -> ATOM;
+}
+extern "C" {
+    pub fn UnregisterClassW(lpClassName: LPCWSTR, hInstance: HINSTANCE) -> BOOL;
+}
+extern "C" {
+    pub fn CreateWindowExW(
+        dwExStyle: DWORD,
+        lpClassName: LPCWSTR,
+        lpWindowName: LPCWSTR,
+        dwStyle: DWORD,
+        X: ::std::os::raw::c_int,
+        Y: ::std::os::raw::c_int,
+        nWidth: ::std::os::raw::c_int,
+        nHeight: ::std::os::raw::c_int,
+        hWndParent: HWND,
+        hMenu: HMENU,
+        hInstance: HINSTANCE,
+        lpParam: LPVOID,
+    ) -> HWND;
+}
+extern "C" {
+    pub fn DestroyWindow(hWnd: HWND) -> BOOL;
+}
+extern "C" {
+    pub fn DefWindowProcW(hWnd: HWND, Msg: UINT, wParam: WPARAM, lParam: LPARAM) -> LRESULT;
+}
+extern "C" {
+    pub fn LoadIconW(hInstance: HINSTANCE, lpIconName: LPCWSTR) -> HICON;
+}
+extern "C" {
+    pub fn LoadCursorW(hInstance: HINSTANCE, lpCursorName: LPCWSTR) -> HCURSOR;
+}
+extern "C" {
+    pub fn AdjustWindowRect(lpRect: LPRECT, dwStyle: DWORD, bMenu: BOOL) -> BOOL;
+}
+extern "C" {
+    pub fn ShowWindow(hWnd: HWND, nCmdShow: ::std::os::raw::c_int) -> BOOL;
+}
+extern "C" {
+    pub fn PeekMessageW(
+        lpMsg: LPMSG,
+        hWnd: HWND,
+        wMsgFilterMin: UINT,
+        wMsgFilterMax: UINT,
+        wRemoveMsg: UINT,
+    ) -> BOOL;
+}
+extern "C" {
+    pub fn GetMessageW(lpMsg: LPMSG, hWnd: HWND, wMsgFilterMin: UINT, wMsgFilterMax: UINT) -> BOOL;
+}
+extern "C" {
+    pub fn TranslateMessage(lpMsg: *const MSG) -> BOOL;
+}
+extern "C" {
+    pub fn DispatchMessageW(lpMsg: *const MSG) -> LRESULT;
+}
+extern "C" {
+    pub fn SetWindowLongPtrW(
+        hWnd: HWND,
+        nIndex: ::std::os::raw::c_int,
+        dwNewLong: LONG_PTR,
+    ) -> LONG_PTR;
+}
+extern "C" {
+    pub fn GetWindowLongPtrW(hWnd: HWND, nIndex: ::std::os::raw::c_int) -> LONG_PTR;
+}
+extern "C" {
+    pub fn SetWindowTextW(hWnd: HWND, lpString: LPCWSTR) -> BOOL;
+}
+extern "C" {
+    pub fn SetWindowPos(
+        hWnd: HWND,
+        hWndInsertAfter: HWND,
+        X: ::std::os::raw::c_int,
+        Y: ::std::os::raw::c_int,
+        cx: ::std::os::raw::c_int,
+        cy: ::std::os::raw::c_int,
+        uFlags: UINT,
+    ) -> BOOL;
+}
+extern "C" {
+    pub fn GetWindowRect(hWnd: HWND, lpRect: LPRECT) -> BOOL;
+}
+extern "C" {
+    pub fn EnumDisplayMonitors(
+        hdc: HDC,
+        lprcClip: LPCRECT,
+        lpfnEnum: MONITORENUMPROC,
+        dwData: LPARAM,
+    ) -> BOOL;
+}
+extern "C" {
+    pub fn GetMonitorInfoW(hMonitor: HMONITOR, lpmi: LPMONITORINFO) -> BOOL;
+}
+extern "C" {
+    pub fn ChangeDisplaySettingsExW(
+        lpszDeviceName: LPCWSTR,
+        lpDevMode: *mut DEVMODEW,
+        hwnd: HWND,
+        dwflags: DWORD,
+        lParam: LPVOID,
+    ) -> LONG;
+}
+pub const GWLP_HINSTANCE: i32 = -6;
+pub const GWLP_HWNDPARENT: i32 = -8;
+pub const GWLP_ID: i32 = -12;
+pub const GWLP_USERDATA: i32 = -21;
+pub const GWLP_WNDPROC: i32 = -4;
+pub const GWL_STYLE: i32 = -16;
+pub const MONITORINFOF_PRIMARY: u32 = 1;
+pub const PM_NOREMOVE: u32 = 0;
+pub const PM_NOYIELD: u32 = 2;
+pub const PM_QS_INPUT: u32 = 67567616;
+pub const PM_QS_PAINT: u32 = 2097152;
+pub const PM_QS_POSTMESSAGE: u32 = 9961472;
+pub const PM_QS_SENDMESSAGE: u32 = 4194304;
+pub const PM_REMOVE: u32 = 1;
+pub const SIZE_MAXHIDE: u32 = 4;
+pub const SIZE_MAXIMIZED: u32 = 2;
+pub const SIZE_MAXSHOW: u32 = 3;
+pub const SIZE_MINIMIZED: u32 = 1;
+pub const SIZE_RESTORED: u32 = 0;
+pub const SWP_ASYNCWINDOWPOS: u32 = 16384;
+pub const SWP_DEFERERASE: u32 = 8192;
+pub const SWP_DRAWFRAME: u32 = 32;
+pub const SWP_FRAMECHANGED: u32 = 32;
+pub const SWP_HIDEWINDOW: u32 = 128;
+pub const SWP_NOACTIVATE: u32 = 16;
+pub const SWP_NOCOPYBITS: u32 = 256;
+pub const SWP_NOMOVE: u32 = 2;
+pub const SWP_NOOWNERZORDER: u32 = 512;
+pub const SWP_NOREDRAW: u32 = 8;
+pub const SWP_NOREPOSITION: u32 = 512;
+pub const SWP_NOSENDCHANGING: u32 = 1024;
+pub const SWP_NOSIZE: u32 = 1;
+pub const SWP_NOZORDER: u32 = 4;
+pub const SWP_SHOWWINDOW: u32 = 64;
+pub const SW_ERASE: u32 = 4;
+pub const SW_FORCEMINIMIZE: u32 = 11;
+pub const SW_HIDE: u32 = 0;
+pub const SW_INVALIDATE: u32 = 2;
+pub const SW_MAX: u32 = 11;
+pub const SW_MAXIMIZE: u32 = 3;
+pub const SW_MINIMIZE: u32 = 6;
+pub const SW_NORMAL: u32 = 1;
+pub const SW_OTHERUNZOOM: u32 = 4;
+pub const SW_OTHERZOOM: u32 = 2;
+pub const SW_PARENTCLOSING: u32 = 1;
+pub const SW_PARENTOPENING: u32 = 3;
+pub const SW_RESTORE: u32 = 9;
+pub const SW_SCROLLCHILDREN: u32 = 1;
+pub const SW_SHOW: u32 = 5;
+pub const SW_SHOWDEFAULT: u32 = 10;
+pub const SW_SHOWMAXIMIZED: u32 = 3;
+pub const SW_SHOWMINIMIZED: u32 = 2;
+pub const SW_SHOWMINNOACTIVE: u32 = 7;
+pub const SW_SHOWNA: u32 = 8;
+pub const SW_SHOWNOACTIVATE: u32 = 4;
+pub const SW_SHOWNORMAL: u32 = 1;
+pub const SW_SMOOTHSCROLL: u32 = 16;
+pub const WHEEL_DELTA: u32 = 120;
+pub const WM_ACTIVATE: u32 = 6;
+pub const WM_ACTIVATEAPP: u32 = 28;
+pub const WM_AFXFIRST: u32 = 864;
+pub const WM_AFXLAST: u32 = 895;
+pub const WM_APP: u32 = 32768;
+pub const WM_APPCOMMAND: u32 = 793;
+pub const WM_ASKCBFORMATNAME: u32 = 780;
+pub const WM_CANCELJOURNAL: u32 = 75;
+pub const WM_CANCELMODE: u32 = 31;
+pub const WM_CAPTURECHANGED: u32 = 533;
+pub const WM_CHANGECBCHAIN: u32 = 781;
+pub const WM_CHANGEUISTATE: u32 = 295;
+pub const WM_CHAR: u32 = 258;
+pub const WM_CHARTOITEM: u32 = 47;
+pub const WM_CHILDACTIVATE: u32 = 34;
+pub const WM_CLEAR: u32 = 771;
+pub const WM_CLIPBOARDUPDATE: u32 = 797;
+pub const WM_CLOSE: u32 = 16;
+pub const WM_COMMAND: u32 = 273;
+pub const WM_COMMNOTIFY: u32 = 68;
+pub const WM_COMPACTING: u32 = 65;
+pub const WM_COMPAREITEM: u32 = 57;
+pub const WM_CONTEXTMENU: u32 = 123;
+pub const WM_COPY: u32 = 769;
+pub const WM_COPYDATA: u32 = 74;
+pub const WM_CREATE: u32 = 1;
+pub const WM_CTLCOLORBTN: u32 = 309;
+pub const WM_CTLCOLORDLG: u32 = 310;
+pub const WM_CTLCOLOREDIT: u32 = 307;
+pub const WM_CTLCOLORLISTBOX: u32 = 308;
+pub const WM_CTLCOLORMSGBOX: u32 = 306;
+pub const WM_CTLCOLORSCROLLBAR: u32 = 311;
+pub const WM_CTLCOLORSTATIC: u32 = 312;
+pub const WM_CUT: u32 = 768;
+pub const WM_DEADCHAR: u32 = 259;
+pub const WM_DELETEITEM: u32 = 45;
+pub const WM_DESTROY: u32 = 2;
+pub const WM_DESTROYCLIPBOARD: u32 = 775;
+pub const WM_DEVICECHANGE: u32 = 537;
+pub const WM_DEVMODECHANGE: u32 = 27;
+pub const WM_DISPLAYCHANGE: u32 = 126;
+pub const WM_DPICHANGED: u32 = 736;
+pub const WM_DPICHANGED_AFTERPARENT: u32 = 739;
+pub const WM_DPICHANGED_BEFOREPARENT: u32 = 738;
+pub const WM_DRAWCLIPBOARD: u32 = 776;
+pub const WM_DRAWITEM: u32 = 43;
+pub const WM_DROPFILES: u32 = 563;
+pub const WM_DWMCOLORIZATIONCOLORCHANGED: u32 = 800;
+pub const WM_DWMCOMPOSITIONCHANGED: u32 = 798;
+pub const WM_DWMNCRENDERINGCHANGED: u32 = 799;
+pub const WM_DWMSENDICONICLIVEPREVIEWBITMAP: u32 = 806;
+pub const WM_DWMSENDICONICTHUMBNAIL: u32 = 803;
+pub const WM_DWMWINDOWMAXIMIZEDCHANGE: u32 = 801;
+pub const WM_ENABLE: u32 = 10;
+pub const WM_ENDSESSION: u32 = 22;
+pub const WM_ENTERIDLE: u32 = 289;
+pub const WM_ENTERMENULOOP: u32 = 529;
+pub const WM_ENTERSIZEMOVE: u32 = 561;
+pub const WM_ERASEBKGND: u32 = 20;
+pub const WM_EXITMENULOOP: u32 = 530;
+pub const WM_EXITSIZEMOVE: u32 = 562;
+pub const WM_FONTCHANGE: u32 = 29;
+pub const WM_GESTURE: u32 = 281;
+pub const WM_GESTURENOTIFY: u32 = 282;
+pub const WM_GETDLGCODE: u32 = 135;
+pub const WM_GETDPISCALEDSIZE: u32 = 740;
+pub const WM_GETFONT: u32 = 49;
+pub const WM_GETHOTKEY: u32 = 51;
+pub const WM_GETICON: u32 = 127;
+pub const WM_GETMINMAXINFO: u32 = 36;
+pub const WM_GETOBJECT: u32 = 61;
+pub const WM_GETTEXT: u32 = 13;
+pub const WM_GETTEXTLENGTH: u32 = 14;
+pub const WM_GETTITLEBARINFOEX: u32 = 831;
+pub const WM_HANDHELDFIRST: u32 = 856;
+pub const WM_HANDHELDLAST: u32 = 863;
+pub const WM_HELP: u32 = 83;
+pub const WM_HOTKEY: u32 = 786;
+pub const WM_HSCROLL: u32 = 276;
+pub const WM_HSCROLLCLIPBOARD: u32 = 782;
+pub const WM_ICONERASEBKGND: u32 = 39;
+pub const WM_IME_CHAR: u32 = 646;
+pub const WM_IME_COMPOSITION: u32 = 271;
+pub const WM_IME_COMPOSITIONFULL: u32 = 644;
+pub const WM_IME_CONTROL: u32 = 643;
+pub const WM_IME_ENDCOMPOSITION: u32 = 270;
+pub const WM_IME_KEYDOWN: u32 = 656;
+pub const WM_IME_KEYLAST: u32 = 271;
+pub const WM_IME_KEYUP: u32 = 657;
+pub const WM_IME_NOTIFY: u32 = 642;
+pub const WM_IME_REQUEST: u32 = 648;
+pub const WM_IME_SELECT: u32 = 645;
+pub const WM_IME_SETCONTEXT: u32 = 641;
+pub const WM_IME_STARTCOMPOSITION: u32 = 269;
+pub const WM_INITDIALOG: u32 = 272;
+pub const WM_INITMENU: u32 = 278;
+pub const WM_INITMENUPOPUP: u32 = 279;
+pub const WM_INPUT: u32 = 255;
+pub const WM_INPUTLANGCHANGE: u32 = 81;
+pub const WM_INPUTLANGCHANGEREQUEST: u32 = 80;
+pub const WM_INPUT_DEVICE_CHANGE: u32 = 254;
+pub const WM_KEYDOWN: u32 = 256;
+pub const WM_KEYFIRST: u32 = 256;
+pub const WM_KEYLAST: u32 = 265;
+pub const WM_KEYUP: u32 = 257;
+pub const WM_KILLFOCUS: u32 = 8;
+pub const WM_LBUTTONDBLCLK: u32 = 515;
+pub const WM_LBUTTONDOWN: u32 = 513;
+pub const WM_LBUTTONUP: u32 = 514;
+pub const WM_MBUTTONDBLCLK: u32 = 521;
+pub const WM_MBUTTONDOWN: u32 = 519;
+pub const WM_MBUTTONUP: u32 = 520;
+pub const WM_MDIACTIVATE: u32 = 546;
+pub const WM_MDICASCADE: u32 = 551;
+pub const WM_MDICREATE: u32 = 544;
+pub const WM_MDIDESTROY: u32 = 545;
+pub const WM_MDIGETACTIVE: u32 = 553;
+pub const WM_MDIICONARRANGE: u32 = 552;
+pub const WM_MDIMAXIMIZE: u32 = 549;
+pub const WM_MDINEXT: u32 = 548;
+pub const WM_MDIREFRESHMENU: u32 = 564;
+pub const WM_MDIRESTORE: u32 = 547;
+pub const WM_MDISETMENU: u32 = 560;
+pub const WM_MDITILE: u32 = 550;
+pub const WM_MEASUREITEM: u32 = 44;
+pub const WM_MENUCHAR: u32 = 288;
+pub const WM_MENUCOMMAND: u32 = 294;
+pub const WM_MENUDRAG: u32 = 291;
+pub const WM_MENUGETOBJECT: u32 = 292;
+pub const WM_MENURBUTTONUP: u32 = 290;
+pub const WM_MENUSELECT: u32 = 287;
+pub const WM_MOUSEACTIVATE: u32 = 33;
+pub const WM_MOUSEFIRST: u32 = 512;
+pub const WM_MOUSEHWHEEL: u32 = 526;
+pub const WM_MOUSELAST: u32 = 526;
+pub const WM_MOUSEMOVE: u32 = 512;
+pub const WM_MOUSEWHEEL: u32 = 522;
+pub const WM_MOVE: u32 = 3;
+pub const WM_MOVING: u32 = 534;
+pub const WM_NCACTIVATE: u32 = 134;
+pub const WM_NCCALCSIZE: u32 = 131;
+pub const WM_NCCREATE: u32 = 129;
+pub const WM_NCDESTROY: u32 = 130;
+pub const WM_NCHITTEST: u32 = 132;
+pub const WM_NCLBUTTONDBLCLK: u32 = 163;
+pub const WM_NCLBUTTONDOWN: u32 = 161;
+pub const WM_NCLBUTTONUP: u32 = 162;
+pub const WM_NCMBUTTONDBLCLK: u32 = 169;
+pub const WM_NCMBUTTONDOWN: u32 = 167;
+pub const WM_NCMBUTTONUP: u32 = 168;
+pub const WM_NCMOUSEHOVER: u32 = 672;
+pub const WM_NCMOUSELEAVE: u32 = 674;
+pub const WM_NCMOUSEMOVE: u32 = 160;
+pub const WM_NCPAINT: u32 = 133;
+pub const WM_NCPOINTERDOWN: u32 = 578;
+pub const WM_NCPOINTERUP: u32 = 579;
+pub const WM_NCPOINTERUPDATE: u32 = 577;
+pub const WM_NCRBUTTONDBLCLK: u32 = 166;
+pub const WM_NCRBUTTONDOWN: u32 = 164;
+pub const WM_NCRBUTTONUP: u32 = 165;
+pub const WM_NCXBUTTONDBLCLK: u32 = 173;
+pub const WM_NCXBUTTONDOWN: u32 = 171;
+pub const WM_NCXBUTTONUP: u32 = 172;
+pub const WM_NEXTDLGCTL: u32 = 40;
+pub const WM_NEXTMENU: u32 = 531;
+pub const WM_NOTIFY: u32 = 78;
+pub const WM_NOTIFYFORMAT: u32 = 85;
+pub const WM_NULL: u32 = 0;
+pub const WM_PAINT: u32 = 15;
+pub const WM_PAINTCLIPBOARD: u32 = 777;
+pub const WM_PAINTICON: u32 = 38;
+pub const WM_PALETTECHANGED: u32 = 785;
+pub const WM_PALETTEISCHANGING: u32 = 784;
+pub const WM_PARENTNOTIFY: u32 = 528;
+pub const WM_PASTE: u32 = 770;
+pub const WM_PENWINFIRST: u32 = 896;
+pub const WM_PENWINLAST: u32 = 911;
+pub const WM_POINTERACTIVATE: u32 = 587;
+pub const WM_POINTERCAPTURECHANGED: u32 = 588;
+pub const WM_POINTERDEVICECHANGE: u32 = 568;
+pub const WM_POINTERDEVICEINRANGE: u32 = 569;
+pub const WM_POINTERDEVICEOUTOFRANGE: u32 = 570;
+pub const WM_POINTERDOWN: u32 = 582;
+pub const WM_POINTERENTER: u32 = 585;
+pub const WM_POINTERHWHEEL: u32 = 591;
+pub const WM_POINTERLEAVE: u32 = 586;
+pub const WM_POINTERROUTEDAWAY: u32 = 594;
+pub const WM_POINTERROUTEDRELEASED: u32 = 595;
+pub const WM_POINTERROUTEDTO: u32 = 593;
+pub const WM_POINTERUP: u32 = 583;
+pub const WM_POINTERUPDATE: u32 = 581;
+pub const WM_POINTERWHEEL: u32 = 590;
+pub const WM_POWER: u32 = 72;
+pub const WM_POWERBROADCAST: u32 = 536;
+pub const WM_PRINT: u32 = 791;
+pub const WM_PRINTCLIENT: u32 = 792;
+pub const WM_QUERYDRAGICON: u32 = 55;
+pub const WM_QUERYENDSESSION: u32 = 17;
+pub const WM_QUERYNEWPALETTE: u32 = 783;
+pub const WM_QUERYOPEN: u32 = 19;
+pub const WM_QUERYUISTATE: u32 = 297;
+pub const WM_QUEUESYNC: u32 = 35;
+pub const WM_QUIT: u32 = 18;
+pub const WM_RBUTTONDBLCLK: u32 = 518;
+pub const WM_RBUTTONDOWN: u32 = 516;
+pub const WM_RBUTTONUP: u32 = 517;
+pub const WM_RENDERALLFORMATS: u32 = 774;
+pub const WM_RENDERFORMAT: u32 = 773;
+pub const WM_SETCURSOR: u32 = 32;
+pub const WM_SETFOCUS: u32 = 7;
+pub const WM_SETFONT: u32 = 48;
+pub const WM_SETHOTKEY: u32 = 50;
+pub const WM_SETICON: u32 = 128;
+pub const WM_SETREDRAW: u32 = 11;
+pub const WM_SETTEXT: u32 = 12;
+pub const WM_SETTINGCHANGE: u32 = 26;
+pub const WM_SHOWWINDOW: u32 = 24;
+pub const WM_SIZE: u32 = 5;
+pub const WM_SIZECLIPBOARD: u32 = 779;
+pub const WM_SIZING: u32 = 532;
+pub const WM_SPOOLERSTATUS: u32 = 42;
+pub const WM_STYLECHANGED: u32 = 125;
+pub const WM_STYLECHANGING: u32 = 124;
+pub const WM_SYNCPAINT: u32 = 136;
+pub const WM_SYSCHAR: u32 = 262;
+pub const WM_SYSCOLORCHANGE: u32 = 21;
+pub const WM_SYSCOMMAND: u32 = 274;
+pub const WM_SYSDEADCHAR: u32 = 263;
+pub const WM_SYSKEYDOWN: u32 = 260;
+pub const WM_SYSKEYUP: u32 = 261;
+pub const WM_TABLET_FIRST: u32 = 704;
+pub const WM_TABLET_LAST: u32 = 735;
+pub const WM_TCARD: u32 = 82;
+pub const WM_THEMECHANGED: u32 = 794;
+pub const WM_TIMECHANGE: u32 = 30;
+pub const WM_TIMER: u32 = 275;
+pub const WM_TOOLTIPDISMISS: u32 = 837;
+pub const WM_TOUCH: u32 = 576;
+pub const WM_TOUCHHITTESTING: u32 = 589;
+pub const WM_UNDO: u32 = 772;
+pub const WM_UNICHAR: u32 = 265;
+pub const WM_UNINITMENUPOPUP: u32 = 293;
+pub const WM_UPDATEUISTATE: u32 = 296;
+pub const WM_USER: u32 = 1024;
+pub const WM_USERCHANGED: u32 = 84;
+pub const WM_VKEYTOITEM: u32 = 46;
+pub const WM_VSCROLL: u32 = 277;
+pub const WM_VSCROLLCLIPBOARD: u32 = 778;
+pub const WM_WINDOWPOSCHANGED: u32 = 71;
+pub const WM_WINDOWPOSCHANGING: u32 = 70;
+pub const WM_WININICHANGE: u32 = 26;
+pub const WM_WTSSESSION_CHANGE: u32 = 689;
+pub const WM_XBUTTONDBLCLK: u32 = 525;
+pub const WM_XBUTTONDOWN: u32 = 523;
+pub const WM_XBUTTONUP: u32 = 524;
+pub const WS_ACTIVECAPTION: u32 = 1;
+pub const WS_BORDER: u32 = 8388608;
+pub const WS_CAPTION: u32 = 12582912;
+pub const WS_CHILD: u32 = 1073741824;
+pub const WS_CHILDWINDOW: u32 = 1073741824;
+pub const WS_CLIPCHILDREN: u32 = 33554432;
+pub const WS_CLIPSIBLINGS: u32 = 67108864;
+pub const WS_DISABLED: u32 = 134217728;
+pub const WS_DLGFRAME: u32 = 4194304;
+pub const WS_EX_ACCEPTFILES: u32 = 16;
+pub const WS_EX_APPWINDOW: u32 = 262144;
+pub const WS_EX_CLIENTEDGE: u32 = 512;
+pub const WS_EX_COMPOSITED: u32 = 33554432;
+pub const WS_EX_CONTEXTHELP: u32 = 1024;
+pub const WS_EX_CONTROLPARENT: u32 = 65536;
+pub const WS_EX_DLGMODALFRAME: u32 = 1;
+pub const WS_EX_LAYERED: u32 = 524288;
+pub const WS_EX_LAYOUTRTL: u32 = 4194304;
+pub const WS_EX_LEFT: u32 = 0;
+pub const WS_EX_LEFTSCROLLBAR: u32 = 16384;
+pub const WS_EX_LTRREADING: u32 = 0;
+pub const WS_EX_MDICHILD: u32 = 64;
+pub const WS_EX_NOACTIVATE: u32 = 134217728;
+pub const WS_EX_NOINHERITLAYOUT: u32 = 1048576;
+pub const WS_EX_NOPARENTNOTIFY: u32 = 4;
+pub const WS_EX_NOREDIRECTIONBITMAP: u32 = 2097152;
+pub const WS_EX_OVERLAPPEDWINDOW: u32 = 768;
+pub const WS_EX_PALETTEWINDOW: u32 = 392;
+pub const WS_EX_RIGHT: u32 = 4096;
+pub const WS_EX_RIGHTSCROLLBAR: u32 = 0;
+pub const WS_EX_RTLREADING: u32 = 8192;
+pub const WS_EX_STATICEDGE: u32 = 131072;
+pub const WS_EX_TOOLWINDOW: u32 = 128;
+pub const WS_EX_TOPMOST: u32 = 8;
+pub const WS_EX_TRANSPARENT: u32 = 32;
+pub const WS_EX_WINDOWEDGE: u32 = 256;
+pub const WS_GROUP: u32 = 131072;
+pub const WS_HSCROLL: u32 = 1048576;
+pub const WS_ICONIC: u32 = 536870912;
+pub const WS_MAXIMIZE: u32 = 16777216;
+pub const WS_MAXIMIZEBOX: u32 = 65536;
+pub const WS_MINIMIZE: u32 = 536870912;
+pub const WS_MINIMIZEBOX: u32 = 131072;
+pub const WS_OVERLAPPED: u32 = 0;
+pub const WS_OVERLAPPEDWINDOW: u32 = 13565952;
+pub const WS_POPUP: u32 = 2147483648;
+pub const WS_POPUPWINDOW: u32 = 2156396544;
+pub const WS_SIZEBOX: u32 = 262144;
+pub const WS_SYSMENU: u32 = 524288;
+pub const WS_TABSTOP: u32 = 65536;
+pub const WS_THICKFRAME: u32 = 262144;
+pub const WS_TILED: u32 = 0;
+pub const WS_TILEDWINDOW: u32 = 13565952;
+pub const WS_VISIBLE: u32 = 268435456;
+pub const WS_VSCROLL: u32 = 2097152;
+pub const XBUTTON1: u32 = 1;
+pub const XBUTTON2: u32 = 2;
+pub const VK_0: u32 = 48;
+pub const VK_1: u32 = 49;
+pub const VK_2: u32 = 50;
+pub const VK_3: u32 = 51;
+pub const VK_4: u32 = 52;
+pub const VK_5: u32 = 53;
+pub const VK_6: u32 = 54;
+pub const VK_7: u32 = 55;
+pub const VK_8: u32 = 56;
+pub const VK_9: u32 = 57;
+pub const VK_A: u32 = 65;
+pub const VK_ABNT_C1: u32 = 193;
+pub const VK_ABNT_C2: u32 = 194;
+pub const VK_ACCEPT: u32 = 30;
+pub const VK_ADD: u32 = 107;
+pub const VK_APPS: u32 = 93;
+pub const VK_ATTN: u32 = 246;
+pub const VK_B: u32 = 66;
+pub const VK_BACK: u32 = 8;
+pub const VK_BROWSER_BACK: u32 = 166;
+pub const VK_BROWSER_FAVORITES: u32 = 171;
+pub const VK_BROWSER_FORWARD: u32 = 167;
+pub const VK_BROWSER_HOME: u32 = 172;
+pub const VK_BROWSER_REFRESH: u32 = 168;
+pub const VK_BROWSER_SEARCH: u32 = 170;
+pub const VK_BROWSER_STOP: u32 = 169;
+pub const VK_C: u32 = 67;
+pub const VK_CANCEL: u32 = 3;
+pub const VK_CAPITAL: u32 = 20;
+pub const VK_CLEAR: u32 = 12;
+pub const VK_CONTROL: u32 = 17;
+pub const VK_CONVERT: u32 = 28;
+pub const VK_CRSEL: u32 = 247;
+pub const VK_D: u32 = 68;
+pub const VK_DBE_ALPHANUMERIC: u32 = 240;
+pub const VK_DBE_CODEINPUT: u32 = 250;
+pub const VK_DBE_DBCSCHAR: u32 = 244;
+pub const VK_DBE_DETERMINESTRING: u32 = 252;
+pub const VK_DBE_ENTERDLGCONVERSIONMODE: u32 = 253;
+pub const VK_DBE_ENTERIMECONFIGMODE: u32 = 248;
+pub const VK_DBE_ENTERWORDREGISTERMODE: u32 = 247;
+pub const VK_DBE_FLUSHSTRING: u32 = 249;
+pub const VK_DBE_HIRAGANA: u32 = 242;
+pub const VK_DBE_KATAKANA: u32 = 241;
+pub const VK_DBE_NOCODEINPUT: u32 = 251;
+pub const VK_DBE_NOROMAN: u32 = 246;
+pub const VK_DBE_ROMAN: u32 = 245;
+pub const VK_DBE_SBCSCHAR: u32 = 243;
+pub const VK_DECIMAL: u32 = 110;
+pub const VK_DELETE: u32 = 46;
+pub const VK_DIVIDE: u32 = 111;
+pub const VK_DOWN: u32 = 40;
+pub const VK_E: u32 = 69;
+pub const VK_END: u32 = 35;
+pub const VK_EREOF: u32 = 249;
+pub const VK_ESCAPE: u32 = 27;
+pub const VK_EXECUTE: u32 = 43;
+pub const VK_EXSEL: u32 = 248;
+pub const VK_F: u32 = 70;
+pub const VK_F1: u32 = 112;
+pub const VK_F10: u32 = 121;
+pub const VK_F11: u32 = 122;
+pub const VK_F12: u32 = 123;
+pub const VK_F13: u32 = 124;
+pub const VK_F14: u32 = 125;
+pub const VK_F15: u32 = 126;
+pub const VK_F16: u32 = 127;
+pub const VK_F17: u32 = 128;
+pub const VK_F18: u32 = 129;
+pub const VK_F19: u32 = 130;
+pub const VK_F2: u32 = 113;
+pub const VK_F20: u32 = 131;
+pub const VK_F21: u32 = 132;
+pub const VK_F22: u32 = 133;
+pub const VK_F23: u32 = 134;
+pub const VK_F24: u32 = 135;
+pub const VK_F3: u32 = 114;
+pub const VK_F4: u32 = 115;
+pub const VK_F5: u32 = 116;
+pub const VK_F6: u32 = 117;
+pub const VK_F7: u32 = 118;
+pub const VK_F8: u32 = 119;
+pub const VK_F9: u32 = 120;
+pub const VK_FINAL: u32 = 24;
+pub const VK_G: u32 = 71;
+pub const VK_GAMEPAD_A: u32 = 195;
+pub const VK_GAMEPAD_B: u32 = 196;
+pub const VK_GAMEPAD_DPAD_DOWN: u32 = 204;
+pub const VK_GAMEPAD_DPAD_LEFT: u32 = 205;
+pub const VK_GAMEPAD_DPAD_RIGHT: u32 = 206;
+pub const VK_GAMEPAD_DPAD_UP: u32 = 203;
+pub const VK_GAMEPAD_LEFT_SHOULDER: u32 = 200;
+pub const VK_GAMEPAD_LEFT_THUMBSTICK_BUTTON: u32 = 209;
+pub const VK_GAMEPAD_LEFT_THUMBSTICK_DOWN: u32 = 212;
+pub const VK_GAMEPAD_LEFT_THUMBSTICK_LEFT: u32 = 214;
+pub const VK_GAMEPAD_LEFT_THUMBSTICK_RIGHT: u32 = 213;
+pub const VK_GAMEPAD_LEFT_THUMBSTICK_UP: u32 = 211;
+pub const VK_GAMEPAD_LEFT_TRIGGER: u32 = 201;
+pub const VK_GAMEPAD_MENU: u32 = 207;
+pub const VK_GAMEPAD_RIGHT_SHOULDER: u32 = 199;
+pub const VK_GAMEPAD_RIGHT_THUMBSTICK_BUTTON: u32 = 210;
+pub const VK_GAMEPAD_RIGHT_THUMBSTICK_DOWN: u32 = 216;
+pub const VK_GAMEPAD_RIGHT_THUMBSTICK_LEFT: u32 = 218;
+pub const VK_GAMEPAD_RIGHT_THUMBSTICK_RIGHT: u32 = 217;
+pub const VK_GAMEPAD_RIGHT_THUMBSTICK_UP: u32 = 215;
+pub const VK_GAMEPAD_RIGHT_TRIGGER: u32 = 202;
+pub const VK_GAMEPAD_VIEW: u32 = 208;
+pub const VK_GAMEPAD_X: u32 = 197;
+pub const VK_GAMEPAD_Y: u32 = 198;
+pub const VK_H: u32 = 72;
+pub const VK_HANGEUL: u32 = 21;
+pub const VK_HANGUL: u32 = 21;
+pub const VK_HANJA: u32 = 25;
+pub const VK_HELP: u32 = 47;
+pub const VK_HOME: u32 = 36;
+pub const VK_I: u32 = 73;
+pub const VK_ICO_00: u32 = 228;
+pub const VK_ICO_CLEAR: u32 = 230;
+pub const VK_ICO_HELP: u32 = 227;
+pub const VK_IME_OFF: u32 = 26;
+pub const VK_IME_ON: u32 = 22;
+pub const VK_INSERT: u32 = 45;
+pub const VK_J: u32 = 74;
+pub const VK_JUNJA: u32 = 23;
+pub const VK_K: u32 = 75;
+pub const VK_KANA: u32 = 21;
+pub const VK_KANJI: u32 = 25;
+pub const VK_L: u32 = 76;
+pub const VK_LAUNCH_APP1: u32 = 182;
+pub const VK_LAUNCH_APP2: u32 = 183;
+pub const VK_LAUNCH_MAIL: u32 = 180;
+pub const VK_LAUNCH_MEDIA_SELECT: u32 = 181;
+pub const VK_LBUTTON: u32 = 1;
+pub const VK_LCONTROL: u32 = 162;
+pub const VK_LEFT: u32 = 37;
+pub const VK_LMENU: u32 = 164;
+pub const VK_LSHIFT: u32 = 160;
+pub const VK_LWIN: u32 = 91;
+pub const VK_M: u32 = 77;
+pub const VK_MBUTTON: u32 = 4;
+pub const VK_MEDIA_NEXT_TRACK: u32 = 176;
+pub const VK_MEDIA_PLAY_PAUSE: u32 = 179;
+pub const VK_MEDIA_PREV_TRACK: u32 = 177;
+pub const VK_MEDIA_STOP: u32 = 178;
+pub const VK_MENU: u32 = 18;
+pub const VK_MODECHANGE: u32 = 31;
+pub const VK_MULTIPLY: u32 = 106;
+pub const VK_N: u32 = 78;
+pub const VK_NAVIGATION_ACCEPT: u32 = 142;
+pub const VK_NAVIGATION_CANCEL: u32 = 143;
+pub const VK_NAVIGATION_DOWN: u32 = 139;
+pub const VK_NAVIGATION_LEFT: u32 = 140;
+pub const VK_NAVIGATION_MENU: u32 = 137;
+pub const VK_NAVIGATION_RIGHT: u32 = 141;
+pub const VK_NAVIGATION_UP: u32 = 138;
+pub const VK_NAVIGATION_VIEW: u32 = 136;
+pub const VK_NEXT: u32 = 34;
+pub const VK_NONAME: u32 = 252;
+pub const VK_NONCONVERT: u32 = 29;
+pub const VK_NUMLOCK: u32 = 144;
+pub const VK_NUMPAD0: u32 = 96;
+pub const VK_NUMPAD1: u32 = 97;
+pub const VK_NUMPAD2: u32 = 98;
+pub const VK_NUMPAD3: u32 = 99;
+pub const VK_NUMPAD4: u32 = 100;
+pub const VK_NUMPAD5: u32 = 101;
+pub const VK_NUMPAD6: u32 = 102;
+pub const VK_NUMPAD7: u32 = 103;
+pub const VK_NUMPAD8: u32 = 104;
+pub const VK_NUMPAD9: u32 = 105;
+pub const VK_O: u32 = 79;
+pub const VK_OEM_1: u32 = 186;
+pub const VK_OEM_102: u32 = 226;
+pub const VK_OEM_2: u32 = 191;
+pub const VK_OEM_3: u32 = 192;
+pub const VK_OEM_4: u32 = 219;
+pub const VK_OEM_5: u32 = 220;
+pub const VK_OEM_6: u32 = 221;
+pub const VK_OEM_7: u32 = 222;
+pub const VK_OEM_8: u32 = 223;
+pub const VK_OEM_ATTN: u32 = 240;
+pub const VK_OEM_AUTO: u32 = 243;
+pub const VK_OEM_AX: u32 = 225;
+pub const VK_OEM_BACKTAB: u32 = 245;
+pub const VK_OEM_CLEAR: u32 = 254;
+pub const VK_OEM_COMMA: u32 = 188;
+pub const VK_OEM_COPY: u32 = 242;
+pub const VK_OEM_CUSEL: u32 = 239;
+pub const VK_OEM_ENLW: u32 = 244;
+pub const VK_OEM_FINISH: u32 = 241;
+pub const VK_OEM_FJ_JISHO: u32 = 146;
+pub const VK_OEM_FJ_LOYA: u32 = 149;
+pub const VK_OEM_FJ_MASSHOU: u32 = 147;
+pub const VK_OEM_FJ_ROYA: u32 = 150;
+pub const VK_OEM_FJ_TOUROKU: u32 = 148;
+pub const VK_OEM_JUMP: u32 = 234;
+pub const VK_OEM_MINUS: u32 = 189;
+pub const VK_OEM_NEC_EQUAL: u32 = 146;
+pub const VK_OEM_PA1: u32 = 235;
+pub const VK_OEM_PA2: u32 = 236;
+pub const VK_OEM_PA3: u32 = 237;
+pub const VK_OEM_PERIOD: u32 = 190;
+pub const VK_OEM_PLUS: u32 = 187;
+pub const VK_OEM_RESET: u32 = 233;
+pub const VK_OEM_WSCTRL: u32 = 238;
+pub const VK_P: u32 = 80;
+pub const VK_PA1: u32 = 253;
+pub const VK_PACKET: u32 = 231;
+pub const VK_PAUSE: u32 = 19;
+pub const VK_PLAY: u32 = 250;
+pub const VK_PRINT: u32 = 42;
+pub const VK_PRIOR: u32 = 33;
+pub const VK_PROCESSKEY: u32 = 229;
+pub const VK_Q: u32 = 81;
+pub const VK_R: u32 = 82;
+pub const VK_RBUTTON: u32 = 2;
+pub const VK_RCONTROL: u32 = 163;
+pub const VK_RETURN: u32 = 13;
+pub const VK_RIGHT: u32 = 39;
+pub const VK_RMENU: u32 = 165;
+pub const VK_RSHIFT: u32 = 161;
+pub const VK_RWIN: u32 = 92;
+pub const VK_S: u32 = 83;
+pub const VK_SCROLL: u32 = 145;
+pub const VK_SELECT: u32 = 41;
+pub const VK_SEPARATOR: u32 = 108;
+pub const VK_SHIFT: u32 = 16;
+pub const VK_SLEEP: u32 = 95;
+pub const VK_SNAPSHOT: u32 = 44;
+pub const VK_SPACE: u32 = 32;
+pub const VK_SUBTRACT: u32 = 109;
+pub const VK_T: u32 = 84;
+pub const VK_TAB: u32 = 9;
+pub const VK_U: u32 = 85;
+pub const VK_UP: u32 = 38;
+pub const VK_V: u32 = 86;
+pub const VK_VOLUME_DOWN: u32 = 174;
+pub const VK_VOLUME_MUTE: u32 = 173;
+pub const VK_VOLUME_UP: u32 = 175;
+pub const VK_W: u32 = 87;
+pub const VK_X: u32 = 88;
+pub const VK_XBUTTON1: u32 = 5;
+pub const VK_XBUTTON2: u32 = 6;
+pub const VK_Y: u32 = 89;
+pub const VK_Z: u32 = 90;
+pub const VK_ZOOM: u32 = 251;
+pub const VK__none_: u32 = 255;
+pub const VULKAN_WIN32_H_: u32 = 1;
+pub const VK_KHR_win32_surface: u32 = 1;
+pub const VK_KHR_WIN32_SURFACE_SPEC_VERSION: u32 = 6;
+pub const VK_KHR_WIN32_SURFACE_EXTENSION_NAME: &[u8; 21] = b"VK_KHR_win32_surface\0";
+pub type VkWin32SurfaceCreateFlagsKHR = VkFlags;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkWin32SurfaceCreateInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: VkWin32SurfaceCreateFlagsKHR,
+    pub hinstance: HINSTANCE,
+    pub hwnd: HWND,
+}
+pub type PFN_vkCreateWin32SurfaceKHR = ::std::option::Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pCreateInfo: *const VkWin32SurfaceCreateInfoKHR,
+        pAllocator: *const VkAllocationCallbacks,
+        pSurface: *mut VkSurfaceKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceWin32PresentationSupportKHR = ::std::option::Option<
+    unsafe extern "C" fn(physicalDevice: VkPhysicalDevice, queueFamilyIndex: u32) -> VkBool32,
+>;
+pub const VK_KHR_external_memory_win32: u32 = 1;
+pub const VK_KHR_EXTERNAL_MEMORY_WIN32_SPEC_VERSION: u32 = 1;
+pub const VK_KHR_EXTERNAL_MEMORY_WIN32_EXTENSION_NAME: &[u8; 29] =
+    b"VK_KHR_external_memory_win32\0";
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkImportMemoryWin32HandleInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub handleType: VkExternalMemoryHandleTypeFlagBits,
+    pub handle: HANDLE,
+    pub name: LPCWSTR,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkExportMemoryWin32HandleInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub pAttributes: *const SECURITY_ATTRIBUTES,
+    pub dwAccess: DWORD,
+    pub name: LPCWSTR,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkMemoryWin32HandlePropertiesKHR {
+    pub sType: VkStructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub memoryTypeBits: u32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkMemoryGetWin32HandleInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub memory: VkDeviceMemory,
+    pub handleType: VkExternalMemoryHandleTypeFlagBits,
+}
+pub type PFN_vkGetMemoryWin32HandleKHR = ::std::option::Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pGetWin32HandleInfo: *const VkMemoryGetWin32HandleInfoKHR,
+        pHandle: *mut HANDLE,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetMemoryWin32HandlePropertiesKHR = ::std::option::Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        handleType: VkExternalMemoryHandleTypeFlagBits,
+        handle: HANDLE,
+        pMemoryWin32HandleProperties: *mut VkMemoryWin32HandlePropertiesKHR,
+    ) -> VkResult,
+>;
+pub const VK_KHR_win32_keyed_mutex: u32 = 1;
+pub const VK_KHR_WIN32_KEYED_MUTEX_SPEC_VERSION: u32 = 1;
+pub const VK_KHR_WIN32_KEYED_MUTEX_EXTENSION_NAME: &[u8; 25] = b"VK_KHR_win32_keyed_mutex\0";
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkWin32KeyedMutexAcquireReleaseInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub acquireCount: u32,
+    pub pAcquireSyncs: *const VkDeviceMemory,
+    pub pAcquireKeys: *const u64,
+    pub pAcquireTimeouts: *const u32,
+    pub releaseCount: u32,
+    pub pReleaseSyncs: *const VkDeviceMemory,
+    pub pReleaseKeys: *const u64,
+}
+pub const VK_KHR_external_semaphore_win32: u32 = 1;
+pub const VK_KHR_EXTERNAL_SEMAPHORE_WIN32_SPEC_VERSION: u32 = 1;
+pub const VK_KHR_EXTERNAL_SEMAPHORE_WIN32_EXTENSION_NAME: &[u8; 32] =
+    b"VK_KHR_external_semaphore_win32\0";
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkImportSemaphoreWin32HandleInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub semaphore: VkSemaphore,
+    pub flags: VkSemaphoreImportFlags,
+    pub handleType: VkExternalSemaphoreHandleTypeFlagBits,
+    pub handle: HANDLE,
+    pub name: LPCWSTR,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkExportSemaphoreWin32HandleInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub pAttributes: *const SECURITY_ATTRIBUTES,
+    pub dwAccess: DWORD,
+    pub name: LPCWSTR,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkD3D12FenceSubmitInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub waitSemaphoreValuesCount: u32,
+    pub pWaitSemaphoreValues: *const u64,
+    pub signalSemaphoreValuesCount: u32,
+    pub pSignalSemaphoreValues: *const u64,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkSemaphoreGetWin32HandleInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub semaphore: VkSemaphore,
+    pub handleType: VkExternalSemaphoreHandleTypeFlagBits,
+}
+pub type PFN_vkImportSemaphoreWin32HandleKHR = ::std::option::Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pImportSemaphoreWin32HandleInfo: *const VkImportSemaphoreWin32HandleInfoKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetSemaphoreWin32HandleKHR = ::std::option::Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pGetWin32HandleInfo: *const VkSemaphoreGetWin32HandleInfoKHR,
+        pHandle: *mut HANDLE,
+    ) -> VkResult,
+>;
+pub const VK_KHR_external_fence_win32: u32 = 1;
+pub const VK_KHR_EXTERNAL_FENCE_WIN32_SPEC_VERSION: u32 = 1;
+pub const VK_KHR_EXTERNAL_FENCE_WIN32_EXTENSION_NAME: &[u8; 28] = b"VK_KHR_external_fence_win32\0";
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkImportFenceWin32HandleInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub fence: VkFence,
+    pub flags: VkFenceImportFlags,
+    pub handleType: VkExternalFenceHandleTypeFlagBits,
+    pub handle: HANDLE,
+    pub name: LPCWSTR,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkExportFenceWin32HandleInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub pAttributes: *const SECURITY_ATTRIBUTES,
+    pub dwAccess: DWORD,
+    pub name: LPCWSTR,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkFenceGetWin32HandleInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub fence: VkFence,
+    pub handleType: VkExternalFenceHandleTypeFlagBits,
+}
+pub type PFN_vkImportFenceWin32HandleKHR = ::std::option::Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pImportFenceWin32HandleInfo: *const VkImportFenceWin32HandleInfoKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetFenceWin32HandleKHR = ::std::option::Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pGetWin32HandleInfo: *const VkFenceGetWin32HandleInfoKHR,
+        pHandle: *mut HANDLE,
+    ) -> VkResult,
+>;
+pub const VK_NV_external_memory_win32: u32 = 1;
+pub const VK_NV_EXTERNAL_MEMORY_WIN32_SPEC_VERSION: u32 = 1;
+pub const VK_NV_EXTERNAL_MEMORY_WIN32_EXTENSION_NAME: &[u8; 28] = b"VK_NV_external_memory_win32\0";
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkImportMemoryWin32HandleInfoNV {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub handleType: VkExternalMemoryHandleTypeFlagsNV,
+    pub handle: HANDLE,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkExportMemoryWin32HandleInfoNV {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub pAttributes: *const SECURITY_ATTRIBUTES,
+    pub dwAccess: DWORD,
+}
+pub type PFN_vkGetMemoryWin32HandleNV = ::std::option::Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        memory: VkDeviceMemory,
+        handleType: VkExternalMemoryHandleTypeFlagsNV,
+        pHandle: *mut HANDLE,
+    ) -> VkResult,
+>;
+pub const VK_NV_win32_keyed_mutex: u32 = 1;
+pub const VK_NV_WIN32_KEYED_MUTEX_SPEC_VERSION: u32 = 2;
+pub const VK_NV_WIN32_KEYED_MUTEX_EXTENSION_NAME: &[u8; 24] = b"VK_NV_win32_keyed_mutex\0";
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkWin32KeyedMutexAcquireReleaseInfoNV {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub acquireCount: u32,
+    pub pAcquireSyncs: *const VkDeviceMemory,
+    pub pAcquireKeys: *const u64,
+    pub pAcquireTimeoutMilliseconds: *const u32,
+    pub releaseCount: u32,
+    pub pReleaseSyncs: *const VkDeviceMemory,
+    pub pReleaseKeys: *const u64,
+}
+pub const VK_EXT_full_screen_exclusive: u32 = 1;
+pub const VK_EXT_FULL_SCREEN_EXCLUSIVE_SPEC_VERSION: u32 = 4;
+pub const VK_EXT_FULL_SCREEN_EXCLUSIVE_EXTENSION_NAME: &[u8; 29] =
+    b"VK_EXT_full_screen_exclusive\0";
+pub const VkFullScreenExclusiveEXT_VK_FULL_SCREEN_EXCLUSIVE_DEFAULT_EXT: VkFullScreenExclusiveEXT =
+    0;
+pub const VkFullScreenExclusiveEXT_VK_FULL_SCREEN_EXCLUSIVE_ALLOWED_EXT: VkFullScreenExclusiveEXT =
+    1;
+pub const VkFullScreenExclusiveEXT_VK_FULL_SCREEN_EXCLUSIVE_DISALLOWED_EXT:
+    VkFullScreenExclusiveEXT = 2;
+pub const VkFullScreenExclusiveEXT_VK_FULL_SCREEN_EXCLUSIVE_APPLICATION_CONTROLLED_EXT:
+    VkFullScreenExclusiveEXT = 3;
+pub const VkFullScreenExclusiveEXT_VK_FULL_SCREEN_EXCLUSIVE_MAX_ENUM_EXT: VkFullScreenExclusiveEXT =
+    2147483647;
+pub type VkFullScreenExclusiveEXT = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkSurfaceFullScreenExclusiveInfoEXT {
+    pub sType: VkStructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub fullScreenExclusive: VkFullScreenExclusiveEXT,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkSurfaceCapabilitiesFullScreenExclusiveEXT {
+    pub sType: VkStructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub fullScreenExclusiveSupported: VkBool32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkSurfaceFullScreenExclusiveWin32InfoEXT {
+    pub sType: VkStructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub hmonitor: HMONITOR,
+}
+pub type PFN_vkGetPhysicalDeviceSurfacePresentModes2EXT = ::std::option::Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pSurfaceInfo: *const VkPhysicalDeviceSurfaceInfo2KHR,
+        pPresentModeCount: *mut u32,
+        pPresentModes: *mut VkPresentModeKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkAcquireFullScreenExclusiveModeEXT = ::std::option::Option<
+    unsafe extern "C" fn(device: VkDevice, swapchain: VkSwapchainKHR) -> VkResult,
+>;
+pub type PFN_vkReleaseFullScreenExclusiveModeEXT = ::std::option::Option<
+    unsafe extern "C" fn(device: VkDevice, swapchain: VkSwapchainKHR) -> VkResult,
+>;
+pub type PFN_vkGetDeviceGroupSurfacePresentModes2EXT = ::std::option::Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pSurfaceInfo: *const VkPhysicalDeviceSurfaceInfo2KHR,
+        pModes: *mut VkDeviceGroupPresentModeFlagsKHR,
+    ) -> VkResult,
+>;
@@ -0,0 +1,18 @@
+// Generated by headersgen from vk.xml, regenerate instead of editing
+pub const EXTENSION_COMMANDS: &[(&str, &[&str])] = &[
+    ("VK_KHR_surface", &["vkDestroySurfaceKHR", "vkGetPhysicalDeviceSurfaceSupportKHR", "vkGetPhysicalDeviceSurfaceCapabilitiesKHR", "vkGetPhysicalDeviceSurfaceFormatsKHR", "vkGetPhysicalDeviceSurfacePresentModesKHR"]),
+    ("VK_KHR_win32_surface", &["vkCreateWin32SurfaceKHR", "vkGetPhysicalDeviceWin32PresentationSupportKHR"]),
+    ("VK_KHR_xlib_surface", &["vkCreateXlibSurfaceKHR", "vkGetPhysicalDeviceXlibPresentationSupportKHR"]),
+    ("VK_KHR_xcb_surface", &["vkCreateXcbSurfaceKHR", "vkGetPhysicalDeviceXcbPresentationSupportKHR"]),
+    ("VK_KHR_wayland_surface", &["vkCreateWaylandSurfaceKHR", "vkGetPhysicalDeviceWaylandPresentationSupportKHR"]),
+    ("VK_EXT_headless_surface", &["vkCreateHeadlessSurfaceEXT"]),
+    ("VK_EXT_debug_utils", &["vkSetDebugUtilsObjectNameEXT", "vkSetDebugUtilsObjectTagEXT", "vkQueueBeginDebugUtilsLabelEXT", "vkQueueEndDebugUtilsLabelEXT", "vkQueueInsertDebugUtilsLabelEXT", "vkCmdBeginDebugUtilsLabelEXT", "vkCmdEndDebugUtilsLabelEXT", "vkCmdInsertDebugUtilsLabelEXT", "vkCreateDebugUtilsMessengerEXT", "vkDestroyDebugUtilsMessengerEXT", "vkSubmitDebugUtilsMessageEXT"]),
+    ("VK_KHR_swapchain", &["vkCreateSwapchainKHR", "vkDestroySwapchainKHR", "vkGetSwapchainImagesKHR", "vkAcquireNextImageKHR", "vkQueuePresentKHR", "vkGetDeviceGroupPresentCapabilitiesKHR", "vkGetDeviceGroupSurfacePresentModesKHR", "vkGetPhysicalDevicePresentRectanglesKHR", "vkAcquireNextImage2KHR"]),
+    ("VK_KHR_timeline_semaphore", &["vkGetSemaphoreCounterValueKHR", "vkWaitSemaphoresKHR", "vkSignalSemaphoreKHR"]),
+    ("VK_KHR_synchronization2", &["vkCmdSetEvent2KHR", "vkCmdResetEvent2KHR", "vkCmdWaitEvents2KHR", "vkCmdPipelineBarrier2KHR", "vkCmdWriteTimestamp2KHR", "vkQueueSubmit2KHR", "vkCmdWriteBufferMarker2AMD", "vkGetQueueCheckpointData2NV"]),
+];
+
+pub fn extension_commands(extension: &str) -> &'static [&'static str]
+{
+    EXTENSION_COMMANDS.iter().find(|(name, _)| *name == extension).map_or(&[], |(_, commands)| *commands)
+}
@@ -1,75 +1,276 @@
+// Generated by headersgen from vk.xml, regenerate instead of editing
+VULKAN_DISPATCH_TABLE!(InstanceFns {
+    // Exported
+    vkGetInstanceProcAddr,
 
-//Exported
-EXPORTED_VULKAN_FUNCTION!(vkGetInstanceProcAddr);
+    // Global Vulkan Functions
+    vkCreateInstance,
+    vkEnumerateInstanceExtensionProperties,
+    vkEnumerateInstanceLayerProperties,
+    vkEnumerateInstanceVersion,
 
-//Global Vulkan Functions
-EXPORTED_VULKAN_FUNCTION!(vkEnumerateInstanceExtensionProperties);
-EXPORTED_VULKAN_FUNCTION!(vkEnumerateInstanceLayerProperties);
-EXPORTED_VULKAN_FUNCTION!(vkCreateInstance);
-EXPORTED_VULKAN_FUNCTION!(vkDestroyInstance);
+    // Instance Level Vulkan Functions
+    vkDestroyInstance,
+    vkEnumeratePhysicalDevices,
+    vkGetPhysicalDeviceFeatures,
+    vkGetPhysicalDeviceFormatProperties,
+    vkGetPhysicalDeviceImageFormatProperties,
+    vkGetPhysicalDeviceProperties,
+    vkGetPhysicalDeviceQueueFamilyProperties,
+    vkGetPhysicalDeviceMemoryProperties,
+    vkGetDeviceProcAddr,
+    vkCreateDevice,
+    vkEnumerateDeviceExtensionProperties,
+    vkEnumerateDeviceLayerProperties,
+    vkGetPhysicalDeviceSparseImageFormatProperties,
+    vkEnumeratePhysicalDeviceGroups,
+    vkGetPhysicalDeviceFeatures2,
+    vkGetPhysicalDeviceProperties2,
+    vkGetPhysicalDeviceFormatProperties2,
+    vkGetPhysicalDeviceImageFormatProperties2,
+    vkGetPhysicalDeviceQueueFamilyProperties2,
+    vkGetPhysicalDeviceMemoryProperties2,
+    vkGetPhysicalDeviceSparseImageFormatProperties2,
+    vkGetPhysicalDeviceExternalBufferProperties,
+    vkGetPhysicalDeviceExternalFenceProperties,
+    vkGetPhysicalDeviceExternalSemaphoreProperties,
+    vkGetPhysicalDeviceToolProperties,
 
-// Instance Level Vulkan Functions
-EXPORTED_VULKAN_FUNCTION!(vkEnumeratePhysicalDevices);
-EXPORTED_VULKAN_FUNCTION!(vkEnumerateDeviceExtensionProperties);
-EXPORTED_VULKAN_FUNCTION!(vkGetPhysicalDeviceProperties);
-EXPORTED_VULKAN_FUNCTION!(vkGetPhysicalDeviceMemoryProperties);
-EXPORTED_VULKAN_FUNCTION!(vkGetPhysicalDeviceFeatures);
-EXPORTED_VULKAN_FUNCTION!(vkCreateDevice);
-EXPORTED_VULKAN_FUNCTION!(vkGetDeviceProcAddr);
-EXPORTED_VULKAN_FUNCTION!(vkGetPhysicalDeviceQueueFamilyProperties);
-EXPORTED_VULKAN_FUNCTION!(vkGetPhysicalDeviceSurfacePresentModesKHR);
-EXPORTED_VULKAN_FUNCTION!(vkCreateCommandPool);
-EXPORTED_VULKAN_FUNCTION!(vkResetCommandPool);
-EXPORTED_VULKAN_FUNCTION!(vkDestroyCommandPool);
-EXPORTED_VULKAN_FUNCTION!(vkAllocateCommandBuffers);
-EXPORTED_VULKAN_FUNCTION!(vkBeginCommandBuffer);
-EXPORTED_VULKAN_FUNCTION!(vkEndCommandBuffer);
-EXPORTED_VULKAN_FUNCTION!(vkResetCommandBuffer);
+    // Instance Level Vulkan Extensions Functions
+    vkDestroySurfaceKHR,
+    vkGetPhysicalDeviceSurfaceSupportKHR,
+    vkGetPhysicalDeviceSurfaceCapabilitiesKHR,
+    vkGetPhysicalDeviceSurfaceFormatsKHR,
+    vkGetPhysicalDeviceSurfacePresentModesKHR,
+    #[cfg(feature = "win32")] vkCreateWin32SurfaceKHR,
+    #[cfg(feature = "win32")] vkGetPhysicalDeviceWin32PresentationSupportKHR,
+    #[cfg(feature = "xlib")] vkCreateXlibSurfaceKHR,
+    #[cfg(feature = "xlib")] vkGetPhysicalDeviceXlibPresentationSupportKHR,
+    #[cfg(feature = "xcb")] vkCreateXcbSurfaceKHR,
+    #[cfg(feature = "xcb")] vkGetPhysicalDeviceXcbPresentationSupportKHR,
+    #[cfg(feature = "wayland")] vkCreateWaylandSurfaceKHR,
+    #[cfg(feature = "wayland")] vkGetPhysicalDeviceWaylandPresentationSupportKHR,
+    vkCreateHeadlessSurfaceEXT,
+    vkCreateDebugUtilsMessengerEXT,
+    vkDestroyDebugUtilsMessengerEXT,
+    vkSubmitDebugUtilsMessageEXT,
+    vkGetPhysicalDevicePresentRectanglesKHR,
+});
 
-// Instance Level Vulkan Extensions Functions
-EXPORTED_VULKAN_FUNCTION!(vkGetPhysicalDeviceSurfaceSupportKHR);
-EXPORTED_VULKAN_FUNCTION!(vkGetPhysicalDeviceSurfaceCapabilitiesKHR);
-EXPORTED_VULKAN_FUNCTION!(vkGetPhysicalDeviceSurfaceFormatsKHR);
-EXPORTED_VULKAN_FUNCTION!(vkDestroySurfaceKHR);
-EXPORTED_VULKAN_FUNCTION!(vkCreateWin32SurfaceKHR);
-
-
-// Device Level Vulkan Function
-EXPORTED_VULKAN_FUNCTION!(vkGetDeviceQueue);
-EXPORTED_VULKAN_FUNCTION!(vkDeviceWaitIdle);
-EXPORTED_VULKAN_FUNCTION!(vkDestroyDevice);
-EXPORTED_VULKAN_FUNCTION!(vkCreateBuffer);
-EXPORTED_VULKAN_FUNCTION!(vkCmdCopyBuffer);
-EXPORTED_VULKAN_FUNCTION!(vkCmdCopyImageToBuffer);
-EXPORTED_VULKAN_FUNCTION!(vkFreeCommandBuffers);
-EXPORTED_VULKAN_FUNCTION!(vkGetBufferMemoryRequirements);
-EXPORTED_VULKAN_FUNCTION!(vkAllocateMemory);
-EXPORTED_VULKAN_FUNCTION!(vkMapMemory);
-EXPORTED_VULKAN_FUNCTION!(vkFlushMappedMemoryRanges);
-EXPORTED_VULKAN_FUNCTION!(vkUnmapMemory);
-EXPORTED_VULKAN_FUNCTION!(vkBindBufferMemory);
-EXPORTED_VULKAN_FUNCTION!(vkCreateBufferView);
-EXPORTED_VULKAN_FUNCTION!(vkCreateSemaphore);
-EXPORTED_VULKAN_FUNCTION!(vkDestroySemaphore);
-EXPORTED_VULKAN_FUNCTION!(vkCreateFence);
-EXPORTED_VULKAN_FUNCTION!(vkWaitForFences);
-EXPORTED_VULKAN_FUNCTION!(vkResetFences);
-EXPORTED_VULKAN_FUNCTION!(vkDestroyFence);
-EXPORTED_VULKAN_FUNCTION!(vkQueueSubmit);
-EXPORTED_VULKAN_FUNCTION!(vkQueueWaitIdle);
-EXPORTED_VULKAN_FUNCTION!(vkCmdPipelineBarrier);
-EXPORTED_VULKAN_FUNCTION!(vkCreateImage);
-EXPORTED_VULKAN_FUNCTION!(vkGetImageMemoryRequirements);
-EXPORTED_VULKAN_FUNCTION!(vkBindImageMemory);
-EXPORTED_VULKAN_FUNCTION!(vkCreateImageView);
-EXPORTED_VULKAN_FUNCTION!(vkCmdCopyBufferToImage);
-EXPORTED_VULKAN_FUNCTION!(vkDestroyImageView);
-EXPORTED_VULKAN_FUNCTION!(vkDestroyImage);
-
-// Device Level Vulkan Extensions Functions
-EXPORTED_VULKAN_FUNCTION!(vkCreateSwapchainKHR);
-EXPORTED_VULKAN_FUNCTION!(vkGetSwapchainImagesKHR);
-EXPORTED_VULKAN_FUNCTION!(vkDestroySwapchainKHR);
-EXPORTED_VULKAN_FUNCTION!(vkAcquireNextImageKHR);
-EXPORTED_VULKAN_FUNCTION!(vkQueuePresentKHR);
+VULKAN_DISPATCH_TABLE!(DeviceFns {
+    // Device Level Vulkan Function
+    vkDestroyDevice,
+    vkGetDeviceQueue,
+    vkQueueSubmit,
+    vkQueueWaitIdle,
+    vkDeviceWaitIdle,
+    vkAllocateMemory,
+    vkFreeMemory,
+    vkMapMemory,
+    vkUnmapMemory,
+    vkFlushMappedMemoryRanges,
+    vkInvalidateMappedMemoryRanges,
+    vkGetDeviceMemoryCommitment,
+    vkBindBufferMemory,
+    vkBindImageMemory,
+    vkGetBufferMemoryRequirements,
+    vkGetImageMemoryRequirements,
+    vkGetImageSparseMemoryRequirements,
+    vkQueueBindSparse,
+    vkCreateFence,
+    vkDestroyFence,
+    vkResetFences,
+    vkGetFenceStatus,
+    vkWaitForFences,
+    vkCreateSemaphore,
+    vkDestroySemaphore,
+    vkCreateEvent,
+    vkDestroyEvent,
+    vkGetEventStatus,
+    vkSetEvent,
+    vkResetEvent,
+    vkCreateQueryPool,
+    vkDestroyQueryPool,
+    vkGetQueryPoolResults,
+    vkCreateBuffer,
+    vkDestroyBuffer,
+    vkCreateBufferView,
+    vkDestroyBufferView,
+    vkCreateImage,
+    vkDestroyImage,
+    vkGetImageSubresourceLayout,
+    vkCreateImageView,
+    vkDestroyImageView,
+    vkCreateShaderModule,
+    vkDestroyShaderModule,
+    vkCreatePipelineCache,
+    vkDestroyPipelineCache,
+    vkGetPipelineCacheData,
+    vkMergePipelineCaches,
+    vkCreateGraphicsPipelines,
+    vkCreateComputePipelines,
+    vkDestroyPipeline,
+    vkCreatePipelineLayout,
+    vkDestroyPipelineLayout,
+    vkCreateSampler,
+    vkDestroySampler,
+    vkCreateDescriptorSetLayout,
+    vkDestroyDescriptorSetLayout,
+    vkCreateDescriptorPool,
+    vkDestroyDescriptorPool,
+    vkResetDescriptorPool,
+    vkAllocateDescriptorSets,
+    vkFreeDescriptorSets,
+    vkUpdateDescriptorSets,
+    vkCreateFramebuffer,
+    vkDestroyFramebuffer,
+    vkCreateRenderPass,
+    vkDestroyRenderPass,
+    vkGetRenderAreaGranularity,
+    vkCreateCommandPool,
+    vkDestroyCommandPool,
+    vkResetCommandPool,
+    vkAllocateCommandBuffers,
+    vkFreeCommandBuffers,
+    vkBeginCommandBuffer,
+    vkEndCommandBuffer,
+    vkResetCommandBuffer,
+    vkCmdBindPipeline,
+    vkCmdSetViewport,
+    vkCmdSetScissor,
+    vkCmdSetLineWidth,
+    vkCmdSetDepthBias,
+    vkCmdSetBlendConstants,
+    vkCmdSetDepthBounds,
+    vkCmdSetStencilCompareMask,
+    vkCmdSetStencilWriteMask,
+    vkCmdSetStencilReference,
+    vkCmdBindDescriptorSets,
+    vkCmdBindIndexBuffer,
+    vkCmdBindVertexBuffers,
+    vkCmdDraw,
+    vkCmdDrawIndexed,
+    vkCmdDrawIndirect,
+    vkCmdDrawIndexedIndirect,
+    vkCmdDispatch,
+    vkCmdDispatchIndirect,
+    vkCmdCopyBuffer,
+    vkCmdCopyImage,
+    vkCmdBlitImage,
+    vkCmdCopyBufferToImage,
+    vkCmdCopyImageToBuffer,
+    vkCmdUpdateBuffer,
+    vkCmdFillBuffer,
+    vkCmdClearColorImage,
+    vkCmdClearDepthStencilImage,
+    vkCmdClearAttachments,
+    vkCmdResolveImage,
+    vkCmdSetEvent,
+    vkCmdResetEvent,
+    vkCmdWaitEvents,
+    vkCmdPipelineBarrier,
+    vkCmdBeginQuery,
+    vkCmdEndQuery,
+    vkCmdResetQueryPool,
+    vkCmdWriteTimestamp,
+    vkCmdCopyQueryPoolResults,
+    vkCmdPushConstants,
+    vkCmdBeginRenderPass,
+    vkCmdNextSubpass,
+    vkCmdEndRenderPass,
+    vkCmdExecuteCommands,
+    vkBindBufferMemory2,
+    vkBindImageMemory2,
+    vkGetDeviceGroupPeerMemoryFeatures,
+    vkCmdSetDeviceMask,
+    vkCmdDispatchBase,
+    vkGetImageMemoryRequirements2,
+    vkGetBufferMemoryRequirements2,
+    vkGetImageSparseMemoryRequirements2,
+    vkTrimCommandPool,
+    vkGetDeviceQueue2,
+    vkCreateSamplerYcbcrConversion,
+    vkDestroySamplerYcbcrConversion,
+    vkCreateDescriptorUpdateTemplate,
+    vkDestroyDescriptorUpdateTemplate,
+    vkUpdateDescriptorSetWithTemplate,
+    vkGetDescriptorSetLayoutSupport,
+    vkCmdDrawIndirectCount,
+    vkCmdDrawIndexedIndirectCount,
+    vkCreateRenderPass2,
+    vkCmdBeginRenderPass2,
+    vkCmdNextSubpass2,
+    vkCmdEndRenderPass2,
+    vkResetQueryPool,
+    vkGetSemaphoreCounterValue,
+    vkWaitSemaphores,
+    vkSignalSemaphore,
+    vkGetBufferDeviceAddress,
+    vkGetBufferOpaqueCaptureAddress,
+    vkGetDeviceMemoryOpaqueCaptureAddress,
+    vkCreatePrivateDataSlot,
+    vkDestroyPrivateDataSlot,
+    vkSetPrivateData,
+    vkGetPrivateData,
+    vkCmdSetEvent2,
+    vkCmdResetEvent2,
+    vkCmdWaitEvents2,
+    vkCmdPipelineBarrier2,
+    vkCmdWriteTimestamp2,
+    vkQueueSubmit2,
+    vkCmdCopyBuffer2,
+    vkCmdCopyImage2,
+    vkCmdCopyBufferToImage2,
+    vkCmdCopyImageToBuffer2,
+    vkCmdBlitImage2,
+    vkCmdResolveImage2,
+    vkCmdBeginRendering,
+    vkCmdEndRendering,
+    vkCmdSetCullMode,
+    vkCmdSetFrontFace,
+    vkCmdSetPrimitiveTopology,
+    vkCmdSetViewportWithCount,
+    vkCmdSetScissorWithCount,
+    vkCmdBindVertexBuffers2,
+    vkCmdSetDepthTestEnable,
+    vkCmdSetDepthWriteEnable,
+    vkCmdSetDepthCompareOp,
+    vkCmdSetDepthBoundsTestEnable,
+    vkCmdSetStencilTestEnable,
+    vkCmdSetStencilOp,
+    vkCmdSetRasterizerDiscardEnable,
+    vkCmdSetDepthBiasEnable,
+    vkCmdSetPrimitiveRestartEnable,
+    vkGetDeviceBufferMemoryRequirements,
+    vkGetDeviceImageMemoryRequirements,
+    vkGetDeviceImageSparseMemoryRequirements,
 
+    // Device Level Vulkan Extensions Functions
+    vkSetDebugUtilsObjectNameEXT,
+    vkSetDebugUtilsObjectTagEXT,
+    vkQueueBeginDebugUtilsLabelEXT,
+    vkQueueEndDebugUtilsLabelEXT,
+    vkQueueInsertDebugUtilsLabelEXT,
+    vkCmdBeginDebugUtilsLabelEXT,
+    vkCmdEndDebugUtilsLabelEXT,
+    vkCmdInsertDebugUtilsLabelEXT,
+    vkCreateSwapchainKHR,
+    vkDestroySwapchainKHR,
+    vkGetSwapchainImagesKHR,
+    vkAcquireNextImageKHR,
+    vkQueuePresentKHR,
+    vkGetDeviceGroupPresentCapabilitiesKHR,
+    vkGetDeviceGroupSurfacePresentModesKHR,
+    vkAcquireNextImage2KHR,
+    vkGetSemaphoreCounterValueKHR,
+    vkWaitSemaphoresKHR,
+    vkSignalSemaphoreKHR,
+    vkCmdSetEvent2KHR,
+    vkCmdResetEvent2KHR,
+    vkCmdWaitEvents2KHR,
+    vkCmdPipelineBarrier2KHR,
+    vkCmdWriteTimestamp2KHR,
+    vkQueueSubmit2KHR,
+    vkCmdWriteBufferMarker2AMD,
+    vkGetQueueCheckpointData2NV,
+});
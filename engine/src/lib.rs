@@ -1,33 +1,90 @@
 #![allow(non_snake_case)]
 #![allow(non_camel_case_types)]
 #![allow(non_upper_case_globals)]
+#![allow(clippy::upper_case_acronyms)]
+#![allow(clippy::cmp_null)]
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+#![allow(clippy::missing_safety_doc)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::new_without_default)]
 
 pub mod vulkan_bindings;
 pub mod vulkan_init;
 pub mod vulkan_window;
 pub mod vulkan_synchro;
 pub mod vulkan_mem;
-mod system_window;
+pub mod vulkan_debug;
+pub mod vulkan_features;
+pub mod vulkan_format;
+pub mod vulkan_error;
+pub mod vulkan_recovery;
+pub mod vulkan_pipeline_cache;
+pub mod vulkan_alloc;
+pub mod vulkan_types;
+pub mod vulkan_structs;
+pub mod system_window;
+pub mod system_events;
+#[cfg(feature = "win32")]
+mod system_window_win32;
+#[cfg(feature = "xlib")]
+mod system_window_xlib;
+#[cfg(feature = "xcb")]
+mod system_window_xcb;
+#[cfg(feature = "wayland")]
+mod system_window_wayland;
 
+fn create_instance(global_exts: Vec<String>) -> &'static mut vulkan_init::VulkanInstance
+{
+    let mut instance_builder = vulkan_init::VulkanInstance::builder()
+        .application("Anvil Editor", vulkan_init::make_api_version(0, 0, 1, 0))
+        .engine("Anvil", vulkan_init::make_api_version(0, 0, 1, 0))
+        .api_version(vulkan_init::make_api_version(0, 1, 3, 0))
+        .extensions(global_exts);
+    if cfg!(debug_assertions)
+    {
+        instance_builder = instance_builder
+            .validation(vulkan_debug::VulkanValidation::default())
+            .track_host_memory();
+    }
+    vulkan_init::initialize_vulkan(instance_builder)
+}
+
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
 pub fn render()
 {
-    let global_exts = vulkan_init::load_extension_names(&[vulkan_bindings::VK_KHR_SURFACE_EXTENSION_NAME, vulkan_bindings::VK_KHR_WIN32_SURFACE_EXTENSION_NAME]);
-    let mut vk_instance = vulkan_init::initialize_vulkan(global_exts);
-    let vk_surface = vulkan_window::VulkanSurface::new(vk_instance).unwrap_or_else(|e| {
-        eprintln!("{}",e);
+    let window_backend = system_window::WindowBackend::default_backend().unwrap_or_else(|| {
+        eprintln!("{}", system_window::SystemWindowError::NO_WINDOW_BACKEND);
+        std::process::exit(1);
+    });
+    println!("Using the {} window backend", window_backend.name());
+    let global_exts = vulkan_init::load_extension_names(&[vulkan_bindings::VK_KHR_SURFACE_EXTENSION_NAME, window_backend.surface_extension_name()]);
+    let vk_instance = create_instance(global_exts);
+    let mut vk_surface = vulkan_window::VulkanSurface::with_backend(vk_instance, window_backend, &system_window::WindowDescriptor::new("Anvil")).unwrap_or_else(|e| {
+        eprintln!("{}", vulkan_error::describe(&e));
         std::process::exit(1);
     });
     let device_exts = vulkan_init::load_extension_names(&[vulkan_bindings::VK_KHR_SWAPCHAIN_EXTENSION_NAME]);
-    let logical_device = vulkan_init::VulkanLogicalDevice::new(
-        &mut vk_instance,
+    let device_features = vulkan_features::VulkanFeatureRequest::new()
+        .optional(|features| {
+            features.core.samplerAnisotropy = vulkan_bindings::VK_TRUE;
+            features.core.fillModeNonSolid = vulkan_bindings::VK_TRUE;
+            features.vulkan12.timelineSemaphore = vulkan_bindings::VK_TRUE;
+            features.vulkan13.synchronization2 = vulkan_bindings::VK_TRUE;
+        });
+    let mut logical_device = vulkan_init::VulkanLogicalDevice::new(
+        vk_instance,
         device_exts, 
-        &[(vulkan_bindings::VkQueueFlagBits_VK_QUEUE_GRAPHICS_BIT | vulkan_bindings::VkQueueFlagBits_VK_QUEUE_COMPUTE_BIT) as u32],
-        &vk_surface.surface,
-        vulkan_bindings::VkPresentModeKHR_VK_PRESENT_MODE_MAILBOX_KHR
+        &[vulkan_types::QueueFlags::GRAPHICS | vulkan_types::QueueFlags::COMPUTE],
+        &device_features,
+        Some(&vk_surface.surface),
+        vulkan_types::PresentModeKHR::MAILBOX,
+        &vulkan_init::VulkanDeviceSelector::default()
     ).unwrap_or_else(|e| {
-        eprintln!("{}",e);
+        eprintln!("{}", vulkan_error::describe(&e));
         std::process::exit(1);
     });
+    logical_device.on_device_lost(Box::new(|error| eprintln!("Vulkan device lost: {}", error)));
+    load_pipeline_cache(&mut logical_device);
 
     //Vulkan Window Test
     // let desired_surface_format = vulkan_bindings::VkSurfaceFormatKHR {
@@ -36,8 +93,8 @@ pub fn render()
     // };
     // vk_surface.configure_swapchain(&logical_device, 
     //     3,
-    //     (vulkan_bindings::VkImageUsageFlagBits_VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT) as u32,
-    //     vulkan_bindings::VkSurfaceTransformFlagBitsKHR_VK_SURFACE_TRANSFORM_IDENTITY_BIT_KHR as u32,
+    //     vulkan_types::ImageUsageFlags::COLOR_ATTACHMENT,
+    //     vulkan_types::SurfaceTransformFlagsKHR::IDENTITY,
     //     &desired_surface_format
     // ).unwrap_or_else(|e| {
     //         eprintln!("{}",e);
@@ -45,77 +102,224 @@ pub fn render()
     // });
     // vk_surface.present_image().unwrap();
 
-    //Vulkan Synchro Test
-    let mut cmd_pool= vulkan_synchro::VulkanCmdPool::new(&logical_device).unwrap_or_else(|e| {
-        eprintln!("{}",e);
-        std::process::exit(1);
-    });
-    let cmd_buffer = cmd_pool.create_buffers(3, 3).unwrap_or_else(|e| {
-        eprintln!("{}",e);
-        std::process::exit(1);
-    });
-    cmd_buffer.begin_primary_buffer(0, vulkan_bindings::VkCommandBufferUsageFlagBits_VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT).unwrap();
-    // buffer.end_primary_buffer(0).unwrap();
-    // buffer.reset_primary_buffer(0, true).unwrap();
-    // cmd_pool.reset_pool(true).unwrap();
-    // let sem = vulkan_synchro::init_semaphore(&logical_device).unwrap();
-    // vulkan_synchro::destroy_semaphore(&logical_device, sem);
-    // let fence = vulkan_synchro::init_fence(&logical_device).unwrap();
-    // vulkan_synchro::wait_fences(&logical_device, &vec![fence], vulkan_bindings::VK_TRUE, 20000000).unwrap();
-    // vulkan_synchro::reset_fences(&logical_device, &vec![fence]).unwrap();
-    // vulkan_synchro::destroy_fence(&logical_device, fence);
-    // let waiting_sems = vulkan_synchro::VulkanWaitSemaphoresInfo {
-        //     semaphores:Vec::new(),
-        //     waiting_stage: Vec::new()
-        // };
-        // let queue  = logical_device.get_device_queue(vulkan_bindings::VkQueueFlagBits_VK_QUEUE_GRAPHICS_BIT as u32, 0).unwrap();
-        // cmd_pool.submit_buffers(queue, &waiting_sems).unwrap();
-        // println!("{}", vulkan_synchro::check_queue_idle(queue));
-        // println!("{}", logical_device.is_idle());
-    // cmd_pool.destroy();
-
-    //Vulkan Mem Tests
-    let mut buffer = vulkan_mem::VulkanBufferMem::new(
-        &logical_device, 
-        100,
-        vulkan_bindings::VkBufferUsageFlagBits_VK_BUFFER_USAGE_TRANSFER_SRC_BIT as u32
-    ).unwrap();
-    // buffer.create_buffer_view(vulkan_bindings::VkFormat_VK_FORMAT_R8G8B8A8_UNORM).unwrap();
-    let mut bytes = vec![0;3];
-    let buffer_mem = buffer.device_memory.as_mut();
-    match buffer_mem
+    run_with_recovery(&mut logical_device);
+
+    //Window Event Loop
+    'events: while !vk_surface.window.close_requested()
     {
-        Some(b) => {
-            b.map_data( bytes.as_mut_ptr() as *mut std::ffi::c_void, std::mem::size_of::<i32>() * bytes.len());
-            b.flush_maped_memory().unwrap();
-        },
-        None => {}
+        for event in vk_surface.window.wait_events()
+        {
+            match event {
+                system_events::WindowEvent::KEY_DOWN { keycode: system_events::KeyCode::ESCAPE, .. } => break 'events,
+                system_events::WindowEvent::KEY_DOWN { keycode: system_events::KeyCode::F11, .. } => {
+                    let fullscreen = match vk_surface.window.descriptor().fullscreen {
+                        system_window::FullscreenMode::WINDOWED => system_window::FullscreenMode::BORDERLESS,
+                        _ => system_window::FullscreenMode::WINDOWED
+                    };
+                    if let Err(e) = vk_surface.window.set_fullscreen(fullscreen)
+                    {
+                        eprintln!("{}", e);
+                    }
+                },
+                _ => {}
+            }
+        }
     }
-    // let mut secondary_buffer = vulkan_mem::VulkanBufferMem::new(
-    //     &logical_device, 
-    //     100,
-    //     vulkan_bindings::VkBufferUsageFlagBits_VK_BUFFER_USAGE_TRANSFER_DST_BIT as u32
-    // ).unwrap();
-    // secondary_buffer.copy_buffer(&buffer).unwrap();
-    // secondary_buffer.flush_copied_buffer(cmd_buffer.primary_buffers[0], &buffer);
-    let mut image = vulkan_mem::VulkanImageMem::new(
-        &logical_device,
-        vulkan_bindings::VkImageType_VK_IMAGE_TYPE_2D,
-        vulkan_bindings::VkFormat_VK_FORMAT_R8G8B8A8_UNORM,
-        vulkan_bindings::VkExtent3D {width: 100, height: 100, depth: 1},
-        1,
-        6,
-        1,
-        vulkan_bindings::VkImageUsageFlagBits_VK_IMAGE_USAGE_TRANSFER_DST_BIT as u32,
-        vulkan_bindings::VkImageLayout_VK_IMAGE_LAYOUT_TRANSFER_DST_OPTIMAL
-    ).unwrap();
-    // image.create_image_view(vulkan_bindings::VkImageViewType_VK_IMAGE_VIEW_TYPE_CUBE, 0).unwrap();
-    image.copy_buffer(&buffer).unwrap();
-    image.flush_buffer_copy(cmd_buffer.primary_buffers[0], &buffer);
-    cmd_buffer.end_primary_buffer(0).unwrap();
-    buffer.destroy();
-    image.destroy();
     vk_surface.destroy();
     logical_device.destroy();
     vk_instance.destroy();
-}
\ No newline at end of file
+}
+
+// no window to open, render_headless still works
+#[cfg(not(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland")))]
+pub fn render()
+{
+    eprintln!("{}", system_window::SystemWindowError::NO_WINDOW_BACKEND);
+    std::process::exit(1);
+}
+
+pub fn render_headless()
+{
+    let vk_instance = create_instance(Vec::new());
+    let mut logical_device = vulkan_init::VulkanLogicalDevice::new_headless(
+        vk_instance,
+        Vec::new(),
+        &[vulkan_types::QueueFlags::COMPUTE],
+        &vulkan_features::VulkanFeatureRequest::new()
+            .optional(|features| {
+                features.vulkan12.timelineSemaphore = vulkan_bindings::VK_TRUE;
+                features.vulkan13.synchronization2 = vulkan_bindings::VK_TRUE;
+            }),
+        &vulkan_init::VulkanDeviceSelector::default()
+    ).unwrap_or_else(|e| {
+        eprintln!("{}", vulkan_error::describe(&e));
+        std::process::exit(1);
+    });
+    logical_device.on_device_lost(Box::new(|error| eprintln!("Vulkan device lost: {}", error)));
+    load_pipeline_cache(&mut logical_device);
+    run_with_recovery(&mut logical_device);
+    logical_device.destroy();
+    vk_instance.destroy();
+}
+
+fn load_pipeline_cache(logical_device: &mut vulkan_init::VulkanLogicalDevice)
+{
+    if let Err(e) = logical_device.load_pipeline_cache(&vulkan_pipeline_cache::default_cache_directory())
+    {
+        report_error(&e.into());
+        return;
+    }
+    if let Some(rejection) = &logical_device.pipeline_cache.rejection
+    {
+        eprintln!("Discarded pipeline cache: {}", rejection);
+    }
+}
+
+// the test's resources are registered for the whole run so a lost device rebuilds them before the retry
+fn run_with_recovery(logical_device: &mut vulkan_init::VulkanLogicalDevice)
+{
+    let mut recovery = vulkan_recovery::VulkanDeviceRecovery::new(logical_device);
+    let mut resources = match TransferResources::new(logical_device) {
+        Ok(resources) => resources,
+        Err(e) => {
+            report_error(&e);
+            return;
+        }
+    };
+    resources.register(&mut recovery);
+    if let Err(e) = resources.run_transfer_test()
+    {
+        report_error(&e);
+        if e.is_device_lost()
+        {
+            match recovery.recover() {
+                Ok(()) => if let Err(e) = resources.run_transfer_test() { report_error(&e) },
+                Err(e) => report_error(&e)
+            }
+        }
+    }
+    resources.unregister(&mut recovery);
+    resources.destroy();
+}
+
+fn report_error(error: &vulkan_error::VulkanError)
+{
+    eprintln!("{}", vulkan_error::describe(error));
+}
+
+struct TransferResources {
+    cmd_pool: vulkan_synchro::VulkanCmdPool,
+    buffer: vulkan_mem::VulkanBufferMem,
+    image: vulkan_mem::VulkanImageMem
+}
+
+impl TransferResources {
+    // whatever was created before a failing step is destroyed again
+    fn new(logical_device: &vulkan_init::VulkanLogicalDevice) -> Result<Self, vulkan_error::VulkanError>
+    {
+        let mut cmd_pool= vulkan_synchro::VulkanCmdPool::for_role(logical_device, vulkan_init::VulkanQueueRole::TRANSFER)?;
+        if let Err(e) = cmd_pool.create_buffers(3, 3)
+        {
+            cmd_pool.destroy();
+            return Err(e.into());
+        }
+        let buffer = match vulkan_mem::VulkanBufferMem::new(
+            logical_device, 
+            100,
+            vulkan_types::BufferUsageFlags::TRANSFER_SRC
+        ) {
+            Ok(buffer) => buffer,
+            Err(e) => {
+                cmd_pool.destroy();
+                return Err(e.into());
+            }
+        };
+        let image = match vulkan_mem::VulkanImageMem::new(
+            logical_device,
+            vulkan_types::ImageType::TYPE_2D,
+            vulkan_bindings::VkFormat_VK_FORMAT_R8G8B8A8_UNORM,
+            vulkan_bindings::VkExtent3D {width: 100, height: 100, depth: 1},
+            1,
+            6,
+            vulkan_types::SampleCountFlags::COUNT_1,
+            vulkan_types::ImageUsageFlags::TRANSFER_DST,
+            vulkan_types::ImageLayout::TRANSFER_DST_OPTIMAL
+        ) {
+            Ok(image) => image,
+            Err(e) => {
+                buffer.destroy();
+                cmd_pool.destroy();
+                return Err(e.into());
+            }
+        };
+        Ok(TransferResources { cmd_pool, buffer, image })
+    }
+
+    // the registered addresses have to stay put, so the resources aren't moved until they are unregistered
+    fn register(&mut self, recovery: &mut vulkan_recovery::VulkanDeviceRecovery)
+    {
+        recovery.register(&mut self.cmd_pool);
+        recovery.register(&mut self.buffer);
+        recovery.register(&mut self.image);
+    }
+
+    fn unregister(& self, recovery: &mut vulkan_recovery::VulkanDeviceRecovery)
+    {
+        recovery.unregister(&self.image);
+        recovery.unregister(&self.buffer);
+        recovery.unregister(&self.cmd_pool);
+    }
+
+    fn run_transfer_test(&mut self) -> Result<(), vulkan_error::VulkanError>
+    {
+        //Vulkan Synchro Test
+        let cmd_buffer = self.cmd_pool.buffers().unwrap();
+        cmd_buffer.begin_primary_buffer(0, vulkan_types::CommandBufferUsageFlags::ONE_TIME_SUBMIT)?;
+        // buffer.end_primary_buffer(0).unwrap();
+        // buffer.reset_primary_buffer(0, true).unwrap();
+        // cmd_pool.reset_pool(true).unwrap();
+        // let sem = vulkan_synchro::init_semaphore(logical_device).unwrap();
+        // vulkan_synchro::destroy_semaphore(logical_device, sem);
+        // let fence = vulkan_synchro::init_fence(logical_device).unwrap();
+        // vulkan_synchro::wait_fences(logical_device, &vec![fence], vulkan_bindings::VK_TRUE, 20000000).unwrap();
+        // vulkan_synchro::reset_fences(logical_device, &vec![fence]).unwrap();
+        // vulkan_synchro::destroy_fence(logical_device, fence);
+        // let timeline = vulkan_synchro::VulkanTimelineSemaphore::new(logical_device, 0).unwrap();
+        // let waiting_sems = vulkan_synchro::VulkanWaitSemaphoresInfo::new();
+        // let signaled_sems = vulkan_synchro::VulkanSignalSemaphoresInfo::new().signal_value(&timeline, 1);
+            // let queue  = logical_device.queue(vulkan_init::VulkanQueueRole::TRANSFER).unwrap().handle;
+            // cmd_pool.submit_buffers(queue, &waiting_sems, &signaled_sems, std::ptr::null_mut()).unwrap();
+            // timeline.wait(1, 20000000).unwrap();
+            // timeline.destroy();
+            // println!("{}", vulkan_synchro::check_queue_idle(logical_device, queue));
+            // println!("{}", logical_device.is_idle());
+
+        //Vulkan Mem Tests
+        // buffer.create_buffer_view(vulkan_bindings::VkFormat_VK_FORMAT_R8G8B8A8_UNORM).unwrap();
+        let mut bytes = vec![0;3];
+        let buffer_mem = self.buffer.device_memory.as_mut();
+        if let Some(b) = buffer_mem {
+            b.map_data( bytes.as_mut_ptr() as *mut std::ffi::c_void, std::mem::size_of::<i32>() * bytes.len());
+            b.flush_maped_memory()?;
+        }
+        // let mut secondary_buffer = vulkan_mem::VulkanBufferMem::new(
+        //     logical_device, 
+        //     100,
+        //     vulkan_types::BufferUsageFlags::TRANSFER_DST
+        // ).unwrap();
+        // secondary_buffer.copy_buffer(&buffer).unwrap();
+        // secondary_buffer.flush_copied_buffer(cmd_buffer.primary_buffers[0], &buffer);
+        // image.create_image_view(vulkan_types::ImageViewType::CUBE, vulkan_types::ImageAspectFlags::COLOR).unwrap();
+        self.image.copy_buffer(&self.buffer)?;
+        self.image.flush_buffer_copy(cmd_buffer.primary_buffers[0], &self.buffer);
+        cmd_buffer.end_primary_buffer(0)?;
+        Ok(())
+    }
+
+    fn destroy(self)
+    {
+        self.image.destroy();
+        self.buffer.destroy();
+        self.cmd_pool.destroy();
+    }
+}
@@ -1,69 +1,271 @@
 {
+    // Generated by headersgen from vk.xml, regenerate instead of editing
     LOAD_EXPORTED_VULKAN_FUNCTION!(vkGetInstanceProcAddr);
-    
+
+    LOAD_GLOBAL_LEVEL_VULKAN_FUNCTION!(vkCreateInstance);
     LOAD_GLOBAL_LEVEL_VULKAN_FUNCTION!(vkEnumerateInstanceExtensionProperties);
     LOAD_GLOBAL_LEVEL_VULKAN_FUNCTION!(vkEnumerateInstanceLayerProperties);
-    LOAD_GLOBAL_LEVEL_VULKAN_FUNCTION!(vkCreateInstance);
-    
+
+    LOAD_OPTIONAL_GLOBAL_LEVEL_VULKAN_FUNCTION!(vkEnumerateInstanceVersion);
+
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkDestroyInstance);
     LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkEnumeratePhysicalDevices);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkEnumerateDeviceExtensionProperties);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkGetPhysicalDeviceProperties);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkGetPhysicalDeviceMemoryProperties);
     LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkGetPhysicalDeviceFeatures);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkGetPhysicalDeviceFormatProperties);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkGetPhysicalDeviceImageFormatProperties);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkGetPhysicalDeviceProperties);
     LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkGetPhysicalDeviceQueueFamilyProperties);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkCreateDevice);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkGetPhysicalDeviceMemoryProperties);
     LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkGetDeviceProcAddr);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkDestroyInstance);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkGetPhysicalDeviceSurfacePresentModesKHR);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkCreateCommandPool);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkResetCommandPool);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkDestroyCommandPool);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkAllocateCommandBuffers);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkBeginCommandBuffer);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkEndCommandBuffer);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkResetCommandBuffer);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkCreateDevice);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkEnumerateDeviceExtensionProperties);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkEnumerateDeviceLayerProperties);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION!(vkGetPhysicalDeviceSparseImageFormatProperties);
+
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkEnumeratePhysicalDeviceGroups, 1, 1);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetPhysicalDeviceFeatures2, 1, 1);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetPhysicalDeviceProperties2, 1, 1);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetPhysicalDeviceFormatProperties2, 1, 1);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetPhysicalDeviceImageFormatProperties2, 1, 1);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetPhysicalDeviceQueueFamilyProperties2, 1, 1);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetPhysicalDeviceMemoryProperties2, 1, 1);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetPhysicalDeviceSparseImageFormatProperties2, 1, 1);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetPhysicalDeviceExternalBufferProperties, 1, 1);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetPhysicalDeviceExternalFenceProperties, 1, 1);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetPhysicalDeviceExternalSemaphoreProperties, 1, 1);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetPhysicalDeviceToolProperties, 1, 3);
 
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkGetPhysicalDeviceSurfaceSupportKHR , VK_KHR_SURFACE_EXTENSION_NAME);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkGetPhysicalDeviceSurfaceCapabilitiesKHR, VK_KHR_SURFACE_EXTENSION_NAME);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkGetPhysicalDeviceSurfaceFormatsKHR , VK_KHR_SURFACE_EXTENSION_NAME);
     LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkDestroySurfaceKHR, VK_KHR_SURFACE_EXTENSION_NAME);
-    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkCreateWin32SurfaceKHR, VK_KHR_WIN32_SURFACE_EXTENSION_NAME);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkGetPhysicalDeviceSurfaceSupportKHR, VK_KHR_SURFACE_EXTENSION_NAME);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkGetPhysicalDeviceSurfaceCapabilitiesKHR, VK_KHR_SURFACE_EXTENSION_NAME);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkGetPhysicalDeviceSurfaceFormatsKHR, VK_KHR_SURFACE_EXTENSION_NAME);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkGetPhysicalDeviceSurfacePresentModesKHR, VK_KHR_SURFACE_EXTENSION_NAME);
+    #[cfg(feature = "win32")] LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkCreateWin32SurfaceKHR, VK_KHR_WIN32_SURFACE_EXTENSION_NAME);
+    #[cfg(feature = "win32")] LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkGetPhysicalDeviceWin32PresentationSupportKHR, VK_KHR_WIN32_SURFACE_EXTENSION_NAME);
+    #[cfg(feature = "xlib")] LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkCreateXlibSurfaceKHR, VK_KHR_XLIB_SURFACE_EXTENSION_NAME);
+    #[cfg(feature = "xlib")] LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkGetPhysicalDeviceXlibPresentationSupportKHR, VK_KHR_XLIB_SURFACE_EXTENSION_NAME);
+    #[cfg(feature = "xcb")] LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkCreateXcbSurfaceKHR, VK_KHR_XCB_SURFACE_EXTENSION_NAME);
+    #[cfg(feature = "xcb")] LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkGetPhysicalDeviceXcbPresentationSupportKHR, VK_KHR_XCB_SURFACE_EXTENSION_NAME);
+    #[cfg(feature = "wayland")] LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkCreateWaylandSurfaceKHR, VK_KHR_WAYLAND_SURFACE_EXTENSION_NAME);
+    #[cfg(feature = "wayland")] LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkGetPhysicalDeviceWaylandPresentationSupportKHR, VK_KHR_WAYLAND_SURFACE_EXTENSION_NAME);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkCreateHeadlessSurfaceEXT, VK_EXT_HEADLESS_SURFACE_EXTENSION_NAME);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkCreateDebugUtilsMessengerEXT, VK_EXT_DEBUG_UTILS_EXTENSION_NAME);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkDestroyDebugUtilsMessengerEXT, VK_EXT_DEBUG_UTILS_EXTENSION_NAME);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkSubmitDebugUtilsMessageEXT, VK_EXT_DEBUG_UTILS_EXTENSION_NAME);
+    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkGetPhysicalDevicePresentRectanglesKHR, VK_KHR_SWAPCHAIN_EXTENSION_NAME);
 
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyDevice);
     LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkGetDeviceQueue);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkQueueSubmit);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkQueueWaitIdle);
     LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDeviceWaitIdle);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyDevice);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateBuffer);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdCopyBuffer);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdCopyImageToBuffer);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkFreeCommandBuffers);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkGetBufferMemoryRequirements);
     LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkAllocateMemory);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkFreeMemory);
     LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkMapMemory);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkFlushMappedMemoryRanges);
     LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkUnmapMemory);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkFlushMappedMemoryRanges);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkInvalidateMappedMemoryRanges);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkGetDeviceMemoryCommitment);
     LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkBindBufferMemory);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateBufferView);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateSemaphore);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroySemaphore);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkBindImageMemory);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkGetBufferMemoryRequirements);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkGetImageMemoryRequirements);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkGetImageSparseMemoryRequirements);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkQueueBindSparse);
     LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateFence);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkWaitForFences);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkResetFences);
     LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyFence);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkQueueSubmit);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkQueueWaitIdle);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdPipelineBarrier);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkResetFences);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkGetFenceStatus);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkWaitForFences);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateSemaphore);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroySemaphore);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateEvent);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyEvent);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkGetEventStatus);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkSetEvent);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkResetEvent);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateQueryPool);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyQueryPool);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkGetQueryPoolResults);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateBuffer);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyBuffer);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateBufferView);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyBufferView);
     LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateImage);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkGetImageMemoryRequirements);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkBindImageMemory);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyImage);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkGetImageSubresourceLayout);
     LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateImageView);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdCopyBufferToImage);
     LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyImageView);
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyImage);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateShaderModule);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyShaderModule);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreatePipelineCache);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyPipelineCache);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkGetPipelineCacheData);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkMergePipelineCaches);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateGraphicsPipelines);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateComputePipelines);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyPipeline);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreatePipelineLayout);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyPipelineLayout);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateSampler);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroySampler);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateDescriptorSetLayout);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyDescriptorSetLayout);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateDescriptorPool);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyDescriptorPool);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkResetDescriptorPool);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkAllocateDescriptorSets);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkFreeDescriptorSets);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkUpdateDescriptorSets);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateFramebuffer);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyFramebuffer);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateRenderPass);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyRenderPass);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkGetRenderAreaGranularity);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateCommandPool);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkDestroyCommandPool);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkResetCommandPool);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkAllocateCommandBuffers);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkFreeCommandBuffers);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkBeginCommandBuffer);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkEndCommandBuffer);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkResetCommandBuffer);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdBindPipeline);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdSetViewport);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdSetScissor);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdSetLineWidth);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdSetDepthBias);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdSetBlendConstants);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdSetDepthBounds);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdSetStencilCompareMask);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdSetStencilWriteMask);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdSetStencilReference);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdBindDescriptorSets);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdBindIndexBuffer);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdBindVertexBuffers);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdDraw);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdDrawIndexed);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdDrawIndirect);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdDrawIndexedIndirect);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdDispatch);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdDispatchIndirect);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdCopyBuffer);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdCopyImage);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdBlitImage);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdCopyBufferToImage);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdCopyImageToBuffer);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdUpdateBuffer);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdFillBuffer);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdClearColorImage);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdClearDepthStencilImage);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdClearAttachments);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdResolveImage);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdSetEvent);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdResetEvent);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdWaitEvents);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdPipelineBarrier);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdBeginQuery);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdEndQuery);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdResetQueryPool);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdWriteTimestamp);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdCopyQueryPoolResults);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdPushConstants);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdBeginRenderPass);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdNextSubpass);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdEndRenderPass);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCmdExecuteCommands);
+
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkBindBufferMemory2, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkBindImageMemory2, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetDeviceGroupPeerMemoryFeatures, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetDeviceMask, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdDispatchBase, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetImageMemoryRequirements2, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetBufferMemoryRequirements2, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetImageSparseMemoryRequirements2, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkTrimCommandPool, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetDeviceQueue2, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCreateSamplerYcbcrConversion, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkDestroySamplerYcbcrConversion, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCreateDescriptorUpdateTemplate, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkDestroyDescriptorUpdateTemplate, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkUpdateDescriptorSetWithTemplate, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetDescriptorSetLayoutSupport, 1, 1);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdDrawIndirectCount, 1, 2);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdDrawIndexedIndirectCount, 1, 2);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCreateRenderPass2, 1, 2);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdBeginRenderPass2, 1, 2);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdNextSubpass2, 1, 2);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdEndRenderPass2, 1, 2);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkResetQueryPool, 1, 2);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetSemaphoreCounterValue, 1, 2);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkWaitSemaphores, 1, 2);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkSignalSemaphore, 1, 2);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetBufferDeviceAddress, 1, 2);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetBufferOpaqueCaptureAddress, 1, 2);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetDeviceMemoryOpaqueCaptureAddress, 1, 2);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCreatePrivateDataSlot, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkDestroyPrivateDataSlot, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkSetPrivateData, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetPrivateData, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetEvent2, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdResetEvent2, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdWaitEvents2, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdPipelineBarrier2, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdWriteTimestamp2, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkQueueSubmit2, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdCopyBuffer2, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdCopyImage2, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdCopyBufferToImage2, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdCopyImageToBuffer2, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdBlitImage2, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdResolveImage2, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdBeginRendering, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdEndRendering, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetCullMode, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetFrontFace, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetPrimitiveTopology, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetViewportWithCount, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetScissorWithCount, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdBindVertexBuffers2, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetDepthTestEnable, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetDepthWriteEnable, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetDepthCompareOp, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetDepthBoundsTestEnable, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetStencilTestEnable, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetStencilOp, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetRasterizerDiscardEnable, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetDepthBiasEnable, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkCmdSetPrimitiveRestartEnable, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetDeviceBufferMemoryRequirements, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetDeviceImageMemoryRequirements, 1, 3);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetDeviceImageSparseMemoryRequirements, 1, 3);
 
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!( vkCreateSwapchainKHR, VK_KHR_SWAPCHAIN_EXTENSION_NAME );
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!( vkDestroySwapchainKHR, VK_KHR_SWAPCHAIN_EXTENSION_NAME );
-    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!( vkGetSwapchainImagesKHR, VK_KHR_SWAPCHAIN_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkSetDebugUtilsObjectNameEXT, VK_EXT_DEBUG_UTILS_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkSetDebugUtilsObjectTagEXT, VK_EXT_DEBUG_UTILS_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkQueueBeginDebugUtilsLabelEXT, VK_EXT_DEBUG_UTILS_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkQueueEndDebugUtilsLabelEXT, VK_EXT_DEBUG_UTILS_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkQueueInsertDebugUtilsLabelEXT, VK_EXT_DEBUG_UTILS_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkCmdBeginDebugUtilsLabelEXT, VK_EXT_DEBUG_UTILS_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkCmdEndDebugUtilsLabelEXT, VK_EXT_DEBUG_UTILS_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkCmdInsertDebugUtilsLabelEXT, VK_EXT_DEBUG_UTILS_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkCreateSwapchainKHR, VK_KHR_SWAPCHAIN_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkDestroySwapchainKHR, VK_KHR_SWAPCHAIN_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkGetSwapchainImagesKHR, VK_KHR_SWAPCHAIN_EXTENSION_NAME);
     LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkAcquireNextImageKHR, VK_KHR_SWAPCHAIN_EXTENSION_NAME);
     LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkQueuePresentKHR, VK_KHR_SWAPCHAIN_EXTENSION_NAME);
-
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkGetDeviceGroupPresentCapabilitiesKHR, VK_KHR_SWAPCHAIN_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkGetDeviceGroupSurfacePresentModesKHR, VK_KHR_SWAPCHAIN_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkAcquireNextImage2KHR, VK_KHR_SWAPCHAIN_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkGetSemaphoreCounterValueKHR, VK_KHR_TIMELINE_SEMAPHORE_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkWaitSemaphoresKHR, VK_KHR_TIMELINE_SEMAPHORE_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkSignalSemaphoreKHR, VK_KHR_TIMELINE_SEMAPHORE_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkCmdSetEvent2KHR, VK_KHR_SYNCHRONIZATION_2_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkCmdResetEvent2KHR, VK_KHR_SYNCHRONIZATION_2_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkCmdWaitEvents2KHR, VK_KHR_SYNCHRONIZATION_2_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkCmdPipelineBarrier2KHR, VK_KHR_SYNCHRONIZATION_2_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkCmdWriteTimestamp2KHR, VK_KHR_SYNCHRONIZATION_2_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkQueueSubmit2KHR, VK_KHR_SYNCHRONIZATION_2_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkCmdWriteBufferMarker2AMD, VK_KHR_SYNCHRONIZATION_2_EXTENSION_NAME);
+    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkGetQueueCheckpointData2NV, VK_KHR_SYNCHRONIZATION_2_EXTENSION_NAME);
 }
@@ -0,0 +1,221 @@
+// layout aware where the backend can tell, the scancode in the key events is the physical key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyCode {
+    A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    DIGIT_0, DIGIT_1, DIGIT_2, DIGIT_3, DIGIT_4, DIGIT_5, DIGIT_6, DIGIT_7, DIGIT_8, DIGIT_9,
+    F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12,
+    ESCAPE,
+    ENTER,
+    SPACE,
+    TAB,
+    BACKSPACE,
+    DELETE,
+    INSERT,
+    HOME,
+    END,
+    PAGE_UP,
+    PAGE_DOWN,
+    LEFT,
+    RIGHT,
+    UP,
+    DOWN,
+    SHIFT,
+    CONTROL,
+    ALT,
+    CAPS_LOCK,
+    UNKNOWN
+}
+
+impl KeyCode {
+    const LETTERS: [KeyCode; 26] = [
+        KeyCode::A, KeyCode::B, KeyCode::C, KeyCode::D, KeyCode::E, KeyCode::F, KeyCode::G, KeyCode::H, KeyCode::I,
+        KeyCode::J, KeyCode::K, KeyCode::L, KeyCode::M, KeyCode::N, KeyCode::O, KeyCode::P, KeyCode::Q, KeyCode::R,
+        KeyCode::S, KeyCode::T, KeyCode::U, KeyCode::V, KeyCode::W, KeyCode::X, KeyCode::Y, KeyCode::Z
+    ];
+    const DIGITS: [KeyCode; 10] = [
+        KeyCode::DIGIT_0, KeyCode::DIGIT_1, KeyCode::DIGIT_2, KeyCode::DIGIT_3, KeyCode::DIGIT_4,
+        KeyCode::DIGIT_5, KeyCode::DIGIT_6, KeyCode::DIGIT_7, KeyCode::DIGIT_8, KeyCode::DIGIT_9
+    ];
+    const FUNCTIONS: [KeyCode; 12] = [
+        KeyCode::F1, KeyCode::F2, KeyCode::F3, KeyCode::F4, KeyCode::F5, KeyCode::F6,
+        KeyCode::F7, KeyCode::F8, KeyCode::F9, KeyCode::F10, KeyCode::F11, KeyCode::F12
+    ];
+
+    // 'a' or 'A' -> KeyCode::A, '7' -> KeyCode::DIGIT_7
+    pub fn from_char(c: char) -> KeyCode
+    {
+        match c {
+            'a'..='z' => KeyCode::LETTERS[c as usize - 'a' as usize],
+            'A'..='Z' => KeyCode::LETTERS[c as usize - 'A' as usize],
+            '0'..='9' => KeyCode::DIGITS[c as usize - '0' as usize],
+            ' ' => KeyCode::SPACE,
+            _ => KeyCode::UNKNOWN
+        }
+    }
+
+    // 1 -> KeyCode::F1
+    pub fn function(number: u32) -> KeyCode
+    {
+        match number {
+            1..=12 => KeyCode::FUNCTIONS[number as usize - 1],
+            _ => KeyCode::UNKNOWN
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    LEFT,
+    RIGHT,
+    MIDDLE,
+    BACK,
+    FORWARD,
+    OTHER(u32)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WindowEvent {
+    CLOSE_REQUESTED,
+    RESIZED { width: u32, height: u32 },
+    FOCUSED(bool),
+    MINIMIZED(bool),
+    KEY_DOWN { scancode: u32, keycode: KeyCode },
+    KEY_UP { scancode: u32, keycode: KeyCode },
+    // not sent on wayland, text there depends on the compositor's keymap and reading it needs libxkbcommon
+    TEXT_INPUT(char),
+    // client area coordinates, origin at the top left
+    MOUSE_MOVED { x: f64, y: f64 },
+    MOUSE_BUTTON_DOWN(MouseButton),
+    MOUSE_BUTTON_UP(MouseButton),
+    // in notches, positive y scrolls away from the user and positive x to the right
+    MOUSE_WHEEL { x: f32, y: f32 }
+}
+
+// POLL hands back whatever is queued, WAIT blocks until there's at least one event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventMode {
+    POLL,
+    WAIT
+}
+
+// X11 keysyms from keysymdef.h, shared by the xlib and xcb backends
+#[cfg(any(feature = "xlib", feature = "xcb"))]
+pub(crate) fn keysym_keycode(keysym: u32) -> KeyCode
+{
+    match keysym {
+        0x20..=0x7e => KeyCode::from_char(keysym as u8 as char),
+        0xffbe..=0xffc9 => KeyCode::function(keysym - 0xffbe + 1),
+        0xff1b => KeyCode::ESCAPE,
+        0xff0d | 0xff8d => KeyCode::ENTER,
+        0xff09 => KeyCode::TAB,
+        0xff08 => KeyCode::BACKSPACE,
+        0xffff => KeyCode::DELETE,
+        0xff63 => KeyCode::INSERT,
+        0xff50 => KeyCode::HOME,
+        0xff57 => KeyCode::END,
+        0xff55 => KeyCode::PAGE_UP,
+        0xff56 => KeyCode::PAGE_DOWN,
+        0xff51 => KeyCode::LEFT,
+        0xff52 => KeyCode::UP,
+        0xff53 => KeyCode::RIGHT,
+        0xff54 => KeyCode::DOWN,
+        0xffe1 | 0xffe2 => KeyCode::SHIFT,
+        0xffe3 | 0xffe4 => KeyCode::CONTROL,
+        0xffe9 | 0xffea | 0xfe03 => KeyCode::ALT,
+        0xffe5 => KeyCode::CAPS_LOCK,
+        _ => KeyCode::UNKNOWN
+    }
+}
+
+// latin-1 keysyms are their code point, the rest of unicode is offset by 0x01000000
+#[cfg(any(feature = "xlib", feature = "xcb"))]
+pub(crate) fn keysym_char(keysym: u32) -> Option<char>
+{
+    match keysym {
+        0x20..=0x7e | 0xa0..=0xff => char::from_u32(keysym),
+        0x01000100..=0x0110ffff => char::from_u32(keysym - 0x01000000),
+        _ => None
+    }
+}
+
+// X11 core pointer buttons, 4 to 7 are the wheel
+#[cfg(any(feature = "xlib", feature = "xcb"))]
+pub(crate) fn x11_button_events(button: u32, pressed: bool) -> Option<WindowEvent>
+{
+    let button = match button {
+        1 => MouseButton::LEFT,
+        2 => MouseButton::MIDDLE,
+        3 => MouseButton::RIGHT,
+        4..=7 if !pressed => return None,
+        4 => return Some(WindowEvent::MOUSE_WHEEL { x: 0.0, y: 1.0 }),
+        5 => return Some(WindowEvent::MOUSE_WHEEL { x: 0.0, y: -1.0 }),
+        6 => return Some(WindowEvent::MOUSE_WHEEL { x: -1.0, y: 0.0 }),
+        7 => return Some(WindowEvent::MOUSE_WHEEL { x: 1.0, y: 0.0 }),
+        8 => MouseButton::BACK,
+        9 => MouseButton::FORWARD,
+        other => MouseButton::OTHER(other)
+    };
+    Some(if pressed { WindowEvent::MOUSE_BUTTON_DOWN(button) } else { WindowEvent::MOUSE_BUTTON_UP(button) })
+}
+
+// linux input event codes, the physical key wayland reports and X11 keycodes minus 8
+#[cfg(feature = "wayland")]
+pub(crate) fn evdev_keycode(key: u32) -> KeyCode
+{
+    match key {
+        1 => KeyCode::ESCAPE,
+        14 => KeyCode::BACKSPACE,
+        15 => KeyCode::TAB,
+        28 | 96 => KeyCode::ENTER,
+        29 | 97 => KeyCode::CONTROL,
+        42 | 54 => KeyCode::SHIFT,
+        56 | 100 => KeyCode::ALT,
+        58 => KeyCode::CAPS_LOCK,
+        59..=68 => KeyCode::function(key - 58),
+        87 => KeyCode::F11,
+        88 => KeyCode::F12,
+        102 => KeyCode::HOME,
+        103 => KeyCode::UP,
+        104 => KeyCode::PAGE_UP,
+        105 => KeyCode::LEFT,
+        106 => KeyCode::RIGHT,
+        107 => KeyCode::END,
+        108 => KeyCode::DOWN,
+        109 => KeyCode::PAGE_DOWN,
+        110 => KeyCode::INSERT,
+        111 => KeyCode::DELETE,
+        _ => evdev_char(key).map_or(KeyCode::UNKNOWN, KeyCode::from_char)
+    }
+}
+
+// what the physical key reads on a US layout, only names the KeyCode, it isn't the text the user typed
+#[cfg(feature = "wayland")]
+fn evdev_char(key: u32) -> Option<char>
+{
+    const ROWS: [(u32, &str); 4] = [
+        (2, "1234567890-="),
+        (16, "qwertyuiop[]"),
+        (30, "asdfghjkl;'`"),
+        (43, "\\zxcvbnm,./")
+    ];
+    if key == 57
+    {
+        return Some(' ');
+    }
+    ROWS.iter()
+        .find(|(first, row)| key >= *first && key < first + row.len() as u32)
+        .and_then(|(first, row)| row.chars().nth((key - first) as usize))
+}
+
+#[cfg(feature = "wayland")]
+pub(crate) fn evdev_button(button: u32) -> MouseButton
+{
+    match button {
+        0x110 => MouseButton::LEFT,
+        0x111 => MouseButton::RIGHT,
+        0x112 => MouseButton::MIDDLE,
+        0x113 => MouseButton::BACK,
+        0x114 => MouseButton::FORWARD,
+        other => MouseButton::OTHER(other)
+    }
+}
@@ -1,89 +1,584 @@
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
 use crate::vulkan_bindings;
-use windows::Win32::UI::WindowsAndMessaging::*;
+#[cfg(feature = "win32")]
+use crate::system_window_win32;
+#[cfg(feature = "xlib")]
+use crate::system_window_xlib;
+#[cfg(feature = "xcb")]
+use crate::system_window_xcb;
+#[cfg(feature = "wayland")]
+use crate::system_window_wayland;
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
+use crate::system_events::{EventMode, WindowEvent};
 
-pub const DISPLAY_WIDTH: i32 = 1920;
-pub const DISPLAY_HEIGHT: i32 = 1080;
+#[derive(Debug)]
+pub enum SystemWindowError {
+    NO_WINDOW_BACKEND,
+    UNKNOWN_WINDOW_BACKEND(String),
+    CANT_OPEN_DISPLAY(String),
+    CANT_CREATE_WINDOW(String),
+    NO_SUCH_MONITOR(usize),
+    CANT_SET_FULLSCREEN(String),
+    UNSUPPORTED_WINDOW_HANDLE(String),
+    EXTERNAL_WINDOW
+}
+
+impl std::fmt::Display for SystemWindowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self
+        {
+            SystemWindowError::NO_WINDOW_BACKEND => write!(f, "The engine was built without a window backend, enable one of the win32, xlib, xcb or wayland features"),
+            SystemWindowError::UNKNOWN_WINDOW_BACKEND(name) => write!(f, "{} isn't a window backend this engine was built with", name),
+            SystemWindowError::CANT_OPEN_DISPLAY(display) => write!(f, "Couldn't connect to the display server \"{}\"", display),
+            SystemWindowError::CANT_CREATE_WINDOW(reason) => write!(f, "Couldn't create a window: {}", reason),
+            SystemWindowError::NO_SUCH_MONITOR(index) => write!(f, "The window system doesn't list a monitor {}", index),
+            SystemWindowError::CANT_SET_FULLSCREEN(reason) => write!(f, "Couldn't switch to fullscreen: {}", reason),
+            SystemWindowError::UNSUPPORTED_WINDOW_HANDLE(handle) => write!(f, "This engine wasn't built for {} windows", handle),
+            SystemWindowError::EXTERNAL_WINDOW => write!(f, "The window belongs to the host application, change it from there")
+        }
+    }
+}
+
+impl std::error::Error for SystemWindowError {}
+
+// window systems the engine was built with, each one behind the cargo feature of the same name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowBackend {
+    #[cfg(feature = "win32")]
+    WIN32,
+    #[cfg(feature = "xlib")]
+    XLIB,
+    #[cfg(feature = "xcb")]
+    XCB,
+    #[cfg(feature = "wayland")]
+    WAYLAND
+}
+
+impl WindowBackend {
+    pub fn name(& self) -> &'static str
+    {
+        match *self {
+            #[cfg(feature = "win32")]
+            WindowBackend::WIN32 => "win32",
+            #[cfg(feature = "xlib")]
+            WindowBackend::XLIB => "xlib",
+            #[cfg(feature = "xcb")]
+            WindowBackend::XCB => "xcb",
+            #[cfg(feature = "wayland")]
+            WindowBackend::WAYLAND => "wayland"
+        }
+    }
+
+    pub fn is_x11(& self) -> bool
+    {
+        matches!(self.name(), "xcb" | "xlib")
+    }
+
+    pub fn from_name(name: &str) -> Result<WindowBackend, SystemWindowError>
+    {
+        WindowBackend::available().into_iter()
+            .find(|backend| backend.name() == name)
+            .ok_or(SystemWindowError::UNKNOWN_WINDOW_BACKEND(name.to_string()))
+    }
+
+    // xcb comes before xlib when both are built in
+    pub fn available() -> Vec<WindowBackend>
+    {
+        vec![
+            #[cfg(feature = "win32")]
+            WindowBackend::WIN32,
+            #[cfg(feature = "wayland")]
+            WindowBackend::WAYLAND,
+            #[cfg(feature = "xcb")]
+            WindowBackend::XCB,
+            #[cfg(feature = "xlib")]
+            WindowBackend::XLIB
+        ]
+    }
+
+    // ANVIL_WINDOW_BACKEND wins, then a wayland session picks wayland and an X11 one (XWayland included) xcb or xlib,
+    // falling back to whatever was built in
+    pub fn default_backend() -> Option<WindowBackend>
+    {
+        if let Ok(name) = std::env::var("ANVIL_WINDOW_BACKEND")
+        {
+            match WindowBackend::from_name(&name) {
+                Ok(backend) => return Some(backend),
+                Err(e) => eprintln!("{}, ignoring ANVIL_WINDOW_BACKEND", e)
+            }
+        }
+        let available = WindowBackend::available();
+        let session_is = |variable: &str| std::env::var_os(variable).is_some_and(|value| !value.is_empty());
+        let wayland_session = session_is("WAYLAND_DISPLAY") || std::env::var("XDG_SESSION_TYPE").is_ok_and(|session| session == "wayland");
+        if wayland_session
+        {
+            if let Ok(backend) = WindowBackend::from_name("wayland")
+            {
+                return Some(backend);
+            }
+        }
+        if session_is("DISPLAY")
+        {
+            if let Some(backend) = available.iter().find(|backend| backend.is_x11())
+            {
+                return Some(*backend);
+            }
+        }
+        available.first().copied()
+    }
+
+    // the instance extension vkCreate*SurfaceKHR comes from, enabled next to VK_KHR_surface
+    pub fn surface_extension_name(& self) -> &'static [u8]
+    {
+        match *self {
+            #[cfg(feature = "win32")]
+            WindowBackend::WIN32 => vulkan_bindings::VK_KHR_WIN32_SURFACE_EXTENSION_NAME,
+            #[cfg(feature = "xlib")]
+            WindowBackend::XLIB => vulkan_bindings::VK_KHR_XLIB_SURFACE_EXTENSION_NAME,
+            #[cfg(feature = "xcb")]
+            WindowBackend::XCB => vulkan_bindings::VK_KHR_XCB_SURFACE_EXTENSION_NAME,
+            #[cfg(feature = "wayland")]
+            WindowBackend::WAYLAND => vulkan_bindings::VK_KHR_WAYLAND_SURFACE_EXTENSION_NAME
+        }
+    }
+}
+
+// BORDERLESS covers the monitor at its current mode, EXCLUSIVE also switches the monitor to the window's size
+// where the window system lets clients change modes (win32), elsewhere it behaves like BORDERLESS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FullscreenMode {
+    WINDOWED,
+    BORDERLESS,
+    EXCLUSIVE
+}
+
+// a monitor's area in the window system's virtual screen coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonitorArea {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub primary: bool
+}
+
+// everything a window gets created with, WindowDescriptor::new("title").size(1280, 720).resizable(false)
+#[derive(Debug, Clone)]
+pub struct WindowDescriptor {
+    pub title: String,
+    // reverse DNS name the wayland compositor groups windows and finds the desktop entry with
+    pub app_id: String,
+    // the client area, which is what the swapchain covers
+    pub width: u32,
+    pub height: u32,
+    // top left of the window frame, None centers it on the monitor. wayland doesn't let clients place windows
+    pub position: Option<(i32, i32)>,
+    pub resizable: bool,
+    pub decorations: bool,
+    pub fullscreen: FullscreenMode,
+    // index in the monitors the window system lists, None picks the primary one
+    pub monitor: Option<usize>
+}
+
+impl Default for WindowDescriptor {
+    fn default() -> Self {
+        WindowDescriptor {
+            title: String::from("Anvil"),
+            app_id: String::from("org.anvil.Anvil"),
+            width: 1920,
+            height: 1080,
+            position: None,
+            resizable: true,
+            decorations: true,
+            fullscreen: FullscreenMode::WINDOWED,
+            monitor: None
+        }
+    }
+}
+
+impl WindowDescriptor {
+    pub fn new(title: &str) -> Self
+    {
+        WindowDescriptor {
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn app_id(mut self, app_id: &str) -> Self
+    {
+        self.app_id = app_id.to_string();
+        self
+    }
+
+    pub fn size(mut self, width: u32, height: u32) -> Self
+    {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    pub fn position(mut self, x: i32, y: i32) -> Self
+    {
+        self.position = Some((x, y));
+        self
+    }
+
+    pub fn resizable(mut self, resizable: bool) -> Self
+    {
+        self.resizable = resizable;
+        self
+    }
+
+    pub fn decorations(mut self, decorations: bool) -> Self
+    {
+        self.decorations = decorations;
+        self
+    }
+
+    pub fn fullscreen(mut self, fullscreen: FullscreenMode) -> Self
+    {
+        self.fullscreen = fullscreen;
+        self
+    }
+
+    pub fn monitor(mut self, monitor: usize) -> Self
+    {
+        self.monitor = Some(monitor);
+        self
+    }
+
+    // the index of the monitor to use out of the ones a backend listed, None when it couldn't list any and no
+    // monitor was asked for
+    #[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
+    pub(crate) fn pick_monitor(& self, monitors: &[MonitorArea]) -> Result<Option<usize>, SystemWindowError>
+    {
+        match self.monitor {
+            Some(index) if index < monitors.len() => Ok(Some(index)),
+            Some(index) => Err(SystemWindowError::NO_SUCH_MONITOR(index)),
+            None => Ok(monitors.iter().position(|monitor| monitor.primary).or(if monitors.is_empty() { None } else { Some(0) }))
+        }
+    }
+
+    // where the window frame goes when windowed, frame_width and frame_height include the decorations
+    #[cfg(any(feature = "win32", feature = "xlib", feature = "xcb"))]
+    pub(crate) fn frame_origin(& self, monitor: Option<MonitorArea>, frame_width: u32, frame_height: u32) -> (i32, i32)
+    {
+        match (self.position, monitor) {
+            (Some(position), _) => position,
+            (None, Some(monitor)) => (
+                monitor.x + (monitor.width as i32 - frame_width as i32).max(0) / 2,
+                monitor.y + (monitor.height as i32 - frame_height as i32).max(0) / 2
+            ),
+            (None, None) => (0, 0)
+        }
+    }
+}
 
+// the native handles a vulkan surface gets created from
+#[derive(Debug, Clone, Copy)]
+pub enum WindowHandle {
+    #[cfg(feature = "win32")]
+    WIN32 {
+        hinstance: vulkan_bindings::HINSTANCE,
+        hwnd: vulkan_bindings::HWND
+    },
+    #[cfg(feature = "xlib")]
+    XLIB {
+        display: *mut vulkan_bindings::Display,
+        window: vulkan_bindings::Window
+    },
+    #[cfg(feature = "xcb")]
+    XCB {
+        connection: *mut vulkan_bindings::xcb_connection_t,
+        window: vulkan_bindings::xcb_window_t
+    },
+    #[cfg(feature = "wayland")]
+    WAYLAND {
+        display: *mut vulkan_bindings::wl_display,
+        surface: *mut vulkan_bindings::wl_surface
+    }
+}
+
+impl WindowHandle {
+    pub fn backend(& self) -> WindowBackend
+    {
+        match *self {
+            #[cfg(feature = "win32")]
+            WindowHandle::WIN32 { .. } => WindowBackend::WIN32,
+            #[cfg(feature = "xlib")]
+            WindowHandle::XLIB { .. } => WindowBackend::XLIB,
+            #[cfg(feature = "xcb")]
+            WindowHandle::XCB { .. } => WindowBackend::XCB,
+            #[cfg(feature = "wayland")]
+            WindowHandle::WAYLAND { .. } => WindowBackend::WAYLAND
+        }
+    }
+}
+
+#[cfg(feature = "raw-window-handle")]
+impl WindowHandle {
+    // the handles of a window another toolkit created, for the window systems this engine was built with
+    pub fn from_raw(window: raw_window_handle::RawWindowHandle, display: raw_window_handle::RawDisplayHandle) -> Result<Self, SystemWindowError>
+    {
+        use raw_window_handle::RawWindowHandle;
+        match (window, display) {
+            // vulkan only checks the module is loaded, the executable's does when the toolkit didn't say
+            #[cfg(feature = "win32")]
+            (RawWindowHandle::Win32(window), _) => Ok(WindowHandle::WIN32 {
+                hinstance: match window.hinstance {
+                    Some(hinstance) => hinstance.get() as vulkan_bindings::HINSTANCE,
+                    None => unsafe { vulkan_bindings::GetModuleHandleW(std::ptr::null()) }
+                },
+                hwnd: window.hwnd.get() as vulkan_bindings::HWND
+            }),
+            #[cfg(feature = "xlib")]
+            (RawWindowHandle::Xlib(window), raw_window_handle::RawDisplayHandle::Xlib(display)) => Ok(WindowHandle::XLIB {
+                display: display.display.ok_or(SystemWindowError::UNSUPPORTED_WINDOW_HANDLE(String::from("xlib without a display")))?.as_ptr() as *mut vulkan_bindings::Display,
+                window: window.window as vulkan_bindings::Window
+            }),
+            #[cfg(feature = "xcb")]
+            (RawWindowHandle::Xcb(window), raw_window_handle::RawDisplayHandle::Xcb(display)) => Ok(WindowHandle::XCB {
+                connection: display.connection.ok_or(SystemWindowError::UNSUPPORTED_WINDOW_HANDLE(String::from("xcb without a connection")))?.as_ptr() as *mut vulkan_bindings::xcb_connection_t,
+                window: window.window.get()
+            }),
+            #[cfg(feature = "wayland")]
+            (RawWindowHandle::Wayland(window), raw_window_handle::RawDisplayHandle::Wayland(display)) => Ok(WindowHandle::WAYLAND {
+                display: display.display.as_ptr() as *mut vulkan_bindings::wl_display,
+                surface: window.surface.as_ptr() as *mut vulkan_bindings::wl_surface
+            }),
+            (window, _) => Err(SystemWindowError::UNSUPPORTED_WINDOW_HANDLE(format!("{:?}", window)))
+        }
+    }
+
+    pub fn from_window<W: raw_window_handle::HasWindowHandle + raw_window_handle::HasDisplayHandle>(window: &W) -> Result<Self, SystemWindowError>
+    {
+        let unavailable = |e: raw_window_handle::HandleError| SystemWindowError::UNSUPPORTED_WINDOW_HANDLE(e.to_string());
+        let window_handle = window.window_handle().map_err(unavailable)?;
+        let display_handle = window.display_handle().map_err(unavailable)?;
+        WindowHandle::from_raw(window_handle.as_raw(), display_handle.as_raw())
+    }
+}
+
+// windows only exist in builds with a window backend, WindowBackend and WindowHandle have no values otherwise
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
+enum NativeWindow {
+    #[cfg(feature = "win32")]
+    WIN32(system_window_win32::Win32Window),
+    #[cfg(feature = "xlib")]
+    XLIB(system_window_xlib::XlibWindow),
+    #[cfg(feature = "xcb")]
+    XCB(system_window_xcb::XcbWindow),
+    #[cfg(feature = "wayland")]
+    WAYLAND(system_window_wayland::WaylandWindow),
+    // created and owned by the host application, its event loop reports the size through set_size
+    EXTERNAL(WindowHandle)
+}
+
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
 pub struct WindowParameters {
-    pub Hinstance: vulkan_bindings::HINSTANCE,
-    pub Hwnd : vulkan_bindings::HWND,
-    pub Title : String
+    pub Title : String,
+    pub backend: WindowBackend,
+    descriptor: WindowDescriptor,
+    close_requested: bool,
+    native: NativeWindow
 }
 
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
 impl WindowParameters {
+    pub fn new(descriptor: &WindowDescriptor) -> Result<Self, SystemWindowError>
+    {
+        let backend = WindowBackend::default_backend().ok_or(SystemWindowError::NO_WINDOW_BACKEND)?;
+        WindowParameters::with_backend(backend, descriptor)
+    }
 
-    pub extern "C" fn window_proc(
-        h_wnd: vulkan_bindings::HWND,
-        u_msg: vulkan_bindings::UINT,
-        w_param: vulkan_bindings::WPARAM,
-        l_param: vulkan_bindings::LPARAM,
-    ) -> vulkan_bindings::LRESULT {
-        unsafe {
-            match u_msg {
-                vulkan_bindings::WM_CLOSE => vulkan_bindings::DestroyWindow(h_wnd),
-                _ => {0}
-            };
-            vulkan_bindings::DefWindowProcW(h_wnd, u_msg, w_param, l_param)
-        }
-    }
-
-    pub fn new(window_title: String) -> Self {
-        unsafe {
-            let h_instance = vulkan_bindings::GetModuleHandleW(std::ptr::null());
-            let class_name = window_title.clone() + "_Window_Class";
-            let mut class_name : Vec<vulkan_bindings::wchar_t> = class_name.encode_utf16().collect();
-            class_name.push(0);
-            let mut wndClass : vulkan_bindings::WNDCLASSW = std::mem::zeroed();
-            wndClass.lpszClassName = class_name.as_ptr();
-            wndClass.hInstance = h_instance;
-            wndClass.hIcon = vulkan_bindings::LoadIconW(std::ptr::null_mut(), IDI_WINLOGO.0);
-            wndClass.hCursor = vulkan_bindings::LoadCursorW(std::ptr::null_mut(), IDC_ARROW.0);
-            wndClass.lpfnWndProc = Some(WindowParameters::window_proc);
-
-            vulkan_bindings::RegisterClassW(& wndClass);
-            let style : vulkan_bindings::DWORD = vulkan_bindings::WS_CAPTION | vulkan_bindings::WS_MINIMIZEBOX | vulkan_bindings::WS_SYSMENU;
-            
-            let mut rect :vulkan_bindings::RECT = std::mem::zeroed();
-            rect.left = 100;
-            rect.top = 100;
-            rect.right = rect.left +  DISPLAY_WIDTH;
-            rect.bottom = rect.top + DISPLAY_HEIGHT;
-
-            vulkan_bindings::AdjustWindowRect(&mut rect, style, 0);
-
-            let mut title : Vec<vulkan_bindings::wchar_t> = window_title.clone().encode_utf16().collect();
-            title.push(0);
-            let m_hwnd = vulkan_bindings::CreateWindowExW(
-                0, 
-                class_name.as_ptr(), 
-                title.as_ptr(),
-                style,
-                rect.left,
-                rect.top,
-                rect.right - rect.left,
-                rect.bottom - rect.top,
-                std::ptr::null_mut(),
-                std::ptr::null_mut(),
-                h_instance,
-                std::ptr::null_mut()
-            );
-
-            // vulkan_bindings::ShowWindow(m_hwnd, vulkan_bindings::SW_SHOW as i32);
-            WindowParameters {
-                Hinstance : h_instance,
-                Hwnd: m_hwnd,
-                Title: window_title
-            }
+    pub fn with_backend(backend: WindowBackend, descriptor: &WindowDescriptor) -> Result<Self, SystemWindowError>
+    {
+        let native = match backend {
+            #[cfg(feature = "win32")]
+            WindowBackend::WIN32 => NativeWindow::WIN32(system_window_win32::Win32Window::new(descriptor)?),
+            #[cfg(feature = "xlib")]
+            WindowBackend::XLIB => NativeWindow::XLIB(system_window_xlib::XlibWindow::new(descriptor)?),
+            #[cfg(feature = "xcb")]
+            WindowBackend::XCB => NativeWindow::XCB(system_window_xcb::XcbWindow::new(descriptor)?),
+            #[cfg(feature = "wayland")]
+            WindowBackend::WAYLAND => NativeWindow::WAYLAND(system_window_wayland::WaylandWindow::new(descriptor)?)
+        };
+        Ok(WindowParameters {
+            Title: descriptor.title.clone(),
+            backend,
+            descriptor: descriptor.clone(),
+            close_requested: false,
+            native
+        })
+    }
+
+    // wraps a window the engine doesn't own, the handles have to stay valid until destroy
+    pub fn from_handle(handle: WindowHandle, width: u32, height: u32) -> Self
+    {
+        WindowParameters {
+            Title: String::new(),
+            backend: handle.backend(),
+            descriptor: WindowDescriptor::new("").size(width, height),
+            close_requested: false,
+            native: NativeWindow::EXTERNAL(handle)
         }
     }
 
-    pub fn destroy(self)
+    pub fn is_external(& self) -> bool
     {
-        let mut class_name : Vec<vulkan_bindings::wchar_t> = "Anvil_Window_Class".encode_utf16().collect();
-        class_name.push(0);
+        matches!(self.native, NativeWindow::EXTERNAL(_))
+    }
 
-        unsafe {
-            vulkan_bindings::UnregisterClassW(class_name.as_ptr(), self.Hinstance);
+    // everything the window system sent since the last call, to be called once per frame
+    pub fn poll_events(&mut self) -> Vec<WindowEvent>
+    {
+        self.pump_events(EventMode::POLL)
+    }
+
+    // blocks until at least one event arrived, for applications that only redraw on input
+    pub fn wait_events(&mut self) -> Vec<WindowEvent>
+    {
+        self.pump_events(EventMode::WAIT)
+    }
+
+    pub fn pump_events(&mut self, mode: EventMode) -> Vec<WindowEvent>
+    {
+        let events = match self.native {
+            #[cfg(feature = "win32")]
+            NativeWindow::WIN32(ref mut window) => window.pump_events(mode),
+            #[cfg(feature = "xlib")]
+            NativeWindow::XLIB(ref mut window) => window.pump_events(mode),
+            #[cfg(feature = "xcb")]
+            NativeWindow::XCB(ref mut window) => window.pump_events(mode),
+            #[cfg(feature = "wayland")]
+            NativeWindow::WAYLAND(ref mut window) => window.pump_events(mode),
+            // the host application reads the events
+            NativeWindow::EXTERNAL(_) => Vec::new()
+        };
+        if events.contains(&WindowEvent::CLOSE_REQUESTED)
+        {
+            self.close_requested = true;
+        }
+        events
+    }
+
+    // the client area as of the last pumped events, wayland lets the compositor pick it through configure events
+    pub fn size(& self) -> (u32, u32)
+    {
+        match self.native {
+            #[cfg(feature = "win32")]
+            NativeWindow::WIN32(ref window) => window.size(),
+            #[cfg(feature = "xlib")]
+            NativeWindow::XLIB(ref window) => window.size(),
+            #[cfg(feature = "xcb")]
+            NativeWindow::XCB(ref window) => window.size(),
+            #[cfg(feature = "wayland")]
+            NativeWindow::WAYLAND(ref window) => window.size(),
+            NativeWindow::EXTERNAL(_) => (self.descriptor.width, self.descriptor.height)
+        }
+    }
+
+    // set once a CLOSE_REQUESTED went through pump_events, the window stays open until destroy
+    pub fn close_requested(& self) -> bool
+    {
+        self.close_requested
+    }
+
+    // what the window was created with, kept up to date by the setters below
+    pub fn descriptor(& self) -> &WindowDescriptor
+    {
+        &self.descriptor
+    }
+
+    pub fn set_title(&mut self, title: &str)
+    {
+        match self.native {
+            #[cfg(feature = "win32")]
+            NativeWindow::WIN32(ref mut window) => window.set_title(title),
+            #[cfg(feature = "xlib")]
+            NativeWindow::XLIB(ref mut window) => window.set_title(title),
+            #[cfg(feature = "xcb")]
+            NativeWindow::XCB(ref mut window) => window.set_title(title),
+            #[cfg(feature = "wayland")]
+            NativeWindow::WAYLAND(ref mut window) => window.set_title(title),
+            // the host owns the title bar
+            NativeWindow::EXTERNAL(_) => return
+        }
+        self.Title = title.to_string();
+        self.descriptor.title = title.to_string();
+    }
+
+    // the windowed client area, a fullscreen window takes it back when it leaves fullscreen. the swapchain follows
+    // the RESIZED event. for an external window it's how the host passes its resizes on
+    pub fn set_size(&mut self, width: u32, height: u32)
+    {
+        match self.native {
+            #[cfg(feature = "win32")]
+            NativeWindow::WIN32(ref mut window) => window.set_size(width, height),
+            #[cfg(feature = "xlib")]
+            NativeWindow::XLIB(ref mut window) => window.set_size(width, height),
+            #[cfg(feature = "xcb")]
+            NativeWindow::XCB(ref mut window) => window.set_size(width, height),
+            #[cfg(feature = "wayland")]
+            NativeWindow::WAYLAND(ref mut window) => window.set_size(width, height),
+            NativeWindow::EXTERNAL(_) => {}
+        }
+        self.descriptor.width = width;
+        self.descriptor.height = height;
+    }
+
+    // on the descriptor's monitor, the window keeps its state when the window system refuses
+    pub fn set_fullscreen(&mut self, fullscreen: FullscreenMode) -> Result<(), SystemWindowError>
+    {
+        if fullscreen == self.descriptor.fullscreen
+        {
+            return Ok(());
+        }
+        let mut descriptor = self.descriptor.clone();
+        descriptor.fullscreen = fullscreen;
+        match self.native {
+            #[cfg(feature = "win32")]
+            NativeWindow::WIN32(ref mut window) => window.set_fullscreen(&descriptor)?,
+            #[cfg(feature = "xlib")]
+            NativeWindow::XLIB(ref mut window) => window.set_fullscreen(&descriptor)?,
+            #[cfg(feature = "xcb")]
+            NativeWindow::XCB(ref mut window) => window.set_fullscreen(&descriptor)?,
+            #[cfg(feature = "wayland")]
+            NativeWindow::WAYLAND(ref mut window) => window.set_fullscreen(&descriptor)?,
+            NativeWindow::EXTERNAL(_) => return Err(SystemWindowError::EXTERNAL_WINDOW)
+        }
+        self.descriptor = descriptor;
+        Ok(())
+    }
+
+    pub fn handle(& self) -> WindowHandle
+    {
+        match self.native {
+            #[cfg(feature = "win32")]
+            NativeWindow::WIN32(ref window) => WindowHandle::WIN32 { hinstance: window.Hinstance, hwnd: window.Hwnd },
+            #[cfg(feature = "xlib")]
+            NativeWindow::XLIB(ref window) => WindowHandle::XLIB { display: window.display, window: window.window },
+            #[cfg(feature = "xcb")]
+            NativeWindow::XCB(ref window) => WindowHandle::XCB { connection: window.connection, window: window.window },
+            #[cfg(feature = "wayland")]
+            NativeWindow::WAYLAND(ref window) => WindowHandle::WAYLAND { display: window.display, surface: window.surface },
+            NativeWindow::EXTERNAL(handle) => handle
         }
     }
-}
\ No newline at end of file
+
+    pub fn destroy(self)
+    {
+        match self.native {
+            #[cfg(feature = "win32")]
+            NativeWindow::WIN32(window) => window.destroy(),
+            #[cfg(feature = "xlib")]
+            NativeWindow::XLIB(window) => window.destroy(),
+            #[cfg(feature = "xcb")]
+            NativeWindow::XCB(window) => window.destroy(),
+            #[cfg(feature = "wayland")]
+            NativeWindow::WAYLAND(window) => window.destroy(),
+            NativeWindow::EXTERNAL(_) => {}
+        }
+    }
+}
@@ -0,0 +1,866 @@
+use crate::vulkan_bindings;
+use crate::system_window::{FullscreenMode, MonitorArea, SystemWindowError, WindowDescriptor};
+use crate::system_events::{self, EventMode, WindowEvent};
+use std::cell::Cell;
+use std::ffi::{c_void, CStr, CString};
+use std::os::raw::{c_char, c_int};
+
+// libwayland only ships the core protocol, xdg-shell's and xdg-decoration's interface tables are normally
+// generated by wayland-scanner so the ones the engine needs are declared here, at version 1
+
+#[repr(transparent)]
+struct WaylandTable<T>(T);
+
+unsafe impl<T> Sync for WaylandTable<T> {}
+
+const XDG_WM_BASE_DESTROY: u32 = 0;
+const XDG_WM_BASE_GET_XDG_SURFACE: u32 = 2;
+const XDG_WM_BASE_PONG: u32 = 3;
+const XDG_SURFACE_DESTROY: u32 = 0;
+const XDG_SURFACE_GET_TOPLEVEL: u32 = 1;
+const XDG_SURFACE_ACK_CONFIGURE: u32 = 4;
+const XDG_TOPLEVEL_DESTROY: u32 = 0;
+const XDG_TOPLEVEL_SET_TITLE: u32 = 2;
+const XDG_TOPLEVEL_SET_APP_ID: u32 = 3;
+const XDG_TOPLEVEL_SET_MAX_SIZE: u32 = 7;
+const XDG_TOPLEVEL_SET_MIN_SIZE: u32 = 8;
+const XDG_TOPLEVEL_SET_FULLSCREEN: u32 = 11;
+const XDG_TOPLEVEL_UNSET_FULLSCREEN: u32 = 12;
+const DECORATION_MANAGER_DESTROY: u32 = 0;
+const DECORATION_MANAGER_GET_TOPLEVEL_DECORATION: u32 = 1;
+const TOPLEVEL_DECORATION_DESTROY: u32 = 0;
+const TOPLEVEL_DECORATION_SET_MODE: u32 = 1;
+const TOPLEVEL_DECORATION_MODE_CLIENT_SIDE: u32 = 1;
+const TOPLEVEL_DECORATION_MODE_SERVER_SIDE: u32 = 2;
+
+// no message the engine receives carries an object, the request side is told the interface explicitly
+static NULL_TYPES: WaylandTable<[*const vulkan_bindings::wl_interface; 4]> = WaylandTable([std::ptr::null(); 4]);
+
+macro_rules! WL_MESSAGE {
+    ($name: literal, $signature: literal) => {
+        vulkan_bindings::wl_message {
+            name: concat!($name, "\0").as_ptr() as *const c_char,
+            signature: concat!($signature, "\0").as_ptr() as *const c_char,
+            types: &NULL_TYPES as *const WaylandTable<[*const vulkan_bindings::wl_interface; 4]> as *mut *const vulkan_bindings::wl_interface
+        }
+    };
+}
+
+static XDG_WM_BASE_REQUESTS: WaylandTable<[vulkan_bindings::wl_message; 4]> = WaylandTable([
+    WL_MESSAGE!("destroy", ""),
+    WL_MESSAGE!("create_positioner", "n"),
+    WL_MESSAGE!("get_xdg_surface", "no"),
+    WL_MESSAGE!("pong", "u")
+]);
+
+static XDG_WM_BASE_EVENTS: WaylandTable<[vulkan_bindings::wl_message; 1]> = WaylandTable([
+    WL_MESSAGE!("ping", "u")
+]);
+
+static XDG_SURFACE_REQUESTS: WaylandTable<[vulkan_bindings::wl_message; 5]> = WaylandTable([
+    WL_MESSAGE!("destroy", ""),
+    WL_MESSAGE!("get_toplevel", "n"),
+    WL_MESSAGE!("get_popup", "n?oo"),
+    WL_MESSAGE!("set_window_geometry", "iiii"),
+    WL_MESSAGE!("ack_configure", "u")
+]);
+
+static XDG_SURFACE_EVENTS: WaylandTable<[vulkan_bindings::wl_message; 1]> = WaylandTable([
+    WL_MESSAGE!("configure", "u")
+]);
+
+static XDG_TOPLEVEL_REQUESTS: WaylandTable<[vulkan_bindings::wl_message; 14]> = WaylandTable([
+    WL_MESSAGE!("destroy", ""),
+    WL_MESSAGE!("set_parent", "?o"),
+    WL_MESSAGE!("set_title", "s"),
+    WL_MESSAGE!("set_app_id", "s"),
+    WL_MESSAGE!("show_window_menu", "ouii"),
+    WL_MESSAGE!("move", "ou"),
+    WL_MESSAGE!("resize", "ouu"),
+    WL_MESSAGE!("set_max_size", "ii"),
+    WL_MESSAGE!("set_min_size", "ii"),
+    WL_MESSAGE!("set_maximized", ""),
+    WL_MESSAGE!("unset_maximized", ""),
+    WL_MESSAGE!("set_fullscreen", "?o"),
+    WL_MESSAGE!("unset_fullscreen", ""),
+    WL_MESSAGE!("set_minimized", "")
+]);
+
+static XDG_TOPLEVEL_EVENTS: WaylandTable<[vulkan_bindings::wl_message; 2]> = WaylandTable([
+    WL_MESSAGE!("configure", "iia"),
+    WL_MESSAGE!("close", "")
+]);
+
+static DECORATION_MANAGER_REQUESTS: WaylandTable<[vulkan_bindings::wl_message; 2]> = WaylandTable([
+    WL_MESSAGE!("destroy", ""),
+    WL_MESSAGE!("get_toplevel_decoration", "no")
+]);
+
+static TOPLEVEL_DECORATION_REQUESTS: WaylandTable<[vulkan_bindings::wl_message; 3]> = WaylandTable([
+    WL_MESSAGE!("destroy", ""),
+    WL_MESSAGE!("set_mode", "u"),
+    WL_MESSAGE!("unset_mode", "")
+]);
+
+static TOPLEVEL_DECORATION_EVENTS: WaylandTable<[vulkan_bindings::wl_message; 1]> = WaylandTable([
+    WL_MESSAGE!("configure", "u")
+]);
+
+static XDG_WM_BASE_INTERFACE: WaylandTable<vulkan_bindings::wl_interface> = WaylandTable(vulkan_bindings::wl_interface {
+    name: c"xdg_wm_base".as_ptr(),
+    version: 1,
+    method_count: 4,
+    methods: &XDG_WM_BASE_REQUESTS as *const WaylandTable<[vulkan_bindings::wl_message; 4]> as *const vulkan_bindings::wl_message,
+    event_count: 1,
+    events: &XDG_WM_BASE_EVENTS as *const WaylandTable<[vulkan_bindings::wl_message; 1]> as *const vulkan_bindings::wl_message
+});
+
+static XDG_SURFACE_INTERFACE: WaylandTable<vulkan_bindings::wl_interface> = WaylandTable(vulkan_bindings::wl_interface {
+    name: c"xdg_surface".as_ptr(),
+    version: 1,
+    method_count: 5,
+    methods: &XDG_SURFACE_REQUESTS as *const WaylandTable<[vulkan_bindings::wl_message; 5]> as *const vulkan_bindings::wl_message,
+    event_count: 1,
+    events: &XDG_SURFACE_EVENTS as *const WaylandTable<[vulkan_bindings::wl_message; 1]> as *const vulkan_bindings::wl_message
+});
+
+static XDG_TOPLEVEL_INTERFACE: WaylandTable<vulkan_bindings::wl_interface> = WaylandTable(vulkan_bindings::wl_interface {
+    name: c"xdg_toplevel".as_ptr(),
+    version: 1,
+    method_count: 14,
+    methods: &XDG_TOPLEVEL_REQUESTS as *const WaylandTable<[vulkan_bindings::wl_message; 14]> as *const vulkan_bindings::wl_message,
+    event_count: 2,
+    events: &XDG_TOPLEVEL_EVENTS as *const WaylandTable<[vulkan_bindings::wl_message; 2]> as *const vulkan_bindings::wl_message
+});
+
+static DECORATION_MANAGER_INTERFACE: WaylandTable<vulkan_bindings::wl_interface> = WaylandTable(vulkan_bindings::wl_interface {
+    name: c"zxdg_decoration_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: &DECORATION_MANAGER_REQUESTS as *const WaylandTable<[vulkan_bindings::wl_message; 2]> as *const vulkan_bindings::wl_message,
+    event_count: 0,
+    events: std::ptr::null()
+});
+
+static TOPLEVEL_DECORATION_INTERFACE: WaylandTable<vulkan_bindings::wl_interface> = WaylandTable(vulkan_bindings::wl_interface {
+    name: c"zxdg_toplevel_decoration_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: &TOPLEVEL_DECORATION_REQUESTS as *const WaylandTable<[vulkan_bindings::wl_message; 3]> as *const vulkan_bindings::wl_message,
+    event_count: 1,
+    events: &TOPLEVEL_DECORATION_EVENTS as *const WaylandTable<[vulkan_bindings::wl_message; 1]> as *const vulkan_bindings::wl_message
+});
+
+#[repr(C)]
+struct XdgWmBaseListener {
+    ping: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32)
+}
+
+#[repr(C)]
+struct XdgSurfaceListener {
+    configure: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32)
+}
+
+#[repr(C)]
+struct XdgToplevelListener {
+    configure: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, i32, i32, *mut vulkan_bindings::wl_array),
+    close: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy)
+}
+
+#[repr(C)]
+struct pollfd {
+    fd: c_int,
+    events: i16,
+    revents: i16
+}
+
+const POLLIN: i16 = 1;
+
+extern "C" {
+    fn poll(fds: *mut pollfd, nfds: std::os::raw::c_ulong, timeout: c_int) -> c_int;
+    fn close(fd: c_int) -> c_int;
+}
+
+// the core protocol listeners at the wl_seat version the engine binds (5), libwayland calls every slot
+// of the bound version so none of them can be left out
+#[repr(C)]
+struct SeatListener {
+    capabilities: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32),
+    name: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, *const c_char)
+}
+
+#[repr(C)]
+struct KeyboardListener {
+    keymap: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32, i32, u32),
+    enter: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32, *mut vulkan_bindings::wl_surface, *mut vulkan_bindings::wl_array),
+    leave: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32, *mut vulkan_bindings::wl_surface),
+    key: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32, u32, u32, u32),
+    modifiers: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32, u32, u32, u32, u32),
+    repeat_info: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, i32, i32)
+}
+
+#[repr(C)]
+struct PointerListener {
+    enter: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32, *mut vulkan_bindings::wl_surface, vulkan_bindings::wl_fixed_t, vulkan_bindings::wl_fixed_t),
+    leave: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32, *mut vulkan_bindings::wl_surface),
+    motion: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32, vulkan_bindings::wl_fixed_t, vulkan_bindings::wl_fixed_t),
+    button: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32, u32, u32, u32),
+    axis: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32, u32, vulkan_bindings::wl_fixed_t),
+    frame: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy),
+    axis_source: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32),
+    axis_stop: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32, u32),
+    axis_discrete: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32, i32)
+}
+
+// wl_output at version 2
+#[repr(C)]
+struct OutputListener {
+    geometry: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, i32, i32, i32, i32, i32, *const c_char, *const c_char, i32),
+    mode: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, u32, i32, i32, i32),
+    done: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy),
+    scale: unsafe extern "C" fn(*mut c_void, *mut vulkan_bindings::wl_proxy, i32)
+}
+
+// wayland has no primary output, the first one the registry announced stands in for it
+struct Output {
+    proxy: *mut vulkan_bindings::wl_proxy,
+    area: MonitorArea
+}
+
+// everything the listeners write to, boxed so its address survives moving the window around
+struct WaylandState {
+    compositor: *mut vulkan_bindings::wl_proxy,
+    wm_base: *mut vulkan_bindings::wl_proxy,
+    decoration_manager: *mut vulkan_bindings::wl_proxy,
+    seat: *mut vulkan_bindings::wl_proxy,
+    outputs: Vec<Output>,
+    keyboard: *mut vulkan_bindings::wl_proxy,
+    pointer: *mut vulkan_bindings::wl_proxy,
+    width: i32,
+    height: i32,
+    // written by the configure callback while new() waits on it
+    configured: Cell<bool>,
+    events: Vec<WindowEvent>
+}
+
+unsafe fn bind(registry: *mut vulkan_bindings::wl_proxy, name: u32, interface: *const vulkan_bindings::wl_interface, version: u32) -> *mut vulkan_bindings::wl_proxy
+{
+    vulkan_bindings::wl_proxy_marshal_flags(
+        registry,
+        vulkan_bindings::WL_REGISTRY_BIND,
+        interface,
+        version,
+        0,
+        name,
+        (*interface).name,
+        version,
+        std::ptr::null_mut::<c_void>()
+    )
+}
+
+unsafe extern "C" fn registry_global(data: *mut c_void, registry: *mut vulkan_bindings::wl_registry, name: u32, interface: *const c_char, version: u32)
+{
+    let state = &mut *(data as *mut WaylandState);
+    let registry = registry as *mut vulkan_bindings::wl_proxy;
+    let (slot, target, target_version): (&mut *mut vulkan_bindings::wl_proxy, *const vulkan_bindings::wl_interface, u32) = match CStr::from_ptr(interface).to_bytes() {
+        b"wl_compositor" => (&mut state.compositor, &vulkan_bindings::wl_compositor_interface, version.min(4)),
+        b"xdg_wm_base" => (&mut state.wm_base, &XDG_WM_BASE_INTERFACE.0, 1),
+        b"zxdg_decoration_manager_v1" => (&mut state.decoration_manager, &DECORATION_MANAGER_INTERFACE.0, 1),
+        b"wl_seat" => (&mut state.seat, &vulkan_bindings::wl_seat_interface, version.min(5)),
+        b"wl_output" => {
+            let output = bind(registry, name, &vulkan_bindings::wl_output_interface, version.min(2));
+            vulkan_bindings::wl_proxy_add_listener(output, &OUTPUT_LISTENER as *const OutputListener as *mut Option<unsafe extern "C" fn()>, data);
+            let primary = state.outputs.is_empty();
+            state.outputs.push(Output { proxy: output, area: MonitorArea { x: 0, y: 0, width: 0, height: 0, primary } });
+            return;
+        },
+        _ => return
+    };
+    if *slot != std::ptr::null_mut()
+    {
+        return;
+    }
+    *slot = bind(registry, name, target, target_version);
+    if std::ptr::eq(target, &vulkan_bindings::wl_seat_interface)
+    {
+        vulkan_bindings::wl_proxy_add_listener(state.seat, &SEAT_LISTENER as *const SeatListener as *mut Option<unsafe extern "C" fn()>, data);
+    }
+}
+
+unsafe extern "C" fn registry_global_remove(_data: *mut c_void, _registry: *mut vulkan_bindings::wl_registry, _name: u32)
+{
+}
+
+static REGISTRY_LISTENER: vulkan_bindings::wl_registry_listener = vulkan_bindings::wl_registry_listener {
+    global: Some(registry_global),
+    global_remove: Some(registry_global_remove)
+};
+
+unsafe extern "C" fn output_geometry(
+    data: *mut c_void,
+    output: *mut vulkan_bindings::wl_proxy,
+    x: i32,
+    y: i32,
+    _physical_width: i32,
+    _physical_height: i32,
+    _subpixel: i32,
+    _make: *const c_char,
+    _model: *const c_char,
+    _transform: i32
+) {
+    let state = &mut *(data as *mut WaylandState);
+    if let Some(output) = state.outputs.iter_mut().find(|known| known.proxy == output)
+    {
+        output.area.x = x;
+        output.area.y = y;
+    }
+}
+
+unsafe extern "C" fn output_mode(data: *mut c_void, output: *mut vulkan_bindings::wl_proxy, flags: u32, width: i32, height: i32, _refresh: i32)
+{
+    let state = &mut *(data as *mut WaylandState);
+    if flags & vulkan_bindings::wl_output_mode_WL_OUTPUT_MODE_CURRENT == 0
+    {
+        return;
+    }
+    if let Some(output) = state.outputs.iter_mut().find(|known| known.proxy == output)
+    {
+        output.area.width = width as u32;
+        output.area.height = height as u32;
+    }
+}
+
+unsafe extern "C" fn output_done(_data: *mut c_void, _output: *mut vulkan_bindings::wl_proxy)
+{
+}
+
+unsafe extern "C" fn output_scale(_data: *mut c_void, _output: *mut vulkan_bindings::wl_proxy, _factor: i32)
+{
+}
+
+static OUTPUT_LISTENER: OutputListener = OutputListener {
+    geometry: output_geometry,
+    mode: output_mode,
+    done: output_done,
+    scale: output_scale
+};
+
+// compositors drop clients that stop answering pings
+unsafe extern "C" fn wm_base_ping(_data: *mut c_void, wm_base: *mut vulkan_bindings::wl_proxy, serial: u32)
+{
+    vulkan_bindings::wl_proxy_marshal_flags(wm_base, XDG_WM_BASE_PONG, std::ptr::null(), vulkan_bindings::wl_proxy_get_version(wm_base), 0, serial);
+}
+
+static WM_BASE_LISTENER: XdgWmBaseListener = XdgWmBaseListener {
+    ping: wm_base_ping
+};
+
+unsafe extern "C" fn xdg_surface_configure(data: *mut c_void, xdg_surface: *mut vulkan_bindings::wl_proxy, serial: u32)
+{
+    let state = &mut *(data as *mut WaylandState);
+    vulkan_bindings::wl_proxy_marshal_flags(xdg_surface, XDG_SURFACE_ACK_CONFIGURE, std::ptr::null(), vulkan_bindings::wl_proxy_get_version(xdg_surface), 0, serial);
+    state.configured.set(true);
+}
+
+static XDG_SURFACE_LISTENER: XdgSurfaceListener = XdgSurfaceListener {
+    configure: xdg_surface_configure
+};
+
+// a 0 size leaves the choice to the client, the window then keeps its current size. xdg-shell has no
+// minimized state so MINIMIZED is never reported on wayland
+unsafe extern "C" fn toplevel_configure(data: *mut c_void, _toplevel: *mut vulkan_bindings::wl_proxy, width: i32, height: i32, _states: *mut vulkan_bindings::wl_array)
+{
+    let state = &mut *(data as *mut WaylandState);
+    if width > 0 && height > 0 && (width, height) != (state.width, state.height)
+    {
+        state.width = width;
+        state.height = height;
+        state.events.push(WindowEvent::RESIZED { width: width as u32, height: height as u32 });
+    }
+}
+
+unsafe extern "C" fn toplevel_close(data: *mut c_void, _toplevel: *mut vulkan_bindings::wl_proxy)
+{
+    let state = &mut *(data as *mut WaylandState);
+    state.events.push(WindowEvent::CLOSE_REQUESTED);
+}
+
+static TOPLEVEL_LISTENER: XdgToplevelListener = XdgToplevelListener {
+    configure: toplevel_configure,
+    close: toplevel_close
+};
+
+unsafe extern "C" fn seat_capabilities(data: *mut c_void, seat: *mut vulkan_bindings::wl_proxy, capabilities: u32)
+{
+    let state = &mut *(data as *mut WaylandState);
+    if capabilities & vulkan_bindings::wl_seat_capability_WL_SEAT_CAPABILITY_KEYBOARD != 0 && state.keyboard == std::ptr::null_mut()
+    {
+        state.keyboard = vulkan_bindings::wl_proxy_marshal_flags(seat, vulkan_bindings::WL_SEAT_GET_KEYBOARD, &vulkan_bindings::wl_keyboard_interface, vulkan_bindings::wl_proxy_get_version(seat), 0, std::ptr::null_mut::<c_void>());
+        vulkan_bindings::wl_proxy_add_listener(state.keyboard, &KEYBOARD_LISTENER as *const KeyboardListener as *mut Option<unsafe extern "C" fn()>, data);
+    }
+    if capabilities & vulkan_bindings::wl_seat_capability_WL_SEAT_CAPABILITY_POINTER != 0 && state.pointer == std::ptr::null_mut()
+    {
+        state.pointer = vulkan_bindings::wl_proxy_marshal_flags(seat, vulkan_bindings::WL_SEAT_GET_POINTER, &vulkan_bindings::wl_pointer_interface, vulkan_bindings::wl_proxy_get_version(seat), 0, std::ptr::null_mut::<c_void>());
+        vulkan_bindings::wl_proxy_add_listener(state.pointer, &POINTER_LISTENER as *const PointerListener as *mut Option<unsafe extern "C" fn()>, data);
+    }
+}
+
+unsafe extern "C" fn seat_name(_data: *mut c_void, _seat: *mut vulkan_bindings::wl_proxy, _name: *const c_char)
+{
+}
+
+static SEAT_LISTENER: SeatListener = SeatListener {
+    capabilities: seat_capabilities,
+    name: seat_name
+};
+
+// applying the keymap needs libxkbcommon, so keycodes name the physical key and no TEXT_INPUT is sent
+unsafe extern "C" fn keyboard_keymap(_data: *mut c_void, _keyboard: *mut vulkan_bindings::wl_proxy, _format: u32, fd: i32, _size: u32)
+{
+    close(fd);
+}
+
+unsafe extern "C" fn keyboard_enter(data: *mut c_void, _keyboard: *mut vulkan_bindings::wl_proxy, _serial: u32, _surface: *mut vulkan_bindings::wl_surface, _keys: *mut vulkan_bindings::wl_array)
+{
+    let state = &mut *(data as *mut WaylandState);
+    state.events.push(WindowEvent::FOCUSED(true));
+}
+
+unsafe extern "C" fn keyboard_leave(data: *mut c_void, _keyboard: *mut vulkan_bindings::wl_proxy, _serial: u32, _surface: *mut vulkan_bindings::wl_surface)
+{
+    let state = &mut *(data as *mut WaylandState);
+    state.events.push(WindowEvent::FOCUSED(false));
+}
+
+unsafe extern "C" fn keyboard_key(data: *mut c_void, _keyboard: *mut vulkan_bindings::wl_proxy, _serial: u32, _time: u32, key: u32, key_state: u32)
+{
+    let state = &mut *(data as *mut WaylandState);
+    let keycode = system_events::evdev_keycode(key);
+    if key_state == vulkan_bindings::wl_keyboard_key_state_WL_KEYBOARD_KEY_STATE_PRESSED
+    {
+        state.events.push(WindowEvent::KEY_DOWN { scancode: key, keycode });
+    }
+    else
+    {
+        state.events.push(WindowEvent::KEY_UP { scancode: key, keycode });
+    }
+}
+
+unsafe extern "C" fn keyboard_modifiers(_data: *mut c_void, _keyboard: *mut vulkan_bindings::wl_proxy, _serial: u32, _depressed: u32, _latched: u32, _locked: u32, _group: u32)
+{
+}
+
+unsafe extern "C" fn keyboard_repeat_info(_data: *mut c_void, _keyboard: *mut vulkan_bindings::wl_proxy, _rate: i32, _delay: i32)
+{
+}
+
+static KEYBOARD_LISTENER: KeyboardListener = KeyboardListener {
+    keymap: keyboard_keymap,
+    enter: keyboard_enter,
+    leave: keyboard_leave,
+    key: keyboard_key,
+    modifiers: keyboard_modifiers,
+    repeat_info: keyboard_repeat_info
+};
+
+fn fixed_to_f64(value: vulkan_bindings::wl_fixed_t) -> f64
+{
+    value as f64 / 256.0
+}
+
+unsafe extern "C" fn pointer_enter(data: *mut c_void, _pointer: *mut vulkan_bindings::wl_proxy, _serial: u32, _surface: *mut vulkan_bindings::wl_surface, x: vulkan_bindings::wl_fixed_t, y: vulkan_bindings::wl_fixed_t)
+{
+    let state = &mut *(data as *mut WaylandState);
+    state.events.push(WindowEvent::MOUSE_MOVED { x: fixed_to_f64(x), y: fixed_to_f64(y) });
+}
+
+unsafe extern "C" fn pointer_leave(_data: *mut c_void, _pointer: *mut vulkan_bindings::wl_proxy, _serial: u32, _surface: *mut vulkan_bindings::wl_surface)
+{
+}
+
+unsafe extern "C" fn pointer_motion(data: *mut c_void, _pointer: *mut vulkan_bindings::wl_proxy, _time: u32, x: vulkan_bindings::wl_fixed_t, y: vulkan_bindings::wl_fixed_t)
+{
+    let state = &mut *(data as *mut WaylandState);
+    state.events.push(WindowEvent::MOUSE_MOVED { x: fixed_to_f64(x), y: fixed_to_f64(y) });
+}
+
+unsafe extern "C" fn pointer_button(data: *mut c_void, _pointer: *mut vulkan_bindings::wl_proxy, _serial: u32, _time: u32, button: u32, button_state: u32)
+{
+    let state = &mut *(data as *mut WaylandState);
+    let button = system_events::evdev_button(button);
+    if button_state == vulkan_bindings::wl_pointer_button_state_WL_POINTER_BUTTON_STATE_PRESSED
+    {
+        state.events.push(WindowEvent::MOUSE_BUTTON_DOWN(button));
+    }
+    else
+    {
+        state.events.push(WindowEvent::MOUSE_BUTTON_UP(button));
+    }
+}
+
+// axis values are in surface pixels, compositors send 10 per wheel notch and positive scrolls down
+unsafe extern "C" fn pointer_axis(data: *mut c_void, _pointer: *mut vulkan_bindings::wl_proxy, _time: u32, axis: u32, value: vulkan_bindings::wl_fixed_t)
+{
+    let state = &mut *(data as *mut WaylandState);
+    let notches = (fixed_to_f64(value) / 10.0) as f32;
+    if axis == vulkan_bindings::wl_pointer_axis_WL_POINTER_AXIS_VERTICAL_SCROLL
+    {
+        state.events.push(WindowEvent::MOUSE_WHEEL { x: 0.0, y: -notches });
+    }
+    else
+    {
+        state.events.push(WindowEvent::MOUSE_WHEEL { x: notches, y: 0.0 });
+    }
+}
+
+unsafe extern "C" fn pointer_frame(_data: *mut c_void, _pointer: *mut vulkan_bindings::wl_proxy)
+{
+}
+
+unsafe extern "C" fn pointer_axis_source(_data: *mut c_void, _pointer: *mut vulkan_bindings::wl_proxy, _source: u32)
+{
+}
+
+unsafe extern "C" fn pointer_axis_stop(_data: *mut c_void, _pointer: *mut vulkan_bindings::wl_proxy, _time: u32, _axis: u32)
+{
+}
+
+unsafe extern "C" fn pointer_axis_discrete(_data: *mut c_void, _pointer: *mut vulkan_bindings::wl_proxy, _axis: u32, _discrete: i32)
+{
+}
+
+static POINTER_LISTENER: PointerListener = PointerListener {
+    enter: pointer_enter,
+    leave: pointer_leave,
+    motion: pointer_motion,
+    button: pointer_button,
+    axis: pointer_axis,
+    frame: pointer_frame,
+    axis_source: pointer_axis_source,
+    axis_stop: pointer_axis_stop,
+    axis_discrete: pointer_axis_discrete
+};
+
+pub struct WaylandWindow {
+    pub display: *mut vulkan_bindings::wl_display,
+    pub surface: *mut vulkan_bindings::wl_surface,
+    registry: *mut vulkan_bindings::wl_proxy,
+    xdg_surface: *mut vulkan_bindings::wl_proxy,
+    toplevel: *mut vulkan_bindings::wl_proxy,
+    decoration: *mut vulkan_bindings::wl_proxy,
+    descriptor: WindowDescriptor,
+    state: Box<WaylandState>
+}
+
+impl WaylandWindow {
+    // the compositor places the window, position is ignored and EXCLUSIVE is BORDERLESS
+    pub fn new(descriptor: &WindowDescriptor) -> Result<Self, SystemWindowError>
+    {
+        unsafe {
+            // WAYLAND_DISPLAY picks the compositor socket
+            let display = vulkan_bindings::wl_display_connect(std::ptr::null());
+            if display == std::ptr::null_mut()
+            {
+                return Err(SystemWindowError::CANT_OPEN_DISPLAY(std::env::var("WAYLAND_DISPLAY").unwrap_or_default()));
+            }
+            let display_proxy = display as *mut vulkan_bindings::wl_proxy;
+            let mut window = WaylandWindow {
+                display,
+                surface: std::ptr::null_mut(),
+                registry: std::ptr::null_mut(),
+                xdg_surface: std::ptr::null_mut(),
+                toplevel: std::ptr::null_mut(),
+                decoration: std::ptr::null_mut(),
+                descriptor: descriptor.clone(),
+                state: Box::new(WaylandState {
+                    compositor: std::ptr::null_mut(),
+                    wm_base: std::ptr::null_mut(),
+                    decoration_manager: std::ptr::null_mut(),
+                    seat: std::ptr::null_mut(),
+                    outputs: Vec::new(),
+                    keyboard: std::ptr::null_mut(),
+                    pointer: std::ptr::null_mut(),
+                    width: descriptor.width as i32,
+                    height: descriptor.height as i32,
+                    configured: Cell::new(false),
+                    events: Vec::new()
+                })
+            };
+            let state = &mut *window.state as *mut WaylandState as *mut c_void;
+
+            window.registry = vulkan_bindings::wl_proxy_marshal_flags(
+                display_proxy,
+                vulkan_bindings::WL_DISPLAY_GET_REGISTRY,
+                &vulkan_bindings::wl_registry_interface,
+                vulkan_bindings::wl_proxy_get_version(display_proxy),
+                0,
+                std::ptr::null_mut::<c_void>()
+            );
+            vulkan_bindings::wl_proxy_add_listener(window.registry, &REGISTRY_LISTENER as *const vulkan_bindings::wl_registry_listener as *mut Option<unsafe extern "C" fn()>, state);
+            // the second roundtrip gets the outputs' geometry and modes
+            vulkan_bindings::wl_display_roundtrip(display);
+            vulkan_bindings::wl_display_roundtrip(display);
+            if window.state.compositor == std::ptr::null_mut() || window.state.wm_base == std::ptr::null_mut()
+            {
+                window.destroy();
+                return Err(SystemWindowError::CANT_CREATE_WINDOW(String::from("the compositor doesn't offer wl_compositor and xdg_wm_base")));
+            }
+            let output = match window.output(descriptor) {
+                Ok(output) => output,
+                Err(e) => {
+                    window.destroy();
+                    return Err(e);
+                }
+            };
+            let compositor = window.state.compositor;
+            let wm_base = window.state.wm_base;
+            vulkan_bindings::wl_proxy_add_listener(wm_base, &WM_BASE_LISTENER as *const XdgWmBaseListener as *mut Option<unsafe extern "C" fn()>, state);
+
+            window.surface = vulkan_bindings::wl_proxy_marshal_flags(
+                compositor,
+                vulkan_bindings::WL_COMPOSITOR_CREATE_SURFACE,
+                &vulkan_bindings::wl_surface_interface,
+                vulkan_bindings::wl_proxy_get_version(compositor),
+                0,
+                std::ptr::null_mut::<c_void>()
+            ) as *mut vulkan_bindings::wl_surface;
+            window.xdg_surface = vulkan_bindings::wl_proxy_marshal_flags(
+                wm_base,
+                XDG_WM_BASE_GET_XDG_SURFACE,
+                &XDG_SURFACE_INTERFACE.0,
+                vulkan_bindings::wl_proxy_get_version(wm_base),
+                0,
+                std::ptr::null_mut::<c_void>(),
+                window.surface as *mut vulkan_bindings::wl_proxy
+            );
+            vulkan_bindings::wl_proxy_add_listener(window.xdg_surface, &XDG_SURFACE_LISTENER as *const XdgSurfaceListener as *mut Option<unsafe extern "C" fn()>, state);
+            window.toplevel = vulkan_bindings::wl_proxy_marshal_flags(
+                window.xdg_surface,
+                XDG_SURFACE_GET_TOPLEVEL,
+                &XDG_TOPLEVEL_INTERFACE.0,
+                vulkan_bindings::wl_proxy_get_version(window.xdg_surface),
+                0,
+                std::ptr::null_mut::<c_void>()
+            );
+            vulkan_bindings::wl_proxy_add_listener(window.toplevel, &TOPLEVEL_LISTENER as *const XdgToplevelListener as *mut Option<unsafe extern "C" fn()>, state);
+
+            let title = CString::new(descriptor.title.as_str()).unwrap_or_default();
+            vulkan_bindings::wl_proxy_marshal_flags(window.toplevel, XDG_TOPLEVEL_SET_TITLE, std::ptr::null(), 1, 0, title.as_ptr());
+            let app_id = CString::new(descriptor.app_id.as_str()).unwrap_or_default();
+            vulkan_bindings::wl_proxy_marshal_flags(window.toplevel, XDG_TOPLEVEL_SET_APP_ID, std::ptr::null(), 1, 0, app_id.as_ptr());
+            window.set_size_limits();
+            // without xdg-decoration nothing draws a frame, the engine doesn't draw client side decorations
+            if window.state.decoration_manager != std::ptr::null_mut()
+            {
+                window.decoration = vulkan_bindings::wl_proxy_marshal_flags(
+                    window.state.decoration_manager,
+                    DECORATION_MANAGER_GET_TOPLEVEL_DECORATION,
+                    &TOPLEVEL_DECORATION_INTERFACE.0,
+                    1,
+                    0,
+                    std::ptr::null_mut::<c_void>(),
+                    window.toplevel
+                );
+                let mode = if descriptor.decorations { TOPLEVEL_DECORATION_MODE_SERVER_SIDE } else { TOPLEVEL_DECORATION_MODE_CLIENT_SIDE };
+                vulkan_bindings::wl_proxy_marshal_flags(window.decoration, TOPLEVEL_DECORATION_SET_MODE, std::ptr::null(), 1, 0, mode);
+            }
+            if descriptor.fullscreen != FullscreenMode::WINDOWED
+            {
+                vulkan_bindings::wl_proxy_marshal_flags(window.toplevel, XDG_TOPLEVEL_SET_FULLSCREEN, std::ptr::null(), 1, 0, output);
+            }
+
+            // the first commit without a buffer asks for the initial configure, nothing may be attached before it's acked
+            vulkan_bindings::wl_proxy_marshal_flags(window.surface as *mut vulkan_bindings::wl_proxy, vulkan_bindings::WL_SURFACE_COMMIT, std::ptr::null(), vulkan_bindings::wl_proxy_get_version(window.surface as *mut vulkan_bindings::wl_proxy), 0);
+            // configured is set from the xdg_surface configure callback
+            while !window.state.configured.get()
+            {
+                if vulkan_bindings::wl_display_dispatch(display) < 0
+                {
+                    window.destroy();
+                    return Err(SystemWindowError::CANT_CREATE_WINDOW(String::from("lost the compositor connection before the first configure")));
+                }
+            }
+            Ok(window)
+        }
+    }
+
+    // the wl_output the descriptor's monitor is, null lets the compositor pick
+    fn output(& self, descriptor: &WindowDescriptor) -> Result<*mut vulkan_bindings::wl_proxy, SystemWindowError>
+    {
+        let areas: Vec<MonitorArea> = self.state.outputs.iter().map(|output| output.area).collect();
+        Ok(descriptor.pick_monitor(&areas)?.map_or(std::ptr::null_mut(), |idx| self.state.outputs[idx].proxy))
+    }
+
+    // a window that isn't resizable has the same min and max size, 0 lifts the limit
+    fn set_size_limits(& self)
+    {
+        let (width, height) = if self.descriptor.resizable { (0, 0) } else { (self.descriptor.width as i32, self.descriptor.height as i32) };
+        unsafe {
+            vulkan_bindings::wl_proxy_marshal_flags(self.toplevel, XDG_TOPLEVEL_SET_MIN_SIZE, std::ptr::null(), 1, 0, width, height);
+            vulkan_bindings::wl_proxy_marshal_flags(self.toplevel, XDG_TOPLEVEL_SET_MAX_SIZE, std::ptr::null(), 1, 0, width, height);
+        }
+    }
+
+    // the client picks the size when windowed, the buffer it presents is the window. RESIZED goes out with the next events
+    fn resize(&mut self, width: u32, height: u32)
+    {
+        if (width as i32, height as i32) != (self.state.width, self.state.height)
+        {
+            self.state.width = width as i32;
+            self.state.height = height as i32;
+            self.state.events.push(WindowEvent::RESIZED { width, height });
+        }
+    }
+
+    pub fn set_title(&mut self, title: &str)
+    {
+        let c_title = CString::new(title).unwrap_or_default();
+        unsafe {
+            vulkan_bindings::wl_proxy_marshal_flags(self.toplevel, XDG_TOPLEVEL_SET_TITLE, std::ptr::null(), 1, 0, c_title.as_ptr());
+            vulkan_bindings::wl_display_flush(self.display);
+        }
+        self.descriptor.title = title.to_string();
+    }
+
+    pub fn set_size(&mut self, width: u32, height: u32)
+    {
+        self.descriptor.width = width;
+        self.descriptor.height = height;
+        self.set_size_limits();
+        if self.descriptor.fullscreen == FullscreenMode::WINDOWED
+        {
+            self.resize(width, height);
+        }
+        unsafe {
+            vulkan_bindings::wl_display_flush(self.display);
+        }
+    }
+
+    // the compositor sends the fullscreen size in a configure, leaving fullscreen goes back to the descriptor's size
+    pub fn set_fullscreen(&mut self, descriptor: &WindowDescriptor) -> Result<(), SystemWindowError>
+    {
+        let output = self.output(descriptor)?;
+        unsafe {
+            if descriptor.fullscreen == FullscreenMode::WINDOWED
+            {
+                vulkan_bindings::wl_proxy_marshal_flags(self.toplevel, XDG_TOPLEVEL_UNSET_FULLSCREEN, std::ptr::null(), 1, 0);
+            }
+            else
+            {
+                vulkan_bindings::wl_proxy_marshal_flags(self.toplevel, XDG_TOPLEVEL_SET_FULLSCREEN, std::ptr::null(), 1, 0, output);
+            }
+            vulkan_bindings::wl_display_flush(self.display);
+        }
+        if descriptor.fullscreen == FullscreenMode::WINDOWED
+        {
+            self.resize(descriptor.width, descriptor.height);
+        }
+        self.descriptor = descriptor.clone();
+        Ok(())
+    }
+
+    // WAIT keeps blocking on the compositor socket until a listener produced an event
+    pub fn pump_events(&mut self, mode: EventMode) -> Vec<WindowEvent>
+    {
+        loop
+        {
+            let waiting = mode == EventMode::WAIT && self.state.events.is_empty();
+            self.dispatch(if waiting { -1 } else { 0 });
+            let connection_lost = unsafe { vulkan_bindings::wl_display_get_error(self.display) != 0 };
+            if !waiting || !self.state.events.is_empty() || connection_lost
+            {
+                break;
+            }
+        }
+        std::mem::take(&mut self.state.events)
+    }
+
+    // reads whatever the compositor sent within timeout_ms (-1 blocks) and runs the listeners on it
+    fn dispatch(&mut self, timeout_ms: i32)
+    {
+        unsafe {
+            while vulkan_bindings::wl_display_prepare_read(self.display) != 0
+            {
+                vulkan_bindings::wl_display_dispatch_pending(self.display);
+            }
+            vulkan_bindings::wl_display_flush(self.display);
+            let mut fd = pollfd {
+                fd: vulkan_bindings::wl_display_get_fd(self.display),
+                events: POLLIN,
+                revents: 0
+            };
+            if poll(&mut fd, 1, timeout_ms) > 0 && (fd.revents & POLLIN) != 0
+            {
+                vulkan_bindings::wl_display_read_events(self.display);
+            }
+            else
+            {
+                vulkan_bindings::wl_display_cancel_read(self.display);
+            }
+            vulkan_bindings::wl_display_dispatch_pending(self.display);
+        }
+    }
+
+    pub fn size(& self) -> (u32, u32)
+    {
+        (self.state.width as u32, self.state.height as u32)
+    }
+
+    pub fn destroy(self)
+    {
+        unsafe {
+            let destroy = vulkan_bindings::WL_MARSHAL_FLAG_DESTROY;
+            if self.decoration != std::ptr::null_mut()
+            {
+                vulkan_bindings::wl_proxy_marshal_flags(self.decoration, TOPLEVEL_DECORATION_DESTROY, std::ptr::null(), 1, destroy);
+            }
+            if self.toplevel != std::ptr::null_mut()
+            {
+                vulkan_bindings::wl_proxy_marshal_flags(self.toplevel, XDG_TOPLEVEL_DESTROY, std::ptr::null(), 1, destroy);
+            }
+            if self.xdg_surface != std::ptr::null_mut()
+            {
+                vulkan_bindings::wl_proxy_marshal_flags(self.xdg_surface, XDG_SURFACE_DESTROY, std::ptr::null(), 1, destroy);
+            }
+            if self.surface != std::ptr::null_mut()
+            {
+                let surface = self.surface as *mut vulkan_bindings::wl_proxy;
+                vulkan_bindings::wl_proxy_marshal_flags(surface, vulkan_bindings::WL_SURFACE_DESTROY, std::ptr::null(), vulkan_bindings::wl_proxy_get_version(surface), destroy);
+            }
+            if self.state.wm_base != std::ptr::null_mut()
+            {
+                vulkan_bindings::wl_proxy_marshal_flags(self.state.wm_base, XDG_WM_BASE_DESTROY, std::ptr::null(), 1, destroy);
+            }
+            if self.state.decoration_manager != std::ptr::null_mut()
+            {
+                vulkan_bindings::wl_proxy_marshal_flags(self.state.decoration_manager, DECORATION_MANAGER_DESTROY, std::ptr::null(), 1, destroy);
+            }
+            // the connection closes right after, so the remaining proxies are only freed client side
+            for input in [self.state.keyboard, self.state.pointer]
+            {
+                if input != std::ptr::null_mut()
+                {
+                    vulkan_bindings::wl_proxy_destroy(input);
+                }
+            }
+            if self.state.seat != std::ptr::null_mut()
+            {
+                vulkan_bindings::wl_proxy_destroy(self.state.seat);
+            }
+            for output in self.state.outputs.iter()
+            {
+                vulkan_bindings::wl_proxy_destroy(output.proxy);
+            }
+            if self.state.compositor != std::ptr::null_mut()
+            {
+                vulkan_bindings::wl_proxy_destroy(self.state.compositor);
+            }
+            if self.registry != std::ptr::null_mut()
+            {
+                vulkan_bindings::wl_proxy_destroy(self.registry);
+            }
+            vulkan_bindings::wl_display_flush(self.display);
+            vulkan_bindings::wl_display_disconnect(self.display);
+        }
+    }
+}
@@ -0,0 +1,490 @@
+use crate::vulkan_bindings;
+use crate::system_window::{FullscreenMode, MonitorArea, SystemWindowError, WindowDescriptor};
+use crate::system_events::{EventMode, KeyCode, MouseButton, WindowEvent};
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+// what window_proc writes to, reached through GWLP_USERDATA
+struct Win32State {
+    events: Vec<WindowEvent>,
+    width: u32,
+    height: u32,
+    minimized: bool,
+    // WM_CHAR hands characters outside the BMP over as two surrogates
+    high_surrogate: Option<u16>
+}
+
+pub struct Win32Window {
+    pub Hinstance: vulkan_bindings::HINSTANCE,
+    pub Hwnd : vulkan_bindings::HWND,
+    class_name: Vec<vulkan_bindings::wchar_t>,
+    descriptor: WindowDescriptor,
+    // the frame to go back to when leaving fullscreen
+    windowed_frame: vulkan_bindings::RECT,
+    // the monitor whose mode EXCLUSIVE changed, restored when leaving it
+    exclusive_device: Option<[vulkan_bindings::wchar_t; 32]>,
+    state: Box<Win32State>
+}
+
+// MONITORINFOEXW, the device name after MONITORINFO is what ChangeDisplaySettingsExW takes
+#[repr(C)]
+struct MonitorInfo {
+    info: vulkan_bindings::MONITORINFO,
+    device: [vulkan_bindings::wchar_t; 32]
+}
+
+impl MonitorInfo {
+    fn area(& self) -> MonitorArea
+    {
+        let rect = self.info.rcMonitor;
+        MonitorArea {
+            x: rect.left,
+            y: rect.top,
+            width: (rect.right - rect.left) as u32,
+            height: (rect.bottom - rect.top) as u32,
+            primary: self.info.dwFlags & vulkan_bindings::MONITORINFOF_PRIMARY != 0
+        }
+    }
+}
+
+extern "C" fn collect_monitor(
+    monitor: vulkan_bindings::HMONITOR,
+    _hdc: vulkan_bindings::HDC,
+    _rect: vulkan_bindings::LPRECT,
+    data: vulkan_bindings::LPARAM
+) -> vulkan_bindings::BOOL {
+    unsafe {
+        (*(data as *mut Vec<vulkan_bindings::HMONITOR>)).push(monitor);
+    }
+    1
+}
+
+// in EnumDisplayMonitors order, which is what WindowDescriptor::monitor indexes
+fn monitors() -> Vec<MonitorInfo>
+{
+    let mut handles: Vec<vulkan_bindings::HMONITOR> = Vec::new();
+    unsafe {
+        vulkan_bindings::EnumDisplayMonitors(std::ptr::null_mut(), std::ptr::null(), Some(collect_monitor), &mut handles as *mut Vec<vulkan_bindings::HMONITOR> as vulkan_bindings::LPARAM);
+        handles.into_iter().map(|monitor| {
+            let mut info : MonitorInfo = std::mem::zeroed();
+            info.info.cbSize = std::mem::size_of::<MonitorInfo>() as u32;
+            vulkan_bindings::GetMonitorInfoW(monitor, &mut info as *mut MonitorInfo as *mut vulkan_bindings::MONITORINFO);
+            info
+        }).collect()
+    }
+}
+
+// size None goes back to the mode saved in the registry
+unsafe fn change_display_mode(device: &[vulkan_bindings::wchar_t; 32], size: Option<(u32, u32)>) -> Result<(), SystemWindowError>
+{
+    let result = match size {
+        Some((width, height)) => {
+            let mut mode : vulkan_bindings::DEVMODEW = std::mem::zeroed();
+            mode.dmSize = std::mem::size_of::<vulkan_bindings::DEVMODEW>() as u16;
+            mode.dmPelsWidth = width;
+            mode.dmPelsHeight = height;
+            mode.dmFields = vulkan_bindings::DM_PELSWIDTH | vulkan_bindings::DM_PELSHEIGHT;
+            vulkan_bindings::ChangeDisplaySettingsExW(device.as_ptr(), &mut mode, std::ptr::null_mut(), vulkan_bindings::CDS_FULLSCREEN, std::ptr::null_mut())
+        },
+        None => vulkan_bindings::ChangeDisplaySettingsExW(device.as_ptr(), std::ptr::null_mut(), std::ptr::null_mut(), 0, std::ptr::null_mut())
+    };
+    if result != vulkan_bindings::DISP_CHANGE_SUCCESSFUL as vulkan_bindings::LONG
+    {
+        return Err(SystemWindowError::CANT_SET_FULLSCREEN(format!("ChangeDisplaySettingsExW returned {}", result)));
+    }
+    Ok(())
+}
+
+fn windowed_style(descriptor: &WindowDescriptor) -> vulkan_bindings::DWORD
+{
+    match (descriptor.decorations, descriptor.resizable) {
+        (true, true) => vulkan_bindings::WS_OVERLAPPEDWINDOW,
+        (true, false) => vulkan_bindings::WS_CAPTION | vulkan_bindings::WS_MINIMIZEBOX | vulkan_bindings::WS_SYSMENU,
+        (false, true) => vulkan_bindings::WS_POPUP | vulkan_bindings::WS_THICKFRAME,
+        (false, false) => vulkan_bindings::WS_POPUP
+    }
+}
+
+fn window_style(descriptor: &WindowDescriptor) -> vulkan_bindings::DWORD
+{
+    if descriptor.fullscreen == FullscreenMode::WINDOWED { windowed_style(descriptor) } else { vulkan_bindings::WS_POPUP }
+}
+
+// the windowed frame around the descriptor's client area
+fn frame_size(descriptor: &WindowDescriptor) -> (i32, i32)
+{
+    unsafe {
+        let mut rect : vulkan_bindings::RECT = std::mem::zeroed();
+        rect.right = descriptor.width as i32;
+        rect.bottom = descriptor.height as i32;
+        vulkan_bindings::AdjustWindowRect(&mut rect, windowed_style(descriptor), 0);
+        (rect.right - rect.left, rect.bottom - rect.top)
+    }
+}
+
+fn windowed_frame(descriptor: &WindowDescriptor, monitor: Option<MonitorArea>) -> vulkan_bindings::RECT
+{
+    let (width, height) = frame_size(descriptor);
+    let (x, y) = descriptor.frame_origin(monitor, width as u32, height as u32);
+    vulkan_bindings::RECT {
+        left: x,
+        top: y,
+        right: x + width,
+        bottom: y + height
+    }
+}
+
+// EXCLUSIVE has already changed the monitor to the descriptor's size by then
+fn fullscreen_frame(descriptor: &WindowDescriptor, monitor: Option<MonitorArea>) -> vulkan_bindings::RECT
+{
+    let monitor = monitor.unwrap_or(MonitorArea { x: 0, y: 0, width: descriptor.width, height: descriptor.height, primary: true });
+    let (width, height) = if descriptor.fullscreen == FullscreenMode::EXCLUSIVE { (descriptor.width, descriptor.height) } else { (monitor.width, monitor.height) };
+    vulkan_bindings::RECT {
+        left: monitor.x,
+        top: monitor.y,
+        right: monitor.x + width as i32,
+        bottom: monitor.y + height as i32
+    }
+}
+
+fn low_word(value: vulkan_bindings::WPARAM) -> u16
+{
+    (value & 0xffff) as u16
+}
+
+fn high_word(value: vulkan_bindings::WPARAM) -> u16
+{
+    ((value >> 16) & 0xffff) as u16
+}
+
+// bits 16-23 of the key messages' lparam, extended keys get the 0xe0 prefix of their set 1 scancode
+fn scancode(l_param: vulkan_bindings::LPARAM) -> u32
+{
+    let scancode = ((l_param as usize >> 16) & 0xff) as u32;
+    if (l_param as usize >> 24) & 1 != 0 { 0xe000 | scancode } else { scancode }
+}
+
+fn virtual_keycode(virtual_key: u32) -> KeyCode
+{
+    match virtual_key {
+        0x30..=0x39 | 0x41..=0x5a => KeyCode::from_char(char::from(virtual_key as u8)),
+        vulkan_bindings::VK_F1..=vulkan_bindings::VK_F12 => KeyCode::function(virtual_key - vulkan_bindings::VK_F1 + 1),
+        vulkan_bindings::VK_ESCAPE => KeyCode::ESCAPE,
+        vulkan_bindings::VK_RETURN => KeyCode::ENTER,
+        vulkan_bindings::VK_SPACE => KeyCode::SPACE,
+        vulkan_bindings::VK_TAB => KeyCode::TAB,
+        vulkan_bindings::VK_BACK => KeyCode::BACKSPACE,
+        vulkan_bindings::VK_DELETE => KeyCode::DELETE,
+        vulkan_bindings::VK_INSERT => KeyCode::INSERT,
+        vulkan_bindings::VK_HOME => KeyCode::HOME,
+        vulkan_bindings::VK_END => KeyCode::END,
+        vulkan_bindings::VK_PRIOR => KeyCode::PAGE_UP,
+        vulkan_bindings::VK_NEXT => KeyCode::PAGE_DOWN,
+        vulkan_bindings::VK_LEFT => KeyCode::LEFT,
+        vulkan_bindings::VK_RIGHT => KeyCode::RIGHT,
+        vulkan_bindings::VK_UP => KeyCode::UP,
+        vulkan_bindings::VK_DOWN => KeyCode::DOWN,
+        vulkan_bindings::VK_SHIFT => KeyCode::SHIFT,
+        vulkan_bindings::VK_CONTROL => KeyCode::CONTROL,
+        vulkan_bindings::VK_MENU => KeyCode::ALT,
+        vulkan_bindings::VK_CAPITAL => KeyCode::CAPS_LOCK,
+        _ => KeyCode::UNKNOWN
+    }
+}
+
+impl Win32State {
+    fn size_changed(&mut self, w_param: vulkan_bindings::WPARAM, l_param: vulkan_bindings::LPARAM)
+    {
+        if w_param as u32 == vulkan_bindings::SIZE_MINIMIZED
+        {
+            if !self.minimized
+            {
+                self.minimized = true;
+                self.events.push(WindowEvent::MINIMIZED(true));
+            }
+            return;
+        }
+        if self.minimized
+        {
+            self.minimized = false;
+            self.events.push(WindowEvent::MINIMIZED(false));
+        }
+        let (width, height) = (low_word(l_param as vulkan_bindings::WPARAM) as u32, high_word(l_param as vulkan_bindings::WPARAM) as u32);
+        if (width, height) != (self.width, self.height)
+        {
+            self.width = width;
+            self.height = height;
+            self.events.push(WindowEvent::RESIZED { width, height });
+        }
+    }
+
+    fn character(&mut self, unit: u16)
+    {
+        let units = match (self.high_surrogate.take(), unit) {
+            (None, 0xd800..=0xdbff) => {
+                self.high_surrogate = Some(unit);
+                return;
+            },
+            (Some(high), 0xdc00..=0xdfff) => vec![high, unit],
+            _ => vec![unit]
+        };
+        for c in char::decode_utf16(units).filter_map(|c| c.ok()).filter(|c| !c.is_control())
+        {
+            self.events.push(WindowEvent::TEXT_INPUT(c));
+        }
+    }
+}
+
+impl Win32Window {
+
+    pub extern "C" fn window_proc(
+        h_wnd: vulkan_bindings::HWND,
+        u_msg: vulkan_bindings::UINT,
+        w_param: vulkan_bindings::WPARAM,
+        l_param: vulkan_bindings::LPARAM,
+    ) -> vulkan_bindings::LRESULT {
+        unsafe {
+            let state = vulkan_bindings::GetWindowLongPtrW(h_wnd, vulkan_bindings::GWLP_USERDATA) as *mut Win32State;
+            if state == std::ptr::null_mut()
+            {
+                return vulkan_bindings::DefWindowProcW(h_wnd, u_msg, w_param, l_param);
+            }
+            let state = &mut *state;
+            let button = |button: MouseButton, pressed: bool| if pressed { WindowEvent::MOUSE_BUTTON_DOWN(button) } else { WindowEvent::MOUSE_BUTTON_UP(button) };
+            match u_msg {
+                // the application decides whether to close, DefWindowProcW would destroy the window right away
+                vulkan_bindings::WM_CLOSE => {
+                    state.events.push(WindowEvent::CLOSE_REQUESTED);
+                    return 0;
+                },
+                vulkan_bindings::WM_SIZE => state.size_changed(w_param, l_param),
+                vulkan_bindings::WM_SETFOCUS => state.events.push(WindowEvent::FOCUSED(true)),
+                vulkan_bindings::WM_KILLFOCUS => state.events.push(WindowEvent::FOCUSED(false)),
+                vulkan_bindings::WM_KEYDOWN | vulkan_bindings::WM_SYSKEYDOWN => state.events.push(WindowEvent::KEY_DOWN {
+                    scancode: scancode(l_param),
+                    keycode: virtual_keycode(w_param as u32)
+                }),
+                vulkan_bindings::WM_KEYUP | vulkan_bindings::WM_SYSKEYUP => state.events.push(WindowEvent::KEY_UP {
+                    scancode: scancode(l_param),
+                    keycode: virtual_keycode(w_param as u32)
+                }),
+                vulkan_bindings::WM_CHAR => state.character(w_param as u16),
+                vulkan_bindings::WM_MOUSEMOVE => state.events.push(WindowEvent::MOUSE_MOVED {
+                    x: low_word(l_param as vulkan_bindings::WPARAM) as i16 as f64,
+                    y: high_word(l_param as vulkan_bindings::WPARAM) as i16 as f64
+                }),
+                vulkan_bindings::WM_LBUTTONDOWN => state.events.push(button(MouseButton::LEFT, true)),
+                vulkan_bindings::WM_LBUTTONUP => state.events.push(button(MouseButton::LEFT, false)),
+                vulkan_bindings::WM_RBUTTONDOWN => state.events.push(button(MouseButton::RIGHT, true)),
+                vulkan_bindings::WM_RBUTTONUP => state.events.push(button(MouseButton::RIGHT, false)),
+                vulkan_bindings::WM_MBUTTONDOWN => state.events.push(button(MouseButton::MIDDLE, true)),
+                vulkan_bindings::WM_MBUTTONUP => state.events.push(button(MouseButton::MIDDLE, false)),
+                vulkan_bindings::WM_XBUTTONDOWN | vulkan_bindings::WM_XBUTTONUP => {
+                    let x_button = if high_word(w_param) as u32 == vulkan_bindings::XBUTTON1 { MouseButton::BACK } else { MouseButton::FORWARD };
+                    state.events.push(button(x_button, u_msg == vulkan_bindings::WM_XBUTTONDOWN));
+                },
+                vulkan_bindings::WM_MOUSEWHEEL => state.events.push(WindowEvent::MOUSE_WHEEL {
+                    x: 0.0,
+                    y: high_word(w_param) as i16 as f32 / vulkan_bindings::WHEEL_DELTA as f32
+                }),
+                vulkan_bindings::WM_MOUSEHWHEEL => state.events.push(WindowEvent::MOUSE_WHEEL {
+                    x: high_word(w_param) as i16 as f32 / vulkan_bindings::WHEEL_DELTA as f32,
+                    y: 0.0
+                }),
+                _ => {}
+            };
+            vulkan_bindings::DefWindowProcW(h_wnd, u_msg, w_param, l_param)
+        }
+    }
+
+    pub fn new(descriptor: &WindowDescriptor) -> Result<Self, SystemWindowError> {
+        unsafe {
+            let monitors = monitors();
+            let areas : Vec<MonitorArea> = monitors.iter().map(|monitor| monitor.area()).collect();
+            let monitor_idx = descriptor.pick_monitor(&areas)?;
+            let monitor = monitor_idx.map(|idx| areas[idx]);
+            let mut exclusive_device = None;
+            if let (FullscreenMode::EXCLUSIVE, Some(idx)) = (descriptor.fullscreen, monitor_idx)
+            {
+                change_display_mode(&monitors[idx].device, Some((descriptor.width, descriptor.height)))?;
+                exclusive_device = Some(monitors[idx].device);
+            }
+
+            let h_instance = vulkan_bindings::GetModuleHandleW(std::ptr::null());
+            let class_name = descriptor.title.clone() + "_Window_Class";
+            let mut class_name : Vec<vulkan_bindings::wchar_t> = class_name.encode_utf16().collect();
+            class_name.push(0);
+            let mut wndClass : vulkan_bindings::WNDCLASSW = std::mem::zeroed();
+            wndClass.lpszClassName = class_name.as_ptr();
+            wndClass.hInstance = h_instance;
+            wndClass.hIcon = vulkan_bindings::LoadIconW(std::ptr::null_mut(), IDI_WINLOGO.0);
+            wndClass.hCursor = vulkan_bindings::LoadCursorW(std::ptr::null_mut(), IDC_ARROW.0);
+            wndClass.lpfnWndProc = Some(Win32Window::window_proc);
+
+            vulkan_bindings::RegisterClassW(& wndClass);
+            let style = window_style(descriptor);
+            let windowed_frame = windowed_frame(descriptor, monitor);
+            let rect = if descriptor.fullscreen == FullscreenMode::WINDOWED { windowed_frame } else { fullscreen_frame(descriptor, monitor) };
+
+            let mut title : Vec<vulkan_bindings::wchar_t> = descriptor.title.encode_utf16().collect();
+            title.push(0);
+            let m_hwnd = vulkan_bindings::CreateWindowExW(
+                0,
+                class_name.as_ptr(),
+                title.as_ptr(),
+                style,
+                rect.left,
+                rect.top,
+                rect.right - rect.left,
+                rect.bottom - rect.top,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                h_instance,
+                std::ptr::null_mut()
+            );
+            if m_hwnd == std::ptr::null_mut()
+            {
+                if let Some(device) = exclusive_device
+                {
+                    let _ = change_display_mode(&device, None);
+                }
+                vulkan_bindings::UnregisterClassW(class_name.as_ptr(), h_instance);
+                return Err(SystemWindowError::CANT_CREATE_WINDOW(String::from("CreateWindowExW returned no window")));
+            }
+
+            let mut window = Win32Window {
+                Hinstance : h_instance,
+                Hwnd: m_hwnd,
+                class_name,
+                descriptor: descriptor.clone(),
+                windowed_frame,
+                exclusive_device,
+                state: Box::new(Win32State {
+                    events: Vec::new(),
+                    width: descriptor.width,
+                    height: descriptor.height,
+                    minimized: false,
+                    high_surrogate: None
+                })
+            };
+            vulkan_bindings::SetWindowLongPtrW(m_hwnd, vulkan_bindings::GWLP_USERDATA, &mut *window.state as *mut Win32State as vulkan_bindings::LONG_PTR);
+            vulkan_bindings::ShowWindow(m_hwnd, vulkan_bindings::SW_SHOW as i32);
+            Ok(window)
+        }
+    }
+
+    pub fn set_title(&mut self, title: &str)
+    {
+        let mut wide_title : Vec<vulkan_bindings::wchar_t> = title.encode_utf16().collect();
+        wide_title.push(0);
+        unsafe {
+            vulkan_bindings::SetWindowTextW(self.Hwnd, wide_title.as_ptr());
+        }
+        self.descriptor.title = title.to_string();
+    }
+
+    // keeps the frame's top left, a fullscreen window only remembers the size for when it leaves fullscreen
+    pub fn set_size(&mut self, width: u32, height: u32)
+    {
+        self.descriptor.width = width;
+        self.descriptor.height = height;
+        let (frame_width, frame_height) = frame_size(&self.descriptor);
+        self.windowed_frame.right = self.windowed_frame.left + frame_width;
+        self.windowed_frame.bottom = self.windowed_frame.top + frame_height;
+        unsafe {
+            if self.descriptor.fullscreen == FullscreenMode::WINDOWED
+            {
+                vulkan_bindings::SetWindowPos(
+                    self.Hwnd,
+                    std::ptr::null_mut(),
+                    0,
+                    0,
+                    frame_width,
+                    frame_height,
+                    vulkan_bindings::SWP_NOMOVE | vulkan_bindings::SWP_NOZORDER | vulkan_bindings::SWP_NOACTIVATE
+                );
+            }
+        }
+    }
+
+    pub fn set_fullscreen(&mut self, descriptor: &WindowDescriptor) -> Result<(), SystemWindowError>
+    {
+        unsafe {
+            if self.descriptor.fullscreen == FullscreenMode::WINDOWED
+            {
+                vulkan_bindings::GetWindowRect(self.Hwnd, &mut self.windowed_frame);
+            }
+            // the monitor areas are only right once the previous mode is back
+            if let Some(device) = self.exclusive_device.take()
+            {
+                let _ = change_display_mode(&device, None);
+            }
+            let monitors = monitors();
+            let areas : Vec<MonitorArea> = monitors.iter().map(|monitor| monitor.area()).collect();
+            let monitor_idx = descriptor.pick_monitor(&areas)?;
+            if let (FullscreenMode::EXCLUSIVE, Some(idx)) = (descriptor.fullscreen, monitor_idx)
+            {
+                change_display_mode(&monitors[idx].device, Some((descriptor.width, descriptor.height)))?;
+                self.exclusive_device = Some(monitors[idx].device);
+            }
+            let rect = if descriptor.fullscreen == FullscreenMode::WINDOWED {
+                self.windowed_frame
+            } else {
+                fullscreen_frame(descriptor, monitor_idx.map(|idx| areas[idx]))
+            };
+            vulkan_bindings::SetWindowLongPtrW(self.Hwnd, vulkan_bindings::GWL_STYLE, (window_style(descriptor) | vulkan_bindings::WS_VISIBLE) as vulkan_bindings::LONG_PTR);
+            vulkan_bindings::SetWindowPos(
+                self.Hwnd,
+                std::ptr::null_mut(),
+                rect.left,
+                rect.top,
+                rect.right - rect.left,
+                rect.bottom - rect.top,
+                vulkan_bindings::SWP_FRAMECHANGED
+            );
+        }
+        self.descriptor = descriptor.clone();
+        Ok(())
+    }
+
+    pub fn size(& self) -> (u32, u32)
+    {
+        (self.state.width, self.state.height)
+    }
+
+    // runs window_proc over the thread's queued messages, WAIT keeps blocking in GetMessageW until one produced an event
+    pub fn pump_events(&mut self, mode: EventMode) -> Vec<WindowEvent>
+    {
+        unsafe {
+            let mut msg : vulkan_bindings::MSG = std::mem::zeroed();
+            while mode == EventMode::WAIT && self.state.events.is_empty()
+            {
+                if vulkan_bindings::GetMessageW(&mut msg, std::ptr::null_mut(), 0, 0) <= 0
+                {
+                    break;
+                }
+                vulkan_bindings::TranslateMessage(&msg);
+                vulkan_bindings::DispatchMessageW(&msg);
+            }
+            while vulkan_bindings::PeekMessageW(&mut msg, std::ptr::null_mut(), 0, 0, vulkan_bindings::PM_REMOVE) != 0
+            {
+                vulkan_bindings::TranslateMessage(&msg);
+                vulkan_bindings::DispatchMessageW(&msg);
+            }
+        }
+        std::mem::take(&mut self.state.events)
+    }
+
+    pub fn destroy(self)
+    {
+        unsafe {
+            // window_proc mustn't reach the state once it's freed
+            vulkan_bindings::SetWindowLongPtrW(self.Hwnd, vulkan_bindings::GWLP_USERDATA, 0);
+            if let Some(device) = self.exclusive_device
+            {
+                let _ = change_display_mode(&device, None);
+            }
+            vulkan_bindings::DestroyWindow(self.Hwnd);
+            vulkan_bindings::UnregisterClassW(self.class_name.as_ptr(), self.Hinstance);
+        }
+    }
+}
@@ -0,0 +1,462 @@
+use crate::vulkan_bindings;
+use crate::system_window::{FullscreenMode, MonitorArea, SystemWindowError, WindowDescriptor};
+use crate::system_events::{self, EventMode, WindowEvent};
+
+extern "C" {
+    fn free(ptr: *mut std::ffi::c_void);
+}
+
+// _NET_WM_STATE client message actions
+const NET_WM_STATE_REMOVE: u32 = 0;
+const NET_WM_STATE_ADD: u32 = 1;
+// _MOTIF_WM_HINTS flag saying the decorations field is set
+const MWM_HINTS_DECORATIONS: u32 = 2;
+// WM_SIZE_HINTS flags, xcb doesn't ship the icccm ones
+const US_POSITION: u32 = 1;
+const P_POSITION: u32 = 4;
+const P_MIN_SIZE: u32 = 16;
+const P_MAX_SIZE: u32 = 32;
+
+pub struct XcbWindow {
+    pub connection: *mut vulkan_bindings::xcb_connection_t,
+    pub window: vulkan_bindings::xcb_window_t,
+    // sent in a client message when the window manager closes the window
+    pub delete_atom: vulkan_bindings::xcb_atom_t,
+    root: vulkan_bindings::xcb_window_t,
+    net_wm_state: vulkan_bindings::xcb_atom_t,
+    net_wm_state_fullscreen: vulkan_bindings::xcb_atom_t,
+    descriptor: WindowDescriptor,
+    width: u32,
+    height: u32,
+    minimized: bool,
+    // the core protocol keycode to keysym table, xkb group switches are not followed
+    min_keycode: u8,
+    keysyms_per_keycode: usize,
+    keysyms: Vec<vulkan_bindings::xcb_keysym_t>
+}
+
+unsafe fn keyboard_mapping(connection: *mut vulkan_bindings::xcb_connection_t, setup: *const vulkan_bindings::xcb_setup_t) -> (usize, Vec<vulkan_bindings::xcb_keysym_t>)
+{
+    let count = (*setup).max_keycode - (*setup).min_keycode + 1;
+    let cookie = vulkan_bindings::xcb_get_keyboard_mapping(connection, (*setup).min_keycode, count);
+    let reply = vulkan_bindings::xcb_get_keyboard_mapping_reply(connection, cookie, std::ptr::null_mut());
+    if reply == std::ptr::null_mut()
+    {
+        return (0, Vec::new());
+    }
+    let keysyms = std::slice::from_raw_parts(
+        vulkan_bindings::xcb_get_keyboard_mapping_keysyms(reply),
+        vulkan_bindings::xcb_get_keyboard_mapping_keysyms_length(reply) as usize
+    ).to_vec();
+    let keysyms_per_keycode = (*reply).keysyms_per_keycode as usize;
+    free(reply as *mut std::ffi::c_void);
+    (keysyms_per_keycode, keysyms)
+}
+
+unsafe fn intern_atom(connection: *mut vulkan_bindings::xcb_connection_t, name: &str) -> vulkan_bindings::xcb_atom_t
+{
+    let cookie = vulkan_bindings::xcb_intern_atom(connection, 0, name.len() as u16, name.as_ptr() as *const std::os::raw::c_char);
+    let reply = vulkan_bindings::xcb_intern_atom_reply(connection, cookie, std::ptr::null_mut());
+    if reply == std::ptr::null_mut()
+    {
+        return vulkan_bindings::xcb_atom_enum_t_XCB_ATOM_NONE;
+    }
+    let atom = (*reply).atom;
+    free(reply as *mut std::ffi::c_void);
+    atom
+}
+
+// WM_NAME is a latin-1 STRING, window managers read the utf-8 title from _NET_WM_NAME
+unsafe fn store_title(connection: *mut vulkan_bindings::xcb_connection_t, window: vulkan_bindings::xcb_window_t, title: &str)
+{
+    vulkan_bindings::xcb_change_property(
+        connection,
+        vulkan_bindings::xcb_prop_mode_t_XCB_PROP_MODE_REPLACE as u8,
+        window,
+        vulkan_bindings::xcb_atom_enum_t_XCB_ATOM_WM_NAME,
+        vulkan_bindings::xcb_atom_enum_t_XCB_ATOM_STRING,
+        8,
+        title.len() as u32,
+        title.as_ptr() as *const std::ffi::c_void
+    );
+    vulkan_bindings::xcb_change_property(
+        connection,
+        vulkan_bindings::xcb_prop_mode_t_XCB_PROP_MODE_REPLACE as u8,
+        window,
+        intern_atom(connection, "_NET_WM_NAME"),
+        intern_atom(connection, "UTF8_STRING"),
+        8,
+        title.len() as u32,
+        title.as_ptr() as *const std::ffi::c_void
+    );
+}
+
+// the active randr monitors, empty when the server doesn't have randr 1.5
+unsafe fn monitors(connection: *mut vulkan_bindings::xcb_connection_t, root: vulkan_bindings::xcb_window_t) -> Vec<MonitorArea>
+{
+    let cookie = vulkan_bindings::xcb_randr_get_monitors(connection, root, 1);
+    let reply = vulkan_bindings::xcb_randr_get_monitors_reply(connection, cookie, std::ptr::null_mut());
+    if reply == std::ptr::null_mut()
+    {
+        return Vec::new();
+    }
+    let mut monitors: Vec<MonitorArea> = Vec::new();
+    let mut infos = vulkan_bindings::xcb_randr_get_monitors_monitors_iterator(reply);
+    while infos.rem > 0
+    {
+        let info = &*infos.data;
+        monitors.push(MonitorArea {
+            x: info.x as i32,
+            y: info.y as i32,
+            width: info.width as u32,
+            height: info.height as u32,
+            primary: info.primary != 0
+        });
+        vulkan_bindings::xcb_randr_monitor_info_next(&mut infos);
+    }
+    free(reply as *mut std::ffi::c_void);
+    monitors
+}
+
+impl XcbWindow {
+    pub fn new(descriptor: &WindowDescriptor) -> Result<Self, SystemWindowError>
+    {
+        unsafe {
+            // DISPLAY picks the server and screen, Xvfb included
+            let mut screen_idx: std::os::raw::c_int = 0;
+            let connection = vulkan_bindings::xcb_connect(std::ptr::null(), &mut screen_idx);
+            if vulkan_bindings::xcb_connection_has_error(connection) != 0
+            {
+                vulkan_bindings::xcb_disconnect(connection);
+                return Err(SystemWindowError::CANT_OPEN_DISPLAY(std::env::var("DISPLAY").unwrap_or_default()));
+            }
+            let setup = vulkan_bindings::xcb_get_setup(connection);
+            let mut screens = vulkan_bindings::xcb_setup_roots_iterator(setup);
+            for _ in 0..screen_idx
+            {
+                vulkan_bindings::xcb_screen_next(&mut screens);
+            }
+            let screen = screens.data;
+            if screen == std::ptr::null_mut()
+            {
+                vulkan_bindings::xcb_disconnect(connection);
+                return Err(SystemWindowError::CANT_CREATE_WINDOW(format!("X server has no screen {}", screen_idx)));
+            }
+
+            let monitors = monitors(connection, (*screen).root);
+            let monitor = match descriptor.pick_monitor(&monitors) {
+                Ok(idx) => idx.map(|idx| monitors[idx]),
+                Err(e) => {
+                    vulkan_bindings::xcb_disconnect(connection);
+                    return Err(e);
+                }
+            };
+            // fullscreen windows start out on their monitor, the window manager then covers the one they're on
+            let (x, y) = match (descriptor.fullscreen, monitor) {
+                (FullscreenMode::WINDOWED, _) | (_, None) => descriptor.frame_origin(monitor, descriptor.width, descriptor.height),
+                (_, Some(monitor)) => (monitor.x, monitor.y)
+            };
+
+            let window = vulkan_bindings::xcb_generate_id(connection);
+            let value_mask = vulkan_bindings::xcb_cw_t_XCB_CW_BACK_PIXEL | vulkan_bindings::xcb_cw_t_XCB_CW_EVENT_MASK;
+            let values: [u32; 2] = [
+                (*screen).black_pixel,
+                vulkan_bindings::xcb_event_mask_t_XCB_EVENT_MASK_EXPOSURE | vulkan_bindings::xcb_event_mask_t_XCB_EVENT_MASK_STRUCTURE_NOTIFY
+                | vulkan_bindings::xcb_event_mask_t_XCB_EVENT_MASK_FOCUS_CHANGE
+                | vulkan_bindings::xcb_event_mask_t_XCB_EVENT_MASK_KEY_PRESS | vulkan_bindings::xcb_event_mask_t_XCB_EVENT_MASK_KEY_RELEASE
+                | vulkan_bindings::xcb_event_mask_t_XCB_EVENT_MASK_BUTTON_PRESS | vulkan_bindings::xcb_event_mask_t_XCB_EVENT_MASK_BUTTON_RELEASE
+                | vulkan_bindings::xcb_event_mask_t_XCB_EVENT_MASK_POINTER_MOTION
+            ];
+            vulkan_bindings::xcb_create_window(
+                connection,
+                vulkan_bindings::XCB_COPY_FROM_PARENT as u8,
+                window,
+                (*screen).root,
+                x as i16,
+                y as i16,
+                descriptor.width as u16,
+                descriptor.height as u16,
+                0,
+                vulkan_bindings::xcb_window_class_t_XCB_WINDOW_CLASS_INPUT_OUTPUT as u16,
+                (*screen).root_visual,
+                value_mask,
+                values.as_ptr() as *const std::ffi::c_void
+            );
+
+            store_title(connection, window, &descriptor.title);
+
+            let protocols_atom = intern_atom(connection, "WM_PROTOCOLS");
+            let delete_atom = intern_atom(connection, "WM_DELETE_WINDOW");
+            vulkan_bindings::xcb_change_property(
+                connection,
+                vulkan_bindings::xcb_prop_mode_t_XCB_PROP_MODE_REPLACE as u8,
+                window,
+                protocols_atom,
+                vulkan_bindings::xcb_atom_enum_t_XCB_ATOM_ATOM,
+                32,
+                1,
+                &delete_atom as *const vulkan_bindings::xcb_atom_t as *const std::ffi::c_void
+            );
+
+            let (keysyms_per_keycode, keysyms) = keyboard_mapping(connection, setup);
+            let window = XcbWindow {
+                connection,
+                window,
+                delete_atom,
+                root: (*screen).root,
+                net_wm_state: intern_atom(connection, "_NET_WM_STATE"),
+                net_wm_state_fullscreen: intern_atom(connection, "_NET_WM_STATE_FULLSCREEN"),
+                descriptor: descriptor.clone(),
+                width: descriptor.width,
+                height: descriptor.height,
+                minimized: false,
+                min_keycode: (*setup).min_keycode,
+                keysyms_per_keycode,
+                keysyms
+            };
+            window.set_normal_hints();
+            if !descriptor.decorations
+            {
+                let motif_hints = intern_atom(connection, "_MOTIF_WM_HINTS");
+                let hints: [u32; 5] = [MWM_HINTS_DECORATIONS, 0, 0, 0, 0];
+                vulkan_bindings::xcb_change_property(
+                    connection,
+                    vulkan_bindings::xcb_prop_mode_t_XCB_PROP_MODE_REPLACE as u8,
+                    window.window,
+                    motif_hints,
+                    motif_hints,
+                    32,
+                    5,
+                    hints.as_ptr() as *const std::ffi::c_void
+                );
+            }
+            // before mapping the state is a plain property, afterwards the window manager has to be asked
+            if descriptor.fullscreen != FullscreenMode::WINDOWED
+            {
+                vulkan_bindings::xcb_change_property(
+                    connection,
+                    vulkan_bindings::xcb_prop_mode_t_XCB_PROP_MODE_REPLACE as u8,
+                    window.window,
+                    window.net_wm_state,
+                    vulkan_bindings::xcb_atom_enum_t_XCB_ATOM_ATOM,
+                    32,
+                    1,
+                    &window.net_wm_state_fullscreen as *const vulkan_bindings::xcb_atom_t as *const std::ffi::c_void
+                );
+            }
+
+            vulkan_bindings::xcb_map_window(connection, window.window);
+            vulkan_bindings::xcb_flush(connection);
+            Ok(window)
+        }
+    }
+
+    // WM_NORMAL_HINTS with the requested position, and a fixed size when the window isn't resizable
+    unsafe fn set_normal_hints(& self)
+    {
+        // flags, x, y, width, height, min size, max size, resize increments, aspect ratios, base size, gravity
+        let mut hints = [0u32; 18];
+        if let Some((x, y)) = self.descriptor.position
+        {
+            hints[0] |= US_POSITION | P_POSITION;
+            hints[1] = x as u32;
+            hints[2] = y as u32;
+        }
+        if !self.descriptor.resizable
+        {
+            hints[0] |= P_MIN_SIZE | P_MAX_SIZE;
+            hints[5] = self.descriptor.width;
+            hints[6] = self.descriptor.height;
+            hints[7] = self.descriptor.width;
+            hints[8] = self.descriptor.height;
+        }
+        vulkan_bindings::xcb_change_property(
+            self.connection,
+            vulkan_bindings::xcb_prop_mode_t_XCB_PROP_MODE_REPLACE as u8,
+            self.window,
+            vulkan_bindings::xcb_atom_enum_t_XCB_ATOM_WM_NORMAL_HINTS,
+            vulkan_bindings::xcb_atom_enum_t_XCB_ATOM_WM_SIZE_HINTS,
+            32,
+            hints.len() as u32,
+            hints.as_ptr() as *const std::ffi::c_void
+        );
+    }
+
+    pub fn set_title(&mut self, title: &str)
+    {
+        unsafe {
+            store_title(self.connection, self.window, title);
+            vulkan_bindings::xcb_flush(self.connection);
+        }
+        self.descriptor.title = title.to_string();
+    }
+
+    unsafe fn resize(& self, width: u32, height: u32)
+    {
+        let mask = vulkan_bindings::xcb_config_window_t_XCB_CONFIG_WINDOW_WIDTH | vulkan_bindings::xcb_config_window_t_XCB_CONFIG_WINDOW_HEIGHT;
+        let values: [u32; 2] = [width, height];
+        vulkan_bindings::xcb_configure_window(self.connection, self.window, mask as u16, values.as_ptr() as *const std::ffi::c_void);
+    }
+
+    // a fullscreen window gets the size back when it leaves fullscreen
+    pub fn set_size(&mut self, width: u32, height: u32)
+    {
+        self.descriptor.width = width;
+        self.descriptor.height = height;
+        unsafe {
+            self.set_normal_hints();
+            if self.descriptor.fullscreen == FullscreenMode::WINDOWED
+            {
+                self.resize(width, height);
+            }
+            vulkan_bindings::xcb_flush(self.connection);
+        }
+    }
+
+    // X11 has no client side mode switch the window manager would honor, EXCLUSIVE is BORDERLESS here
+    pub fn set_fullscreen(&mut self, descriptor: &WindowDescriptor) -> Result<(), SystemWindowError>
+    {
+        unsafe {
+            let monitors = monitors(self.connection, self.root);
+            let monitor = descriptor.pick_monitor(&monitors)?.map(|idx| monitors[idx]);
+            let fullscreen = descriptor.fullscreen != FullscreenMode::WINDOWED;
+            if let (true, Some(monitor)) = (fullscreen, monitor)
+            {
+                let mask = vulkan_bindings::xcb_config_window_t_XCB_CONFIG_WINDOW_X | vulkan_bindings::xcb_config_window_t_XCB_CONFIG_WINDOW_Y;
+                let values: [u32; 2] = [monitor.x as u32, monitor.y as u32];
+                vulkan_bindings::xcb_configure_window(self.connection, self.window, mask as u16, values.as_ptr() as *const std::ffi::c_void);
+            }
+            let mut event : vulkan_bindings::xcb_client_message_event_t = std::mem::zeroed();
+            event.response_type = vulkan_bindings::XCB_CLIENT_MESSAGE as u8;
+            event.format = 32;
+            event.window = self.window;
+            event.type_ = self.net_wm_state;
+            event.data.data32[0] = if fullscreen { NET_WM_STATE_ADD } else { NET_WM_STATE_REMOVE };
+            event.data.data32[1] = self.net_wm_state_fullscreen;
+            // 1 says the request comes from an application
+            event.data.data32[3] = 1;
+            let mask = vulkan_bindings::xcb_event_mask_t_XCB_EVENT_MASK_SUBSTRUCTURE_REDIRECT | vulkan_bindings::xcb_event_mask_t_XCB_EVENT_MASK_SUBSTRUCTURE_NOTIFY;
+            vulkan_bindings::xcb_send_event(self.connection, 0, self.root, mask, &event as *const vulkan_bindings::xcb_client_message_event_t as *const std::os::raw::c_char);
+            if !fullscreen
+            {
+                self.resize(descriptor.width, descriptor.height);
+            }
+            vulkan_bindings::xcb_flush(self.connection);
+        }
+        self.descriptor = descriptor.clone();
+        Ok(())
+    }
+
+    pub fn size(& self) -> (u32, u32)
+    {
+        (self.width, self.height)
+    }
+
+    // first column unshifted, second shifted, letters only list the lowercase keysym
+    fn keysym(& self, keycode: u8, state: u16) -> u32
+    {
+        let base = keycode.saturating_sub(self.min_keycode) as usize * self.keysyms_per_keycode;
+        let column = |idx: usize| if idx < self.keysyms_per_keycode { self.keysyms.get(base + idx).copied().unwrap_or(0) } else { 0 };
+        let (lower, upper) = (column(0), column(1));
+        let letter = (0x61..=0x7a).contains(&lower) && (upper == 0 || upper == lower - 0x20);
+        let shift = state as u32 & vulkan_bindings::xcb_mod_mask_t_XCB_MOD_MASK_SHIFT != 0;
+        let lock = state as u32 & vulkan_bindings::xcb_mod_mask_t_XCB_MOD_MASK_LOCK != 0;
+        match (letter, shift, shift != lock) {
+            (true, _, true) => lower - 0x20,
+            (false, true, _) if upper != 0 => upper,
+            _ => lower
+        }
+    }
+
+    // WAIT keeps blocking in xcb_wait_for_event until something translated into an event
+    pub fn pump_events(&mut self, mode: EventMode) -> Vec<WindowEvent>
+    {
+        let mut events: Vec<WindowEvent> = Vec::new();
+        unsafe {
+            loop
+            {
+                let event = if mode == EventMode::WAIT && events.is_empty() {
+                    vulkan_bindings::xcb_wait_for_event(self.connection)
+                } else {
+                    vulkan_bindings::xcb_poll_for_event(self.connection)
+                };
+                if event == std::ptr::null_mut()
+                {
+                    break;
+                }
+                self.translate(event, &mut events);
+                free(event as *mut std::ffi::c_void);
+            }
+        }
+        events
+    }
+
+    unsafe fn translate(&mut self, event: *mut vulkan_bindings::xcb_generic_event_t, events: &mut Vec<WindowEvent>)
+    {
+        let response_type = ((*event).response_type & 0x7f) as u32;
+        match response_type {
+            vulkan_bindings::XCB_KEY_PRESS | vulkan_bindings::XCB_KEY_RELEASE => {
+                let key = &*(event as *const vulkan_bindings::xcb_key_press_event_t);
+                // X keycodes are evdev codes shifted by 8
+                let scancode = key.detail.saturating_sub(8) as u32;
+                let keycode = system_events::keysym_keycode(self.keysym(key.detail, 0));
+                if response_type == vulkan_bindings::XCB_KEY_RELEASE
+                {
+                    events.push(WindowEvent::KEY_UP { scancode, keycode });
+                    return;
+                }
+                events.push(WindowEvent::KEY_DOWN { scancode, keycode });
+                if let Some(c) = system_events::keysym_char(self.keysym(key.detail, key.state)).filter(|c| !c.is_control())
+                {
+                    events.push(WindowEvent::TEXT_INPUT(c));
+                }
+            },
+            vulkan_bindings::XCB_BUTTON_PRESS | vulkan_bindings::XCB_BUTTON_RELEASE => {
+                let button = &*(event as *const vulkan_bindings::xcb_button_press_event_t);
+                events.extend(system_events::x11_button_events(button.detail as u32, response_type == vulkan_bindings::XCB_BUTTON_PRESS));
+            },
+            vulkan_bindings::XCB_MOTION_NOTIFY => {
+                let motion = &*(event as *const vulkan_bindings::xcb_motion_notify_event_t);
+                events.push(WindowEvent::MOUSE_MOVED { x: motion.event_x as f64, y: motion.event_y as f64 });
+            },
+            vulkan_bindings::XCB_FOCUS_IN => events.push(WindowEvent::FOCUSED(true)),
+            vulkan_bindings::XCB_FOCUS_OUT => events.push(WindowEvent::FOCUSED(false)),
+            // window managers unmap iconified windows
+            vulkan_bindings::XCB_UNMAP_NOTIFY | vulkan_bindings::XCB_MAP_NOTIFY => {
+                let minimized = response_type == vulkan_bindings::XCB_UNMAP_NOTIFY;
+                if minimized != self.minimized
+                {
+                    self.minimized = minimized;
+                    events.push(WindowEvent::MINIMIZED(minimized));
+                }
+            },
+            vulkan_bindings::XCB_CONFIGURE_NOTIFY => {
+                let configure = &*(event as *const vulkan_bindings::xcb_configure_notify_event_t);
+                let (width, height) = (configure.width as u32, configure.height as u32);
+                if (width, height) != (self.width, self.height)
+                {
+                    self.width = width;
+                    self.height = height;
+                    events.push(WindowEvent::RESIZED { width, height });
+                }
+            },
+            vulkan_bindings::XCB_CLIENT_MESSAGE => {
+                let message = &*(event as *const vulkan_bindings::xcb_client_message_event_t);
+                if message.data.data32[0] == self.delete_atom
+                {
+                    events.push(WindowEvent::CLOSE_REQUESTED);
+                }
+            },
+            _ => {}
+        }
+    }
+
+    pub fn destroy(self)
+    {
+        unsafe {
+            vulkan_bindings::xcb_destroy_window(self.connection, self.window);
+            vulkan_bindings::xcb_flush(self.connection);
+            vulkan_bindings::xcb_disconnect(self.connection);
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use crate::vulkan_bindings;
+use crate::system_window::{FullscreenMode, MonitorArea, SystemWindowError, WindowDescriptor};
+use crate::system_events::{self, EventMode, WindowEvent};
+use std::ffi::CString;
+
+// _NET_WM_STATE client message actions
+const NET_WM_STATE_REMOVE: std::os::raw::c_long = 0;
+const NET_WM_STATE_ADD: std::os::raw::c_long = 1;
+// _MOTIF_WM_HINTS flag saying the decorations field is set
+const MWM_HINTS_DECORATIONS: std::os::raw::c_long = 2;
+
+pub struct XlibWindow {
+    pub display: *mut vulkan_bindings::Display,
+    pub window: vulkan_bindings::Window,
+    // sent in a client message when the window manager closes the window
+    pub delete_atom: vulkan_bindings::Atom,
+    root: vulkan_bindings::Window,
+    net_wm_state: vulkan_bindings::Atom,
+    net_wm_state_fullscreen: vulkan_bindings::Atom,
+    descriptor: WindowDescriptor,
+    width: u32,
+    height: u32,
+    minimized: bool
+}
+
+unsafe fn intern_atom(display: *mut vulkan_bindings::Display, name: &str) -> vulkan_bindings::Atom
+{
+    let name = CString::new(name).unwrap();
+    vulkan_bindings::XInternAtom(display, name.as_ptr(), 0)
+}
+
+// XStoreName sets the latin-1 WM_NAME, window managers read the utf-8 title from _NET_WM_NAME
+unsafe fn store_title(display: *mut vulkan_bindings::Display, window: vulkan_bindings::Window, title: &str)
+{
+    let title = CString::new(title).unwrap_or_default();
+    vulkan_bindings::XStoreName(display, window, title.as_ptr());
+    vulkan_bindings::XChangeProperty(
+        display,
+        window,
+        intern_atom(display, "_NET_WM_NAME"),
+        intern_atom(display, "UTF8_STRING"),
+        8,
+        vulkan_bindings::PropModeReplace as i32,
+        title.as_ptr() as *const u8,
+        title.as_bytes().len() as i32
+    );
+}
+
+// the active randr monitors, empty when the server doesn't have randr 1.5
+unsafe fn monitors(display: *mut vulkan_bindings::Display, root: vulkan_bindings::Window) -> Vec<MonitorArea>
+{
+    let mut count: std::os::raw::c_int = 0;
+    let infos = vulkan_bindings::XRRGetMonitors(display, root, 1, &mut count);
+    if infos == std::ptr::null_mut()
+    {
+        return Vec::new();
+    }
+    let monitors = std::slice::from_raw_parts(infos, count as usize).iter().map(|info| MonitorArea {
+        x: info.x,
+        y: info.y,
+        width: info.width as u32,
+        height: info.height as u32,
+        primary: info.primary != 0
+    }).collect();
+    vulkan_bindings::XRRFreeMonitors(infos);
+    monitors
+}
+
+impl XlibWindow {
+    pub fn new(descriptor: &WindowDescriptor) -> Result<Self, SystemWindowError>
+    {
+        unsafe {
+            let display = vulkan_bindings::XOpenDisplay(std::ptr::null());
+            if display == std::ptr::null_mut()
+            {
+                return Err(SystemWindowError::CANT_OPEN_DISPLAY(std::env::var("DISPLAY").unwrap_or_default()));
+            }
+            let screen = vulkan_bindings::XDefaultScreen(display);
+            let root = vulkan_bindings::XRootWindow(display, screen);
+            let monitors = monitors(display, root);
+            let monitor = match descriptor.pick_monitor(&monitors) {
+                Ok(idx) => idx.map(|idx| monitors[idx]),
+                Err(e) => {
+                    vulkan_bindings::XCloseDisplay(display);
+                    return Err(e);
+                }
+            };
+            // fullscreen windows start out on their monitor, the window manager then covers the one they're on
+            let (x, y) = match (descriptor.fullscreen, monitor) {
+                (FullscreenMode::WINDOWED, _) | (_, None) => descriptor.frame_origin(monitor, descriptor.width, descriptor.height),
+                (_, Some(monitor)) => (monitor.x, monitor.y)
+            };
+            let black = vulkan_bindings::XBlackPixel(display, screen);
+            let window = vulkan_bindings::XCreateSimpleWindow(display, root, x, y, descriptor.width, descriptor.height, 0, black, black);
+            if window == 0
+            {
+                vulkan_bindings::XCloseDisplay(display);
+                return Err(SystemWindowError::CANT_CREATE_WINDOW(String::from("XCreateSimpleWindow returned no window")));
+            }
+
+            store_title(display, window, &descriptor.title);
+            let event_mask = vulkan_bindings::ExposureMask | vulkan_bindings::StructureNotifyMask | vulkan_bindings::FocusChangeMask
+                | vulkan_bindings::KeyPressMask | vulkan_bindings::KeyReleaseMask
+                | vulkan_bindings::ButtonPressMask | vulkan_bindings::ButtonReleaseMask | vulkan_bindings::PointerMotionMask;
+            vulkan_bindings::XSelectInput(display, window, event_mask as std::os::raw::c_long);
+
+            let mut delete_atom = intern_atom(display, "WM_DELETE_WINDOW");
+            vulkan_bindings::XSetWMProtocols(display, window, &mut delete_atom, 1);
+
+            let window = XlibWindow {
+                display,
+                window,
+                delete_atom,
+                root,
+                net_wm_state: intern_atom(display, "_NET_WM_STATE"),
+                net_wm_state_fullscreen: intern_atom(display, "_NET_WM_STATE_FULLSCREEN"),
+                descriptor: descriptor.clone(),
+                width: descriptor.width,
+                height: descriptor.height,
+                minimized: false
+            };
+            window.set_normal_hints();
+            if !descriptor.decorations
+            {
+                let motif_hints = intern_atom(display, "_MOTIF_WM_HINTS");
+                let hints: [std::os::raw::c_long; 5] = [MWM_HINTS_DECORATIONS, 0, 0, 0, 0];
+                vulkan_bindings::XChangeProperty(display, window.window, motif_hints, motif_hints, 32, vulkan_bindings::PropModeReplace as i32, hints.as_ptr() as *const u8, 5);
+            }
+            // before mapping the state is a plain property, afterwards the window manager has to be asked
+            if descriptor.fullscreen != FullscreenMode::WINDOWED
+            {
+                vulkan_bindings::XChangeProperty(
+                    display,
+                    window.window,
+                    window.net_wm_state,
+                    intern_atom(display, "ATOM"),
+                    32,
+                    vulkan_bindings::PropModeReplace as i32,
+                    &window.net_wm_state_fullscreen as *const vulkan_bindings::Atom as *const u8,
+                    1
+                );
+            }
+
+            vulkan_bindings::XMapWindow(display, window.window);
+            vulkan_bindings::XFlush(display);
+            Ok(window)
+        }
+    }
+
+    // the requested position, and a fixed size when the window isn't resizable
+    unsafe fn set_normal_hints(& self)
+    {
+        let mut hints : vulkan_bindings::XSizeHints = std::mem::zeroed();
+        if let Some((x, y)) = self.descriptor.position
+        {
+            hints.flags |= (vulkan_bindings::USPosition | vulkan_bindings::PPosition) as std::os::raw::c_long;
+            hints.x = x;
+            hints.y = y;
+        }
+        if !self.descriptor.resizable
+        {
+            hints.flags |= (vulkan_bindings::PMinSize | vulkan_bindings::PMaxSize) as std::os::raw::c_long;
+            hints.min_width = self.descriptor.width as i32;
+            hints.max_width = self.descriptor.width as i32;
+            hints.min_height = self.descriptor.height as i32;
+            hints.max_height = self.descriptor.height as i32;
+        }
+        vulkan_bindings::XSetWMNormalHints(self.display, self.window, &mut hints);
+    }
+
+    pub fn set_title(&mut self, title: &str)
+    {
+        unsafe {
+            store_title(self.display, self.window, title);
+            vulkan_bindings::XFlush(self.display);
+        }
+        self.descriptor.title = title.to_string();
+    }
+
+    // a fullscreen window gets the size back when it leaves fullscreen
+    pub fn set_size(&mut self, width: u32, height: u32)
+    {
+        self.descriptor.width = width;
+        self.descriptor.height = height;
+        unsafe {
+            self.set_normal_hints();
+            if self.descriptor.fullscreen == FullscreenMode::WINDOWED
+            {
+                vulkan_bindings::XResizeWindow(self.display, self.window, width, height);
+            }
+            vulkan_bindings::XFlush(self.display);
+        }
+    }
+
+    // X11 has no client side mode switch the window manager would honor, EXCLUSIVE is BORDERLESS here
+    pub fn set_fullscreen(&mut self, descriptor: &WindowDescriptor) -> Result<(), SystemWindowError>
+    {
+        unsafe {
+            let monitors = monitors(self.display, self.root);
+            let monitor = descriptor.pick_monitor(&monitors)?.map(|idx| monitors[idx]);
+            let fullscreen = descriptor.fullscreen != FullscreenMode::WINDOWED;
+            if let (true, Some(monitor)) = (fullscreen, monitor)
+            {
+                vulkan_bindings::XMoveWindow(self.display, self.window, monitor.x, monitor.y);
+            }
+            let mut event : vulkan_bindings::XEvent = std::mem::zeroed();
+            event.xclient.type_ = vulkan_bindings::ClientMessage as i32;
+            event.xclient.window = self.window;
+            event.xclient.message_type = self.net_wm_state;
+            event.xclient.format = 32;
+            event.xclient.data.l[0] = if fullscreen { NET_WM_STATE_ADD } else { NET_WM_STATE_REMOVE };
+            event.xclient.data.l[1] = self.net_wm_state_fullscreen as std::os::raw::c_long;
+            // 1 says the request comes from an application
+            event.xclient.data.l[3] = 1;
+            let mask = vulkan_bindings::SubstructureRedirectMask | vulkan_bindings::SubstructureNotifyMask;
+            vulkan_bindings::XSendEvent(self.display, self.root, 0, mask as std::os::raw::c_long, &mut event);
+            if !fullscreen
+            {
+                vulkan_bindings::XResizeWindow(self.display, self.window, descriptor.width, descriptor.height);
+            }
+            vulkan_bindings::XFlush(self.display);
+        }
+        self.descriptor = descriptor.clone();
+        Ok(())
+    }
+
+    pub fn size(& self) -> (u32, u32)
+    {
+        (self.width, self.height)
+    }
+
+    // WAIT keeps blocking in XNextEvent until something translated into an event
+    pub fn pump_events(&mut self, mode: EventMode) -> Vec<WindowEvent>
+    {
+        let mut events: Vec<WindowEvent> = Vec::new();
+        unsafe {
+            while vulkan_bindings::XPending(self.display) > 0 || (mode == EventMode::WAIT && events.is_empty())
+            {
+                let mut event : vulkan_bindings::XEvent = std::mem::zeroed();
+                vulkan_bindings::XNextEvent(self.display, &mut event);
+                self.translate(&mut event, &mut events);
+            }
+        }
+        events
+    }
+
+    unsafe fn translate(&mut self, event: &mut vulkan_bindings::XEvent, events: &mut Vec<WindowEvent>)
+    {
+        match event.type_ as u32 {
+            vulkan_bindings::KeyPress | vulkan_bindings::KeyRelease => {
+                let key = &mut event.xkey;
+                // X keycodes are evdev codes shifted by 8
+                let scancode = key.keycode.saturating_sub(8);
+                let keycode = system_events::keysym_keycode(vulkan_bindings::XLookupKeysym(key, 0) as u32);
+                if key.type_ as u32 == vulkan_bindings::KeyRelease
+                {
+                    events.push(WindowEvent::KEY_UP { scancode, keycode });
+                    return;
+                }
+                events.push(WindowEvent::KEY_DOWN { scancode, keycode });
+                let mut buffer = [0 as std::os::raw::c_char; 16];
+                let mut keysym : vulkan_bindings::KeySym = 0;
+                vulkan_bindings::XLookupString(key, buffer.as_mut_ptr(), buffer.len() as i32, &mut keysym, std::ptr::null_mut());
+                if let Some(c) = system_events::keysym_char(keysym as u32).filter(|c| !c.is_control())
+                {
+                    events.push(WindowEvent::TEXT_INPUT(c));
+                }
+            },
+            vulkan_bindings::ButtonPress | vulkan_bindings::ButtonRelease => {
+                let pressed = event.type_ as u32 == vulkan_bindings::ButtonPress;
+                events.extend(system_events::x11_button_events(event.xbutton.button, pressed));
+            },
+            vulkan_bindings::MotionNotify => events.push(WindowEvent::MOUSE_MOVED {
+                x: event.xmotion.x as f64,
+                y: event.xmotion.y as f64
+            }),
+            vulkan_bindings::FocusIn => events.push(WindowEvent::FOCUSED(true)),
+            vulkan_bindings::FocusOut => events.push(WindowEvent::FOCUSED(false)),
+            // window managers unmap iconified windows
+            vulkan_bindings::UnmapNotify | vulkan_bindings::MapNotify => {
+                let minimized = event.type_ as u32 == vulkan_bindings::UnmapNotify;
+                if minimized != self.minimized
+                {
+                    self.minimized = minimized;
+                    events.push(WindowEvent::MINIMIZED(minimized));
+                }
+            },
+            vulkan_bindings::ConfigureNotify => {
+                let (width, height) = (event.xconfigure.width as u32, event.xconfigure.height as u32);
+                if (width, height) != (self.width, self.height)
+                {
+                    self.width = width;
+                    self.height = height;
+                    events.push(WindowEvent::RESIZED { width, height });
+                }
+            },
+            vulkan_bindings::ClientMessage if event.xclient.data.l[0] as vulkan_bindings::Atom == self.delete_atom => {
+                events.push(WindowEvent::CLOSE_REQUESTED);
+            },
+            _ => {}
+        }
+    }
+
+    pub fn destroy(self)
+    {
+        unsafe {
+            vulkan_bindings::XDestroyWindow(self.display, self.window);
+            vulkan_bindings::XCloseDisplay(self.display);
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::vulkan_bindings;
+
+// VK_SYSTEM_ALLOCATION_SCOPE_COMMAND up to VK_SYSTEM_ALLOCATION_SCOPE_INSTANCE
+pub const ALLOCATION_SCOPE_COUNT: usize = 5;
+
+// size, alignment and scope sit right before every block so free and realloc can find the original layout back
+const HEADER_SIZE: usize = 3 * std::mem::size_of::<usize>();
+const MIN_ALIGNMENT: usize = 16;
+
+pub fn scope_name(scope: vulkan_bindings::VkSystemAllocationScope) -> &'static str
+{
+    match scope {
+        vulkan_bindings::VkSystemAllocationScope_VK_SYSTEM_ALLOCATION_SCOPE_COMMAND => "command",
+        vulkan_bindings::VkSystemAllocationScope_VK_SYSTEM_ALLOCATION_SCOPE_OBJECT => "object",
+        vulkan_bindings::VkSystemAllocationScope_VK_SYSTEM_ALLOCATION_SCOPE_CACHE => "cache",
+        vulkan_bindings::VkSystemAllocationScope_VK_SYSTEM_ALLOCATION_SCOPE_DEVICE => "device",
+        vulkan_bindings::VkSystemAllocationScope_VK_SYSTEM_ALLOCATION_SCOPE_INSTANCE => "instance",
+        _ => "unknown"
+    }
+}
+
+#[derive(Default)]
+struct VulkanScopeCounters {
+    bytes: AtomicUsize,
+    allocations: AtomicUsize,
+    peak_bytes: AtomicUsize,
+    total_allocations: AtomicUsize,
+    internal_bytes: AtomicUsize
+}
+
+impl VulkanScopeCounters {
+    fn usage(& self, scope: vulkan_bindings::VkSystemAllocationScope) -> VulkanScopeUsage
+    {
+        VulkanScopeUsage {
+            scope,
+            bytes: self.bytes.load(Ordering::Relaxed),
+            allocations: self.allocations.load(Ordering::Relaxed),
+            peak_bytes: self.peak_bytes.load(Ordering::Relaxed),
+            total_allocations: self.total_allocations.load(Ordering::Relaxed),
+            internal_bytes: self.internal_bytes.load(Ordering::Relaxed)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VulkanScopeUsage {
+    pub scope: vulkan_bindings::VkSystemAllocationScope,
+    pub bytes: usize,
+    pub allocations: usize,
+    pub peak_bytes: usize,
+    pub total_allocations: usize,
+    pub internal_bytes: usize
+}
+
+impl VulkanScopeUsage {
+    pub fn is_live(& self) -> bool
+    {
+        self.allocations != 0 || self.bytes != 0 || self.internal_bytes != 0
+    }
+}
+
+impl std::fmt::Display for VulkanScopeUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} bytes in {} allocations (peak {} bytes, {} allocations total, {} internal bytes)",
+            scope_name(self.scope), self.bytes, self.allocations, self.peak_bytes, self.total_allocations, self.internal_bytes)
+    }
+}
+
+// boxed so the address handed to the driver as pUserData never moves
+pub struct VulkanHostAllocator {
+    pub callbacks: vulkan_bindings::VkAllocationCallbacks,
+    scopes: [VulkanScopeCounters; ALLOCATION_SCOPE_COUNT],
+    // scopes past VK_SYSTEM_ALLOCATION_SCOPE_INSTANCE, kept apart so they don't blur the instance numbers
+    unknown_scope: VulkanScopeCounters,
+    unknown_frees: AtomicUsize
+}
+
+impl VulkanHostAllocator {
+    pub fn new() -> Box<Self>
+    {
+        let mut allocator = Box::new(VulkanHostAllocator {
+            callbacks: vulkan_bindings::VkAllocationCallbacks {
+                pUserData: std::ptr::null_mut(),
+                pfnAllocation: Some(vk_allocation),
+                pfnReallocation: Some(vk_reallocation),
+                pfnFree: Some(vk_free),
+                pfnInternalAllocation: Some(vk_internal_allocation),
+                pfnInternalFree: Some(vk_internal_free)
+            },
+            scopes: Default::default(),
+            unknown_scope: VulkanScopeCounters::default(),
+            unknown_frees: AtomicUsize::new(0)
+        });
+        allocator.callbacks.pUserData = &mut *allocator as *mut VulkanHostAllocator as *mut std::ffi::c_void;
+        allocator
+    }
+
+    pub fn callbacks(& self) -> *const vulkan_bindings::VkAllocationCallbacks
+    {
+        &self.callbacks
+    }
+
+    fn counters(& self, scope: vulkan_bindings::VkSystemAllocationScope) -> &VulkanScopeCounters
+    {
+        self.scopes.get(scope as usize).unwrap_or(&self.unknown_scope)
+    }
+
+    // one entry per known scope, unknown scopes are listed together after them once the driver used one
+    pub fn usage(& self) -> Vec<VulkanScopeUsage>
+    {
+        let mut usage: Vec<VulkanScopeUsage> = self.scopes.iter().enumerate()
+            .map(|(idx, counters)| counters.usage(idx as vulkan_bindings::VkSystemAllocationScope))
+            .collect();
+        let unknown = self.unknown_scope.usage(ALLOCATION_SCOPE_COUNT as vulkan_bindings::VkSystemAllocationScope);
+        if unknown.total_allocations != 0 || unknown.is_live()
+        {
+            usage.push(unknown);
+        }
+        usage
+    }
+
+    pub fn live_bytes(& self) -> usize
+    {
+        self.usage().iter().map(|usage| usage.bytes).sum()
+    }
+
+    pub fn live_allocations(& self) -> usize
+    {
+        self.usage().iter().map(|usage| usage.allocations).sum()
+    }
+
+    // scopes still holding memory once everything has been destroyed
+    pub fn leaks(& self) -> Vec<VulkanScopeUsage>
+    {
+        self.usage().into_iter().filter(|usage| usage.is_live()).collect()
+    }
+
+    pub fn unknown_frees(& self) -> usize
+    {
+        self.unknown_frees.load(Ordering::Relaxed)
+    }
+
+    fn track_allocation(& self, scope: vulkan_bindings::VkSystemAllocationScope, size: usize)
+    {
+        let counters = self.counters(scope);
+        let bytes = counters.bytes.fetch_add(size, Ordering::Relaxed) + size;
+        counters.peak_bytes.fetch_max(bytes, Ordering::Relaxed);
+        counters.allocations.fetch_add(1, Ordering::Relaxed);
+        counters.total_allocations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn track_free(& self, scope: vulkan_bindings::VkSystemAllocationScope, size: usize)
+    {
+        let counters = self.counters(scope);
+        counters.bytes.fetch_sub(size, Ordering::Relaxed);
+        counters.allocations.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    unsafe fn allocate(& self, size: usize, alignment: usize, scope: vulkan_bindings::VkSystemAllocationScope) -> *mut std::ffi::c_void
+    {
+        if size == 0 || !alignment.is_power_of_two()
+        {
+            return std::ptr::null_mut();
+        }
+        let alignment = alignment.max(MIN_ALIGNMENT);
+        let offset = (HEADER_SIZE + alignment - 1) & !(alignment - 1);
+        let layout = match std::alloc::Layout::from_size_align(offset + size, alignment) {
+            Ok(layout) => layout,
+            Err(_) => return std::ptr::null_mut()
+        };
+        let base = std::alloc::alloc(layout);
+        if base == std::ptr::null_mut()
+        {
+            return std::ptr::null_mut();
+        }
+        let memory = base.add(offset);
+        let header = memory.sub(HEADER_SIZE) as *mut usize;
+        header.write(size);
+        header.add(1).write(alignment);
+        header.add(2).write(scope as usize);
+        self.track_allocation(scope, size);
+        memory as *mut std::ffi::c_void
+    }
+
+    unsafe fn block(memory: *mut std::ffi::c_void) -> (usize, usize, vulkan_bindings::VkSystemAllocationScope)
+    {
+        let header = (memory as *mut u8).sub(HEADER_SIZE) as *const usize;
+        (header.read(), header.add(1).read(), header.add(2).read() as vulkan_bindings::VkSystemAllocationScope)
+    }
+
+    unsafe fn free(& self, memory: *mut std::ffi::c_void)
+    {
+        if memory == std::ptr::null_mut()
+        {
+            return;
+        }
+        let (size, alignment, scope) = VulkanHostAllocator::block(memory);
+        let offset = (HEADER_SIZE + alignment - 1) & !(alignment - 1);
+        match std::alloc::Layout::from_size_align(offset + size, alignment) {
+            Ok(layout) => {
+                self.track_free(scope, size);
+                std::alloc::dealloc((memory as *mut u8).sub(offset), layout);
+            },
+            Err(_) => {
+                self.unknown_frees.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    unsafe fn reallocate(& self, original: *mut std::ffi::c_void, size: usize, alignment: usize, scope: vulkan_bindings::VkSystemAllocationScope) -> *mut std::ffi::c_void
+    {
+        if original == std::ptr::null_mut()
+        {
+            return self.allocate(size, alignment, scope);
+        }
+        if size == 0
+        {
+            self.free(original);
+            return std::ptr::null_mut();
+        }
+        let memory = self.allocate(size, alignment, scope);
+        if memory == std::ptr::null_mut()
+        {
+            return std::ptr::null_mut();
+        }
+        let (original_size, _, _) = VulkanHostAllocator::block(original);
+        std::ptr::copy_nonoverlapping(original as *const u8, memory as *mut u8, original_size.min(size));
+        self.free(original);
+        memory
+    }
+}
+
+unsafe extern "C" fn vk_allocation(
+    user_data: *mut std::ffi::c_void,
+    size: usize,
+    alignment: usize,
+    scope: vulkan_bindings::VkSystemAllocationScope
+) -> *mut std::ffi::c_void
+{
+    let allocator = &*(user_data as *const VulkanHostAllocator);
+    allocator.allocate(size, alignment, scope)
+}
+
+unsafe extern "C" fn vk_reallocation(
+    user_data: *mut std::ffi::c_void,
+    original: *mut std::ffi::c_void,
+    size: usize,
+    alignment: usize,
+    scope: vulkan_bindings::VkSystemAllocationScope
+) -> *mut std::ffi::c_void
+{
+    let allocator = &*(user_data as *const VulkanHostAllocator);
+    allocator.reallocate(original, size, alignment, scope)
+}
+
+unsafe extern "C" fn vk_free(user_data: *mut std::ffi::c_void, memory: *mut std::ffi::c_void)
+{
+    let allocator = &*(user_data as *const VulkanHostAllocator);
+    allocator.free(memory);
+}
+
+// the driver allocated on its own, only the byte count is tracked
+unsafe extern "C" fn vk_internal_allocation(
+    user_data: *mut std::ffi::c_void,
+    size: usize,
+    _allocation_type: vulkan_bindings::VkInternalAllocationType,
+    scope: vulkan_bindings::VkSystemAllocationScope
+)
+{
+    let allocator = &*(user_data as *const VulkanHostAllocator);
+    allocator.counters(scope).internal_bytes.fetch_add(size, Ordering::Relaxed);
+}
+
+unsafe extern "C" fn vk_internal_free(
+    user_data: *mut std::ffi::c_void,
+    size: usize,
+    _allocation_type: vulkan_bindings::VkInternalAllocationType,
+    scope: vulkan_bindings::VkSystemAllocationScope
+)
+{
+    let allocator = &*(user_data as *const VulkanHostAllocator);
+    allocator.counters(scope).internal_bytes.fetch_sub(size, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INSTANCE: vulkan_bindings::VkSystemAllocationScope = vulkan_bindings::VkSystemAllocationScope_VK_SYSTEM_ALLOCATION_SCOPE_INSTANCE;
+    const OBJECT: vulkan_bindings::VkSystemAllocationScope = vulkan_bindings::VkSystemAllocationScope_VK_SYSTEM_ALLOCATION_SCOPE_OBJECT;
+
+    // goes through the callbacks the way a driver would
+    unsafe fn allocate(allocator: &VulkanHostAllocator, size: usize, alignment: usize, scope: vulkan_bindings::VkSystemAllocationScope) -> *mut u8
+    {
+        allocator.callbacks.pfnAllocation.unwrap()(allocator.callbacks.pUserData, size, alignment, scope) as *mut u8
+    }
+
+    unsafe fn reallocate(allocator: &VulkanHostAllocator, original: *mut u8, size: usize, alignment: usize, scope: vulkan_bindings::VkSystemAllocationScope) -> *mut u8
+    {
+        allocator.callbacks.pfnReallocation.unwrap()(allocator.callbacks.pUserData, original as *mut std::ffi::c_void, size, alignment, scope) as *mut u8
+    }
+
+    unsafe fn free(allocator: &VulkanHostAllocator, memory: *mut u8)
+    {
+        allocator.callbacks.pfnFree.unwrap()(allocator.callbacks.pUserData, memory as *mut std::ffi::c_void);
+    }
+
+    #[test]
+    fn allocations_honour_the_requested_alignment()
+    {
+        let allocator = VulkanHostAllocator::new();
+        unsafe {
+            for alignment in [1, 16, 64, 256]
+            {
+                let memory = allocate(&allocator, 100, alignment, OBJECT);
+                assert!(!memory.is_null());
+                assert_eq!(memory as usize % alignment, 0);
+                let memory = reallocate(&allocator, memory, 300, alignment, OBJECT);
+                assert_eq!(memory as usize % alignment, 0);
+                free(&allocator, memory);
+            }
+        }
+        assert_eq!(allocator.live_bytes(), 0);
+        assert_eq!(allocator.live_allocations(), 0);
+        assert!(allocator.leaks().is_empty());
+        assert_eq!(allocator.unknown_frees(), 0);
+    }
+
+    #[test]
+    fn reallocation_keeps_the_data()
+    {
+        let allocator = VulkanHostAllocator::new();
+        unsafe {
+            for alignment in [1, 16, 64, 256]
+            {
+                let memory = allocate(&allocator, 64, alignment, OBJECT);
+                for idx in 0..64
+                {
+                    memory.add(idx).write(idx as u8);
+                }
+                let grown = reallocate(&allocator, memory, 4096, alignment, OBJECT);
+                assert!((0..64).all(|idx| grown.add(idx).read() == idx as u8));
+                let shrunk = reallocate(&allocator, grown, 16, alignment, OBJECT);
+                assert!((0..16).all(|idx| shrunk.add(idx).read() == idx as u8));
+                assert_eq!(allocator.live_bytes(), 16);
+                assert!(reallocate(&allocator, shrunk, 0, alignment, OBJECT).is_null());
+            }
+        }
+        assert!(allocator.leaks().is_empty());
+    }
+
+    #[test]
+    fn counters_follow_the_live_blocks()
+    {
+        let allocator = VulkanHostAllocator::new();
+        unsafe {
+            let first = allocate(&allocator, 100, 16, OBJECT);
+            let second = allocate(&allocator, 50, 64, INSTANCE);
+            assert_eq!(allocator.live_bytes(), 150);
+            assert_eq!(allocator.live_allocations(), 2);
+            assert_eq!(allocator.leaks().len(), 2);
+            free(&allocator, first);
+            free(&allocator, second);
+            // freeing nothing is allowed and isn't counted
+            free(&allocator, std::ptr::null_mut());
+        }
+        let object = allocator.usage()[OBJECT as usize];
+        assert_eq!((object.bytes, object.allocations, object.peak_bytes, object.total_allocations), (0, 0, 100, 1));
+        assert!(allocator.leaks().is_empty());
+    }
+
+    #[test]
+    fn unknown_scopes_are_counted_apart_from_the_instance()
+    {
+        let allocator = VulkanHostAllocator::new();
+        assert_eq!(allocator.usage().len(), ALLOCATION_SCOPE_COUNT);
+        unsafe {
+            let memory = allocate(&allocator, 32, 16, 7);
+            let usage = allocator.usage();
+            assert_eq!(usage[INSTANCE as usize].allocations, 0);
+            assert_eq!(usage[ALLOCATION_SCOPE_COUNT].allocations, 1);
+            assert_eq!(scope_name(usage[ALLOCATION_SCOPE_COUNT].scope), "unknown");
+            assert_eq!(allocator.live_bytes(), 32);
+            free(&allocator, memory);
+        }
+        assert!(allocator.leaks().is_empty());
+        assert_eq!(allocator.usage()[ALLOCATION_SCOPE_COUNT].total_allocations, 1);
+    }
+}
@@ -3,5 +3,26 @@
 #![allow(non_upper_case_globals)]
 #![allow(dead_code)]
 #![allow(unused_imports)]
+#![allow(clippy::useless_transmute)]
 
-include!("../bindings/binding.rs");
+include!("../bindings/core.rs");
+
+#[cfg(feature = "win32")]
+include!("../bindings/win32.rs");
+#[cfg(feature = "xlib")]
+include!("../bindings/xlib.rs");
+#[cfg(feature = "xlib")]
+#[link(name = "X11")]
+#[link(name = "Xrandr")]
+extern "C" {}
+#[cfg(feature = "xcb")]
+include!("../bindings/xcb.rs");
+#[cfg(feature = "xcb")]
+#[link(name = "xcb")]
+#[link(name = "xcb-randr")]
+extern "C" {}
+#[cfg(feature = "wayland")]
+include!("../bindings/wayland.rs");
+#[cfg(feature = "wayland")]
+#[link(name = "wayland-client")]
+extern "C" {}
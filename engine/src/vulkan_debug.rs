@@ -0,0 +1,199 @@
+use std::ffi::CStr;
+use crate::vulkan_bindings;
+use crate::vulkan_init;
+use crate::vulkan_error;
+
+pub const VALIDATION_LAYER_NAME: &str = "VK_LAYER_KHRONOS_validation";
+
+// drivers and layers may report from any thread
+pub type VulkanDebugCallback = Box<dyn Fn(vulkan_bindings::VkDebugUtilsMessageSeverityFlagBitsEXT, vulkan_bindings::VkDebugUtilsMessageTypeFlagsEXT, &str) + Send + Sync>;
+
+pub struct VulkanValidation {
+    pub severities: vulkan_bindings::VkDebugUtilsMessageSeverityFlagsEXT,
+    pub message_types: vulkan_bindings::VkDebugUtilsMessageTypeFlagsEXT,
+    pub callback: VulkanDebugCallback
+}
+
+impl VulkanValidation {
+    pub fn new(callback: VulkanDebugCallback) -> Self
+    {
+        VulkanValidation {
+            severities: (vulkan_bindings::VkDebugUtilsMessageSeverityFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT
+                | vulkan_bindings::VkDebugUtilsMessageSeverityFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT),
+            message_types: (vulkan_bindings::VkDebugUtilsMessageTypeFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_TYPE_GENERAL_BIT_EXT
+                | vulkan_bindings::VkDebugUtilsMessageTypeFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT
+                | vulkan_bindings::VkDebugUtilsMessageTypeFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_TYPE_PERFORMANCE_BIT_EXT),
+            callback
+        }
+    }
+}
+
+impl Default for VulkanValidation {
+    fn default() -> Self {
+        VulkanValidation::new(Box::new(log_debug_message))
+    }
+}
+
+pub fn severity_name(severity: vulkan_bindings::VkDebugUtilsMessageSeverityFlagBitsEXT) -> &'static str
+{
+    match severity
+    {
+        vulkan_bindings::VkDebugUtilsMessageSeverityFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_SEVERITY_VERBOSE_BIT_EXT => "VERBOSE",
+        vulkan_bindings::VkDebugUtilsMessageSeverityFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_SEVERITY_INFO_BIT_EXT => "INFO",
+        vulkan_bindings::VkDebugUtilsMessageSeverityFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT => "WARNING",
+        vulkan_bindings::VkDebugUtilsMessageSeverityFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT => "ERROR",
+        _ => "UNKNOWN"
+    }
+}
+
+pub fn message_type_names(message_types: vulkan_bindings::VkDebugUtilsMessageTypeFlagsEXT) -> String
+{
+    let mut names: Vec<&str> = Vec::new();
+    if message_types & vulkan_bindings::VkDebugUtilsMessageTypeFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_TYPE_GENERAL_BIT_EXT != 0
+    {
+        names.push("GENERAL");
+    }
+    if message_types & vulkan_bindings::VkDebugUtilsMessageTypeFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT != 0
+    {
+        names.push("VALIDATION");
+    }
+    if message_types & vulkan_bindings::VkDebugUtilsMessageTypeFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_TYPE_PERFORMANCE_BIT_EXT != 0
+    {
+        names.push("PERFORMANCE");
+    }
+    names.join("|")
+}
+
+pub fn log_debug_message(severity: vulkan_bindings::VkDebugUtilsMessageSeverityFlagBitsEXT, message_types: vulkan_bindings::VkDebugUtilsMessageTypeFlagsEXT, message: &str)
+{
+    eprintln!("[vulkan {} {}] {}", severity_name(severity), message_type_names(message_types), message);
+}
+
+unsafe extern "C" fn debug_utils_callback(
+    severity: vulkan_bindings::VkDebugUtilsMessageSeverityFlagBitsEXT,
+    message_types: vulkan_bindings::VkDebugUtilsMessageTypeFlagsEXT,
+    callback_data: *const vulkan_bindings::VkDebugUtilsMessengerCallbackDataEXT,
+    user_data: *mut std::ffi::c_void
+) -> vulkan_bindings::VkBool32
+{
+    if user_data == std::ptr::null_mut()
+    {
+        return vulkan_bindings::VK_FALSE;
+    }
+    let callback = &*(user_data as *const VulkanDebugCallback);
+    let message = if callback_data == std::ptr::null() || (*callback_data).pMessage == std::ptr::null()
+    {
+        String::new()
+    }
+    else
+    {
+        CStr::from_ptr((*callback_data).pMessage).to_string_lossy().into_owned()
+    };
+    // a panic can't unwind into the driver, the message is dropped instead
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback(severity, message_types, &message)));
+    vulkan_bindings::VK_FALSE
+}
+
+pub struct VulkanDebugMessenger {
+    pub handle: vulkan_bindings::VkDebugUtilsMessengerEXT,
+    severities: vulkan_bindings::VkDebugUtilsMessageSeverityFlagsEXT,
+    message_types: vulkan_bindings::VkDebugUtilsMessageTypeFlagsEXT,
+    callback: Box<VulkanDebugCallback>
+}
+
+impl VulkanDebugMessenger {
+    pub fn new(validation: VulkanValidation) -> Self
+    {
+        VulkanDebugMessenger {
+            handle: std::ptr::null_mut(),
+            severities: validation.severities,
+            message_types: validation.message_types,
+            callback: Box::new(validation.callback)
+        }
+    }
+
+    pub fn create_info(&self) -> vulkan_bindings::VkDebugUtilsMessengerCreateInfoEXT
+    {
+        vulkan_bindings::VkDebugUtilsMessengerCreateInfoEXT {
+            sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT,
+            pNext: std::ptr::null(),
+            flags: 0,
+            messageSeverity: self.severities,
+            messageType: self.message_types,
+            pfnUserCallback: Some(debug_utils_callback),
+            pUserData: self.callback.as_ref() as *const VulkanDebugCallback as *mut std::ffi::c_void
+        }
+    }
+
+    pub fn attach(&mut self, instance: vulkan_bindings::VkInstance, fns: &vulkan_init::InstanceFns, allocator: *const vulkan_bindings::VkAllocationCallbacks) -> Result<(), vulkan_init::VulkanInitError>
+    {
+        unsafe {
+            let fn_vkCreateDebugUtilsMessengerEXT = match fns.vkCreateDebugUtilsMessengerEXT {
+                Some(f) => f,
+                None => return Err(vulkan_init::VulkanInitError::FAILED_CREATING_DEBUG_MESSENGER(vulkan_error::VulkanCallError::missing("vkCreateDebugUtilsMessengerEXT")))
+            };
+            let create_info = self.create_info();
+            let result = fn_vkCreateDebugUtilsMessengerEXT(instance, &create_info, allocator, &mut self.handle);
+            if result != vulkan_bindings::VkResult_VK_SUCCESS || self.handle == std::ptr::null_mut()
+            {
+                return Err(vulkan_init::VulkanInitError::FAILED_CREATING_DEBUG_MESSENGER(vulkan_error::VulkanCallError::new("vkCreateDebugUtilsMessengerEXT", result)));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn destroy(&mut self, instance: vulkan_bindings::VkInstance, fns: &vulkan_init::InstanceFns, allocator: *const vulkan_bindings::VkAllocationCallbacks)
+    {
+        unsafe {
+            if self.handle == std::ptr::null_mut()
+            {
+                return;
+            }
+            if let Some(fn_vkDestroyDebugUtilsMessengerEXT) = fns.vkDestroyDebugUtilsMessengerEXT
+            {
+                fn_vkDestroyDebugUtilsMessengerEXT(instance, self.handle, allocator);
+            }
+            self.handle = std::ptr::null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    const WARNING: vulkan_bindings::VkDebugUtilsMessageSeverityFlagBitsEXT = vulkan_bindings::VkDebugUtilsMessageSeverityFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT;
+    const VALIDATION: vulkan_bindings::VkDebugUtilsMessageTypeFlagsEXT = vulkan_bindings::VkDebugUtilsMessageTypeFlagBitsEXT_VK_DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT;
+
+    // what the loader hands to the messenger callback
+    fn report(messenger: &VulkanDebugMessenger, message: &CStr) -> vulkan_bindings::VkBool32
+    {
+        let create_info = messenger.create_info();
+        unsafe {
+            let mut callback_data: vulkan_bindings::VkDebugUtilsMessengerCallbackDataEXT = std::mem::zeroed();
+            callback_data.sType = vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT;
+            callback_data.pMessage = message.as_ptr();
+            create_info.pfnUserCallback.unwrap()(WARNING, VALIDATION, &callback_data, create_info.pUserData)
+        }
+    }
+
+    #[test]
+    fn messages_reach_the_callback()
+    {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        let messenger = VulkanDebugMessenger::new(VulkanValidation::new(Box::new(move |severity, message_types, message| {
+            sink.lock().unwrap().push((severity, message_types, message.to_string()));
+        })));
+        assert_eq!(report(&messenger, c"vkCreateBuffer: size is 0"), vulkan_bindings::VK_FALSE);
+        assert_eq!(*received.lock().unwrap(), vec![(WARNING, VALIDATION, "vkCreateBuffer: size is 0".to_string())]);
+    }
+
+    #[test]
+    fn panicking_callbacks_dont_unwind_into_the_driver()
+    {
+        let messenger = VulkanDebugMessenger::new(VulkanValidation::new(Box::new(|_, _, message| panic!("{}", message))));
+        assert_eq!(report(&messenger, c"device lost"), vulkan_bindings::VK_FALSE);
+    }
+}
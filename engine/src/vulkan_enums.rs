@@ -0,0 +1,491 @@
+// Generated by headersgen from vk.xml, regenerate instead of editing
+VULKAN_ENUM!(ImageType: VkImageType {
+    TYPE_1D = VkImageType_VK_IMAGE_TYPE_1D,
+    TYPE_2D = VkImageType_VK_IMAGE_TYPE_2D,
+    TYPE_3D = VkImageType_VK_IMAGE_TYPE_3D
+});
+
+VULKAN_ENUM!(ImageViewType: VkImageViewType {
+    TYPE_1D = VkImageViewType_VK_IMAGE_VIEW_TYPE_1D,
+    TYPE_2D = VkImageViewType_VK_IMAGE_VIEW_TYPE_2D,
+    TYPE_3D = VkImageViewType_VK_IMAGE_VIEW_TYPE_3D,
+    CUBE = VkImageViewType_VK_IMAGE_VIEW_TYPE_CUBE,
+    TYPE_1D_ARRAY = VkImageViewType_VK_IMAGE_VIEW_TYPE_1D_ARRAY,
+    TYPE_2D_ARRAY = VkImageViewType_VK_IMAGE_VIEW_TYPE_2D_ARRAY,
+    CUBE_ARRAY = VkImageViewType_VK_IMAGE_VIEW_TYPE_CUBE_ARRAY
+});
+
+VULKAN_ENUM!(ImageTiling: VkImageTiling {
+    OPTIMAL = VkImageTiling_VK_IMAGE_TILING_OPTIMAL,
+    LINEAR = VkImageTiling_VK_IMAGE_TILING_LINEAR
+});
+
+VULKAN_ENUM!(ImageLayout: VkImageLayout {
+    UNDEFINED = VkImageLayout_VK_IMAGE_LAYOUT_UNDEFINED,
+    GENERAL = VkImageLayout_VK_IMAGE_LAYOUT_GENERAL,
+    COLOR_ATTACHMENT_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_COLOR_ATTACHMENT_OPTIMAL,
+    DEPTH_STENCIL_ATTACHMENT_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_DEPTH_STENCIL_ATTACHMENT_OPTIMAL,
+    DEPTH_STENCIL_READ_ONLY_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_DEPTH_STENCIL_READ_ONLY_OPTIMAL,
+    SHADER_READ_ONLY_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_SHADER_READ_ONLY_OPTIMAL,
+    TRANSFER_SRC_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_TRANSFER_SRC_OPTIMAL,
+    TRANSFER_DST_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_TRANSFER_DST_OPTIMAL,
+    PREINITIALIZED = VkImageLayout_VK_IMAGE_LAYOUT_PREINITIALIZED,
+    DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL,
+    DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL,
+    DEPTH_ATTACHMENT_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_DEPTH_ATTACHMENT_OPTIMAL,
+    DEPTH_READ_ONLY_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_DEPTH_READ_ONLY_OPTIMAL,
+    STENCIL_ATTACHMENT_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_STENCIL_ATTACHMENT_OPTIMAL,
+    STENCIL_READ_ONLY_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_STENCIL_READ_ONLY_OPTIMAL,
+    READ_ONLY_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_READ_ONLY_OPTIMAL,
+    ATTACHMENT_OPTIMAL = VkImageLayout_VK_IMAGE_LAYOUT_ATTACHMENT_OPTIMAL,
+    PRESENT_SRC_KHR = VkImageLayout_VK_IMAGE_LAYOUT_PRESENT_SRC_KHR
+});
+
+VULKAN_ENUM!(Format: VkFormat {
+    UNDEFINED = VkFormat_VK_FORMAT_UNDEFINED,
+    R4G4_UNORM_PACK8 = VkFormat_VK_FORMAT_R4G4_UNORM_PACK8,
+    R4G4B4A4_UNORM_PACK16 = VkFormat_VK_FORMAT_R4G4B4A4_UNORM_PACK16,
+    B4G4R4A4_UNORM_PACK16 = VkFormat_VK_FORMAT_B4G4R4A4_UNORM_PACK16,
+    R5G6B5_UNORM_PACK16 = VkFormat_VK_FORMAT_R5G6B5_UNORM_PACK16,
+    B5G6R5_UNORM_PACK16 = VkFormat_VK_FORMAT_B5G6R5_UNORM_PACK16,
+    R5G5B5A1_UNORM_PACK16 = VkFormat_VK_FORMAT_R5G5B5A1_UNORM_PACK16,
+    B5G5R5A1_UNORM_PACK16 = VkFormat_VK_FORMAT_B5G5R5A1_UNORM_PACK16,
+    A1R5G5B5_UNORM_PACK16 = VkFormat_VK_FORMAT_A1R5G5B5_UNORM_PACK16,
+    R8_UNORM = VkFormat_VK_FORMAT_R8_UNORM,
+    R8_SNORM = VkFormat_VK_FORMAT_R8_SNORM,
+    R8_USCALED = VkFormat_VK_FORMAT_R8_USCALED,
+    R8_SSCALED = VkFormat_VK_FORMAT_R8_SSCALED,
+    R8_UINT = VkFormat_VK_FORMAT_R8_UINT,
+    R8_SINT = VkFormat_VK_FORMAT_R8_SINT,
+    R8_SRGB = VkFormat_VK_FORMAT_R8_SRGB,
+    R8G8_UNORM = VkFormat_VK_FORMAT_R8G8_UNORM,
+    R8G8_SNORM = VkFormat_VK_FORMAT_R8G8_SNORM,
+    R8G8_USCALED = VkFormat_VK_FORMAT_R8G8_USCALED,
+    R8G8_SSCALED = VkFormat_VK_FORMAT_R8G8_SSCALED,
+    R8G8_UINT = VkFormat_VK_FORMAT_R8G8_UINT,
+    R8G8_SINT = VkFormat_VK_FORMAT_R8G8_SINT,
+    R8G8_SRGB = VkFormat_VK_FORMAT_R8G8_SRGB,
+    R8G8B8_UNORM = VkFormat_VK_FORMAT_R8G8B8_UNORM,
+    R8G8B8_SNORM = VkFormat_VK_FORMAT_R8G8B8_SNORM,
+    R8G8B8_USCALED = VkFormat_VK_FORMAT_R8G8B8_USCALED,
+    R8G8B8_SSCALED = VkFormat_VK_FORMAT_R8G8B8_SSCALED,
+    R8G8B8_UINT = VkFormat_VK_FORMAT_R8G8B8_UINT,
+    R8G8B8_SINT = VkFormat_VK_FORMAT_R8G8B8_SINT,
+    R8G8B8_SRGB = VkFormat_VK_FORMAT_R8G8B8_SRGB,
+    B8G8R8_UNORM = VkFormat_VK_FORMAT_B8G8R8_UNORM,
+    B8G8R8_SNORM = VkFormat_VK_FORMAT_B8G8R8_SNORM,
+    B8G8R8_USCALED = VkFormat_VK_FORMAT_B8G8R8_USCALED,
+    B8G8R8_SSCALED = VkFormat_VK_FORMAT_B8G8R8_SSCALED,
+    B8G8R8_UINT = VkFormat_VK_FORMAT_B8G8R8_UINT,
+    B8G8R8_SINT = VkFormat_VK_FORMAT_B8G8R8_SINT,
+    B8G8R8_SRGB = VkFormat_VK_FORMAT_B8G8R8_SRGB,
+    R8G8B8A8_UNORM = VkFormat_VK_FORMAT_R8G8B8A8_UNORM,
+    R8G8B8A8_SNORM = VkFormat_VK_FORMAT_R8G8B8A8_SNORM,
+    R8G8B8A8_USCALED = VkFormat_VK_FORMAT_R8G8B8A8_USCALED,
+    R8G8B8A8_SSCALED = VkFormat_VK_FORMAT_R8G8B8A8_SSCALED,
+    R8G8B8A8_UINT = VkFormat_VK_FORMAT_R8G8B8A8_UINT,
+    R8G8B8A8_SINT = VkFormat_VK_FORMAT_R8G8B8A8_SINT,
+    R8G8B8A8_SRGB = VkFormat_VK_FORMAT_R8G8B8A8_SRGB,
+    B8G8R8A8_UNORM = VkFormat_VK_FORMAT_B8G8R8A8_UNORM,
+    B8G8R8A8_SNORM = VkFormat_VK_FORMAT_B8G8R8A8_SNORM,
+    B8G8R8A8_USCALED = VkFormat_VK_FORMAT_B8G8R8A8_USCALED,
+    B8G8R8A8_SSCALED = VkFormat_VK_FORMAT_B8G8R8A8_SSCALED,
+    B8G8R8A8_UINT = VkFormat_VK_FORMAT_B8G8R8A8_UINT,
+    B8G8R8A8_SINT = VkFormat_VK_FORMAT_B8G8R8A8_SINT,
+    B8G8R8A8_SRGB = VkFormat_VK_FORMAT_B8G8R8A8_SRGB,
+    A8B8G8R8_UNORM_PACK32 = VkFormat_VK_FORMAT_A8B8G8R8_UNORM_PACK32,
+    A8B8G8R8_SNORM_PACK32 = VkFormat_VK_FORMAT_A8B8G8R8_SNORM_PACK32,
+    A8B8G8R8_USCALED_PACK32 = VkFormat_VK_FORMAT_A8B8G8R8_USCALED_PACK32,
+    A8B8G8R8_SSCALED_PACK32 = VkFormat_VK_FORMAT_A8B8G8R8_SSCALED_PACK32,
+    A8B8G8R8_UINT_PACK32 = VkFormat_VK_FORMAT_A8B8G8R8_UINT_PACK32,
+    A8B8G8R8_SINT_PACK32 = VkFormat_VK_FORMAT_A8B8G8R8_SINT_PACK32,
+    A8B8G8R8_SRGB_PACK32 = VkFormat_VK_FORMAT_A8B8G8R8_SRGB_PACK32,
+    A2R10G10B10_UNORM_PACK32 = VkFormat_VK_FORMAT_A2R10G10B10_UNORM_PACK32,
+    A2R10G10B10_SNORM_PACK32 = VkFormat_VK_FORMAT_A2R10G10B10_SNORM_PACK32,
+    A2R10G10B10_USCALED_PACK32 = VkFormat_VK_FORMAT_A2R10G10B10_USCALED_PACK32,
+    A2R10G10B10_SSCALED_PACK32 = VkFormat_VK_FORMAT_A2R10G10B10_SSCALED_PACK32,
+    A2R10G10B10_UINT_PACK32 = VkFormat_VK_FORMAT_A2R10G10B10_UINT_PACK32,
+    A2R10G10B10_SINT_PACK32 = VkFormat_VK_FORMAT_A2R10G10B10_SINT_PACK32,
+    A2B10G10R10_UNORM_PACK32 = VkFormat_VK_FORMAT_A2B10G10R10_UNORM_PACK32,
+    A2B10G10R10_SNORM_PACK32 = VkFormat_VK_FORMAT_A2B10G10R10_SNORM_PACK32,
+    A2B10G10R10_USCALED_PACK32 = VkFormat_VK_FORMAT_A2B10G10R10_USCALED_PACK32,
+    A2B10G10R10_SSCALED_PACK32 = VkFormat_VK_FORMAT_A2B10G10R10_SSCALED_PACK32,
+    A2B10G10R10_UINT_PACK32 = VkFormat_VK_FORMAT_A2B10G10R10_UINT_PACK32,
+    A2B10G10R10_SINT_PACK32 = VkFormat_VK_FORMAT_A2B10G10R10_SINT_PACK32,
+    R16_UNORM = VkFormat_VK_FORMAT_R16_UNORM,
+    R16_SNORM = VkFormat_VK_FORMAT_R16_SNORM,
+    R16_USCALED = VkFormat_VK_FORMAT_R16_USCALED,
+    R16_SSCALED = VkFormat_VK_FORMAT_R16_SSCALED,
+    R16_UINT = VkFormat_VK_FORMAT_R16_UINT,
+    R16_SINT = VkFormat_VK_FORMAT_R16_SINT,
+    R16_SFLOAT = VkFormat_VK_FORMAT_R16_SFLOAT,
+    R16G16_UNORM = VkFormat_VK_FORMAT_R16G16_UNORM,
+    R16G16_SNORM = VkFormat_VK_FORMAT_R16G16_SNORM,
+    R16G16_USCALED = VkFormat_VK_FORMAT_R16G16_USCALED,
+    R16G16_SSCALED = VkFormat_VK_FORMAT_R16G16_SSCALED,
+    R16G16_UINT = VkFormat_VK_FORMAT_R16G16_UINT,
+    R16G16_SINT = VkFormat_VK_FORMAT_R16G16_SINT,
+    R16G16_SFLOAT = VkFormat_VK_FORMAT_R16G16_SFLOAT,
+    R16G16B16_UNORM = VkFormat_VK_FORMAT_R16G16B16_UNORM,
+    R16G16B16_SNORM = VkFormat_VK_FORMAT_R16G16B16_SNORM,
+    R16G16B16_USCALED = VkFormat_VK_FORMAT_R16G16B16_USCALED,
+    R16G16B16_SSCALED = VkFormat_VK_FORMAT_R16G16B16_SSCALED,
+    R16G16B16_UINT = VkFormat_VK_FORMAT_R16G16B16_UINT,
+    R16G16B16_SINT = VkFormat_VK_FORMAT_R16G16B16_SINT,
+    R16G16B16_SFLOAT = VkFormat_VK_FORMAT_R16G16B16_SFLOAT,
+    R16G16B16A16_UNORM = VkFormat_VK_FORMAT_R16G16B16A16_UNORM,
+    R16G16B16A16_SNORM = VkFormat_VK_FORMAT_R16G16B16A16_SNORM,
+    R16G16B16A16_USCALED = VkFormat_VK_FORMAT_R16G16B16A16_USCALED,
+    R16G16B16A16_SSCALED = VkFormat_VK_FORMAT_R16G16B16A16_SSCALED,
+    R16G16B16A16_UINT = VkFormat_VK_FORMAT_R16G16B16A16_UINT,
+    R16G16B16A16_SINT = VkFormat_VK_FORMAT_R16G16B16A16_SINT,
+    R16G16B16A16_SFLOAT = VkFormat_VK_FORMAT_R16G16B16A16_SFLOAT,
+    R32_UINT = VkFormat_VK_FORMAT_R32_UINT,
+    R32_SINT = VkFormat_VK_FORMAT_R32_SINT,
+    R32_SFLOAT = VkFormat_VK_FORMAT_R32_SFLOAT,
+    R32G32_UINT = VkFormat_VK_FORMAT_R32G32_UINT,
+    R32G32_SINT = VkFormat_VK_FORMAT_R32G32_SINT,
+    R32G32_SFLOAT = VkFormat_VK_FORMAT_R32G32_SFLOAT,
+    R32G32B32_UINT = VkFormat_VK_FORMAT_R32G32B32_UINT,
+    R32G32B32_SINT = VkFormat_VK_FORMAT_R32G32B32_SINT,
+    R32G32B32_SFLOAT = VkFormat_VK_FORMAT_R32G32B32_SFLOAT,
+    R32G32B32A32_UINT = VkFormat_VK_FORMAT_R32G32B32A32_UINT,
+    R32G32B32A32_SINT = VkFormat_VK_FORMAT_R32G32B32A32_SINT,
+    R32G32B32A32_SFLOAT = VkFormat_VK_FORMAT_R32G32B32A32_SFLOAT,
+    R64_UINT = VkFormat_VK_FORMAT_R64_UINT,
+    R64_SINT = VkFormat_VK_FORMAT_R64_SINT,
+    R64_SFLOAT = VkFormat_VK_FORMAT_R64_SFLOAT,
+    R64G64_UINT = VkFormat_VK_FORMAT_R64G64_UINT,
+    R64G64_SINT = VkFormat_VK_FORMAT_R64G64_SINT,
+    R64G64_SFLOAT = VkFormat_VK_FORMAT_R64G64_SFLOAT,
+    R64G64B64_UINT = VkFormat_VK_FORMAT_R64G64B64_UINT,
+    R64G64B64_SINT = VkFormat_VK_FORMAT_R64G64B64_SINT,
+    R64G64B64_SFLOAT = VkFormat_VK_FORMAT_R64G64B64_SFLOAT,
+    R64G64B64A64_UINT = VkFormat_VK_FORMAT_R64G64B64A64_UINT,
+    R64G64B64A64_SINT = VkFormat_VK_FORMAT_R64G64B64A64_SINT,
+    R64G64B64A64_SFLOAT = VkFormat_VK_FORMAT_R64G64B64A64_SFLOAT,
+    B10G11R11_UFLOAT_PACK32 = VkFormat_VK_FORMAT_B10G11R11_UFLOAT_PACK32,
+    E5B9G9R9_UFLOAT_PACK32 = VkFormat_VK_FORMAT_E5B9G9R9_UFLOAT_PACK32,
+    D16_UNORM = VkFormat_VK_FORMAT_D16_UNORM,
+    X8_D24_UNORM_PACK32 = VkFormat_VK_FORMAT_X8_D24_UNORM_PACK32,
+    D32_SFLOAT = VkFormat_VK_FORMAT_D32_SFLOAT,
+    S8_UINT = VkFormat_VK_FORMAT_S8_UINT,
+    D16_UNORM_S8_UINT = VkFormat_VK_FORMAT_D16_UNORM_S8_UINT,
+    D24_UNORM_S8_UINT = VkFormat_VK_FORMAT_D24_UNORM_S8_UINT,
+    D32_SFLOAT_S8_UINT = VkFormat_VK_FORMAT_D32_SFLOAT_S8_UINT,
+    BC1_RGB_UNORM_BLOCK = VkFormat_VK_FORMAT_BC1_RGB_UNORM_BLOCK,
+    BC1_RGB_SRGB_BLOCK = VkFormat_VK_FORMAT_BC1_RGB_SRGB_BLOCK,
+    BC1_RGBA_UNORM_BLOCK = VkFormat_VK_FORMAT_BC1_RGBA_UNORM_BLOCK,
+    BC1_RGBA_SRGB_BLOCK = VkFormat_VK_FORMAT_BC1_RGBA_SRGB_BLOCK,
+    BC2_UNORM_BLOCK = VkFormat_VK_FORMAT_BC2_UNORM_BLOCK,
+    BC2_SRGB_BLOCK = VkFormat_VK_FORMAT_BC2_SRGB_BLOCK,
+    BC3_UNORM_BLOCK = VkFormat_VK_FORMAT_BC3_UNORM_BLOCK,
+    BC3_SRGB_BLOCK = VkFormat_VK_FORMAT_BC3_SRGB_BLOCK,
+    BC4_UNORM_BLOCK = VkFormat_VK_FORMAT_BC4_UNORM_BLOCK,
+    BC4_SNORM_BLOCK = VkFormat_VK_FORMAT_BC4_SNORM_BLOCK,
+    BC5_UNORM_BLOCK = VkFormat_VK_FORMAT_BC5_UNORM_BLOCK,
+    BC5_SNORM_BLOCK = VkFormat_VK_FORMAT_BC5_SNORM_BLOCK,
+    BC6H_UFLOAT_BLOCK = VkFormat_VK_FORMAT_BC6H_UFLOAT_BLOCK,
+    BC6H_SFLOAT_BLOCK = VkFormat_VK_FORMAT_BC6H_SFLOAT_BLOCK,
+    BC7_UNORM_BLOCK = VkFormat_VK_FORMAT_BC7_UNORM_BLOCK,
+    BC7_SRGB_BLOCK = VkFormat_VK_FORMAT_BC7_SRGB_BLOCK,
+    ETC2_R8G8B8_UNORM_BLOCK = VkFormat_VK_FORMAT_ETC2_R8G8B8_UNORM_BLOCK,
+    ETC2_R8G8B8_SRGB_BLOCK = VkFormat_VK_FORMAT_ETC2_R8G8B8_SRGB_BLOCK,
+    ETC2_R8G8B8A1_UNORM_BLOCK = VkFormat_VK_FORMAT_ETC2_R8G8B8A1_UNORM_BLOCK,
+    ETC2_R8G8B8A1_SRGB_BLOCK = VkFormat_VK_FORMAT_ETC2_R8G8B8A1_SRGB_BLOCK,
+    ETC2_R8G8B8A8_UNORM_BLOCK = VkFormat_VK_FORMAT_ETC2_R8G8B8A8_UNORM_BLOCK,
+    ETC2_R8G8B8A8_SRGB_BLOCK = VkFormat_VK_FORMAT_ETC2_R8G8B8A8_SRGB_BLOCK,
+    EAC_R11_UNORM_BLOCK = VkFormat_VK_FORMAT_EAC_R11_UNORM_BLOCK,
+    EAC_R11_SNORM_BLOCK = VkFormat_VK_FORMAT_EAC_R11_SNORM_BLOCK,
+    EAC_R11G11_UNORM_BLOCK = VkFormat_VK_FORMAT_EAC_R11G11_UNORM_BLOCK,
+    EAC_R11G11_SNORM_BLOCK = VkFormat_VK_FORMAT_EAC_R11G11_SNORM_BLOCK,
+    ASTC_4x4_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_4x4_UNORM_BLOCK,
+    ASTC_4x4_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_4x4_SRGB_BLOCK,
+    ASTC_5x4_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_5x4_UNORM_BLOCK,
+    ASTC_5x4_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_5x4_SRGB_BLOCK,
+    ASTC_5x5_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_5x5_UNORM_BLOCK,
+    ASTC_5x5_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_5x5_SRGB_BLOCK,
+    ASTC_6x5_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_6x5_UNORM_BLOCK,
+    ASTC_6x5_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_6x5_SRGB_BLOCK,
+    ASTC_6x6_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_6x6_UNORM_BLOCK,
+    ASTC_6x6_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_6x6_SRGB_BLOCK,
+    ASTC_8x5_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_8x5_UNORM_BLOCK,
+    ASTC_8x5_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_8x5_SRGB_BLOCK,
+    ASTC_8x6_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_8x6_UNORM_BLOCK,
+    ASTC_8x6_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_8x6_SRGB_BLOCK,
+    ASTC_8x8_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_8x8_UNORM_BLOCK,
+    ASTC_8x8_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_8x8_SRGB_BLOCK,
+    ASTC_10x5_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_10x5_UNORM_BLOCK,
+    ASTC_10x5_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_10x5_SRGB_BLOCK,
+    ASTC_10x6_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_10x6_UNORM_BLOCK,
+    ASTC_10x6_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_10x6_SRGB_BLOCK,
+    ASTC_10x8_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_10x8_UNORM_BLOCK,
+    ASTC_10x8_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_10x8_SRGB_BLOCK,
+    ASTC_10x10_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_10x10_UNORM_BLOCK,
+    ASTC_10x10_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_10x10_SRGB_BLOCK,
+    ASTC_12x10_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_12x10_UNORM_BLOCK,
+    ASTC_12x10_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_12x10_SRGB_BLOCK,
+    ASTC_12x12_UNORM_BLOCK = VkFormat_VK_FORMAT_ASTC_12x12_UNORM_BLOCK,
+    ASTC_12x12_SRGB_BLOCK = VkFormat_VK_FORMAT_ASTC_12x12_SRGB_BLOCK,
+    ASTC_4x4_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_4x4_SFLOAT_BLOCK,
+    ASTC_5x4_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_5x4_SFLOAT_BLOCK,
+    ASTC_5x5_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_5x5_SFLOAT_BLOCK,
+    ASTC_6x5_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_6x5_SFLOAT_BLOCK,
+    ASTC_6x6_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_6x6_SFLOAT_BLOCK,
+    ASTC_8x5_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_8x5_SFLOAT_BLOCK,
+    ASTC_8x6_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_8x6_SFLOAT_BLOCK,
+    ASTC_8x8_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_8x8_SFLOAT_BLOCK,
+    ASTC_10x5_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_10x5_SFLOAT_BLOCK,
+    ASTC_10x6_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_10x6_SFLOAT_BLOCK,
+    ASTC_10x8_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_10x8_SFLOAT_BLOCK,
+    ASTC_10x10_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_10x10_SFLOAT_BLOCK,
+    ASTC_12x10_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_12x10_SFLOAT_BLOCK,
+    ASTC_12x12_SFLOAT_BLOCK = VkFormat_VK_FORMAT_ASTC_12x12_SFLOAT_BLOCK,
+    G8B8G8R8_422_UNORM = VkFormat_VK_FORMAT_G8B8G8R8_422_UNORM,
+    B8G8R8G8_422_UNORM = VkFormat_VK_FORMAT_B8G8R8G8_422_UNORM,
+    G8_B8_R8_3PLANE_420_UNORM = VkFormat_VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM,
+    G8_B8R8_2PLANE_420_UNORM = VkFormat_VK_FORMAT_G8_B8R8_2PLANE_420_UNORM,
+    G8_B8_R8_3PLANE_422_UNORM = VkFormat_VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM,
+    G8_B8R8_2PLANE_422_UNORM = VkFormat_VK_FORMAT_G8_B8R8_2PLANE_422_UNORM,
+    G8_B8_R8_3PLANE_444_UNORM = VkFormat_VK_FORMAT_G8_B8_R8_3PLANE_444_UNORM,
+    R10X6_UNORM_PACK16 = VkFormat_VK_FORMAT_R10X6_UNORM_PACK16,
+    R10X6G10X6_UNORM_2PACK16 = VkFormat_VK_FORMAT_R10X6G10X6_UNORM_2PACK16,
+    R10X6G10X6B10X6A10X6_UNORM_4PACK16 = VkFormat_VK_FORMAT_R10X6G10X6B10X6A10X6_UNORM_4PACK16,
+    G10X6B10X6G10X6R10X6_422_UNORM_4PACK16 = VkFormat_VK_FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16,
+    B10X6G10X6R10X6G10X6_422_UNORM_4PACK16 = VkFormat_VK_FORMAT_B10X6G10X6R10X6G10X6_422_UNORM_4PACK16,
+    G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16 = VkFormat_VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16,
+    G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16 = VkFormat_VK_FORMAT_G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16,
+    G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16 = VkFormat_VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16,
+    G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16 = VkFormat_VK_FORMAT_G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16,
+    G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16 = VkFormat_VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16,
+    R12X4_UNORM_PACK16 = VkFormat_VK_FORMAT_R12X4_UNORM_PACK16,
+    R12X4G12X4_UNORM_2PACK16 = VkFormat_VK_FORMAT_R12X4G12X4_UNORM_2PACK16,
+    R12X4G12X4B12X4A12X4_UNORM_4PACK16 = VkFormat_VK_FORMAT_R12X4G12X4B12X4A12X4_UNORM_4PACK16,
+    G12X4B12X4G12X4R12X4_422_UNORM_4PACK16 = VkFormat_VK_FORMAT_G12X4B12X4G12X4R12X4_422_UNORM_4PACK16,
+    B12X4G12X4R12X4G12X4_422_UNORM_4PACK16 = VkFormat_VK_FORMAT_B12X4G12X4R12X4G12X4_422_UNORM_4PACK16,
+    G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16 = VkFormat_VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16,
+    G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16 = VkFormat_VK_FORMAT_G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16,
+    G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16 = VkFormat_VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16,
+    G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16 = VkFormat_VK_FORMAT_G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16,
+    G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16 = VkFormat_VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16,
+    G16B16G16R16_422_UNORM = VkFormat_VK_FORMAT_G16B16G16R16_422_UNORM,
+    B16G16R16G16_422_UNORM = VkFormat_VK_FORMAT_B16G16R16G16_422_UNORM,
+    G16_B16_R16_3PLANE_420_UNORM = VkFormat_VK_FORMAT_G16_B16_R16_3PLANE_420_UNORM,
+    G16_B16R16_2PLANE_420_UNORM = VkFormat_VK_FORMAT_G16_B16R16_2PLANE_420_UNORM,
+    G16_B16_R16_3PLANE_422_UNORM = VkFormat_VK_FORMAT_G16_B16_R16_3PLANE_422_UNORM,
+    G16_B16R16_2PLANE_422_UNORM = VkFormat_VK_FORMAT_G16_B16R16_2PLANE_422_UNORM,
+    G16_B16_R16_3PLANE_444_UNORM = VkFormat_VK_FORMAT_G16_B16_R16_3PLANE_444_UNORM,
+    G8_B8R8_2PLANE_444_UNORM = VkFormat_VK_FORMAT_G8_B8R8_2PLANE_444_UNORM,
+    G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16 = VkFormat_VK_FORMAT_G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16,
+    G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16 = VkFormat_VK_FORMAT_G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16,
+    G16_B16R16_2PLANE_444_UNORM = VkFormat_VK_FORMAT_G16_B16R16_2PLANE_444_UNORM,
+    A4R4G4B4_UNORM_PACK16 = VkFormat_VK_FORMAT_A4R4G4B4_UNORM_PACK16,
+    A4B4G4R4_UNORM_PACK16 = VkFormat_VK_FORMAT_A4B4G4R4_UNORM_PACK16
+});
+
+VULKAN_ENUM!(PresentModeKHR: VkPresentModeKHR {
+    IMMEDIATE = VkPresentModeKHR_VK_PRESENT_MODE_IMMEDIATE_KHR,
+    MAILBOX = VkPresentModeKHR_VK_PRESENT_MODE_MAILBOX_KHR,
+    FIFO = VkPresentModeKHR_VK_PRESENT_MODE_FIFO_KHR,
+    FIFO_RELAXED = VkPresentModeKHR_VK_PRESENT_MODE_FIFO_RELAXED_KHR
+});
+
+VULKAN_FLAGS!(QueueFlags: VkQueueFlags {
+    GRAPHICS = VkQueueFlagBits_VK_QUEUE_GRAPHICS_BIT,
+    COMPUTE = VkQueueFlagBits_VK_QUEUE_COMPUTE_BIT,
+    TRANSFER = VkQueueFlagBits_VK_QUEUE_TRANSFER_BIT,
+    SPARSE_BINDING = VkQueueFlagBits_VK_QUEUE_SPARSE_BINDING_BIT,
+    PROTECTED = VkQueueFlagBits_VK_QUEUE_PROTECTED_BIT
+});
+
+VULKAN_FLAGS!(CommandBufferUsageFlags: VkCommandBufferUsageFlags {
+    ONE_TIME_SUBMIT = VkCommandBufferUsageFlagBits_VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT,
+    RENDER_PASS_CONTINUE = VkCommandBufferUsageFlagBits_VK_COMMAND_BUFFER_USAGE_RENDER_PASS_CONTINUE_BIT,
+    SIMULTANEOUS_USE = VkCommandBufferUsageFlagBits_VK_COMMAND_BUFFER_USAGE_SIMULTANEOUS_USE_BIT
+});
+
+VULKAN_FLAGS!(BufferUsageFlags: VkBufferUsageFlags {
+    TRANSFER_SRC = VkBufferUsageFlagBits_VK_BUFFER_USAGE_TRANSFER_SRC_BIT,
+    TRANSFER_DST = VkBufferUsageFlagBits_VK_BUFFER_USAGE_TRANSFER_DST_BIT,
+    UNIFORM_TEXEL_BUFFER = VkBufferUsageFlagBits_VK_BUFFER_USAGE_UNIFORM_TEXEL_BUFFER_BIT,
+    STORAGE_TEXEL_BUFFER = VkBufferUsageFlagBits_VK_BUFFER_USAGE_STORAGE_TEXEL_BUFFER_BIT,
+    UNIFORM_BUFFER = VkBufferUsageFlagBits_VK_BUFFER_USAGE_UNIFORM_BUFFER_BIT,
+    STORAGE_BUFFER = VkBufferUsageFlagBits_VK_BUFFER_USAGE_STORAGE_BUFFER_BIT,
+    INDEX_BUFFER = VkBufferUsageFlagBits_VK_BUFFER_USAGE_INDEX_BUFFER_BIT,
+    VERTEX_BUFFER = VkBufferUsageFlagBits_VK_BUFFER_USAGE_VERTEX_BUFFER_BIT,
+    INDIRECT_BUFFER = VkBufferUsageFlagBits_VK_BUFFER_USAGE_INDIRECT_BUFFER_BIT,
+    SHADER_DEVICE_ADDRESS = VkBufferUsageFlagBits_VK_BUFFER_USAGE_SHADER_DEVICE_ADDRESS_BIT
+});
+
+VULKAN_FLAGS!(ImageUsageFlags: VkImageUsageFlags {
+    TRANSFER_SRC = VkImageUsageFlagBits_VK_IMAGE_USAGE_TRANSFER_SRC_BIT,
+    TRANSFER_DST = VkImageUsageFlagBits_VK_IMAGE_USAGE_TRANSFER_DST_BIT,
+    SAMPLED = VkImageUsageFlagBits_VK_IMAGE_USAGE_SAMPLED_BIT,
+    STORAGE = VkImageUsageFlagBits_VK_IMAGE_USAGE_STORAGE_BIT,
+    COLOR_ATTACHMENT = VkImageUsageFlagBits_VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT,
+    DEPTH_STENCIL_ATTACHMENT = VkImageUsageFlagBits_VK_IMAGE_USAGE_DEPTH_STENCIL_ATTACHMENT_BIT,
+    TRANSIENT_ATTACHMENT = VkImageUsageFlagBits_VK_IMAGE_USAGE_TRANSIENT_ATTACHMENT_BIT,
+    INPUT_ATTACHMENT = VkImageUsageFlagBits_VK_IMAGE_USAGE_INPUT_ATTACHMENT_BIT
+});
+
+VULKAN_FLAGS!(ImageAspectFlags: VkImageAspectFlags {
+    COLOR = VkImageAspectFlagBits_VK_IMAGE_ASPECT_COLOR_BIT,
+    DEPTH = VkImageAspectFlagBits_VK_IMAGE_ASPECT_DEPTH_BIT,
+    STENCIL = VkImageAspectFlagBits_VK_IMAGE_ASPECT_STENCIL_BIT,
+    METADATA = VkImageAspectFlagBits_VK_IMAGE_ASPECT_METADATA_BIT,
+    PLANE_0 = VkImageAspectFlagBits_VK_IMAGE_ASPECT_PLANE_0_BIT,
+    PLANE_1 = VkImageAspectFlagBits_VK_IMAGE_ASPECT_PLANE_1_BIT,
+    PLANE_2 = VkImageAspectFlagBits_VK_IMAGE_ASPECT_PLANE_2_BIT,
+    NONE = VkImageAspectFlagBits_VK_IMAGE_ASPECT_NONE
+});
+
+VULKAN_FLAGS!(MemoryPropertyFlags: VkMemoryPropertyFlags {
+    DEVICE_LOCAL = VkMemoryPropertyFlagBits_VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT,
+    HOST_VISIBLE = VkMemoryPropertyFlagBits_VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT,
+    HOST_COHERENT = VkMemoryPropertyFlagBits_VK_MEMORY_PROPERTY_HOST_COHERENT_BIT,
+    HOST_CACHED = VkMemoryPropertyFlagBits_VK_MEMORY_PROPERTY_HOST_CACHED_BIT,
+    LAZILY_ALLOCATED = VkMemoryPropertyFlagBits_VK_MEMORY_PROPERTY_LAZILY_ALLOCATED_BIT,
+    PROTECTED = VkMemoryPropertyFlagBits_VK_MEMORY_PROPERTY_PROTECTED_BIT
+});
+
+VULKAN_FLAGS!(SampleCountFlags: VkSampleCountFlags {
+    COUNT_1 = VkSampleCountFlagBits_VK_SAMPLE_COUNT_1_BIT,
+    COUNT_2 = VkSampleCountFlagBits_VK_SAMPLE_COUNT_2_BIT,
+    COUNT_4 = VkSampleCountFlagBits_VK_SAMPLE_COUNT_4_BIT,
+    COUNT_8 = VkSampleCountFlagBits_VK_SAMPLE_COUNT_8_BIT,
+    COUNT_16 = VkSampleCountFlagBits_VK_SAMPLE_COUNT_16_BIT,
+    COUNT_32 = VkSampleCountFlagBits_VK_SAMPLE_COUNT_32_BIT,
+    COUNT_64 = VkSampleCountFlagBits_VK_SAMPLE_COUNT_64_BIT
+});
+
+VULKAN_FLAGS!(FormatFeatureFlags: VkFormatFeatureFlags {
+    SAMPLED_IMAGE = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT,
+    STORAGE_IMAGE = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_STORAGE_IMAGE_BIT,
+    STORAGE_IMAGE_ATOMIC = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_STORAGE_IMAGE_ATOMIC_BIT,
+    UNIFORM_TEXEL_BUFFER = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_UNIFORM_TEXEL_BUFFER_BIT,
+    STORAGE_TEXEL_BUFFER = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_STORAGE_TEXEL_BUFFER_BIT,
+    STORAGE_TEXEL_BUFFER_ATOMIC = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_STORAGE_TEXEL_BUFFER_ATOMIC_BIT,
+    VERTEX_BUFFER = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_VERTEX_BUFFER_BIT,
+    COLOR_ATTACHMENT = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BIT,
+    COLOR_ATTACHMENT_BLEND = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BLEND_BIT,
+    DEPTH_STENCIL_ATTACHMENT = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_DEPTH_STENCIL_ATTACHMENT_BIT,
+    BLIT_SRC = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_BLIT_SRC_BIT,
+    BLIT_DST = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_BLIT_DST_BIT,
+    SAMPLED_IMAGE_FILTER_LINEAR = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_LINEAR_BIT,
+    TRANSFER_SRC = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_TRANSFER_SRC_BIT,
+    TRANSFER_DST = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_TRANSFER_DST_BIT,
+    MIDPOINT_CHROMA_SAMPLES = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_MIDPOINT_CHROMA_SAMPLES_BIT,
+    SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_BIT,
+    SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_BIT,
+    SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_BIT,
+    SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_BIT,
+    DISJOINT = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_DISJOINT_BIT,
+    COSITED_CHROMA_SAMPLES = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_COSITED_CHROMA_SAMPLES_BIT,
+    SAMPLED_IMAGE_FILTER_MINMAX = VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_MINMAX_BIT
+});
+
+VULKAN_FLAGS!(SurfaceTransformFlagsKHR: VkSurfaceTransformFlagsKHR {
+    IDENTITY = VkSurfaceTransformFlagBitsKHR_VK_SURFACE_TRANSFORM_IDENTITY_BIT_KHR,
+    ROTATE_90 = VkSurfaceTransformFlagBitsKHR_VK_SURFACE_TRANSFORM_ROTATE_90_BIT_KHR,
+    ROTATE_180 = VkSurfaceTransformFlagBitsKHR_VK_SURFACE_TRANSFORM_ROTATE_180_BIT_KHR,
+    ROTATE_270 = VkSurfaceTransformFlagBitsKHR_VK_SURFACE_TRANSFORM_ROTATE_270_BIT_KHR,
+    HORIZONTAL_MIRROR = VkSurfaceTransformFlagBitsKHR_VK_SURFACE_TRANSFORM_HORIZONTAL_MIRROR_BIT_KHR,
+    HORIZONTAL_MIRROR_ROTATE_90 = VkSurfaceTransformFlagBitsKHR_VK_SURFACE_TRANSFORM_HORIZONTAL_MIRROR_ROTATE_90_BIT_KHR,
+    HORIZONTAL_MIRROR_ROTATE_180 = VkSurfaceTransformFlagBitsKHR_VK_SURFACE_TRANSFORM_HORIZONTAL_MIRROR_ROTATE_180_BIT_KHR,
+    HORIZONTAL_MIRROR_ROTATE_270 = VkSurfaceTransformFlagBitsKHR_VK_SURFACE_TRANSFORM_HORIZONTAL_MIRROR_ROTATE_270_BIT_KHR,
+    INHERIT = VkSurfaceTransformFlagBitsKHR_VK_SURFACE_TRANSFORM_INHERIT_BIT_KHR
+});
+
+VULKAN_FLAGS!(AccessFlags: VkAccessFlags {
+    INDIRECT_COMMAND_READ = VkAccessFlagBits_VK_ACCESS_INDIRECT_COMMAND_READ_BIT,
+    INDEX_READ = VkAccessFlagBits_VK_ACCESS_INDEX_READ_BIT,
+    VERTEX_ATTRIBUTE_READ = VkAccessFlagBits_VK_ACCESS_VERTEX_ATTRIBUTE_READ_BIT,
+    UNIFORM_READ = VkAccessFlagBits_VK_ACCESS_UNIFORM_READ_BIT,
+    INPUT_ATTACHMENT_READ = VkAccessFlagBits_VK_ACCESS_INPUT_ATTACHMENT_READ_BIT,
+    SHADER_READ = VkAccessFlagBits_VK_ACCESS_SHADER_READ_BIT,
+    SHADER_WRITE = VkAccessFlagBits_VK_ACCESS_SHADER_WRITE_BIT,
+    COLOR_ATTACHMENT_READ = VkAccessFlagBits_VK_ACCESS_COLOR_ATTACHMENT_READ_BIT,
+    COLOR_ATTACHMENT_WRITE = VkAccessFlagBits_VK_ACCESS_COLOR_ATTACHMENT_WRITE_BIT,
+    DEPTH_STENCIL_ATTACHMENT_READ = VkAccessFlagBits_VK_ACCESS_DEPTH_STENCIL_ATTACHMENT_READ_BIT,
+    DEPTH_STENCIL_ATTACHMENT_WRITE = VkAccessFlagBits_VK_ACCESS_DEPTH_STENCIL_ATTACHMENT_WRITE_BIT,
+    TRANSFER_READ = VkAccessFlagBits_VK_ACCESS_TRANSFER_READ_BIT,
+    TRANSFER_WRITE = VkAccessFlagBits_VK_ACCESS_TRANSFER_WRITE_BIT,
+    HOST_READ = VkAccessFlagBits_VK_ACCESS_HOST_READ_BIT,
+    HOST_WRITE = VkAccessFlagBits_VK_ACCESS_HOST_WRITE_BIT,
+    MEMORY_READ = VkAccessFlagBits_VK_ACCESS_MEMORY_READ_BIT,
+    MEMORY_WRITE = VkAccessFlagBits_VK_ACCESS_MEMORY_WRITE_BIT,
+    NONE = VkAccessFlagBits_VK_ACCESS_NONE
+});
+
+VULKAN_FLAGS!(PipelineStageFlags: VkPipelineStageFlags {
+    TOP_OF_PIPE = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_TOP_OF_PIPE_BIT,
+    DRAW_INDIRECT = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_DRAW_INDIRECT_BIT,
+    VERTEX_INPUT = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_VERTEX_INPUT_BIT,
+    VERTEX_SHADER = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_VERTEX_SHADER_BIT,
+    TESSELLATION_CONTROL_SHADER = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_TESSELLATION_CONTROL_SHADER_BIT,
+    TESSELLATION_EVALUATION_SHADER = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_TESSELLATION_EVALUATION_SHADER_BIT,
+    GEOMETRY_SHADER = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_GEOMETRY_SHADER_BIT,
+    FRAGMENT_SHADER = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_FRAGMENT_SHADER_BIT,
+    EARLY_FRAGMENT_TESTS = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_EARLY_FRAGMENT_TESTS_BIT,
+    LATE_FRAGMENT_TESTS = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_LATE_FRAGMENT_TESTS_BIT,
+    COLOR_ATTACHMENT_OUTPUT = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_COLOR_ATTACHMENT_OUTPUT_BIT,
+    COMPUTE_SHADER = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_COMPUTE_SHADER_BIT,
+    TRANSFER = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_TRANSFER_BIT,
+    BOTTOM_OF_PIPE = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_BOTTOM_OF_PIPE_BIT,
+    HOST = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_HOST_BIT,
+    ALL_GRAPHICS = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_ALL_GRAPHICS_BIT,
+    ALL_COMMANDS = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_ALL_COMMANDS_BIT,
+    NONE = VkPipelineStageFlagBits_VK_PIPELINE_STAGE_NONE
+});
+
+VULKAN_FLAGS!(AccessFlags2: VkAccessFlags2 {
+    NONE = VK_ACCESS_2_NONE,
+    INDIRECT_COMMAND_READ = VK_ACCESS_2_INDIRECT_COMMAND_READ_BIT,
+    INDEX_READ = VK_ACCESS_2_INDEX_READ_BIT,
+    VERTEX_ATTRIBUTE_READ = VK_ACCESS_2_VERTEX_ATTRIBUTE_READ_BIT,
+    UNIFORM_READ = VK_ACCESS_2_UNIFORM_READ_BIT,
+    INPUT_ATTACHMENT_READ = VK_ACCESS_2_INPUT_ATTACHMENT_READ_BIT,
+    SHADER_READ = VK_ACCESS_2_SHADER_READ_BIT,
+    SHADER_WRITE = VK_ACCESS_2_SHADER_WRITE_BIT,
+    COLOR_ATTACHMENT_READ = VK_ACCESS_2_COLOR_ATTACHMENT_READ_BIT,
+    COLOR_ATTACHMENT_WRITE = VK_ACCESS_2_COLOR_ATTACHMENT_WRITE_BIT,
+    DEPTH_STENCIL_ATTACHMENT_READ = VK_ACCESS_2_DEPTH_STENCIL_ATTACHMENT_READ_BIT,
+    DEPTH_STENCIL_ATTACHMENT_WRITE = VK_ACCESS_2_DEPTH_STENCIL_ATTACHMENT_WRITE_BIT,
+    TRANSFER_READ = VK_ACCESS_2_TRANSFER_READ_BIT,
+    TRANSFER_WRITE = VK_ACCESS_2_TRANSFER_WRITE_BIT,
+    HOST_READ = VK_ACCESS_2_HOST_READ_BIT,
+    HOST_WRITE = VK_ACCESS_2_HOST_WRITE_BIT,
+    MEMORY_READ = VK_ACCESS_2_MEMORY_READ_BIT,
+    MEMORY_WRITE = VK_ACCESS_2_MEMORY_WRITE_BIT,
+    SHADER_SAMPLED_READ = VK_ACCESS_2_SHADER_SAMPLED_READ_BIT,
+    SHADER_STORAGE_READ = VK_ACCESS_2_SHADER_STORAGE_READ_BIT,
+    SHADER_STORAGE_WRITE = VK_ACCESS_2_SHADER_STORAGE_WRITE_BIT
+});
+
+VULKAN_FLAGS!(PipelineStageFlags2: VkPipelineStageFlags2 {
+    NONE = VK_PIPELINE_STAGE_2_NONE,
+    TOP_OF_PIPE = VK_PIPELINE_STAGE_2_TOP_OF_PIPE_BIT,
+    DRAW_INDIRECT = VK_PIPELINE_STAGE_2_DRAW_INDIRECT_BIT,
+    VERTEX_INPUT = VK_PIPELINE_STAGE_2_VERTEX_INPUT_BIT,
+    VERTEX_SHADER = VK_PIPELINE_STAGE_2_VERTEX_SHADER_BIT,
+    TESSELLATION_CONTROL_SHADER = VK_PIPELINE_STAGE_2_TESSELLATION_CONTROL_SHADER_BIT,
+    TESSELLATION_EVALUATION_SHADER = VK_PIPELINE_STAGE_2_TESSELLATION_EVALUATION_SHADER_BIT,
+    GEOMETRY_SHADER = VK_PIPELINE_STAGE_2_GEOMETRY_SHADER_BIT,
+    FRAGMENT_SHADER = VK_PIPELINE_STAGE_2_FRAGMENT_SHADER_BIT,
+    EARLY_FRAGMENT_TESTS = VK_PIPELINE_STAGE_2_EARLY_FRAGMENT_TESTS_BIT,
+    LATE_FRAGMENT_TESTS = VK_PIPELINE_STAGE_2_LATE_FRAGMENT_TESTS_BIT,
+    COLOR_ATTACHMENT_OUTPUT = VK_PIPELINE_STAGE_2_COLOR_ATTACHMENT_OUTPUT_BIT,
+    COMPUTE_SHADER = VK_PIPELINE_STAGE_2_COMPUTE_SHADER_BIT,
+    ALL_TRANSFER = VK_PIPELINE_STAGE_2_ALL_TRANSFER_BIT,
+    BOTTOM_OF_PIPE = VK_PIPELINE_STAGE_2_BOTTOM_OF_PIPE_BIT,
+    HOST = VK_PIPELINE_STAGE_2_HOST_BIT,
+    ALL_GRAPHICS = VK_PIPELINE_STAGE_2_ALL_GRAPHICS_BIT,
+    ALL_COMMANDS = VK_PIPELINE_STAGE_2_ALL_COMMANDS_BIT,
+    COPY = VK_PIPELINE_STAGE_2_COPY_BIT,
+    RESOLVE = VK_PIPELINE_STAGE_2_RESOLVE_BIT,
+    BLIT = VK_PIPELINE_STAGE_2_BLIT_BIT,
+    CLEAR = VK_PIPELINE_STAGE_2_CLEAR_BIT,
+    INDEX_INPUT = VK_PIPELINE_STAGE_2_INDEX_INPUT_BIT,
+    VERTEX_ATTRIBUTE_INPUT = VK_PIPELINE_STAGE_2_VERTEX_ATTRIBUTE_INPUT_BIT,
+    PRE_RASTERIZATION_SHADERS = VK_PIPELINE_STAGE_2_PRE_RASTERIZATION_SHADERS_BIT
+});
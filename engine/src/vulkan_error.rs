@@ -0,0 +1,226 @@
+use paste::paste;
+use crate::vulkan_bindings;
+use crate::vulkan_init;
+use crate::vulkan_window;
+use crate::vulkan_synchro;
+use crate::vulkan_mem;
+use crate::vulkan_format;
+use crate::vulkan_pipeline_cache;
+
+macro_rules! VK_RESULT_NAMES {
+    ($result: expr, [$($name: ident),* $(,)?]) => {
+        paste! {
+            match $result
+            {
+                $(vulkan_bindings::[<VkResult_ $name>] => stringify!($name),)*
+                _ => "VK_RESULT_UNKNOWN"
+            }
+        }
+    };
+}
+
+pub fn result_name(result: vulkan_bindings::VkResult) -> &'static str
+{
+    VK_RESULT_NAMES!(result, [
+        VK_SUCCESS, VK_NOT_READY, VK_TIMEOUT, VK_EVENT_SET, VK_EVENT_RESET, VK_INCOMPLETE,
+        VK_ERROR_OUT_OF_HOST_MEMORY, VK_ERROR_OUT_OF_DEVICE_MEMORY, VK_ERROR_INITIALIZATION_FAILED, VK_ERROR_DEVICE_LOST,
+        VK_ERROR_MEMORY_MAP_FAILED, VK_ERROR_LAYER_NOT_PRESENT, VK_ERROR_EXTENSION_NOT_PRESENT, VK_ERROR_FEATURE_NOT_PRESENT,
+        VK_ERROR_INCOMPATIBLE_DRIVER, VK_ERROR_TOO_MANY_OBJECTS, VK_ERROR_FORMAT_NOT_SUPPORTED, VK_ERROR_FRAGMENTED_POOL,
+        VK_ERROR_UNKNOWN, VK_ERROR_OUT_OF_POOL_MEMORY, VK_ERROR_INVALID_EXTERNAL_HANDLE, VK_ERROR_FRAGMENTATION,
+        VK_ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS, VK_PIPELINE_COMPILE_REQUIRED,
+        VK_ERROR_SURFACE_LOST_KHR, VK_ERROR_NATIVE_WINDOW_IN_USE_KHR, VK_SUBOPTIMAL_KHR, VK_ERROR_OUT_OF_DATE_KHR,
+        VK_ERROR_INCOMPATIBLE_DISPLAY_KHR, VK_ERROR_VALIDATION_FAILED_EXT
+    ])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VulkanCallError {
+    pub call: &'static str,
+    pub result: vulkan_bindings::VkResult
+}
+
+impl VulkanCallError {
+    pub fn new(call: &'static str, result: vulkan_bindings::VkResult) -> Self
+    {
+        VulkanCallError { call, result }
+    }
+
+    // used when the function pointer itself couldn't be loaded
+    pub fn missing(call: &'static str) -> Self
+    {
+        VulkanCallError::new(call, vulkan_bindings::VkResult_VK_ERROR_EXTENSION_NOT_PRESENT)
+    }
+
+    pub fn result_name(& self) -> &'static str
+    {
+        result_name(self.result)
+    }
+
+    pub fn is_device_lost(& self) -> bool
+    {
+        self.result == vulkan_bindings::VkResult_VK_ERROR_DEVICE_LOST
+    }
+
+    pub fn is_out_of_memory(& self) -> bool
+    {
+        self.result == vulkan_bindings::VkResult_VK_ERROR_OUT_OF_HOST_MEMORY
+        || self.result == vulkan_bindings::VkResult_VK_ERROR_OUT_OF_DEVICE_MEMORY
+    }
+}
+
+impl std::fmt::Display for VulkanCallError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} returned {} ({})", self.call, self.result_name(), self.result)
+    }
+}
+
+impl std::error::Error for VulkanCallError {}
+
+#[derive(Debug)]
+pub enum VulkanError {
+    INIT(vulkan_init::VulkanInitError),
+    WINDOW(vulkan_window::VulkanWindowError),
+    SYNCHRO(vulkan_synchro::VulkanSynchroError),
+    MEM(vulkan_mem::VulkanMemError),
+    FORMAT(vulkan_format::VulkanFormatError),
+    PIPELINE_CACHE(vulkan_pipeline_cache::VulkanPipelineCacheError)
+}
+
+impl VulkanError {
+    pub fn call_error(& self) -> Option<&VulkanCallError>
+    {
+        match self
+        {
+            VulkanError::INIT(e) => e.call_error(),
+            VulkanError::WINDOW(e) => e.call_error(),
+            VulkanError::SYNCHRO(e) => e.call_error(),
+            VulkanError::MEM(e) => e.call_error(),
+            VulkanError::FORMAT(_) => None,
+            VulkanError::PIPELINE_CACHE(e) => e.call_error()
+        }
+    }
+
+    pub fn result(& self) -> Option<vulkan_bindings::VkResult>
+    {
+        self.call_error().map(|e| e.result)
+    }
+
+    pub fn call(& self) -> Option<&'static str>
+    {
+        self.call_error().map(|e| e.call)
+    }
+
+    pub fn is_device_lost(& self) -> bool
+    {
+        self.call_error().is_some_and(|e| e.is_device_lost())
+    }
+}
+
+impl std::fmt::Display for VulkanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self
+        {
+            VulkanError::INIT(_) => write!(f, "Vulkan initialization failed"),
+            VulkanError::WINDOW(_) => write!(f, "Vulkan presentation failed"),
+            VulkanError::SYNCHRO(_) => write!(f, "Vulkan synchronization failed"),
+            VulkanError::MEM(_) => write!(f, "Vulkan memory operation failed"),
+            VulkanError::FORMAT(_) => write!(f, "Vulkan format selection failed"),
+            VulkanError::PIPELINE_CACHE(_) => write!(f, "Vulkan pipeline cache failed")
+        }
+    }
+}
+
+// each layer only prints its own message, the subsystem error and then the failed call follow through source()
+impl std::error::Error for VulkanError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self
+        {
+            VulkanError::INIT(e) => Some(e),
+            VulkanError::WINDOW(e) => Some(e),
+            VulkanError::SYNCHRO(e) => Some(e),
+            VulkanError::MEM(e) => Some(e),
+            VulkanError::FORMAT(e) => Some(e),
+            VulkanError::PIPELINE_CACHE(e) => Some(e)
+        }
+    }
+}
+
+// the message followed by every error of the source chain, one per line
+pub fn describe(error: &(dyn std::error::Error + 'static)) -> String
+{
+    let mut description = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source
+    {
+        description.push_str(&format!("\n  caused by: {}", cause));
+        source = cause.source();
+    }
+    description
+}
+
+impl From<vulkan_init::VulkanInitError> for VulkanError {
+    fn from(value: vulkan_init::VulkanInitError) -> Self {
+        VulkanError::INIT(value)
+    }
+}
+
+impl From<Box<vulkan_init::VulkanInitError>> for VulkanError {
+    fn from(value: Box<vulkan_init::VulkanInitError>) -> Self {
+        VulkanError::INIT(*value)
+    }
+}
+
+impl From<vulkan_window::VulkanWindowError> for VulkanError {
+    fn from(value: vulkan_window::VulkanWindowError) -> Self {
+        VulkanError::WINDOW(value)
+    }
+}
+
+impl From<vulkan_synchro::VulkanSynchroError> for VulkanError {
+    fn from(value: vulkan_synchro::VulkanSynchroError) -> Self {
+        VulkanError::SYNCHRO(value)
+    }
+}
+
+impl From<vulkan_mem::VulkanMemError> for VulkanError {
+    fn from(value: vulkan_mem::VulkanMemError) -> Self {
+        VulkanError::MEM(value)
+    }
+}
+
+impl From<vulkan_format::VulkanFormatError> for VulkanError {
+    fn from(value: vulkan_format::VulkanFormatError) -> Self {
+        VulkanError::FORMAT(value)
+    }
+}
+
+impl From<vulkan_pipeline_cache::VulkanPipelineCacheError> for VulkanError {
+    fn from(value: vulkan_pipeline_cache::VulkanPipelineCacheError) -> Self {
+        VulkanError::PIPELINE_CACHE(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_chain_leads_to_the_failed_call()
+    {
+        let call = VulkanCallError::new("vkQueueSubmit", vulkan_bindings::VkResult_VK_ERROR_DEVICE_LOST);
+        let error = VulkanError::from(vulkan_synchro::VulkanSynchroError::FAILED_SUBMITING_BUFFERS(call));
+        let mut chain = Vec::new();
+        let mut source: Option<&(dyn std::error::Error + 'static)> = Some(&error);
+        while let Some(cause) = source
+        {
+            chain.push(cause.to_string());
+            source = cause.source();
+        }
+        assert_eq!(chain, vec!["Vulkan synchronization failed", "Failed Submiting buffers", "vkQueueSubmit returned VK_ERROR_DEVICE_LOST (-4)"]);
+        let source = std::error::Error::source(&error).and_then(|e| e.source()).unwrap();
+        assert_eq!(source.downcast_ref::<VulkanCallError>(), Some(&call));
+        assert_eq!(describe(&error), "Vulkan synchronization failed\n  caused by: Failed Submiting buffers\n  caused by: vkQueueSubmit returned VK_ERROR_DEVICE_LOST (-4)");
+        assert_eq!(error.call_error(), Some(&call));
+        assert!(error.is_device_lost());
+    }
+}
@@ -0,0 +1,340 @@
+use crate::vulkan_bindings;
+use crate::vulkan_init;
+
+macro_rules! VULKAN_FEATURE_FIELDS {
+    ($($group: ident : [$($field: ident),* $(,)?]),* $(,)?) => {
+        impl VulkanFeatures {
+            pub fn zip(& self, other: &VulkanFeatures, mut visit: impl FnMut(&'static str, vulkan_bindings::VkBool32, vulkan_bindings::VkBool32))
+            {
+                $($(visit(concat!(stringify!($group), ".", stringify!($field)), self.$group.$field, other.$group.$field);)*)*
+            }
+
+            pub fn zip_mut(&mut self, other: &VulkanFeatures, mut merge: impl FnMut(&'static str, &mut vulkan_bindings::VkBool32, vulkan_bindings::VkBool32))
+            {
+                $($(merge(concat!(stringify!($group), ".", stringify!($field)), &mut self.$group.$field, other.$group.$field);)*)*
+            }
+        }
+    };
+}
+
+#[derive(Clone, Copy)]
+pub struct VulkanFeatures {
+    pub core : vulkan_bindings::VkPhysicalDeviceFeatures,
+    pub vulkan11 : vulkan_bindings::VkPhysicalDeviceVulkan11Features,
+    pub vulkan12 : vulkan_bindings::VkPhysicalDeviceVulkan12Features,
+    pub vulkan13 : vulkan_bindings::VkPhysicalDeviceVulkan13Features,
+    // extension structs standing in for the versioned ones on older api versions, only set while chained
+    timeline_semaphore_khr : Option<vulkan_bindings::VkPhysicalDeviceTimelineSemaphoreFeatures>,
+    synchronization2_khr : Option<vulkan_bindings::VkPhysicalDeviceSynchronization2Features>
+}
+
+VULKAN_FEATURE_FIELDS!(
+    core: [
+        robustBufferAccess, fullDrawIndexUint32, imageCubeArray, independentBlend, geometryShader,
+        tessellationShader, sampleRateShading, dualSrcBlend, logicOp, multiDrawIndirect,
+        drawIndirectFirstInstance, depthClamp, depthBiasClamp, fillModeNonSolid, depthBounds,
+        wideLines, largePoints, alphaToOne, multiViewport, samplerAnisotropy,
+        textureCompressionETC2, textureCompressionASTC_LDR, textureCompressionBC, occlusionQueryPrecise, pipelineStatisticsQuery,
+        vertexPipelineStoresAndAtomics, fragmentStoresAndAtomics, shaderTessellationAndGeometryPointSize, shaderImageGatherExtended, shaderStorageImageExtendedFormats,
+        shaderStorageImageMultisample, shaderStorageImageReadWithoutFormat, shaderStorageImageWriteWithoutFormat, shaderUniformBufferArrayDynamicIndexing, shaderSampledImageArrayDynamicIndexing,
+        shaderStorageBufferArrayDynamicIndexing, shaderStorageImageArrayDynamicIndexing, shaderClipDistance, shaderCullDistance, shaderFloat64,
+        shaderInt64, shaderInt16, shaderResourceResidency, shaderResourceMinLod, sparseBinding,
+        sparseResidencyBuffer, sparseResidencyImage2D, sparseResidencyImage3D, sparseResidency2Samples, sparseResidency4Samples,
+        sparseResidency8Samples, sparseResidency16Samples, sparseResidencyAliased, variableMultisampleRate, inheritedQueries
+    ],
+    vulkan11: [
+        storageBuffer16BitAccess, uniformAndStorageBuffer16BitAccess, storagePushConstant16, storageInputOutput16, multiview,
+        multiviewGeometryShader, multiviewTessellationShader, variablePointersStorageBuffer, variablePointers, protectedMemory,
+        samplerYcbcrConversion, shaderDrawParameters
+    ],
+    vulkan12: [
+        samplerMirrorClampToEdge, drawIndirectCount, storageBuffer8BitAccess, uniformAndStorageBuffer8BitAccess, storagePushConstant8,
+        shaderBufferInt64Atomics, shaderSharedInt64Atomics, shaderFloat16, shaderInt8, descriptorIndexing,
+        shaderInputAttachmentArrayDynamicIndexing, shaderUniformTexelBufferArrayDynamicIndexing, shaderStorageTexelBufferArrayDynamicIndexing, shaderUniformBufferArrayNonUniformIndexing, shaderSampledImageArrayNonUniformIndexing,
+        shaderStorageBufferArrayNonUniformIndexing, shaderStorageImageArrayNonUniformIndexing, shaderInputAttachmentArrayNonUniformIndexing, shaderUniformTexelBufferArrayNonUniformIndexing, shaderStorageTexelBufferArrayNonUniformIndexing,
+        descriptorBindingUniformBufferUpdateAfterBind, descriptorBindingSampledImageUpdateAfterBind, descriptorBindingStorageImageUpdateAfterBind, descriptorBindingStorageBufferUpdateAfterBind, descriptorBindingUniformTexelBufferUpdateAfterBind,
+        descriptorBindingStorageTexelBufferUpdateAfterBind, descriptorBindingUpdateUnusedWhilePending, descriptorBindingPartiallyBound, descriptorBindingVariableDescriptorCount, runtimeDescriptorArray,
+        samplerFilterMinmax, scalarBlockLayout, imagelessFramebuffer, uniformBufferStandardLayout, shaderSubgroupExtendedTypes,
+        separateDepthStencilLayouts, hostQueryReset, timelineSemaphore, bufferDeviceAddress, bufferDeviceAddressCaptureReplay,
+        bufferDeviceAddressMultiDevice, vulkanMemoryModel, vulkanMemoryModelDeviceScope, vulkanMemoryModelAvailabilityVisibilityChains, shaderOutputViewportIndex,
+        shaderOutputLayer, subgroupBroadcastDynamicId
+    ],
+    vulkan13: [
+        robustImageAccess, inlineUniformBlock, descriptorBindingInlineUniformBlockUpdateAfterBind, pipelineCreationCacheControl, privateData,
+        shaderDemoteToHelperInvocation, shaderTerminateInvocation, subgroupSizeControl, computeFullSubgroups, synchronization2,
+        textureCompressionASTC_HDR, shaderZeroInitializeWorkgroupMemory, dynamicRendering, shaderIntegerDotProduct, maintenance4
+    ]
+);
+
+fn has_extension(extensions: &[String], name: &[u8]) -> bool
+{
+    extensions.iter().any(|extension| extension.as_bytes() == name.strip_suffix(b"\0").unwrap_or(name))
+}
+
+impl VulkanFeatures {
+    pub fn new() -> Self
+    {
+        unsafe {
+            let mut features = VulkanFeatures {
+                core: std::mem::zeroed(),
+                vulkan11: std::mem::zeroed(),
+                vulkan12: std::mem::zeroed(),
+                vulkan13: std::mem::zeroed(),
+                timeline_semaphore_khr: None,
+                synchronization2_khr: None
+            };
+            features.vulkan11.sType = vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_1_FEATURES;
+            features.vulkan12.sType = vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_2_FEATURES;
+            features.vulkan13.sType = vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_3_FEATURES;
+            features
+        }
+    }
+
+    // links the feature structs the api version knows about, plus the extension structs of the enabled extensions
+    // the api version doesn't cover. the returned struct points into self so self must not move while it's in use,
+    // call unchain once the query or create is done
+    pub fn chain(&mut self, api_version: u32, extensions: &[String]) -> vulkan_bindings::VkPhysicalDeviceFeatures2
+    {
+        let mut head : *mut std::ffi::c_void = std::ptr::null_mut();
+        self.unchain();
+        if api_version >= vulkan_init::make_api_version(0, 1, 3, 0)
+        {
+            head = &mut self.vulkan13 as *mut vulkan_bindings::VkPhysicalDeviceVulkan13Features as *mut std::ffi::c_void;
+        }
+        if api_version >= vulkan_init::make_api_version(0, 1, 2, 0)
+        {
+            self.vulkan12.pNext = head;
+            self.vulkan11.pNext = &mut self.vulkan12 as *mut vulkan_bindings::VkPhysicalDeviceVulkan12Features as *mut std::ffi::c_void;
+            head = &mut self.vulkan11 as *mut vulkan_bindings::VkPhysicalDeviceVulkan11Features as *mut std::ffi::c_void;
+        }
+        if api_version < vulkan_init::make_api_version(0, 1, 3, 0) && has_extension(extensions, vulkan_bindings::VK_KHR_SYNCHRONIZATION_2_EXTENSION_NAME)
+        {
+            let synchronization2 = self.synchronization2_khr.insert(vulkan_bindings::VkPhysicalDeviceSynchronization2Features {
+                sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES,
+                pNext: head,
+                synchronization2: self.vulkan13.synchronization2
+            });
+            head = synchronization2 as *mut vulkan_bindings::VkPhysicalDeviceSynchronization2Features as *mut std::ffi::c_void;
+        }
+        if api_version < vulkan_init::make_api_version(0, 1, 2, 0) && has_extension(extensions, vulkan_bindings::VK_KHR_TIMELINE_SEMAPHORE_EXTENSION_NAME)
+        {
+            let timeline = self.timeline_semaphore_khr.insert(vulkan_bindings::VkPhysicalDeviceTimelineSemaphoreFeatures {
+                sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES,
+                pNext: head,
+                timelineSemaphore: self.vulkan12.timelineSemaphore
+            });
+            head = timeline as *mut vulkan_bindings::VkPhysicalDeviceTimelineSemaphoreFeatures as *mut std::ffi::c_void;
+        }
+        vulkan_bindings::VkPhysicalDeviceFeatures2 {
+            sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2,
+            pNext: head,
+            features: self.core
+        }
+    }
+
+    // a chained copy would keep pointing at the struct it was copied from, what the driver wrote in the
+    // extension structs goes back to the versioned fields
+    pub fn unchain(&mut self)
+    {
+        if let Some(timeline) = self.timeline_semaphore_khr.take()
+        {
+            self.vulkan12.timelineSemaphore = timeline.timelineSemaphore;
+        }
+        if let Some(synchronization2) = self.synchronization2_khr.take()
+        {
+            self.vulkan13.synchronization2 = synchronization2.synchronization2;
+        }
+        self.vulkan11.pNext = std::ptr::null_mut();
+        self.vulkan12.pNext = std::ptr::null_mut();
+        self.vulkan13.pNext = std::ptr::null_mut();
+    }
+
+    // whether chain(api_version, extensions) has the driver fill in the feature, the others are left as they were
+    pub fn is_chained(name: &str, api_version: u32, extensions: &[String]) -> bool
+    {
+        let vulkan_1_2 = api_version >= vulkan_init::make_api_version(0, 1, 2, 0);
+        let vulkan_1_3 = api_version >= vulkan_init::make_api_version(0, 1, 3, 0);
+        match name.split_once('.') {
+            Some(("core", _)) => true,
+            Some(("vulkan12", "timelineSemaphore")) => vulkan_1_2 || has_extension(extensions, vulkan_bindings::VK_KHR_TIMELINE_SEMAPHORE_EXTENSION_NAME),
+            Some(("vulkan13", "synchronization2")) => vulkan_1_3 || has_extension(extensions, vulkan_bindings::VK_KHR_SYNCHRONIZATION_2_EXTENSION_NAME),
+            Some(("vulkan11" | "vulkan12", _)) => vulkan_1_2,
+            Some(("vulkan13", _)) => vulkan_1_3,
+            _ => false
+        }
+    }
+
+    pub fn is_empty(& self) -> bool
+    {
+        self.names().len() == 0
+    }
+
+    pub fn names(& self) -> Vec<&'static str>
+    {
+        let mut names = Vec::new();
+        self.zip(self, |name, enabled, _| {
+            if enabled != vulkan_bindings::VK_FALSE
+            {
+                names.push(name);
+            }
+        });
+        names
+    }
+
+    pub fn missing(& self, available: &VulkanFeatures) -> Vec<String>
+    {
+        let mut missing = Vec::new();
+        self.zip(available, |name, wanted, supported| {
+            if wanted != vulkan_bindings::VK_FALSE && supported == vulkan_bindings::VK_FALSE
+            {
+                missing.push(name.to_string());
+            }
+        });
+        missing
+    }
+
+    pub fn intersection(& self, available: &VulkanFeatures) -> VulkanFeatures
+    {
+        let mut features = *self;
+        features.zip_mut(available, |_, wanted, supported| {
+            if supported == vulkan_bindings::VK_FALSE
+            {
+                *wanted = vulkan_bindings::VK_FALSE;
+            }
+        });
+        features
+    }
+
+    pub fn union(& self, other: &VulkanFeatures) -> VulkanFeatures
+    {
+        let mut features = *self;
+        features.zip_mut(other, |_, enabled, other_enabled| {
+            if other_enabled != vulkan_bindings::VK_FALSE
+            {
+                *enabled = vulkan_bindings::VK_TRUE;
+            }
+        });
+        features
+    }
+}
+
+impl Default for VulkanFeatures {
+    fn default() -> Self {
+        VulkanFeatures::new()
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct VulkanFeatureRequest {
+    pub required : VulkanFeatures,
+    pub optional : VulkanFeatures
+}
+
+impl VulkanFeatureRequest {
+    pub fn new() -> Self
+    {
+        VulkanFeatureRequest::default()
+    }
+
+    pub fn required(mut self, setup: impl FnOnce(&mut VulkanFeatures)) -> Self
+    {
+        setup(&mut self.required);
+        self
+    }
+
+    pub fn optional(mut self, setup: impl FnOnce(&mut VulkanFeatures)) -> Self
+    {
+        setup(&mut self.optional);
+        self
+    }
+
+    pub fn missing(& self, available: &VulkanFeatures) -> Vec<String>
+    {
+        self.required.missing(available)
+    }
+
+    pub fn resolve(& self, available: &VulkanFeatures) -> VulkanFeatures
+    {
+        self.required.union(&self.optional.intersection(available))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unchain_clears_the_links_chain_made()
+    {
+        let mut features = VulkanFeatures::new();
+        let features2 = features.chain(vulkan_init::make_api_version(0, 1, 3, 0), &[]);
+        assert!(!features2.pNext.is_null());
+        assert!(!features.vulkan11.pNext.is_null() && !features.vulkan12.pNext.is_null());
+        features.unchain();
+        assert!(features.vulkan11.pNext.is_null() && features.vulkan12.pNext.is_null() && features.vulkan13.pNext.is_null());
+    }
+
+    #[test]
+    fn older_api_versions_chain_less()
+    {
+        let mut features = VulkanFeatures::new();
+        assert!(features.chain(vulkan_init::make_api_version(0, 1, 1, 0), &[]).pNext.is_null());
+        let features2 = features.chain(vulkan_init::make_api_version(0, 1, 2, 0), &[]);
+        assert_eq!(features2.pNext, &mut features.vulkan11 as *mut vulkan_bindings::VkPhysicalDeviceVulkan11Features as *mut std::ffi::c_void);
+        assert!(features.vulkan12.pNext.is_null());
+    }
+
+    #[test]
+    fn timeline_extension_struct_stands_in_below_1_2()
+    {
+        let extensions = vulkan_init::load_extension_names(&[vulkan_bindings::VK_KHR_TIMELINE_SEMAPHORE_EXTENSION_NAME]);
+        let mut features = VulkanFeatures::new();
+        let features2 = features.chain(vulkan_init::make_api_version(0, 1, 1, 0), &extensions);
+        unsafe {
+            // what a driver would write through the chain
+            let timeline = &mut *(features2.pNext as *mut vulkan_bindings::VkPhysicalDeviceTimelineSemaphoreFeatures);
+            assert_eq!(timeline.sType, vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES);
+            assert!(timeline.pNext.is_null());
+            timeline.timelineSemaphore = vulkan_bindings::VK_TRUE;
+        }
+        features.unchain();
+        assert_eq!(features.vulkan12.timelineSemaphore, vulkan_bindings::VK_TRUE);
+        // 1.2 has it in the vulkan12 struct, the extension struct isn't chained
+        features.chain(vulkan_init::make_api_version(0, 1, 2, 0), &extensions);
+        assert!(features.timeline_semaphore_khr.is_none());
+    }
+
+    #[test]
+    fn synchronization2_extension_struct_stands_in_below_1_3()
+    {
+        let extensions = vulkan_init::load_extension_names(&[vulkan_bindings::VK_KHR_SYNCHRONIZATION_2_EXTENSION_NAME]);
+        let mut features = VulkanFeatures::new();
+        features.vulkan13.synchronization2 = vulkan_bindings::VK_TRUE;
+        let features2 = features.chain(vulkan_init::make_api_version(0, 1, 2, 0), &extensions);
+        unsafe {
+            // the enabled value is passed on to the device and the rest of the chain follows
+            let synchronization2 = &*(features2.pNext as *const vulkan_bindings::VkPhysicalDeviceSynchronization2Features);
+            assert_eq!(synchronization2.sType, vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES);
+            assert_eq!(synchronization2.synchronization2, vulkan_bindings::VK_TRUE);
+            assert_eq!(synchronization2.pNext, &mut features.vulkan11 as *mut vulkan_bindings::VkPhysicalDeviceVulkan11Features as *mut std::ffi::c_void);
+        }
+        features.unchain();
+        assert_eq!(features.vulkan13.synchronization2, vulkan_bindings::VK_TRUE);
+        features.chain(vulkan_init::make_api_version(0, 1, 3, 0), &extensions);
+        assert!(features.synchronization2_khr.is_none());
+    }
+
+    #[test]
+    fn features_older_api_versions_dont_chain_arent_reported()
+    {
+        let vulkan_1_1 = vulkan_init::make_api_version(0, 1, 1, 0);
+        let extensions = vulkan_init::load_extension_names(&[vulkan_bindings::VK_KHR_TIMELINE_SEMAPHORE_EXTENSION_NAME]);
+        assert!(VulkanFeatures::is_chained("core.samplerAnisotropy", vulkan_1_1, &[]));
+        assert!(!VulkanFeatures::is_chained("vulkan11.multiview", vulkan_1_1, &[]));
+        assert!(!VulkanFeatures::is_chained("vulkan12.timelineSemaphore", vulkan_1_1, &[]));
+        assert!(VulkanFeatures::is_chained("vulkan12.timelineSemaphore", vulkan_1_1, &extensions));
+        assert!(!VulkanFeatures::is_chained("vulkan12.hostQueryReset", vulkan_1_1, &extensions));
+        assert!(VulkanFeatures::is_chained("vulkan12.hostQueryReset", vulkan_init::make_api_version(0, 1, 2, 0), &[]));
+        assert!(!VulkanFeatures::is_chained("vulkan13.dynamicRendering", vulkan_init::make_api_version(0, 1, 2, 0), &[]));
+    }
+}
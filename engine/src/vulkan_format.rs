@@ -0,0 +1,219 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use crate::vulkan_bindings;
+use crate::vulkan_init;
+use crate::vulkan_types;
+
+pub const DEPTH_FORMATS: [vulkan_bindings::VkFormat; 3] = [
+    vulkan_bindings::VkFormat_VK_FORMAT_D32_SFLOAT,
+    vulkan_bindings::VkFormat_VK_FORMAT_D24_UNORM_S8_UINT,
+    vulkan_bindings::VkFormat_VK_FORMAT_D16_UNORM
+];
+
+pub const DEPTH_STENCIL_FORMATS: [vulkan_bindings::VkFormat; 3] = [
+    vulkan_bindings::VkFormat_VK_FORMAT_D32_SFLOAT_S8_UINT,
+    vulkan_bindings::VkFormat_VK_FORMAT_D24_UNORM_S8_UINT,
+    vulkan_bindings::VkFormat_VK_FORMAT_D16_UNORM_S8_UINT
+];
+
+type ImageFormatKey = (vulkan_bindings::VkFormat, vulkan_types::ImageType, vulkan_types::ImageTiling, vulkan_types::ImageUsageFlags, vulkan_bindings::VkImageCreateFlags);
+
+#[derive(Debug)]
+pub enum VulkanFormatError {
+    NO_SUPPORTED_FORMAT(Vec<vulkan_bindings::VkFormat>, vulkan_types::ImageTiling, vulkan_types::FormatFeatureFlags),
+    NO_SUPPORTED_BUFFER_FORMAT(Vec<vulkan_bindings::VkFormat>, vulkan_types::FormatFeatureFlags),
+    UNSUPPORTED_IMAGE_FORMAT(vulkan_bindings::VkFormat, vulkan_types::ImageType, vulkan_types::ImageTiling, vulkan_types::ImageUsageFlags),
+    IMAGE_EXCEEDS_FORMAT_LIMITS(vulkan_bindings::VkFormat, String)
+}
+
+pub fn format_name(format: vulkan_bindings::VkFormat) -> String
+{
+    format!("{:?}", vulkan_types::Format::from_raw(format))
+}
+
+fn format_names(formats: &[vulkan_bindings::VkFormat]) -> String
+{
+    formats.iter().map(|format| format_name(*format)).collect::<Vec<String>>().join(", ")
+}
+
+impl std::fmt::Display for VulkanFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VulkanFormatError::NO_SUPPORTED_FORMAT(candidates, tiling, features) =>
+                write!(f, "None of [{}] support format features {:?} with {:?} tiling", format_names(candidates), features, tiling),
+            VulkanFormatError::NO_SUPPORTED_BUFFER_FORMAT(candidates, features) =>
+                write!(f, "None of [{}] support buffer format features {:?}", format_names(candidates), features),
+            VulkanFormatError::UNSUPPORTED_IMAGE_FORMAT(format, img_type, tiling, usage) =>
+                write!(f, "{} can't be used for image type {:?} with {:?} tiling and usage {:?}", format_name(*format), img_type, tiling, usage),
+            VulkanFormatError::IMAGE_EXCEEDS_FORMAT_LIMITS(format, reason) =>
+                write!(f, "{} image exceeds the format limits: {}", format_name(*format), reason)
+        }
+    }
+}
+
+impl std::error::Error for VulkanFormatError {}
+
+#[derive(Default)]
+pub struct VulkanFormatCache {
+    properties : RefCell<HashMap<vulkan_bindings::VkFormat, vulkan_bindings::VkFormatProperties>>,
+    image_properties : RefCell<HashMap<ImageFormatKey, Option<vulkan_bindings::VkImageFormatProperties>>>
+}
+
+pub fn image_usage_format_features(usage: vulkan_types::ImageUsageFlags) -> vulkan_types::FormatFeatureFlags
+{
+    let usage_features = [
+        (vulkan_types::ImageUsageFlags::SAMPLED, vulkan_types::FormatFeatureFlags::SAMPLED_IMAGE),
+        (vulkan_types::ImageUsageFlags::STORAGE, vulkan_types::FormatFeatureFlags::STORAGE_IMAGE),
+        (vulkan_types::ImageUsageFlags::COLOR_ATTACHMENT, vulkan_types::FormatFeatureFlags::COLOR_ATTACHMENT),
+        (vulkan_types::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT, vulkan_types::FormatFeatureFlags::DEPTH_STENCIL_ATTACHMENT),
+        (vulkan_types::ImageUsageFlags::TRANSFER_SRC, vulkan_types::FormatFeatureFlags::TRANSFER_SRC),
+        (vulkan_types::ImageUsageFlags::TRANSFER_DST, vulkan_types::FormatFeatureFlags::TRANSFER_DST)
+    ];
+    let mut features = vulkan_types::FormatFeatureFlags::empty();
+    for (usage_bit, feature_bit) in usage_features
+    {
+        if usage.intersects(usage_bit)
+        {
+            features |= feature_bit;
+        }
+    }
+    features
+}
+
+impl vulkan_init::VulkanPhysicalDevice {
+    pub fn format_properties(& self, format: vulkan_bindings::VkFormat) -> vulkan_bindings::VkFormatProperties
+    {
+        if let Some(properties) = self.formats.properties.borrow().get(&format)
+        {
+            return *properties;
+        }
+        unsafe {
+            let mut format_properties : vulkan_bindings::VkFormatProperties = std::mem::zeroed();
+            let fn_vkGetPhysicalDeviceFormatProperties = self.fns.vkGetPhysicalDeviceFormatProperties.unwrap();
+            fn_vkGetPhysicalDeviceFormatProperties(self.ph_device, format, &mut format_properties);
+            self.formats.properties.borrow_mut().insert(format, format_properties);
+            format_properties
+        }
+    }
+
+    pub fn image_format_properties(& self,
+        format: vulkan_bindings::VkFormat,
+        img_type: vulkan_types::ImageType,
+        tiling: vulkan_types::ImageTiling,
+        usage: vulkan_types::ImageUsageFlags,
+        flags: vulkan_bindings::VkImageCreateFlags
+    ) -> Option<vulkan_bindings::VkImageFormatProperties>
+    {
+        let key = (format, img_type, tiling, usage, flags);
+        if let Some(properties) = self.formats.image_properties.borrow().get(&key)
+        {
+            return *properties;
+        }
+        unsafe {
+            let mut image_format_properties : vulkan_bindings::VkImageFormatProperties = std::mem::zeroed();
+            let fn_vkGetPhysicalDeviceImageFormatProperties = self.fns.vkGetPhysicalDeviceImageFormatProperties.unwrap();
+            let result = fn_vkGetPhysicalDeviceImageFormatProperties(self.ph_device, format, img_type.as_raw(), tiling.as_raw(), usage.as_raw(), flags, &mut image_format_properties);
+            let properties = if result == vulkan_bindings::VkResult_VK_SUCCESS { Some(image_format_properties) } else { None };
+            self.formats.image_properties.borrow_mut().insert(key, properties);
+            properties
+        }
+    }
+
+    pub fn supports_format(& self, format: vulkan_bindings::VkFormat, tiling: vulkan_types::ImageTiling, features: vulkan_types::FormatFeatureFlags) -> bool
+    {
+        let properties = self.format_properties(format);
+        let supported = match tiling {
+            vulkan_types::ImageTiling::LINEAR => properties.linearTilingFeatures,
+            vulkan_types::ImageTiling::OPTIMAL => properties.optimalTilingFeatures,
+            _ => 0
+        };
+        vulkan_types::FormatFeatureFlags::from_raw(supported).contains(features)
+    }
+
+    pub fn supports_buffer_format(& self, format: vulkan_bindings::VkFormat, features: vulkan_types::FormatFeatureFlags) -> bool
+    {
+        vulkan_types::FormatFeatureFlags::from_raw(self.format_properties(format).bufferFeatures).contains(features)
+    }
+
+    pub fn choose_format(& self,
+        candidates: &[vulkan_bindings::VkFormat],
+        tiling: vulkan_types::ImageTiling,
+        features: vulkan_types::FormatFeatureFlags
+    ) -> Result<vulkan_bindings::VkFormat, VulkanFormatError>
+    {
+        for format in candidates
+        {
+            if self.supports_format(*format, tiling, features)
+            {
+                return Ok(*format);
+            }
+        }
+        Err(VulkanFormatError::NO_SUPPORTED_FORMAT(candidates.to_vec(), tiling, features))
+    }
+
+    pub fn choose_image_format(& self,
+        candidates: &[vulkan_bindings::VkFormat],
+        tiling: vulkan_types::ImageTiling,
+        usage: vulkan_types::ImageUsageFlags
+    ) -> Result<vulkan_bindings::VkFormat, VulkanFormatError>
+    {
+        self.choose_format(candidates, tiling, image_usage_format_features(usage))
+    }
+
+    pub fn choose_buffer_format(& self, candidates: &[vulkan_bindings::VkFormat], features: vulkan_types::FormatFeatureFlags) -> Result<vulkan_bindings::VkFormat, VulkanFormatError>
+    {
+        for format in candidates
+        {
+            if self.supports_buffer_format(*format, features)
+            {
+                return Ok(*format);
+            }
+        }
+        Err(VulkanFormatError::NO_SUPPORTED_BUFFER_FORMAT(candidates.to_vec(), features))
+    }
+
+    pub fn choose_depth_format(& self, with_stencil: bool) -> Result<vulkan_bindings::VkFormat, VulkanFormatError>
+    {
+        let candidates: &[vulkan_bindings::VkFormat] = if with_stencil { &DEPTH_STENCIL_FORMATS } else { &DEPTH_FORMATS };
+        self.choose_format(candidates,
+            vulkan_types::ImageTiling::OPTIMAL,
+            vulkan_types::FormatFeatureFlags::DEPTH_STENCIL_ATTACHMENT
+        )
+    }
+
+    pub fn check_image_support(& self,
+        format: vulkan_bindings::VkFormat,
+        img_type: vulkan_types::ImageType,
+        tiling: vulkan_types::ImageTiling,
+        usage: vulkan_types::ImageUsageFlags,
+        dimensions: &vulkan_bindings::VkExtent3D,
+        mipmap_lvl: u32,
+        layer_num: u32,
+        sample_count: vulkan_types::SampleCountFlags
+    ) -> Result<(), VulkanFormatError>
+    {
+        let properties = match self.image_format_properties(format, img_type, tiling, usage, 0) {
+            Some(properties) => properties,
+            None => return Err(VulkanFormatError::UNSUPPORTED_IMAGE_FORMAT(format, img_type, tiling, usage))
+        };
+        let max_extent = &properties.maxExtent;
+        if dimensions.width > max_extent.width || dimensions.height > max_extent.height || dimensions.depth > max_extent.depth
+        {
+            return Err(VulkanFormatError::IMAGE_EXCEEDS_FORMAT_LIMITS(format, format!("extent {}x{}x{} is above {}x{}x{}",
+                dimensions.width, dimensions.height, dimensions.depth, max_extent.width, max_extent.height, max_extent.depth)));
+        }
+        if mipmap_lvl > properties.maxMipLevels
+        {
+            return Err(VulkanFormatError::IMAGE_EXCEEDS_FORMAT_LIMITS(format, format!("{} mip levels is above {}", mipmap_lvl, properties.maxMipLevels)));
+        }
+        if layer_num > properties.maxArrayLayers
+        {
+            return Err(VulkanFormatError::IMAGE_EXCEEDS_FORMAT_LIMITS(format, format!("{} array layers is above {}", layer_num, properties.maxArrayLayers)));
+        }
+        if !vulkan_types::SampleCountFlags::from_raw(properties.sampleCounts).contains(sample_count)
+        {
+            return Err(VulkanFormatError::IMAGE_EXCEEDS_FORMAT_LIMITS(format, format!("sample count {:?} isn't supported", sample_count)));
+        }
+        Ok(())
+    }
+}
@@ -1,14 +1,31 @@
 use paste::paste;
 use std::ffi::CString;
 use crate::vulkan_bindings;
+use crate::vulkan_debug;
+use crate::vulkan_features;
+use crate::vulkan_format;
+use crate::vulkan_error;
+use crate::vulkan_pipeline_cache;
+use crate::vulkan_alloc;
+use crate::vulkan_types;
 
 pub static mut VULKAN_INSTANCE:Option<VulkanInstance>= None;
 
-macro_rules! EXPORTED_VULKAN_FUNCTION {
-    ($name: ident) => {
+macro_rules! VULKAN_DISPATCH_TABLE {
+    ($table: ident { $($(#[$attr: meta])* $name: ident),* $(,)? }) => {
         paste! {
-        #[allow(non_upper_case_globals)]
-        pub static mut $name :  vulkan_bindings::[<PFN_$name>] = None;
+            #[derive(Clone, Copy)]
+            pub struct $table {
+                $($(#[$attr])* pub $name : vulkan_bindings::[<PFN_$name>],)*
+            }
+
+            impl Default for $table {
+                fn default() -> Self {
+                    $table {
+                        $($(#[$attr])* $name : None,)*
+                    }
+                }
+            }
         }
     };
 }
@@ -29,60 +46,91 @@ macro_rules! DEFAULTED_IMPORT_MACROS {
         #[allow(unused_macros)]
         macro_rules!  LOAD_GLOBAL_LEVEL_VULKAN_FUNCTION {($func: ident) => {}}
         #[allow(unused_macros)]
+        macro_rules!  LOAD_OPTIONAL_GLOBAL_LEVEL_VULKAN_FUNCTION {($func: ident) => {}}
+        #[allow(unused_macros)]
         macro_rules!  LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION {($func: ident) => {}}
         #[allow(unused_macros)]
         macro_rules! LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS {($function: ident, $ext: ident) => {}}
         #[allow(unused_macros)]
+        macro_rules! LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION {($function: ident, $major: expr, $minor: expr) => {}}
+        #[allow(unused_macros)]
         macro_rules! LOAD_DEVICE_LEVEL_VULKAN_FUNCTION {($func: ident) => {}}
         #[allow(unused_macros)]
         macro_rules!  LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION {($func: ident, $ext:ident) => {}}
+        #[allow(unused_macros)]
+        macro_rules! LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION {($function: ident, $major: expr, $minor: expr) => {}}
     };
 }
 
 include!("./imported_functions.rs");
+include!("./extension_commands.rs");
 
 #[derive(Debug)]
 pub enum VulkanInitError {
-    UNLOADABLE_LIBRARY,
+    UNLOADABLE_LIBRARY(Vec<(String, String)>),
     EXPORTED_VK_FUNCTION_ERROR(String),
     GLOBAL_VK_FUNCTION_ERROR(String),
-    UNLOADABLE_EXTENSIONS,
+    UNLOADABLE_EXTENSIONS(vulkan_error::VulkanCallError),
     UNAVAILABLE_EXTENSION(String),
-    FAILED_INSTANTIATING_VULKAN,
+    FAILED_INSTANTIATING_VULKAN(vulkan_error::VulkanCallError),
     INSTANCE_VK_FUNCTION_ERROR(String),
     INSTANCE_VK_EXT_FUNCTION_ERROR(String),
-    UNAVAILABLE_VULKAN_PHYSICAL_DEVICES,
-    UNAVAILABLE_PHYSICAL_DEVICE_EXTENSIONS,
+    UNAVAILABLE_VULKAN_PHYSICAL_DEVICES(vulkan_error::VulkanCallError),
+    UNAVAILABLE_PHYSICAL_DEVICE_EXTENSIONS(vulkan_error::VulkanCallError),
     FAILED_TO_LIST_FAMILY_QUEUES,
     NO_VALID_DESIRED_FAMILY_QUEUE,
     UNAVAILABLE_DESIRED_PHYSICAL_DEVICE_EXTENSION(String),
-    FAILED_INSTANTIATING_LOGICAL_DEVICE,
+    FAILED_INSTANTIATING_LOGICAL_DEVICE(vulkan_error::VulkanCallError),
     DEVICE_LEVEL_FUNCTION_ERROR(String),
     WRONG_DEVICE_QUEUE_INDEX,
-    NO_CAPABLE_PHYSICAL_DEVICE,
-    UNAVAILABLE_PRESENTATION_MODE,
-    FAILED_CREATING_VK_SEMAPHORE,
-    FAILED_CREATING_VK_FENCE,
+    NO_CAPABLE_PHYSICAL_DEVICE(Vec<VulkanDeviceReport>),
+    UNAVAILABLE_PRESENTATION_MODE(vulkan_error::VulkanCallError),
+    FAILED_CREATING_VK_SEMAPHORE(vulkan_error::VulkanCallError),
+    FAILED_CREATING_VK_FENCE(vulkan_error::VulkanCallError),
+    UNLOADABLE_LAYERS(vulkan_error::VulkanCallError),
+    UNAVAILABLE_LAYER(String),
+    UNSUPPORTED_API_VERSION(u32),
+    FAILED_CREATING_DEBUG_MESSENGER(vulkan_error::VulkanCallError),
+    FAILED_CREATING_HEADLESS_SURFACE(vulkan_error::VulkanCallError),
 }
 
 impl std::fmt::Display for VulkanInitError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            VulkanInitError::UNLOADABLE_LIBRARY => write!(f, "Couldn't load vulkan library"),
-            VulkanInitError::UNLOADABLE_EXTENSIONS => write!(f, "Couldn't load vulkan available extensions"),
+            VulkanInitError::UNLOADABLE_LIBRARY(attempts) => {
+                write!(f, "Couldn't load vulkan library")?;
+                for (path, reason) in attempts
+                {
+                    write!(f, "\n  {}: {}", path, reason)?;
+                }
+                Ok(())
+            },
+            VulkanInitError::UNLOADABLE_EXTENSIONS(_) => write!(f, "Couldn't load vulkan available extensions"),
             VulkanInitError::UNAVAILABLE_EXTENSION(exts) => write!(f, "Can't initiate this unavailable extensions: {}", exts),
-            VulkanInitError::FAILED_INSTANTIATING_VULKAN => write!(f, "An Error Occured During Vulkan Instantiation"),
-            VulkanInitError::UNAVAILABLE_VULKAN_PHYSICAL_DEVICES => write!(f, "Couldn't load any vulkan capable physical device"),
-            VulkanInitError::UNAVAILABLE_PHYSICAL_DEVICE_EXTENSIONS => write!(f, "Couldn't load any physical device extension"),
+            VulkanInitError::FAILED_INSTANTIATING_VULKAN(_) => write!(f, "An Error Occured During Vulkan Instantiation"),
+            VulkanInitError::UNAVAILABLE_VULKAN_PHYSICAL_DEVICES(_) => write!(f, "Couldn't load any vulkan capable physical device"),
+            VulkanInitError::UNAVAILABLE_PHYSICAL_DEVICE_EXTENSIONS(_) => write!(f, "Couldn't load any physical device extension"),
             VulkanInitError::FAILED_TO_LIST_FAMILY_QUEUES => write!(f, "Couldn't list any family queues on physical device"),
             VulkanInitError::NO_VALID_DESIRED_FAMILY_QUEUE => write!(f, "Couldn't find valid desired family queue"),
             VulkanInitError::UNAVAILABLE_DESIRED_PHYSICAL_DEVICE_EXTENSION(ext) => write!(f, "Couldn't find this physical device extension: {}", ext),
-            VulkanInitError::FAILED_INSTANTIATING_LOGICAL_DEVICE => write!(f, "Couldn't initiate Logical Device"),
+            VulkanInitError::FAILED_INSTANTIATING_LOGICAL_DEVICE(_) => write!(f, "Couldn't initiate Logical Device"),
             VulkanInitError::WRONG_DEVICE_QUEUE_INDEX => write!(f, "Wrong Device queue index given"),
-            VulkanInitError::NO_CAPABLE_PHYSICAL_DEVICE => write!(f, "No Capable Physical Device in this machine"),
-            VulkanInitError::UNAVAILABLE_PRESENTATION_MODE => write!(f, "Couldn't load any Presentation Mode"),
-            VulkanInitError::FAILED_CREATING_VK_SEMAPHORE => write!(f, "Couldn't create vkSemaphore"),
-            VulkanInitError::FAILED_CREATING_VK_FENCE => write!(f, "Couldn't create vkFence"),
+            VulkanInitError::NO_CAPABLE_PHYSICAL_DEVICE(reports) => {
+                write!(f, "No Capable Physical Device in this machine")?;
+                for report in reports
+                {
+                    write!(f, "\n  {}", report)?;
+                }
+                Ok(())
+            },
+            VulkanInitError::UNAVAILABLE_PRESENTATION_MODE(_) => write!(f, "Couldn't load any Presentation Mode"),
+            VulkanInitError::FAILED_CREATING_VK_SEMAPHORE(_) => write!(f, "Couldn't create vkSemaphore"),
+            VulkanInitError::FAILED_CREATING_VK_FENCE(_) => write!(f, "Couldn't create vkFence"),
+            VulkanInitError::UNLOADABLE_LAYERS(_) => write!(f, "Couldn't load vulkan available layers"),
+            VulkanInitError::UNAVAILABLE_LAYER(layer) => write!(f, "Can't enable this unavailable layer: {}", layer),
+            VulkanInitError::UNSUPPORTED_API_VERSION(version) => write!(f, "Vulkan api version {} isn't supported", api_version_to_string(*version)),
+            VulkanInitError::FAILED_CREATING_DEBUG_MESSENGER(_) => write!(f, "Couldn't create the debug utils messenger"),
+            VulkanInitError::FAILED_CREATING_HEADLESS_SURFACE(_) => write!(f, "Couldn't create a headless surface"),
             VulkanInitError::EXPORTED_VK_FUNCTION_ERROR(msg) 
             | VulkanInitError::GLOBAL_VK_FUNCTION_ERROR(msg)
             | VulkanInitError::INSTANCE_VK_FUNCTION_ERROR(msg)
@@ -92,7 +140,66 @@ impl std::fmt::Display for VulkanInitError {
     }
 }
 
-impl std::error::Error for VulkanInitError {}
+impl VulkanInitError {
+    pub fn call_error(& self) -> Option<&vulkan_error::VulkanCallError>
+    {
+        match self
+        {
+            VulkanInitError::UNLOADABLE_EXTENSIONS(e)
+            | VulkanInitError::FAILED_INSTANTIATING_VULKAN(e)
+            | VulkanInitError::UNAVAILABLE_VULKAN_PHYSICAL_DEVICES(e)
+            | VulkanInitError::UNAVAILABLE_PHYSICAL_DEVICE_EXTENSIONS(e)
+            | VulkanInitError::FAILED_INSTANTIATING_LOGICAL_DEVICE(e)
+            | VulkanInitError::UNAVAILABLE_PRESENTATION_MODE(e)
+            | VulkanInitError::FAILED_CREATING_VK_SEMAPHORE(e)
+            | VulkanInitError::FAILED_CREATING_VK_FENCE(e)
+            | VulkanInitError::UNLOADABLE_LAYERS(e)
+            | VulkanInitError::FAILED_CREATING_DEBUG_MESSENGER(e)
+            | VulkanInitError::FAILED_CREATING_HEADLESS_SURFACE(e) => Some(e),
+            _ => None
+        }
+    }
+}
+
+impl std::error::Error for VulkanInitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.call_error().map(|e| e as &(dyn std::error::Error + 'static))
+    }
+}
+
+pub fn make_api_version(variant: u32, major: u32, minor: u32, patch: u32) -> u32
+{
+    VK_MAKE_API_VERSION!(variant, major, minor, patch)
+}
+
+pub fn api_version_major(version: u32) -> u32
+{
+    (version >> 22) & 0x7F
+}
+
+pub fn api_version_minor(version: u32) -> u32
+{
+    (version >> 12) & 0x3FF
+}
+
+pub fn api_version_patch(version: u32) -> u32
+{
+    version & 0xFFF
+}
+
+pub fn api_version_to_string(version: u32) -> String
+{
+    format!("{}.{}.{}", api_version_major(version), api_version_minor(version), api_version_patch(version))
+}
+
+pub fn vk_chars_to_string(chars: &[i8]) -> String
+{
+    let bytes : Vec<u8> = chars.iter()
+        .take_while(|c| **c != 0)
+        .map(|c| *c as u8)
+        .collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
 
 pub fn load_extension_names(extensions: &[&[u8]]) -> Vec<String>
 {
@@ -106,51 +213,266 @@ pub fn load_extension_names(extensions: &[&[u8]]) -> Vec<String>
 
 pub struct QueueInfo {
     familyIndex : usize,
-    capability : vulkan_bindings::VkQueueFlags,
+    capability : vulkan_types::QueueFlags,
     priorities : Vec<f32>
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VulkanQueueRole {
+    GRAPHICS,
+    PRESENT,
+    ASYNC_COMPUTE,
+    TRANSFER
+}
+
+impl VulkanQueueRole {
+    pub const ALL: [VulkanQueueRole; 4] = [VulkanQueueRole::GRAPHICS, VulkanQueueRole::PRESENT, VulkanQueueRole::ASYNC_COMPUTE, VulkanQueueRole::TRANSFER];
+
+    pub fn capability(& self) -> vulkan_types::QueueFlags
+    {
+        match self {
+            VulkanQueueRole::GRAPHICS => vulkan_types::QueueFlags::GRAPHICS,
+            VulkanQueueRole::PRESENT => vulkan_types::QueueFlags::empty(),
+            VulkanQueueRole::ASYNC_COMPUTE => vulkan_types::QueueFlags::COMPUTE,
+            VulkanQueueRole::TRANSFER => vulkan_types::QueueFlags::TRANSFER
+        }
+    }
+}
+
+impl std::fmt::Display for VulkanQueueRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VulkanQueueRole::GRAPHICS => write!(f, "graphics"),
+            VulkanQueueRole::PRESENT => write!(f, "present"),
+            VulkanQueueRole::ASYNC_COMPUTE => write!(f, "async compute"),
+            VulkanQueueRole::TRANSFER => write!(f, "transfer")
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct VulkanQueue {
+    pub role : VulkanQueueRole,
+    pub family_index : u32,
+    pub queue_index : u32,
+    pub handle : vulkan_bindings::VkQueue,
+    pub dedicated : bool
+}
+
+pub const VULKAN_LIBRARY_ENV : &str = "ANVIL_VULKAN_LIBRARY";
+
+pub fn default_library_names() -> &'static [&'static str]
+{
+    if cfg!(target_os = "windows")
+    {
+        &["vulkan-1.dll"]
+    }
+    else if cfg!(target_os = "macos")
+    {
+        &["libvulkan.1.dylib", "libvulkan.dylib", "libMoltenVK.dylib"]
+    }
+    else
+    {
+        &["libvulkan.so.1", "libvulkan.so"]
+    }
+}
+
+// the explicit path first, then the environment override, then the platform defaults
+fn library_candidates(library_path: Option<&str>, env_path: Option<String>) -> Vec<String>
+{
+    let mut candidates : Vec<String> = Vec::new();
+    candidates.extend(library_path.map(|path| path.to_string()));
+    candidates.extend(env_path.filter(|path| !path.is_empty()));
+    candidates.extend(default_library_names().iter().map(|name| name.to_string()));
+    candidates.dedup();
+    candidates
+}
+
+// every candidate that fails to load is listed in UNLOADABLE_LIBRARY, explicit ones included
+pub fn load_vulkan_library(library_path: Option<&str>) -> Result<(libloading::Library, String), VulkanInitError>
+{
+    let candidates = library_candidates(library_path, std::env::var(VULKAN_LIBRARY_ENV).ok());
+    let mut attempts : Vec<(String, String)> = Vec::new();
+    for candidate in candidates
+    {
+        match unsafe { libloading::Library::new(&candidate) }
+        {
+            Ok(library) => return Ok((library, candidate)),
+            Err(e) => attempts.push((candidate, e.to_string()))
+        }
+    }
+    Err(VulkanInitError::UNLOADABLE_LIBRARY(attempts))
+}
+
+pub struct VulkanInstanceBuilder {
+    library_path : Option<String>,
+    application_name : String,
+    application_version : u32,
+    engine_name : String,
+    engine_version : u32,
+    api_version : u32,
+    layers : Vec<String>,
+    extensions : Vec<String>,
+    validation : Option<vulkan_debug::VulkanValidation>,
+    track_host_memory : bool
+}
+
+impl VulkanInstanceBuilder {
+    pub fn new() -> Self
+    {
+        VulkanInstanceBuilder {
+            library_path : None,
+            application_name : String::from("anvil"),
+            application_version : VK_MAKE_API_VERSION!(0, 1, 0, 0),
+            engine_name : String::from("anvil"),
+            engine_version : VK_MAKE_API_VERSION!(0, 1, 0, 0),
+            api_version : VK_MAKE_API_VERSION!(0, 1, 3, 0),
+            layers : Vec::new(),
+            extensions : Vec::new(),
+            validation : None,
+            track_host_memory : false
+        }
+    }
+
+    pub fn library_path(mut self, path: &str) -> Self
+    {
+        self.library_path = Some(path.to_string());
+        self
+    }
+
+    pub fn application(mut self, name: &str, version: u32) -> Self
+    {
+        self.application_name = name.to_string();
+        self.application_version = version;
+        self
+    }
+
+    pub fn engine(mut self, name: &str, version: u32) -> Self
+    {
+        self.engine_name = name.to_string();
+        self.engine_version = version;
+        self
+    }
+
+    pub fn api_version(mut self, highest_version: u32) -> Self
+    {
+        self.api_version = highest_version;
+        self
+    }
+
+    pub fn layers(mut self, layers: Vec<String>) -> Self
+    {
+        self.layers = layers;
+        self
+    }
+
+    pub fn extensions(mut self, extensions: Vec<String>) -> Self
+    {
+        self.extensions = extensions;
+        self
+    }
+
+    pub fn validation(mut self, validation: vulkan_debug::VulkanValidation) -> Self
+    {
+        self.validation = Some(validation);
+        self
+    }
+
+    // routes every host allocation the driver makes through VulkanHostAllocator
+    pub fn track_host_memory(mut self) -> Self
+    {
+        self.track_host_memory = true;
+        self
+    }
+
+    pub fn build(self) -> Result<VulkanInstance, vulkan_error::VulkanError>
+    {
+        let (vulkan_library, library_path) = load_vulkan_library(self.library_path.as_deref())?;
+        let mut vulkan_instance = VulkanInstance {
+            vulkan_library,
+            library_path,
+            application_name : self.application_name,
+            application_version : self.application_version,
+            engine_name : self.engine_name,
+            engine_version : self.engine_version,
+            available_extensions : Vec::new(),
+            enabled_extensions: self.extensions,
+            available_layers : Vec::new(),
+            enabled_layers : self.layers,
+            debug_messenger : None,
+            host_allocator : if self.track_host_memory { Some(vulkan_alloc::VulkanHostAllocator::new()) } else { None },
+            loader_version : VK_MAKE_API_VERSION!(0, 1, 0, 0),
+            api_version : VK_MAKE_API_VERSION!(0, 1, 0, 0),
+            instance : std::ptr::null_mut(),
+            fns : InstanceFns::default(),
+            physical_devices: Vec::new()
+        };
+        vulkan_instance.load_global_functions()?;
+        vulkan_instance.negotiate_api_version(self.api_version)?;
+        vulkan_instance.load_available_extensions()?;
+        vulkan_instance.load_available_layers()?;
+        vulkan_instance.layers_are_valid()?;
+        if let Some(validation) = self.validation
+        {
+            vulkan_instance.enable_validation(validation);
+        }
+        vulkan_instance.extensions_are_valid()?;
+        vulkan_instance.instantiate_vulkan()?;
+        vulkan_instance.load_vulkan_instance_functions()?;
+        vulkan_instance.attach_debug_messenger();
+        vulkan_instance.load_physical_devices()?;
+        Ok(vulkan_instance)
+    }
+}
+
 pub struct VulkanInstance {
     vulkan_library: libloading::Library,
+    pub library_path : String,
+    pub application_name : String,
+    pub application_version : u32,
+    pub engine_name : String,
+    pub engine_version : u32,
     available_extensions : Vec<vulkan_bindings::VkExtensionProperties>,
     enabled_extensions : Vec<String>,
+    available_layers : Vec<vulkan_bindings::VkLayerProperties>,
+    enabled_layers : Vec<String>,
+    debug_messenger : Option<vulkan_debug::VulkanDebugMessenger>,
+    host_allocator : Option<Box<vulkan_alloc::VulkanHostAllocator>>,
+    pub loader_version : u32,
+    pub api_version : u32,
     pub instance : vulkan_bindings::VkInstance,
+    pub fns : InstanceFns,
     physical_devices : Vec<VulkanPhysicalDevice>
 }
 
 impl VulkanInstance {
 
-    pub fn new(desired_extensions : Vec<String>) -> Result<Self, Box<dyn std::error::Error>> {
-        unsafe {
-            let mut vulkan_instance = VulkanInstance {
-                vulkan_library : if cfg!(target_os = "linux") { libloading::Library::new("libvulkan.so.1")? } else { libloading::Library::new("vulkan-1.dll")?},
-                available_extensions : Vec::new(),
-                enabled_extensions: desired_extensions,
-                instance : std::ptr::null_mut(),
-                physical_devices: Vec::new()
-            };
-            vulkan_instance.load_global_functions()?;
-            vulkan_instance.load_available_extensions()?;
-            vulkan_instance.extensions_are_valid()?;
-            vulkan_instance.instantiate_vulkan()?;
-            vulkan_instance.load_vulkan_instance_functions()?;
-            vulkan_instance.load_physical_devices()?;
-            Ok(vulkan_instance)
+    pub fn new(desired_extensions : Vec<String>, validation : Option<vulkan_debug::VulkanValidation>) -> Result<Self, vulkan_error::VulkanError> {
+        let mut builder = VulkanInstanceBuilder::new().extensions(desired_extensions);
+        if let Some(validation) = validation
+        {
+            builder = builder.validation(validation);
         }
+        builder.build()
     }
 
-    pub fn load_global_functions(&mut self) -> Result<(), Box<dyn std::error::Error>>
+    pub fn builder() -> VulkanInstanceBuilder
+    {
+        VulkanInstanceBuilder::new()
+    }
+
+    pub fn load_global_functions(&mut self) -> Result<(), Box<VulkanInitError>>
     {
         unsafe 
         {
             DEFAULTED_IMPORT_MACROS!();
             macro_rules! LOAD_EXPORTED_VULKAN_FUNCTION {
                 ($function:ident) => {
-                    $function = self.vulkan_library.get(stringify!($function).as_bytes())
+                    self.fns.$function = self.vulkan_library.get(stringify!($function).as_bytes())
                         .ok()
                         .map(|symbol| *symbol);
 
-                    match $function {
+                    match self.fns.$function {
                         Some(_) => (),
                         None => {
                             let err = VulkanInitError::EXPORTED_VK_FUNCTION_ERROR(
@@ -165,11 +487,11 @@ impl VulkanInstance {
             macro_rules! LOAD_GLOBAL_LEVEL_VULKAN_FUNCTION {
                 ($function: ident) => {
                     paste! {
-                        let fn_vkGetInstanceProcAddr = vkGetInstanceProcAddr.unwrap();
+                        let fn_vkGetInstanceProcAddr = self.fns.vkGetInstanceProcAddr.unwrap();
                         let func_name = CString::new(stringify!($function)).unwrap();
                         let temp = fn_vkGetInstanceProcAddr(std::ptr::null_mut(), func_name.as_ptr());
-                        $function = std::mem::transmute::<vulkan_bindings::PFN_vkVoidFunction, vulkan_bindings::[<PFN_$function>]>(temp);
-                        match $function {
+                        self.fns.$function = std::mem::transmute::<vulkan_bindings::PFN_vkVoidFunction, vulkan_bindings::[<PFN_$function>]>(temp);
+                        match self.fns.$function {
                             Some(_) => { 
                             },
                             None => {
@@ -182,19 +504,57 @@ impl VulkanInstance {
                     }
                 }
             }
+
+            macro_rules! LOAD_OPTIONAL_GLOBAL_LEVEL_VULKAN_FUNCTION {
+                ($function: ident) => {
+                    paste! {
+                        let fn_vkGetInstanceProcAddr = self.fns.vkGetInstanceProcAddr.unwrap();
+                        let func_name = CString::new(stringify!($function)).unwrap();
+                        let temp = fn_vkGetInstanceProcAddr(std::ptr::null_mut(), func_name.as_ptr());
+                        self.fns.$function = std::mem::transmute::<vulkan_bindings::PFN_vkVoidFunction, vulkan_bindings::[<PFN_$function>]>(temp);
+                    }
+                }
+            }
             include!("loaded_functions.rs");
             Ok(())
         }
     }
 
+    pub fn negotiate_api_version(&mut self, highest_version: u32) -> Result<(), Box<VulkanInitError>>
+    {
+        unsafe {
+            // vkEnumerateInstanceVersion is missing from 1.0 loaders
+            self.loader_version = match self.fns.vkEnumerateInstanceVersion {
+                Some(fn_vkEnumerateInstanceVersion) => {
+                    let mut version: u32 = 0;
+                    let result = fn_vkEnumerateInstanceVersion(&mut version);
+                    if result != vulkan_bindings::VkResult_VK_SUCCESS
+                    {
+                        return Err(Box::new(VulkanInitError::FAILED_INSTANTIATING_VULKAN(vulkan_error::VulkanCallError::new("vkEnumerateInstanceVersion", result))));
+                    }
+                    version
+                },
+                None => VK_MAKE_API_VERSION!(0, 1, 0, 0)
+            };
+        }
+        if api_version_major(highest_version) < 1
+        {
+            return Err(Box::new(VulkanInitError::UNSUPPORTED_API_VERSION(highest_version)));
+        }
+        let highest_version = VK_MAKE_API_VERSION!(0, api_version_major(highest_version), api_version_minor(highest_version), 0);
+        let loader_version = VK_MAKE_API_VERSION!(0, api_version_major(self.loader_version), api_version_minor(self.loader_version), 0);
+        self.api_version = highest_version.min(loader_version);
+        Ok(())
+    }
+
     pub fn load_available_extensions(&mut self) -> Result<(), Box<VulkanInitError> >
     {
         unsafe {
             let mut extensions_count:u32 = 0;
-            let result = vkEnumerateInstanceExtensionProperties.unwrap()(std::ptr::null(), &mut extensions_count, std::ptr::null_mut());
+            let result = self.fns.vkEnumerateInstanceExtensionProperties.unwrap()(std::ptr::null(), &mut extensions_count, std::ptr::null_mut());
             if result != vulkan_bindings::VkResult_VK_SUCCESS || extensions_count == 0
             {
-                return Err(Box::new(VulkanInitError::UNLOADABLE_EXTENSIONS));
+                return Err(Box::new(VulkanInitError::UNLOADABLE_EXTENSIONS(vulkan_error::VulkanCallError::new("vkEnumerateInstanceExtensionProperties", result))));
             }
     
             self.available_extensions.resize(extensions_count as usize
@@ -203,15 +563,213 @@ impl VulkanInstance {
                     specVersion: 0
                 }
             );
-            let result = vkEnumerateInstanceExtensionProperties.unwrap()(std::ptr::null(), &mut extensions_count, self.available_extensions.as_mut_ptr());
+            let result = self.fns.vkEnumerateInstanceExtensionProperties.unwrap()(std::ptr::null(), &mut extensions_count, self.available_extensions.as_mut_ptr());
             if result != vulkan_bindings::VkResult_VK_SUCCESS ||  extensions_count == 0
             {
-                return Err(Box::new(VulkanInitError::UNLOADABLE_EXTENSIONS));
+                return Err(Box::new(VulkanInitError::UNLOADABLE_EXTENSIONS(vulkan_error::VulkanCallError::new("vkEnumerateInstanceExtensionProperties", result))));
             }
             Ok(())
         }
     }
 
+    pub fn load_available_layers(&mut self) -> Result<(), Box<VulkanInitError>>
+    {
+        unsafe {
+            let fn_vkEnumerateInstanceLayerProperties = self.fns.vkEnumerateInstanceLayerProperties.unwrap();
+            let mut layers_count:u32 = 0;
+            let result = fn_vkEnumerateInstanceLayerProperties(&mut layers_count, std::ptr::null_mut());
+            if result != vulkan_bindings::VkResult_VK_SUCCESS
+            {
+                return Err(Box::new(VulkanInitError::UNLOADABLE_LAYERS(vulkan_error::VulkanCallError::new("vkEnumerateInstanceLayerProperties", result))));
+            }
+            if layers_count == 0
+            {
+                return Ok(());
+            }
+            self.available_layers.resize(layers_count as usize, std::mem::zeroed());
+            let result = fn_vkEnumerateInstanceLayerProperties(&mut layers_count, self.available_layers.as_mut_ptr());
+            if result != vulkan_bindings::VkResult_VK_SUCCESS
+            {
+                return Err(Box::new(VulkanInitError::UNLOADABLE_LAYERS(vulkan_error::VulkanCallError::new("vkEnumerateInstanceLayerProperties", result))));
+            }
+            self.available_layers.truncate(layers_count as usize);
+            Ok(())
+        }
+    }
+
+    pub fn layers_are_valid(& self) -> Result<(), Box<VulkanInitError>>
+    {
+        for desired in &self.enabled_layers
+        {
+            if !self.has_layer(desired)
+            {
+                return Err(Box::new(VulkanInitError::UNAVAILABLE_LAYER(desired.to_string())));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn has_layer(& self, layer_name: &str) -> bool
+    {
+        unsafe {
+            for layer in &self.available_layers {
+                let layer = std::mem::transmute::<[i8;256], [u8;256]>(layer.layerName);
+                let layer = String::from_utf8(layer.to_vec()).unwrap().trim_end_matches('\0').to_string();
+                if layer == layer_name
+                {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    pub fn has_extension(& self, extension_name: &str) -> bool
+    {
+        unsafe {
+            for extension in &self.available_extensions {
+                let extension = std::mem::transmute::<[i8;256], [u8;256]>(extension.extensionName);
+                let extension = String::from_utf8(extension.to_vec()).unwrap().trim_end_matches('\0').to_string();
+                if extension == extension_name
+                {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    pub fn enable_validation(&mut self, validation: vulkan_debug::VulkanValidation)
+    {
+        if self.has_layer(vulkan_debug::VALIDATION_LAYER_NAME)
+        {
+            if !self.enabled_layers.iter().any(|l| l == vulkan_debug::VALIDATION_LAYER_NAME)
+            {
+                self.enabled_layers.push(vulkan_debug::VALIDATION_LAYER_NAME.to_string());
+            }
+        }
+        else
+        {
+            eprintln!("{} isn't installed; continuing without validation layers", vulkan_debug::VALIDATION_LAYER_NAME);
+        }
+        let debug_utils = load_extension_names(&[vulkan_bindings::VK_EXT_DEBUG_UTILS_EXTENSION_NAME]).remove(0);
+        if self.has_extension(&debug_utils)
+        {
+            if !self.enabled_extensions.contains(&debug_utils)
+            {
+                self.enabled_extensions.push(debug_utils);
+            }
+            self.debug_messenger = Some(vulkan_debug::VulkanDebugMessenger::new(validation));
+        }
+        else
+        {
+            eprintln!("{} isn't available; validation messages won't be forwarded", debug_utils);
+        }
+    }
+
+    pub fn attach_debug_messenger(&mut self)
+    {
+        let allocator = self.allocation_callbacks();
+        if let Some(messenger) = self.debug_messenger.as_mut()
+        {
+            match messenger.attach(self.instance, &self.fns, allocator)
+            {
+                Ok(_) => (),
+                Err(e) => {
+                    eprintln!("{}; continuing without validation messages", e);
+                    self.debug_messenger = None;
+                }
+            }
+        }
+    }
+
+    // null unless the builder asked for host memory tracking, in which case the driver allocates through it
+    pub fn allocation_callbacks(& self) -> *const vulkan_bindings::VkAllocationCallbacks
+    {
+        match &self.host_allocator {
+            Some(allocator) => allocator.callbacks(),
+            None => std::ptr::null()
+        }
+    }
+
+    pub fn host_memory_usage(& self) -> Option<Vec<vulkan_alloc::VulkanScopeUsage>>
+    {
+        self.host_allocator.as_ref().map(|allocator| allocator.usage())
+    }
+
+    // anything still allocated once vkDestroyInstance returned belongs to an object that was never destroyed,
+    // the allocator is leaked in that case since the driver may still call back into it
+    fn report_host_memory_leaks(&mut self)
+    {
+        let allocator = match self.host_allocator.take() {
+            Some(allocator) => allocator,
+            None => return
+        };
+        let leaks = allocator.leaks();
+        if leaks.is_empty()
+        {
+            return;
+        }
+        eprintln!("Vulkan host memory still allocated after destroying the instance:");
+        for leak in &leaks
+        {
+            eprintln!("  {}", leak);
+        }
+        Box::leak(allocator);
+    }
+
+    pub fn available_extensions(& self) -> &Vec<vulkan_bindings::VkExtensionProperties>
+    {
+        &self.available_extensions
+    }
+
+    pub fn available_layers(& self) -> &Vec<vulkan_bindings::VkLayerProperties>
+    {
+        &self.available_layers
+    }
+
+    pub fn physical_devices(& self) -> &Vec<VulkanPhysicalDevice>
+    {
+        &self.physical_devices
+    }
+
+    pub fn physical_devices_mut(&mut self) -> &mut Vec<VulkanPhysicalDevice>
+    {
+        &mut self.physical_devices
+    }
+
+    pub fn create_headless_surface(& self) -> Result<vulkan_bindings::VkSurfaceKHR, VulkanInitError>
+    {
+        unsafe {
+            let fn_vkCreateHeadlessSurfaceEXT = match self.fns.vkCreateHeadlessSurfaceEXT {
+                Some(f) => f,
+                None => return Err(VulkanInitError::FAILED_CREATING_HEADLESS_SURFACE(vulkan_error::VulkanCallError::missing("vkCreateHeadlessSurfaceEXT")))
+            };
+            let surface_create_info = vulkan_bindings::VkHeadlessSurfaceCreateInfoEXT {
+                sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_HEADLESS_SURFACE_CREATE_INFO_EXT,
+                pNext: std::ptr::null(),
+                flags: 0
+            };
+            let mut surface : vulkan_bindings::VkSurfaceKHR = std::ptr::null_mut();
+            let result = fn_vkCreateHeadlessSurfaceEXT(self.instance, &surface_create_info, self.allocation_callbacks(), &mut surface);
+            if result != vulkan_bindings::VkResult_VK_SUCCESS || surface == std::ptr::null_mut()
+            {
+                return Err(VulkanInitError::FAILED_CREATING_HEADLESS_SURFACE(vulkan_error::VulkanCallError::new("vkCreateHeadlessSurfaceEXT", result)));
+            }
+            Ok(surface)
+        }
+    }
+
+    pub fn destroy_surface(& self, surface: vulkan_bindings::VkSurfaceKHR)
+    {
+        unsafe {
+            if let Some(fn_vkDestroySurfaceKHR) = self.fns.vkDestroySurfaceKHR
+            {
+                fn_vkDestroySurfaceKHR(self.instance, surface, self.allocation_callbacks());
+            }
+        }
+    }
+
     pub fn list_available_extensions(& self)
     {
         unsafe {
@@ -237,7 +795,7 @@ impl VulkanInstance {
                         break;
                     }
                 }
-                if found == false
+                if !found
                 {
                     return Err(Box::new(VulkanInitError::UNAVAILABLE_EXTENSION(desired.to_string())));
                 }
@@ -248,15 +806,16 @@ impl VulkanInstance {
 
     pub fn instantiate_vulkan(&mut self) -> Result<(), Box<VulkanInitError>>
     {
-        let app_name = CString::new("anvil").unwrap();     
+        let app_name = CString::new(self.application_name.as_bytes()).unwrap();
+        let engine_name = CString::new(self.engine_name.as_bytes()).unwrap();
         let app_info  = vulkan_bindings::VkApplicationInfo {
             sType : vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_APPLICATION_INFO,
             pNext : std::ptr::null(),
             pApplicationName : app_name.as_ptr(),
-            applicationVersion : VK_MAKE_API_VERSION!(0, 1 , 0, 0),
-            pEngineName : app_name.as_ptr(),
-            engineVersion : VK_MAKE_API_VERSION!(0, 1 , 0, 0),
-            apiVersion: VK_MAKE_API_VERSION!(0, 1 , 0, 0)
+            applicationVersion : self.application_version,
+            pEngineName : engine_name.as_ptr(),
+            engineVersion : self.engine_version,
+            apiVersion: self.api_version
         };
         
         unsafe {
@@ -267,40 +826,48 @@ impl VulkanInstance {
             let desired_extensions_ptrs : Vec<* const i8> = desired_extensions_cstr.iter()
             .map(|s| s.as_ptr())
             .collect();
+            let enabled_layers_cstr: Vec<CString> = self.enabled_layers.iter()
+            .map(|s| CString::new(s.as_bytes()).unwrap())
+            .collect();
+            let enabled_layers_ptrs : Vec<* const i8> = enabled_layers_cstr.iter()
+            .map(|s| s.as_ptr())
+            .collect();
+            // chaining the messenger info also reports issues raised by vkCreateInstance / vkDestroyInstance
+            let debug_create_info = self.debug_messenger.as_ref().map(|m| m.create_info());
             let instance_creation_info = vulkan_bindings::VkInstanceCreateInfo {
                 sType : vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO,
-                pNext : std::ptr::null(),
+                pNext : match &debug_create_info { Some(info) => info as *const vulkan_bindings::VkDebugUtilsMessengerCreateInfoEXT as *const std::ffi::c_void, None => std::ptr::null() },
                 flags: 0,
                 pApplicationInfo : &app_info,
-                enabledLayerCount: 0,
-                ppEnabledLayerNames: std::ptr::null(),
+                enabledLayerCount: enabled_layers_ptrs.len() as u32,
+                ppEnabledLayerNames: if !enabled_layers_ptrs.is_empty() { enabled_layers_ptrs.as_ptr() } else { std::ptr::null() },
                 enabledExtensionCount: desired_extensions_ptrs.len() as u32,
-                ppEnabledExtensionNames : if desired_extensions_ptrs.len() > 0 { desired_extensions_ptrs.as_ptr() } else { std::ptr::null() }
+                ppEnabledExtensionNames : if !desired_extensions_ptrs.is_empty() { desired_extensions_ptrs.as_ptr() } else { std::ptr::null() }
             };
-            let  result = vkCreateInstance.unwrap()(&instance_creation_info, std::ptr::null(), &mut self.instance);
+            let  result = self.fns.vkCreateInstance.unwrap()(&instance_creation_info, self.allocation_callbacks(), &mut self.instance);
             if result != vulkan_bindings::VkResult_VK_SUCCESS || self.instance == std::ptr::null_mut() 
             {
-                return  Err(Box::new(VulkanInitError::FAILED_INSTANTIATING_VULKAN));
+                return  Err(Box::new(VulkanInitError::FAILED_INSTANTIATING_VULKAN(vulkan_error::VulkanCallError::new("vkCreateInstance", result))));
             }
         }
-        return  Ok(());
+        Ok(())
     }
 
-    pub fn load_vulkan_instance_functions(&self) -> Result<(), Box<VulkanInitError>>
+    pub fn load_vulkan_instance_functions(&mut self) -> Result<(), Box<VulkanInitError>>
     {
         unsafe {
             DEFAULTED_IMPORT_MACROS!();
             macro_rules! LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION {
                 ($function: ident) => {
                     paste! {
-                        let proc_addr = vkGetInstanceProcAddr.unwrap();
+                        let proc_addr = self.fns.vkGetInstanceProcAddr.unwrap();
                         let func_name = CString::new(stringify!($function)).unwrap();
                         let func = proc_addr(self.instance, func_name.as_ptr());
-                        $function = std::mem::transmute::<vulkan_bindings::PFN_vkVoidFunction, vulkan_bindings::[<PFN_$function>]>(func);
-                        match $function {
+                        self.fns.$function = std::mem::transmute::<vulkan_bindings::PFN_vkVoidFunction, vulkan_bindings::[<PFN_$function>]>(func);
+                        match self.fns.$function {
                             Some(_) => (),
                             None => {
-                                let err = VulkanInitError::INSTANCE_VK_FUNCTION_ERROR(format!("Couldn't load instance level vulkan function: {}", stringify!($func) ) );
+                                let err = VulkanInitError::INSTANCE_VK_FUNCTION_ERROR(format!("Couldn't load instance level vulkan function: {}", stringify!($function) ) );
                                 return Err(Box::new(err)) 
                             }
                         }
@@ -311,7 +878,7 @@ impl VulkanInstance {
             macro_rules! LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS {
                 ($function: ident, $ext: ident) => {
                     paste! {
-                        let proc_addr = vkGetInstanceProcAddr.unwrap();
+                        let proc_addr = self.fns.vkGetInstanceProcAddr.unwrap();
                         let func_extension_name = String::from_utf8(vulkan_bindings::[<$ext>].into()).unwrap().trim_end_matches('\0').to_string();
                         let cstr_func_name = CString::new(stringify!($function)).unwrap();
                         for extension in &self.enabled_extensions
@@ -319,8 +886,8 @@ impl VulkanInstance {
                             if func_extension_name == *extension
                             {
                                 let func = proc_addr(self.instance, cstr_func_name.as_ptr());
-                                $function = std::mem::transmute::<vulkan_bindings::PFN_vkVoidFunction, vulkan_bindings::[<PFN_$function>]>(func);
-                                match $function {
+                                self.fns.$function = std::mem::transmute::<vulkan_bindings::PFN_vkVoidFunction, vulkan_bindings::[<PFN_$function>]>(func);
+                                match self.fns.$function {
                                     Some(_) => (),
                                     None => {
                                         let err = VulkanInitError::INSTANCE_VK_EXT_FUNCTION_ERROR(
@@ -331,11 +898,26 @@ impl VulkanInstance {
                                 };
                             }
                         }
-                        if $function.is_none() {
-                            let err = VulkanInitError::INSTANCE_VK_EXT_FUNCTION_ERROR(
-                                format!("Couldn't load instance level extension vulkan function: {}", stringify!($function))
-                            );
-                            return  Err(Box::new(err));
+                    }
+                };
+            }
+
+            macro_rules! LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION {
+                ($function: ident, $major: expr, $minor: expr) => {
+                    paste! {
+                        if self.api_version >= VK_MAKE_API_VERSION!(0, $major, $minor, 0)
+                        {
+                            let proc_addr = self.fns.vkGetInstanceProcAddr.unwrap();
+                            let func_name = CString::new(stringify!($function)).unwrap();
+                            let func = proc_addr(self.instance, func_name.as_ptr());
+                            self.fns.$function = std::mem::transmute::<vulkan_bindings::PFN_vkVoidFunction, vulkan_bindings::[<PFN_$function>]>(func);
+                            if self.fns.$function.is_none()
+                            {
+                                let err = VulkanInitError::INSTANCE_VK_FUNCTION_ERROR(
+                                    format!("Couldn't load instance level vulkan function: {}", stringify!($function))
+                                );
+                                return Err(Box::new(err));
+                            }
                         }
                     }
                 };
@@ -349,23 +931,23 @@ impl VulkanInstance {
     pub fn load_physical_devices(&mut self) -> Result<(), VulkanInitError>
     {
         unsafe {
-            let fn_vkEnumeratePhysicalDevices = vkEnumeratePhysicalDevices.unwrap();
+            let fn_vkEnumeratePhysicalDevices = self.fns.vkEnumeratePhysicalDevices.unwrap();
             let mut devices_count = 0;
             let result = fn_vkEnumeratePhysicalDevices(self.instance, &mut devices_count, std::ptr::null_mut());
             if result != vulkan_bindings::VkResult_VK_SUCCESS || devices_count == 0
             {
-                return  Err(VulkanInitError::UNAVAILABLE_VULKAN_PHYSICAL_DEVICES);
+                return  Err(VulkanInitError::UNAVAILABLE_VULKAN_PHYSICAL_DEVICES(vulkan_error::VulkanCallError::new("vkEnumeratePhysicalDevices", result)));
             }
             let mut physical_devices : Vec<vulkan_bindings::VkPhysicalDevice> = vec![std::ptr::null_mut(); devices_count as usize];
             let result = fn_vkEnumeratePhysicalDevices(self.instance, &mut devices_count, physical_devices.as_mut_ptr());
             if result != vulkan_bindings::VkResult_VK_SUCCESS || devices_count == 0
             {
-                return  Err(VulkanInitError::UNAVAILABLE_VULKAN_PHYSICAL_DEVICES);
+                return  Err(VulkanInitError::UNAVAILABLE_VULKAN_PHYSICAL_DEVICES(vulkan_error::VulkanCallError::new("vkEnumeratePhysicalDevices", result)));
             }
             self.physical_devices.reserve(physical_devices.len());
             for (idx,  ph_device) in physical_devices.into_iter().enumerate()
             {
-                self.physical_devices.push(VulkanPhysicalDevice::new(ph_device));
+                self.physical_devices.push(VulkanPhysicalDevice::new(ph_device, self.fns, self.api_version));
                 self.physical_devices[idx].load_infos()?;
             }
         }
@@ -375,10 +957,23 @@ impl VulkanInstance {
     pub fn destroy(&mut self)
     {
         unsafe {
-            let fn_vkDestroyInstance = vkDestroyInstance.unwrap();
-            fn_vkDestroyInstance(self.instance, std::ptr::null());
+            let allocator = self.allocation_callbacks();
+            if let Some(mut messenger) = self.debug_messenger.take()
+            {
+                messenger.destroy(self.instance, &self.fns, allocator);
+            }
+            let fn_vkDestroyInstance = self.fns.vkDestroyInstance.unwrap();
+            fn_vkDestroyInstance(self.instance, allocator);
             self.instance = std::ptr::null_mut();
-            VULKAN_INSTANCE = None;
+            self.report_host_memory_leaks();
+            let is_global = match (*std::ptr::addr_of!(VULKAN_INSTANCE)).as_ref() {
+                Some(global) => std::ptr::eq(global, self),
+                None => false
+            };
+            if is_global
+            {
+                VULKAN_INSTANCE = None;
+            }
         }
     }
 }
@@ -386,30 +981,38 @@ impl VulkanInstance {
 pub struct VulkanPhysicalDevice {
     pub ph_device : vulkan_bindings::VkPhysicalDevice,
     pub extensions : Vec<vulkan_bindings::VkExtensionProperties>,
-    pub features : vulkan_bindings::VkPhysicalDeviceFeatures,
+    pub features : vulkan_features::VulkanFeatures,
     pub properties : vulkan_bindings::VkPhysicalDeviceProperties,
     pub mem_properties : vulkan_bindings::VkPhysicalDeviceMemoryProperties,
+    pub device_uuid : Option<[u8; 16]>,
     pub family_queues: Vec<vulkan_bindings::VkQueueFamilyProperties>,
     pub desired_queues : Vec<QueueInfo>,
     pub supports_presentation : bool,
     pub presentation_queue_idx : i32,
-    pub supported_presentation_modes : Vec<vulkan_bindings::VkPresentModeKHR>
+    pub supported_presentation_modes : Vec<vulkan_bindings::VkPresentModeKHR>,
+    pub instance_api_version : u32,
+    pub formats : vulkan_format::VulkanFormatCache,
+    pub fns : InstanceFns
 }
 
 impl VulkanPhysicalDevice {
-    pub fn new(ph_device : vulkan_bindings::VkPhysicalDevice) -> Self {
+    pub fn new(ph_device : vulkan_bindings::VkPhysicalDevice, fns : InstanceFns, instance_api_version : u32) -> Self {
         unsafe  {
             Self {
                 ph_device,
                 extensions : Vec::new(),
-                features : std::mem::zeroed(),
+                features : vulkan_features::VulkanFeatures::new(),
                 properties: std::mem::zeroed(),
                 mem_properties: std::mem::zeroed(),
+                device_uuid: None,
                 family_queues : Vec::new(),
                 desired_queues: Vec::new(),
                 supports_presentation: false,
                 presentation_queue_idx : -1,
-                supported_presentation_modes: Vec::new()
+                supported_presentation_modes: Vec::new(),
+                instance_api_version,
+                formats: vulkan_format::VulkanFormatCache::default(),
+                fns
             }
         }
     }
@@ -427,43 +1030,116 @@ impl VulkanPhysicalDevice {
     pub fn load_extensions(&mut self) -> Result<(), VulkanInitError>
     {
         unsafe {
-            let fn_vkEnumerateDeviceExtensionProperties = vkEnumerateDeviceExtensionProperties.unwrap();
+            let fn_vkEnumerateDeviceExtensionProperties = self.fns.vkEnumerateDeviceExtensionProperties.unwrap();
             let mut extension_count = 0;
             let result = fn_vkEnumerateDeviceExtensionProperties(self.ph_device, std::ptr::null(), &mut extension_count, std::ptr::null_mut());
             if result != vulkan_bindings::VkResult_VK_SUCCESS || extension_count == 0
             {
-                return  Err(VulkanInitError::UNAVAILABLE_PHYSICAL_DEVICE_EXTENSIONS);
+                return  Err(VulkanInitError::UNAVAILABLE_PHYSICAL_DEVICE_EXTENSIONS(vulkan_error::VulkanCallError::new("vkEnumerateDeviceExtensionProperties", result)));
             }
             self.extensions.resize(extension_count as usize, vulkan_bindings::VkExtensionProperties { extensionName: [0;256], specVersion: 0 });
             let result = fn_vkEnumerateDeviceExtensionProperties(self.ph_device, std::ptr::null(), &mut extension_count, self.extensions.as_mut_ptr());
             if result != vulkan_bindings::VkResult_VK_SUCCESS || extension_count == 0
             {
-                return  Err(VulkanInitError::UNAVAILABLE_PHYSICAL_DEVICE_EXTENSIONS);
+                return  Err(VulkanInitError::UNAVAILABLE_PHYSICAL_DEVICE_EXTENSIONS(vulkan_error::VulkanCallError::new("vkEnumerateDeviceExtensionProperties", result)));
             }
         }
         Ok(())
     }
 
+    pub fn api_version(& self) -> u32
+    {
+        self.instance_api_version.min(self.properties.apiVersion)
+    }
+
     pub fn load_features(&mut self)
     {
         unsafe {
-            let fn_vkGetPhysicalDeviceFeatures = vkGetPhysicalDeviceFeatures.unwrap();
-            fn_vkGetPhysicalDeviceFeatures(self.ph_device, &mut self.features);
+            let api_version = self.api_version();
+            match self.fns.vkGetPhysicalDeviceFeatures2 {
+                Some(fn_vkGetPhysicalDeviceFeatures2) if api_version >= VK_MAKE_API_VERSION!(0, 1, 1, 0) => {
+                    let mut features2 = self.features.chain(api_version, &self.extension_names());
+                    fn_vkGetPhysicalDeviceFeatures2(self.ph_device, &mut features2);
+                    self.features.core = features2.features;
+                    self.features.unchain();
+                },
+                _ => {
+                    let fn_vkGetPhysicalDeviceFeatures = self.fns.vkGetPhysicalDeviceFeatures.unwrap();
+                    fn_vkGetPhysicalDeviceFeatures(self.ph_device, &mut self.features.core);
+                }
+            }
         }
     }
 
+    // load_features leaves what it couldn't query VK_FALSE, whether the device supports it or not
+    pub fn reports_feature(& self, name: &str) -> bool
+    {
+        let api_version = self.api_version();
+        if self.fns.vkGetPhysicalDeviceFeatures2.is_none() || api_version < VK_MAKE_API_VERSION!(0, 1, 1, 0)
+        {
+            return name.starts_with("core.");
+        }
+        vulkan_features::VulkanFeatures::is_chained(name, api_version, &self.extension_names())
+    }
+
+    fn extension_names(& self) -> Vec<String>
+    {
+        self.extensions.iter().map(|extension| vk_chars_to_string(&extension.extensionName)).collect()
+    }
+
     pub fn load_properties(&mut self)
     {
         unsafe {
-            let fn_vkGetPhysicalDeviceProperties = vkGetPhysicalDeviceProperties.unwrap();
+            let fn_vkGetPhysicalDeviceProperties = self.fns.vkGetPhysicalDeviceProperties.unwrap();
             fn_vkGetPhysicalDeviceProperties(self.ph_device, &mut self.properties);
         }
     }
 
+    pub fn load_id_properties(&mut self)
+    {
+        unsafe {
+            let fn_vkGetPhysicalDeviceProperties2 = match self.fns.vkGetPhysicalDeviceProperties2 {
+                Some(f) if self.properties.apiVersion >= VK_MAKE_API_VERSION!(0, 1, 1, 0) => f,
+                _ => return
+            };
+            let mut id_properties : vulkan_bindings::VkPhysicalDeviceIDProperties = std::mem::zeroed();
+            id_properties.sType = vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ID_PROPERTIES;
+            let mut properties2 : vulkan_bindings::VkPhysicalDeviceProperties2 = std::mem::zeroed();
+            properties2.sType = vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PROPERTIES_2;
+            properties2.pNext = &mut id_properties as *mut vulkan_bindings::VkPhysicalDeviceIDProperties as *mut std::ffi::c_void;
+            fn_vkGetPhysicalDeviceProperties2(self.ph_device, &mut properties2);
+            self.device_uuid = Some(id_properties.deviceUUID);
+        }
+    }
+
+    pub fn name(& self) -> String
+    {
+        vk_chars_to_string(&self.properties.deviceName)
+    }
+
+    pub fn vendor(& self) -> PhysicalDeviceVendorsId
+    {
+        PhysicalDeviceVendorsId::new(self.properties.vendorID)
+    }
+
+    pub fn device_local_memory(& self) -> u64
+    {
+        let mem_properties = &self.mem_properties;
+        let mut size = 0;
+        for heap in &mem_properties.memoryHeaps[..mem_properties.memoryHeapCount as usize]
+        {
+            if heap.flags & vulkan_bindings::VkMemoryHeapFlagBits_VK_MEMORY_HEAP_DEVICE_LOCAL_BIT != 0
+            {
+                size += heap.size;
+            }
+        }
+        size
+    }
+
     pub fn load_family_queues(&mut self) -> Result<(), VulkanInitError>
     {
         unsafe{
-            let fn_vkGetPhysicalDeviceQueueFamilyProperties = vkGetPhysicalDeviceQueueFamilyProperties.unwrap();
+            let fn_vkGetPhysicalDeviceQueueFamilyProperties = self.fns.vkGetPhysicalDeviceQueueFamilyProperties.unwrap();
             let mut family_queues_count = 0;
     
             fn_vkGetPhysicalDeviceQueueFamilyProperties(self.ph_device, &mut family_queues_count, std::ptr::null_mut());
@@ -486,7 +1162,7 @@ impl VulkanPhysicalDevice {
     {
         unsafe
         {
-            let fn_vkGetPhysicalDeviceMemoryProperties = vkGetPhysicalDeviceMemoryProperties.unwrap();
+            let fn_vkGetPhysicalDeviceMemoryProperties = self.fns.vkGetPhysicalDeviceMemoryProperties.unwrap();
             fn_vkGetPhysicalDeviceMemoryProperties(self.ph_device, &mut self.mem_properties);
         }
     }
@@ -496,7 +1172,7 @@ impl VulkanPhysicalDevice {
         unsafe 
         {
             let mut supports: u32 = 0;
-            let fn_vkGetPhysicalDeviceSurfaceSupportKHR = vkGetPhysicalDeviceSurfaceSupportKHR.unwrap();
+            let fn_vkGetPhysicalDeviceSurfaceSupportKHR = self.fns.vkGetPhysicalDeviceSurfaceSupportKHR.unwrap();
             let result = fn_vkGetPhysicalDeviceSurfaceSupportKHR(self.ph_device, queue_idx, *surface, &mut supports);
             if result == vulkan_bindings::VkResult_VK_SUCCESS && supports == vulkan_bindings::VK_TRUE
             {
@@ -506,39 +1182,116 @@ impl VulkanPhysicalDevice {
         }
     }
 
+    pub fn find_role_family(& self, role: VulkanQueueRole, surface : Option<&vulkan_bindings::VkSurfaceKHR>) -> Option<u32>
+    {
+        let graphics = vulkan_types::QueueFlags::GRAPHICS;
+        let compute = vulkan_types::QueueFlags::COMPUTE;
+        let transfer = vulkan_types::QueueFlags::TRANSFER;
+        let none = vulkan_types::QueueFlags::empty();
+        let find = |wanted: vulkan_types::QueueFlags, excluded: vulkan_types::QueueFlags| {
+            self.family_queues.iter()
+                .position(|family| {
+                    let flags = vulkan_types::QueueFlags::from_raw(family.queueFlags);
+                    family.queueCount > 0 && flags.contains(wanted) && !flags.intersects(excluded)
+                })
+                .map(|idx| idx as u32)
+        };
+        match role {
+            VulkanQueueRole::GRAPHICS => find(graphics, none),
+            VulkanQueueRole::PRESENT => {
+                let surface = surface?;
+                if let Some(graphics_family) = find(graphics, none)
+                {
+                    if self.supports_presentation(graphics_family, surface)
+                    {
+                        return Some(graphics_family);
+                    }
+                }
+                (0..self.family_queues.len() as u32)
+                    .find(|idx| self.family_queues[*idx as usize].queueCount > 0 && self.supports_presentation(*idx, surface))
+            },
+            VulkanQueueRole::ASYNC_COMPUTE => find(compute, graphics).or_else(|| find(compute, none)),
+            // graphics and compute families always accept transfer commands even when they don't advertise it
+            VulkanQueueRole::TRANSFER => find(transfer, graphics | compute)
+                .or_else(|| find(transfer, graphics))
+                .or_else(|| find(graphics, none))
+                .or_else(|| find(compute, none))
+        }
+    }
+
+    pub fn find_role_families(& self, surface : Option<&vulkan_bindings::VkSurfaceKHR>) -> Vec<(VulkanQueueRole, u32)>
+    {
+        let mut roles = Vec::new();
+        for role in VulkanQueueRole::ALL
+        {
+            if let Some(family_index) = self.find_role_family(role, surface)
+            {
+                roles.push((role, family_index));
+            }
+        }
+        roles
+    }
+
+    pub fn reserve_role_queues(&mut self, roles: &[(VulkanQueueRole, u32)])
+    {
+        for (role, family_index) in roles
+        {
+            let already_reserved = self.desired_queues.iter().any(|queue| queue.familyIndex == *family_index as usize);
+            if !already_reserved
+            {
+                self.desired_queues.push(QueueInfo { familyIndex: *family_index as usize, capability: role.capability(), priorities: vec![0.5f32] });
+            }
+        }
+    }
+
     pub fn load_infos(&mut self) -> Result<(), VulkanInitError>
     {
         self.load_extensions()?;
-        self.load_features();
         self.load_properties();
+        self.load_features();
         self.load_memory_properties();
+        self.load_id_properties();
         self.load_family_queues()?;
         Ok(())
     }
 
-    pub fn has_desired_family_queues(&mut self, desired_capabilities: &[vulkan_bindings::VkQueueFlags], surface : &vulkan_bindings::VkSurfaceKHR) -> bool
+    pub fn has_desired_family_queues(&mut self, desired_capabilities: &[vulkan_types::QueueFlags], surface : Option<&vulkan_bindings::VkSurfaceKHR>) -> bool
     {
+        self.desired_queues.clear();
+        self.supports_presentation = false;
+        self.presentation_queue_idx = -1;
         let mut found_capabilites = 0;
         let mut found_presentation_queue = false;
         let mut family_queues = self.family_queues.clone();
-        'outer: for capability_idx in 0..desired_capabilities.len() {
+        'outer: for &capability in desired_capabilities {
             for (queue_idx,  queue )in family_queues.iter_mut().enumerate() {
-                if queue.queueCount > 0 && (queue.queueFlags & desired_capabilities[capability_idx] != 0)
+                if queue.queueCount > 0 && vulkan_types::QueueFlags::from_raw(queue.queueFlags).intersects(capability)
                 {
-                    if !found_presentation_queue && self.supports_presentation(queue_idx as u32, surface)
+                    if let Some(surface) = surface
                     {
-                        self.supports_presentation = true;
-                        self.presentation_queue_idx = queue_idx as i32;
-                        found_presentation_queue = true
+                        if !found_presentation_queue && self.supports_presentation(queue_idx as u32, surface)
+                        {
+                            self.supports_presentation = true;
+                            self.presentation_queue_idx = queue_idx as i32;
+                            found_presentation_queue = true
+                        }
                     }
-                    self.desired_queues.push(QueueInfo { familyIndex: queue_idx, capability: desired_capabilities[capability_idx] ,priorities: vec![0.5f32; queue.queueCount as usize]});
+                    self.desired_queues.push(QueueInfo { familyIndex: queue_idx, capability, priorities: vec![0.5f32; queue.queueCount as usize]});
                     queue.queueCount = 0;
                     found_capabilites += 1;
                     continue 'outer;
                 }
             }
         }
-        found_capabilites == desired_capabilities.len() && found_presentation_queue
+        found_capabilites == desired_capabilities.len() && (surface.is_none() || found_presentation_queue)
+    }
+
+    pub fn missing_extensions(& self, desired_extensions: &[String]) -> Vec<String>
+    {
+        desired_extensions.iter()
+            .filter(|desired| !self.extensions.iter().any(|available| vk_chars_to_string(&available.extensionName) == **desired))
+            .cloned()
+            .collect()
     }
 
     pub fn has_desired_extensions(& self, desired_extensions: &Vec<String>) -> bool
@@ -556,7 +1309,7 @@ impl VulkanPhysicalDevice {
                         break;
                     }
                 }
-                if found == false
+                if !found
                 {
                     return false;
                 }
@@ -569,7 +1322,7 @@ impl VulkanPhysicalDevice {
     {
         let mut presentation_modes_count :u32 = 0;
         unsafe {
-            let fn_vkGetPhysicalDeviceSurfacePresentModesKHR = vkGetPhysicalDeviceSurfacePresentModesKHR.unwrap();
+            let fn_vkGetPhysicalDeviceSurfacePresentModesKHR = self.fns.vkGetPhysicalDeviceSurfacePresentModesKHR.unwrap();
             let result = fn_vkGetPhysicalDeviceSurfacePresentModesKHR(self.ph_device, 
                 *presentation_surface, 
                 &mut presentation_modes_count, 
@@ -577,7 +1330,7 @@ impl VulkanPhysicalDevice {
             );
             if result != vulkan_bindings::VkResult_VK_SUCCESS || presentation_modes_count == 0
             {
-                return Err(VulkanInitError::UNAVAILABLE_PRESENTATION_MODE);
+                return Err(VulkanInitError::UNAVAILABLE_PRESENTATION_MODE(vulkan_error::VulkanCallError::new("vkGetPhysicalDeviceSurfacePresentModesKHR", result)));
             }
             self.supported_presentation_modes.resize(presentation_modes_count as usize, 0);
             let result = fn_vkGetPhysicalDeviceSurfacePresentModesKHR(self.ph_device,
@@ -587,26 +1340,62 @@ impl VulkanPhysicalDevice {
             );
             if result != vulkan_bindings::VkResult_VK_SUCCESS || presentation_modes_count == 0
             {
-                return Err(VulkanInitError::UNAVAILABLE_PRESENTATION_MODE)   
+                return Err(VulkanInitError::UNAVAILABLE_PRESENTATION_MODE(vulkan_error::VulkanCallError::new("vkGetPhysicalDeviceSurfacePresentModesKHR", result)))   
             }
         }
         Ok(())
     }
 
-    pub fn supports_presentation_mode(& self,  mode: &vulkan_bindings::VkPresentModeKHR) -> bool
+    pub fn supports_presentation_mode(& self,  mode: &vulkan_types::PresentModeKHR) -> bool
     {
         for supported_mode in &self.supported_presentation_modes
         {
-            if supported_mode == mode
+            if *supported_mode == mode.as_raw()
             {
                 return true;
             }
         }
-        return false;
+        false
+    }
+
+    pub fn check_requirements(&mut self,
+        desired_extensions: &[String],
+        desired_capabilites: &[vulkan_types::QueueFlags],
+        desired_features: &vulkan_features::VulkanFeatureRequest,
+        surface : Option<&vulkan_bindings::VkSurfaceKHR>,
+        presentation_mode : &vulkan_types::PresentModeKHR
+    ) -> Result<(), VulkanDeviceRejection>
+    {
+        let missing_extensions = self.missing_extensions(desired_extensions);
+        if !missing_extensions.is_empty()
+        {
+            return Err(VulkanDeviceRejection::MISSING_EXTENSIONS(missing_extensions));
+        }
+        let missing_features = desired_features.missing(&self.features);
+        if !missing_features.is_empty()
+        {
+            return Err(VulkanDeviceRejection::MISSING_FEATURES(missing_features));
+        }
+        if !self.has_desired_family_queues(desired_capabilites, surface)
+        {
+            if self.desired_queues.len() < desired_capabilites.len()
+            {
+                return Err(VulkanDeviceRejection::MISSING_QUEUE_CAPABILITIES);
+            }
+            return Err(VulkanDeviceRejection::NO_PRESENTATION_SUPPORT);
+        }
+        if let Some(surface) = surface
+        {
+            if self.load_presentation_mode(surface).is_err() || !self.supports_presentation_mode(presentation_mode)
+            {
+                return Err(VulkanDeviceRejection::UNSUPPORTED_PRESENTATION_MODE);
+            }
+        }
+        Ok(())
     }
 }
 
-enum PhysicalDeviceVendorsId {
+pub enum PhysicalDeviceVendorsId {
     NVIDIA = 0x10DE,
     AMD = 0x1002,
     INTEL = 0x8086,
@@ -624,6 +1413,102 @@ impl PhysicalDeviceVendorsId {
     }
 }
 
+pub fn default_device_score(ph_device: &VulkanPhysicalDevice) -> u64
+{
+    let device_type_score: u64 = match ph_device.properties.deviceType {
+        vulkan_bindings::VkPhysicalDeviceType_VK_PHYSICAL_DEVICE_TYPE_DISCRETE_GPU => 4_000_000,
+        vulkan_bindings::VkPhysicalDeviceType_VK_PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU => 3_000_000,
+        vulkan_bindings::VkPhysicalDeviceType_VK_PHYSICAL_DEVICE_TYPE_VIRTUAL_GPU => 2_000_000,
+        vulkan_bindings::VkPhysicalDeviceType_VK_PHYSICAL_DEVICE_TYPE_CPU => 1_000_000,
+        _ => 0
+    };
+    // vram in MiB, capped so it never outweighs the device type
+    let vram_score = (ph_device.device_local_memory() >> 20).min(999_999);
+    let limits_score = (ph_device.properties.limits.maxImageDimension2D as u64 >> 10).min(999);
+    let vendor_score = match ph_device.vendor() {
+        PhysicalDeviceVendorsId::UNDEFINED_VENDOR => 1,
+        _ => 2
+    };
+    (device_type_score + vram_score) * 1_000 + limits_score + vendor_score
+}
+
+pub enum VulkanDeviceSelector {
+    BEST_SCORE(fn(&VulkanPhysicalDevice) -> u64),
+    INDEX(usize),
+    NAME(String),
+    UUID([u8; 16])
+}
+
+impl Default for VulkanDeviceSelector {
+    fn default() -> Self {
+        VulkanDeviceSelector::BEST_SCORE(default_device_score)
+    }
+}
+
+impl VulkanDeviceSelector {
+    pub fn accepts(& self, idx: usize, ph_device: &VulkanPhysicalDevice) -> bool
+    {
+        match self {
+            VulkanDeviceSelector::BEST_SCORE(_) => true,
+            VulkanDeviceSelector::INDEX(wanted) => *wanted == idx,
+            VulkanDeviceSelector::NAME(wanted) => ph_device.name().to_lowercase().contains(&wanted.to_lowercase()),
+            VulkanDeviceSelector::UUID(wanted) => ph_device.device_uuid == Some(*wanted)
+        }
+    }
+
+    pub fn score(& self, ph_device: &VulkanPhysicalDevice) -> u64
+    {
+        match self {
+            VulkanDeviceSelector::BEST_SCORE(scoring) => scoring(ph_device),
+            _ => default_device_score(ph_device)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum VulkanDeviceRejection {
+    MISSING_EXTENSIONS(Vec<String>),
+    MISSING_FEATURES(Vec<String>),
+    MISSING_QUEUE_CAPABILITIES,
+    NO_PRESENTATION_SUPPORT,
+    UNSUPPORTED_PRESENTATION_MODE,
+    NOT_SELECTED,
+    NULL_SCORE,
+    OUTSCORED
+}
+
+impl std::fmt::Display for VulkanDeviceRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VulkanDeviceRejection::MISSING_EXTENSIONS(exts) => write!(f, "missing extensions: {}", exts.join(", ")),
+            VulkanDeviceRejection::MISSING_FEATURES(features) => write!(f, "missing features: {}", features.join(", ")),
+            VulkanDeviceRejection::MISSING_QUEUE_CAPABILITIES => write!(f, "no family queue matches the desired capabilities"),
+            VulkanDeviceRejection::NO_PRESENTATION_SUPPORT => write!(f, "none of the desired family queues can present to the surface"),
+            VulkanDeviceRejection::UNSUPPORTED_PRESENTATION_MODE => write!(f, "desired presentation mode isn't supported"),
+            VulkanDeviceRejection::NOT_SELECTED => write!(f, "doesn't match the device selector"),
+            VulkanDeviceRejection::NULL_SCORE => write!(f, "scored 0 by the scoring function"),
+            VulkanDeviceRejection::OUTSCORED => write!(f, "another device had a higher score")
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct VulkanDeviceReport {
+    pub index: usize,
+    pub name: String,
+    pub score: u64,
+    pub rejection: Option<VulkanDeviceRejection>
+}
+
+impl std::fmt::Display for VulkanDeviceReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.rejection {
+            Some(rejection) => write!(f, "#{} {} (score {}): rejected, {}", self.index, self.name, self.score, rejection),
+            None => write!(f, "#{} {} (score {}): selected", self.index, self.name, self.score)
+        }
+    }
+}
+
 impl std::fmt::Display for VulkanPhysicalDevice {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let device_type = match self.properties.deviceType {
@@ -638,54 +1523,123 @@ impl std::fmt::Display for VulkanPhysicalDevice {
             PhysicalDeviceVendorsId::INTEL => "Intel",
             _ => "Undefined Vendor"
         };
-        unsafe {
-            let device_name = std::mem::transmute::<[i8;256], [u8;256]>(self.properties.deviceName);
-            write!(f, "the physical device {} of type {} from {}", String::from_utf8(device_name.to_vec()).unwrap() , device_type, vendor)
-        }
+        write!(f, "the physical device {} of type {} from {}", self.name(), device_type, vendor)
     }
 }
 
+pub type VulkanDeviceLostCallback = Box<dyn Fn(&vulkan_error::VulkanCallError)>;
+
 pub struct  VulkanLogicalDevice {
     pub device : vulkan_bindings::VkDevice,
     pub demanded_queues : Vec<vulkan_bindings::VkDeviceQueueCreateInfo>,
     pub enabled_extensions : Vec<String>,
+    pub enabled_features : vulkan_features::VulkanFeatures,
     pub physical_device : *const VulkanPhysicalDevice,
-    pub presentation_mode: vulkan_bindings::VkPresentModeKHR
+    pub presentation_mode: vulkan_types::PresentModeKHR,
+    pub headless: bool,
+    pub selection_report: Vec<VulkanDeviceReport>,
+    pub queues : Vec<VulkanQueue>,
+    pub fns : DeviceFns,
+    pub allocation_callbacks : *const vulkan_bindings::VkAllocationCallbacks,
+    pub pipeline_cache : vulkan_pipeline_cache::VulkanPipelineCache,
+    lost : std::cell::Cell<bool>,
+    lost_callback : Option<VulkanDeviceLostCallback>
 }
 
 impl  VulkanLogicalDevice {
 
     pub fn new(vulkan_instance: &mut VulkanInstance, 
         desired_extensions: Vec<String>, 
-        desired_capabilites: &[vulkan_bindings::VkQueueFlags],
-        surface : &vulkan_bindings::VkSurfaceKHR,
-        presentation_mode : vulkan_bindings::VkPresentModeKHR ) 
+        desired_capabilites: &[vulkan_types::QueueFlags],
+        desired_features: &vulkan_features::VulkanFeatureRequest,
+        surface : Option<&vulkan_bindings::VkSurfaceKHR>,
+        presentation_mode : vulkan_types::PresentModeKHR,
+        selector : &VulkanDeviceSelector) 
         -> Result<Self, VulkanInitError> 
     {
         let mut vulkan_logical_device = VulkanLogicalDevice {
             device : std::ptr::null_mut(),
             demanded_queues : Vec::new(),
             enabled_extensions : desired_extensions,
+            enabled_features : vulkan_features::VulkanFeatures::new(),
             physical_device : std::ptr::null(),
-            presentation_mode: 0
+            presentation_mode: vulkan_types::PresentModeKHR::IMMEDIATE,
+            headless: surface.is_none(),
+            selection_report: Vec::new(),
+            queues: Vec::new(),
+            fns: DeviceFns::default(),
+            allocation_callbacks: vulkan_instance.allocation_callbacks(),
+            pipeline_cache: vulkan_pipeline_cache::VulkanPipelineCache::new(),
+            lost: std::cell::Cell::new(false),
+            lost_callback: None
         };
-        let ref mut physical_devices = vulkan_instance.physical_devices;
-        for ph_device in  physical_devices {
-            ph_device.load_presentation_mode(surface)?;
-            if ph_device.has_desired_extensions(&vulkan_logical_device.enabled_extensions) 
-                && ph_device.has_desired_family_queues(desired_capabilites, surface)
-                && ph_device.supports_presentation
-                && ph_device.supports_presentation_mode(&presentation_mode)
+        let physical_devices = &mut vulkan_instance.physical_devices;
+        let mut selected: Option<usize> = None;
+        for (idx, ph_device) in physical_devices.iter_mut().enumerate() {
+            let mut report = VulkanDeviceReport {
+                index: idx,
+                name: ph_device.name(),
+                score: 0,
+                rejection: None
+            };
+            if let Err(rejection) = ph_device.check_requirements(&vulkan_logical_device.enabled_extensions, desired_capabilites, desired_features, surface, &presentation_mode)
             {
-                vulkan_logical_device.presentation_mode = presentation_mode;
-                vulkan_logical_device.physical_device = ph_device;
-                vulkan_logical_device.init_device_queue_info();
-                vulkan_logical_device.create_logical_device()?;
-                vulkan_logical_device.load_device_functions()?;
-                return Ok(vulkan_logical_device); 
+                report.rejection = Some(rejection);
             }
+            else if !selector.accepts(idx, ph_device)
+            {
+                report.rejection = Some(VulkanDeviceRejection::NOT_SELECTED);
+            }
+            else
+            {
+                report.score = selector.score(ph_device);
+                if report.score == 0
+                {
+                    report.rejection = Some(VulkanDeviceRejection::NULL_SCORE);
+                }
+                else
+                {
+                    match selected {
+                        Some(best) if vulkan_logical_device.selection_report[best].score >= report.score => {
+                            report.rejection = Some(VulkanDeviceRejection::OUTSCORED);
+                        },
+                        Some(best) => {
+                            vulkan_logical_device.selection_report[best].rejection = Some(VulkanDeviceRejection::OUTSCORED);
+                            selected = Some(idx);
+                        },
+                        None => selected = Some(idx)
+                    }
+                }
+            }
+            vulkan_logical_device.selection_report.push(report);
+        }
+        let selected = match selected {
+            Some(idx) => idx,
+            None => return Err(VulkanInitError::NO_CAPABLE_PHYSICAL_DEVICE(vulkan_logical_device.selection_report))
+        };
+        if !vulkan_logical_device.headless
+        {
+            vulkan_logical_device.presentation_mode = presentation_mode;
         }
-        Err(VulkanInitError::NO_CAPABLE_PHYSICAL_DEVICE)
+        let roles = physical_devices[selected].find_role_families(surface);
+        physical_devices[selected].reserve_role_queues(&roles);
+        vulkan_logical_device.physical_device = &physical_devices[selected];
+        vulkan_logical_device.enabled_features = desired_features.resolve(&physical_devices[selected].features);
+        vulkan_logical_device.init_device_queue_info();
+        vulkan_logical_device.create_logical_device()?;
+        vulkan_logical_device.load_device_functions()?;
+        vulkan_logical_device.load_role_queues(&roles);
+        Ok(vulkan_logical_device)
+    }
+
+    pub fn new_headless(vulkan_instance: &mut VulkanInstance,
+        desired_extensions: Vec<String>,
+        desired_capabilites: &[vulkan_types::QueueFlags],
+        desired_features: &vulkan_features::VulkanFeatureRequest,
+        selector : &VulkanDeviceSelector)
+        -> Result<Self, VulkanInitError>
+    {
+        VulkanLogicalDevice::new(vulkan_instance, desired_extensions, desired_capabilites, desired_features, None, vulkan_types::PresentModeKHR::IMMEDIATE, selector)
     }
 
     pub fn init_device_queue_info(&mut self)
@@ -708,36 +1662,41 @@ impl  VulkanLogicalDevice {
     pub fn create_logical_device(&mut self) -> Result<(), VulkanInitError>
     {
         unsafe {
-            let ref physical_device = *self.physical_device;
+            let physical_device = &*self.physical_device;
             let enabled_ph_device_exts_cstr: Vec<CString> = self.enabled_extensions.iter()
             .map(|s| CString::new(s.as_bytes()).unwrap())
             .collect();
             let enabled_ph_device_exts_ptrs: Vec<* const i8> = enabled_ph_device_exts_cstr.iter()
             .map(|cs| cs.as_ptr())
             .collect();
+            let api_version = physical_device.api_version();
+            let mut enabled_features = self.enabled_features;
+            let features2 = enabled_features.chain(api_version, &self.enabled_extensions);
+            let use_features2 = api_version >= VK_MAKE_API_VERSION!(0, 1, 1, 0);
             let device_create_info = vulkan_bindings::VkDeviceCreateInfo{
                 sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO,
-                pNext: std::ptr::null(),
+                pNext: if use_features2 { &features2 as *const vulkan_bindings::VkPhysicalDeviceFeatures2 as *const std::ffi::c_void } else { std::ptr::null() },
                 flags: 0,
                 queueCreateInfoCount: self.demanded_queues.len() as u32,
-                pQueueCreateInfos: if self.demanded_queues.len() > 0  {  self.demanded_queues.as_ptr() } else { std::ptr::null() },
+                pQueueCreateInfos: if !self.demanded_queues.is_empty()  {  self.demanded_queues.as_ptr() } else { std::ptr::null() },
                 enabledLayerCount: 0,
                 ppEnabledLayerNames: std::ptr::null(),
                 enabledExtensionCount: enabled_ph_device_exts_ptrs.len() as u32,
-                ppEnabledExtensionNames: if enabled_ph_device_exts_ptrs.len() > 0 { enabled_ph_device_exts_ptrs.as_ptr() } else { std::ptr::null() },
-                pEnabledFeatures : std::ptr::null()
+                ppEnabledExtensionNames: if !enabled_ph_device_exts_ptrs.is_empty() { enabled_ph_device_exts_ptrs.as_ptr() } else { std::ptr::null() },
+                pEnabledFeatures : if use_features2 { std::ptr::null() } else { &enabled_features.core }
             };
-            let fn_vkCreateDevice = vkCreateDevice.unwrap();
-            let result = fn_vkCreateDevice(physical_device.ph_device, &device_create_info, std::ptr::null(), &mut self.device);
+            let fn_vkCreateDevice = physical_device.fns.vkCreateDevice.unwrap();
+            let result = fn_vkCreateDevice(physical_device.ph_device, &device_create_info, self.allocation_callbacks, &mut self.device);
+            enabled_features.unchain();
             if result != vulkan_bindings::VkResult_VK_SUCCESS || self.device == std::ptr::null_mut()
             {
-                return Err(VulkanInitError::FAILED_INSTANTIATING_LOGICAL_DEVICE);
+                return Err(VulkanInitError::FAILED_INSTANTIATING_LOGICAL_DEVICE(vulkan_error::VulkanCallError::new("vkCreateDevice", result)));
             }
         }
         Ok(())
     }
 
-    pub fn load_device_functions(& self) -> Result<(), VulkanInitError>
+    pub fn load_device_functions(&mut self) -> Result<(), VulkanInitError>
     {
         unsafe {
             DEFAULTED_IMPORT_MACROS!();
@@ -745,11 +1704,11 @@ impl  VulkanLogicalDevice {
             macro_rules! LOAD_DEVICE_LEVEL_VULKAN_FUNCTION {
                 ($function: ident) => {
                     paste! {
-                        let fn_vkGetDeviceProcAddr = vkGetDeviceProcAddr.unwrap();
+                        let fn_vkGetDeviceProcAddr = (*self.physical_device).fns.vkGetDeviceProcAddr.unwrap();
                         let func_name = CString::new(stringify!($function)).unwrap();
                         let func = fn_vkGetDeviceProcAddr(self.device , func_name.as_ptr());
-                        $function = std::mem::transmute::<vulkan_bindings::PFN_vkVoidFunction, vulkan_bindings::[<PFN_$function>]>(func);
-                        match $function {
+                        self.fns.$function = std::mem::transmute::<vulkan_bindings::PFN_vkVoidFunction, vulkan_bindings::[<PFN_$function>]>(func);
+                        match self.fns.$function {
                             Some(_) => (),
                             None => {
                                 let err = VulkanInitError::INSTANCE_VK_EXT_FUNCTION_ERROR(
@@ -765,7 +1724,7 @@ impl  VulkanLogicalDevice {
             macro_rules!  LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION {
                 ($function: ident , $ext: ident) => {
                     paste!{
-                        let fn_vkGetDeviceProcAddr = vkGetDeviceProcAddr.unwrap();
+                        let fn_vkGetDeviceProcAddr = (*self.physical_device).fns.vkGetDeviceProcAddr.unwrap();
                         let func_name = CString::new(stringify!($function)).unwrap();
                         let extension_name = String::from_utf8(vulkan_bindings::[<$ext>].into()).unwrap().trim_end_matches('\0').to_string();
                         for ph_ext in &self.enabled_extensions
@@ -773,8 +1732,8 @@ impl  VulkanLogicalDevice {
                             if extension_name == *ph_ext
                             {
                                 let func = fn_vkGetDeviceProcAddr(self.device, func_name.as_ptr());
-                                $function = std::mem::transmute::<vulkan_bindings::PFN_vkVoidFunction, vulkan_bindings::[<PFN_$function>] >(func);
-                                if $function.is_none()
+                                self.fns.$function = std::mem::transmute::<vulkan_bindings::PFN_vkVoidFunction, vulkan_bindings::[<PFN_$function>] >(func);
+                                if self.fns.$function.is_none()
                                 {
                                     let err = VulkanInitError::INSTANCE_VK_EXT_FUNCTION_ERROR(
                                         format!("Couldn't load device level extension vulkan function: {}", stringify!($function))
@@ -783,32 +1742,93 @@ impl  VulkanLogicalDevice {
                                 }
                             }
                         }
-                        if $function.is_none() {
-                            let err = VulkanInitError::INSTANCE_VK_EXT_FUNCTION_ERROR(
-                                format!("Couldn't load device level extension vulkan function: {}", stringify!($function))
-                            );
-                            return  Err(err);
+                    }
+                };
+            }
+
+            // the device only offers the commands of the lower of the instance's and the device's api versions
+            let api_version = (*self.physical_device).instance_api_version.min((*self.physical_device).properties.apiVersion);
+            macro_rules! LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION {
+                ($function: ident, $major: expr, $minor: expr) => {
+                    paste! {
+                        if api_version >= VK_MAKE_API_VERSION!(0, $major, $minor, 0)
+                        {
+                            let fn_vkGetDeviceProcAddr = (*self.physical_device).fns.vkGetDeviceProcAddr.unwrap();
+                            let func_name = CString::new(stringify!($function)).unwrap();
+                            let func = fn_vkGetDeviceProcAddr(self.device, func_name.as_ptr());
+                            self.fns.$function = std::mem::transmute::<vulkan_bindings::PFN_vkVoidFunction, vulkan_bindings::[<PFN_$function>]>(func);
+                            if self.fns.$function.is_none()
+                            {
+                                let err = VulkanInitError::DEVICE_LEVEL_FUNCTION_ERROR(
+                                    format!("Couldn't load device level vulkan function: {}", stringify!($function))
+                                );
+                                return Err(err);
+                            }
                         }
                     }
                 };
             }
-            
+
             include!("loaded_functions.rs");
         }
         Ok(())
     }
 
 
-    pub fn get_device_queue(& self , desired_capability: vulkan_bindings::VkQueueFlags , queue_idx: usize) -> Option<vulkan_bindings::VkQueue>
+    pub fn load_role_queues(&mut self, roles: &[(VulkanQueueRole, u32)])
+    {
+        unsafe {
+            let fn_vkGetDeviceQueue = self.fns.vkGetDeviceQueue.unwrap();
+            let graphics_family = roles.iter().find(|(role, _)| *role == VulkanQueueRole::GRAPHICS).map(|(_, family)| *family);
+            self.queues.clear();
+            for (role, family_index) in roles
+            {
+                let mut handle : vulkan_bindings::VkQueue = std::ptr::null_mut();
+                fn_vkGetDeviceQueue(self.device, *family_index, 0, &mut handle);
+                self.queues.push(VulkanQueue {
+                    role: *role,
+                    family_index: *family_index,
+                    queue_index: 0,
+                    handle,
+                    dedicated: graphics_family != Some(*family_index)
+                });
+            }
+        }
+    }
+
+    pub fn queue(& self, role: VulkanQueueRole) -> Option<&VulkanQueue>
+    {
+        self.queues.iter().find(|queue| queue.role == role)
+    }
+
+    pub fn queue_family_index(& self, role: VulkanQueueRole) -> Option<u32>
+    {
+        self.queue(role).map(|queue| queue.family_index)
+    }
+
+    // source and destination family indices for an ownership transfer barrier,
+    // ignored on both sides when the roles share a family
+    pub fn ownership_transfer(& self, from: VulkanQueueRole, to: VulkanQueueRole) -> Option<(u32, u32)>
+    {
+        let from = self.queue_family_index(from)?;
+        let to = self.queue_family_index(to)?;
+        if from == to
+        {
+            return Some((vulkan_bindings::VK_QUEUE_FAMILY_IGNORED as u32, vulkan_bindings::VK_QUEUE_FAMILY_IGNORED as u32));
+        }
+        Some((from, to))
+    }
+
+    pub fn get_device_queue(& self , desired_capability: vulkan_types::QueueFlags , queue_idx: usize) -> Option<vulkan_bindings::VkQueue>
     {
         unsafe 
         {
             let mut queue : vulkan_bindings::VkQueue = std::ptr::null_mut();
-            let ref physical_device = *self.physical_device;
-            let fn_vkGetDeviceQueue = vkGetDeviceQueue.unwrap();
+            let physical_device = &*self.physical_device;
+            let fn_vkGetDeviceQueue = self.fns.vkGetDeviceQueue.unwrap();
 
             for fam_queue in  &physical_device.desired_queues {
-                if (fam_queue.capability &  desired_capability != 0) && queue_idx < fam_queue.priorities.len() 
+                if fam_queue.capability.intersects(desired_capability) && queue_idx < fam_queue.priorities.len() 
                 {
                     fn_vkGetDeviceQueue(self.device, fam_queue.familyIndex as u32 , queue_idx as u32, &mut queue);
                     return Some(queue);
@@ -822,39 +1842,170 @@ impl  VulkanLogicalDevice {
     {
         unsafe
         {
-            let fn_vkDeviceWaitIdle = vkDeviceWaitIdle.unwrap();
+            let fn_vkDeviceWaitIdle = self.fns.vkDeviceWaitIdle.unwrap();
             let result = fn_vkDeviceWaitIdle(self.device);
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
+                self.track_call_error(vulkan_error::VulkanCallError::new("vkDeviceWaitIdle", result));
                 return false;
             }
-            return true;
+            true
         }
     }
 
-    pub fn destroy(mut self)
+    // core since 1.2 or through VK_KHR_timeline_semaphore, either way the timelineSemaphore feature has to be enabled
+    pub fn supports_timeline_semaphores(& self) -> bool
+    {
+        self.enabled_features.vulkan12.timelineSemaphore != vulkan_bindings::VK_FALSE
+            && (self.fns.vkWaitSemaphores.is_some() || self.fns.vkWaitSemaphoresKHR.is_some())
+    }
+
+    // core since 1.3 or through VK_KHR_synchronization2, either way the synchronization2 feature has to be enabled
+    pub fn supports_synchronization2(& self) -> bool
+    {
+        self.enabled_features.vulkan13.synchronization2 != vulkan_bindings::VK_FALSE
+            && (self.fns.vkCmdPipelineBarrier2.is_some() || self.fns.vkCmdPipelineBarrier2KHR.is_some())
+    }
+
+    pub fn on_device_lost(&mut self, callback: VulkanDeviceLostCallback)
+    {
+        self.lost_callback = Some(callback);
+    }
+
+    pub fn is_lost(& self) -> bool
+    {
+        self.lost.get()
+    }
+
+    // flags the device the first time a call reports VK_ERROR_DEVICE_LOST and notifies the application
+    pub fn track_call_error(& self, error: vulkan_error::VulkanCallError) -> vulkan_error::VulkanCallError
+    {
+        if error.is_device_lost() && !self.lost.replace(true)
+        {
+            if let Some(callback) = &self.lost_callback
+            {
+                callback(&error);
+            }
+        }
+        error
+    }
+
+    // rebuilds the VkDevice on the same physical device with the same extensions, features and queue roles,
+    // every object created from the old device has to be released before
+    pub fn recreate(&mut self) -> Result<(), VulkanInitError>
+    {
+        let roles : Vec<(VulkanQueueRole, u32)> = self.queues.iter().map(|queue| (queue.role, queue.family_index)).collect();
+        self.destroy_device();
+        self.demanded_queues.clear();
+        self.fns = DeviceFns::default();
+        self.init_device_queue_info();
+        self.create_logical_device()?;
+        self.load_device_functions()?;
+        self.load_role_queues(&roles);
+        self.lost.set(false);
+        self.reload_pipeline_cache();
+        Ok(())
+    }
+
+    // without a directory the cache only lives as long as the device
+    pub fn load_pipeline_cache(&mut self, directory: &std::path::Path) -> Result<(), vulkan_pipeline_cache::VulkanPipelineCacheError>
     {
         unsafe {
-            let fn_vkDestroyDevice = vkDestroyDevice.unwrap();
-            fn_vkDestroyDevice(self.device, std::ptr::null());
+            let properties = &(*self.physical_device).properties;
+            self.pipeline_cache.load(&self.fns, self.device, self.allocation_callbacks, properties, directory)
+        }
+    }
+
+    pub fn save_pipeline_cache(& self) -> Result<(), vulkan_pipeline_cache::VulkanPipelineCacheError>
+    {
+        self.pipeline_cache.save(&self.fns, self.device)
+    }
+
+    // falls back to an empty cache if the file can't be read back after a device loss
+    fn reload_pipeline_cache(&mut self)
+    {
+        let directory = match self.pipeline_cache.path.as_ref().and_then(|path| path.parent()) {
+            Some(directory) => directory.to_path_buf(),
+            None => return
+        };
+        if let Err(e) = self.load_pipeline_cache(&directory)
+        {
+            eprintln!("{}", vulkan_error::describe(&e));
+            let _ = self.pipeline_cache.create(&self.fns, self.device, self.allocation_callbacks, &[]);
+        }
+    }
+
+    fn destroy_device(&mut self)
+    {
+        unsafe {
+            if self.device == std::ptr::null_mut()
+            {
+                return;
+            }
+            self.pipeline_cache.destroy(&self.fns, self.device, self.allocation_callbacks);
+            if let Some(fn_vkDestroyDevice) = self.fns.vkDestroyDevice
+            {
+                fn_vkDestroyDevice(self.device, self.allocation_callbacks);
+            }
             self.device = std::ptr::null_mut();
         }
     }
 
+    pub fn destroy(mut self)
+    {
+        if self.pipeline_cache.path.is_some() && !self.is_lost()
+        {
+            if let Err(e) = self.save_pipeline_cache()
+            {
+                eprintln!("{}", vulkan_error::describe(&e));
+            }
+        }
+        self.destroy_device();
+    }
+
 }
 
-pub fn initialize_vulkan(desired_global_extensions : Vec<String>) -> &'static mut VulkanInstance
+pub fn initialize_vulkan(instance_builder : VulkanInstanceBuilder) -> &'static mut VulkanInstance
 {
     unsafe {
-        VULKAN_INSTANCE = match VulkanInstance::new(desired_global_extensions) {
+        VULKAN_INSTANCE = match instance_builder.build() {
             Ok(i) => Some(i),
             Err(e) =>{
-                eprintln!("{}", e);
+                eprintln!("{}", vulkan_error::describe(&e));
                 std::process::exit(1);
             }
         };
         let vk = (*std::ptr::addr_of_mut!(VULKAN_INSTANCE)).as_mut();
-        let vk = vk.unwrap();
-        vk
+        
+        (vk.unwrap()) as _
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_library_path_comes_before_the_fallbacks()
+    {
+        let defaults : Vec<String> = default_library_names().iter().map(|name| name.to_string()).collect();
+        let candidates = library_candidates(Some("/opt/explicit/libvulkan.so"), Some("/opt/env/libvulkan.so".to_string()));
+        assert_eq!(candidates[..2], ["/opt/explicit/libvulkan.so".to_string(), "/opt/env/libvulkan.so".to_string()]);
+        assert_eq!(candidates[2..], defaults[..]);
+        // an empty override is the same as none
+        assert_eq!(library_candidates(None, Some(String::new())), defaults);
+    }
+
+    #[test]
+    fn failed_explicit_path_is_reported()
+    {
+        match load_vulkan_library(Some("/nonexistent/libvulkan.so.1")) {
+            Ok((_, path)) => assert!(default_library_names().contains(&path.as_str()) || std::env::var(VULKAN_LIBRARY_ENV).is_ok()),
+            Err(VulkanInitError::UNLOADABLE_LIBRARY(attempts)) => {
+                assert_eq!(attempts[0].0, "/nonexistent/libvulkan.so.1");
+                assert!(attempts.len() > default_library_names().len());
+            },
+            Err(e) => panic!("unexpected error {}", e)
+        }
     }
 }
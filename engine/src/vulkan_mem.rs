@@ -1,19 +1,28 @@
 use crate::vulkan_bindings;
 use crate::vulkan_init;
+use crate::vulkan_format;
+use crate::vulkan_error;
+use crate::vulkan_synchro;
+use crate::vulkan_types;
+use crate::vulkan_structs;
 
 #[derive(Debug)]
 pub enum VulkanMemError
 {
-    COULDNT_ALLOCATE_BUFFER,
-    COULDNT_ALLOCATE_DEVICE_MEMORY,
-    FAILED_CREATING_BUFFER_VIEW,
-    COULDNT_ALLOCATE_IMAGE,
-    COULDNT_BIND_IMAGE_MEMORY,
-    FAILED_CREATING_IMAGE_VIEW,
-    FAILED_GETTING_MEMORY_POINTER,
-    COULDNT_FLUSH_MEMORY,
+    COULDNT_ALLOCATE_BUFFER(vulkan_error::VulkanCallError),
+    COULDNT_ALLOCATE_DEVICE_MEMORY(vulkan_error::VulkanCallError),
+    NO_COMPATIBLE_MEMORY_TYPE(u32, u32),
+    COULDNT_BIND_BUFFER_MEMORY(vulkan_error::VulkanCallError),
+    FAILED_CREATING_BUFFER_VIEW(vulkan_error::VulkanCallError),
+    COULDNT_ALLOCATE_IMAGE(vulkan_error::VulkanCallError),
+    COULDNT_BIND_IMAGE_MEMORY(vulkan_error::VulkanCallError),
+    FAILED_CREATING_IMAGE_VIEW(vulkan_error::VulkanCallError),
+    FAILED_GETTING_MEMORY_POINTER(vulkan_error::VulkanCallError),
+    COULDNT_FLUSH_MEMORY(vulkan_error::VulkanCallError),
     CANT_COPY_FROM_SRC,
-    CANT_WRITE_TO_DST
+    CANT_WRITE_TO_DST,
+    UNSUPPORTED_FORMAT(vulkan_format::VulkanFormatError),
+    UNAVAILABLE_QUEUE_ROLE(vulkan_init::VulkanQueueRole)
 }
 
 impl std::fmt::Display for VulkanMemError
@@ -21,41 +30,238 @@ impl std::fmt::Display for VulkanMemError
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self
         {
-            VulkanMemError::COULDNT_ALLOCATE_BUFFER => write!(f,"Couldn't allocate a buffer memory"),
-            VulkanMemError::COULDNT_ALLOCATE_DEVICE_MEMORY => write!(f, "Couldn't allocate device memory for buffer"),
-            VulkanMemError::FAILED_CREATING_BUFFER_VIEW => write!(f, "Failed creating buffer view"),
-            VulkanMemError::COULDNT_ALLOCATE_IMAGE => write!(f, "Couldn't allocate an image"),
-            VulkanMemError::COULDNT_BIND_IMAGE_MEMORY => write!(f, "Couldn't bind image memory"),
-            VulkanMemError::FAILED_CREATING_IMAGE_VIEW => write!(f, "Failed creating image view"),
-            VulkanMemError::FAILED_GETTING_MEMORY_POINTER => write!(f, "Failed getting memory pointer"),
-            VulkanMemError::COULDNT_FLUSH_MEMORY => write!(f,"Couldn't flush memory"),
+            VulkanMemError::COULDNT_ALLOCATE_BUFFER(_) => write!(f, "Couldn't allocate a buffer memory"),
+            VulkanMemError::COULDNT_ALLOCATE_DEVICE_MEMORY(_) => write!(f, "Couldn't allocate device memory for buffer"),
+            VulkanMemError::NO_COMPATIBLE_MEMORY_TYPE(type_bits, properties) => write!(f, "No memory type in {:#x} has the properties {:#x}", type_bits, properties),
+            VulkanMemError::COULDNT_BIND_BUFFER_MEMORY(_) => write!(f, "Couldn't bind buffer memory"),
+            VulkanMemError::FAILED_CREATING_BUFFER_VIEW(_) => write!(f, "Failed creating buffer view"),
+            VulkanMemError::COULDNT_ALLOCATE_IMAGE(_) => write!(f, "Couldn't allocate an image"),
+            VulkanMemError::COULDNT_BIND_IMAGE_MEMORY(_) => write!(f, "Couldn't bind image memory"),
+            VulkanMemError::FAILED_CREATING_IMAGE_VIEW(_) => write!(f, "Failed creating image view"),
+            VulkanMemError::FAILED_GETTING_MEMORY_POINTER(_) => write!(f, "Failed getting memory pointer"),
+            VulkanMemError::COULDNT_FLUSH_MEMORY(_) => write!(f, "Couldn't flush memory"),
             VulkanMemError::CANT_COPY_FROM_SRC => write!(f, "Cant copy from source buffer"),
-            VulkanMemError::CANT_WRITE_TO_DST => write!(f, "Can't write to destination buffer")
+            VulkanMemError::CANT_WRITE_TO_DST => write!(f, "Can't write to destination buffer"),
+            VulkanMemError::UNSUPPORTED_FORMAT(_) => write!(f, "No usable format for the resource"),
+            VulkanMemError::UNAVAILABLE_QUEUE_ROLE(role) => write!(f, "The logical device has no {} queue to transfer ownership with", role)
         }
     }
 }
 
-impl std::error::Error for VulkanMemError {}
+impl VulkanMemError {
+    pub fn call_error(& self) -> Option<&vulkan_error::VulkanCallError>
+    {
+        match self
+        {
+            VulkanMemError::COULDNT_ALLOCATE_BUFFER(e)
+            | VulkanMemError::COULDNT_ALLOCATE_DEVICE_MEMORY(e)
+            | VulkanMemError::COULDNT_BIND_BUFFER_MEMORY(e)
+            | VulkanMemError::FAILED_CREATING_BUFFER_VIEW(e)
+            | VulkanMemError::COULDNT_ALLOCATE_IMAGE(e)
+            | VulkanMemError::COULDNT_BIND_IMAGE_MEMORY(e)
+            | VulkanMemError::FAILED_CREATING_IMAGE_VIEW(e)
+            | VulkanMemError::FAILED_GETTING_MEMORY_POINTER(e)
+            | VulkanMemError::COULDNT_FLUSH_MEMORY(e) => Some(e),
+            _ => None
+        }
+    }
+}
 
+impl std::error::Error for VulkanMemError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self
+        {
+            VulkanMemError::UNSUPPORTED_FORMAT(e) => Some(e),
+            _ => self.call_error().map(|e| e as &(dyn std::error::Error + 'static))
+        }
+    }
+}
+
+impl From<vulkan_format::VulkanFormatError> for VulkanMemError {
+    fn from(value: vulkan_format::VulkanFormatError) -> Self {
+        VulkanMemError::UNSUPPORTED_FORMAT(value)
+    }
+}
+
+// the roles owning the resource before and after the barrier, the same role on both sides or roles sharing a
+// family make it a plain barrier and different families an ownership transfer
 pub struct VulkanBufferTransition
 {
     pub buffer: vulkan_bindings::VkBuffer,
-    pub current_access: vulkan_bindings::VkAccessFlags,
-    pub new_access: vulkan_bindings::VkAccessFlags,
-    pub current_fam_queue: u32,
-    pub new_fam_queue : u32
+    pub current_access: vulkan_types::AccessFlags,
+    pub new_access: vulkan_types::AccessFlags,
+    pub current_role: vulkan_init::VulkanQueueRole,
+    pub new_role: vulkan_init::VulkanQueueRole
 }
 
 pub struct VulkanImageTransition
 {
     pub image: vulkan_bindings::VkImage,
-    pub current_access : vulkan_bindings::VkAccessFlags,
-    pub new_access: vulkan_bindings::VkAccessFlags,
-    pub current_layout: vulkan_bindings::VkImageLayout,
-    pub new_layout : vulkan_bindings::VkImageLayout,
-    pub current_fam_queue : u32,
-    pub new_fam_queue: u32,
-    pub aspect : vulkan_bindings::VkImageAspectFlags
+    pub current_access : vulkan_types::AccessFlags,
+    pub new_access: vulkan_types::AccessFlags,
+    pub current_layout: vulkan_types::ImageLayout,
+    pub new_layout : vulkan_types::ImageLayout,
+    pub current_role: vulkan_init::VulkanQueueRole,
+    pub new_role: vulkan_init::VulkanQueueRole,
+    pub aspect : vulkan_types::ImageAspectFlags
+}
+
+// synchronization2 transitions carry their own stages instead of sharing one pair for the whole barrier
+pub struct VulkanBufferTransition2
+{
+    pub buffer: vulkan_bindings::VkBuffer,
+    pub src_stage: vulkan_types::PipelineStageFlags2,
+    pub src_access: vulkan_types::AccessFlags2,
+    pub dst_stage: vulkan_types::PipelineStageFlags2,
+    pub dst_access: vulkan_types::AccessFlags2,
+    pub current_role: vulkan_init::VulkanQueueRole,
+    pub new_role: vulkan_init::VulkanQueueRole
+}
+
+pub struct VulkanImageTransition2
+{
+    pub image: vulkan_bindings::VkImage,
+    pub src_stage: vulkan_types::PipelineStageFlags2,
+    pub src_access: vulkan_types::AccessFlags2,
+    pub dst_stage: vulkan_types::PipelineStageFlags2,
+    pub dst_access: vulkan_types::AccessFlags2,
+    pub current_layout: vulkan_types::ImageLayout,
+    pub new_layout : vulkan_types::ImageLayout,
+    pub current_role: vulkan_init::VulkanQueueRole,
+    pub new_role: vulkan_init::VulkanQueueRole,
+    pub aspect : vulkan_types::ImageAspectFlags
+}
+
+// src and dst family indices of a transition through VulkanLogicalDevice::ownership_transfer
+fn queue_families(logical_device: &vulkan_init::VulkanLogicalDevice, current_role: vulkan_init::VulkanQueueRole, new_role: vulkan_init::VulkanQueueRole) -> Result<(u32, u32), VulkanMemError>
+{
+    logical_device.ownership_transfer(current_role, new_role).ok_or_else(|| {
+        let missing = if logical_device.queue_family_index(current_role).is_none() { current_role } else { new_role };
+        VulkanMemError::UNAVAILABLE_QUEUE_ROLE(missing)
+    })
+}
+
+// records the barrier with vkCmdPipelineBarrier2 when available, otherwise the stages of every transition
+// are merged into a single legacy vkCmdPipelineBarrier
+pub fn record_barriers2(
+    logical_device: &vulkan_init::VulkanLogicalDevice,
+    cmd_buffer: vulkan_bindings::VkCommandBuffer,
+    buffer_transitions: &[VulkanBufferTransition2],
+    image_transitions: &[VulkanImageTransition2]
+) -> Result<(), VulkanMemError>
+{
+    if buffer_transitions.is_empty() && image_transitions.is_empty()
+    {
+        return Ok(());
+    }
+    let buffer_families = buffer_transitions.iter()
+        .map(|transition| queue_families(logical_device, transition.current_role, transition.new_role))
+        .collect::<Result<Vec<(u32, u32)>, VulkanMemError>>()?;
+    let image_families = image_transitions.iter()
+        .map(|transition| queue_families(logical_device, transition.current_role, transition.new_role))
+        .collect::<Result<Vec<(u32, u32)>, VulkanMemError>>()?;
+    let whole_image = |aspect: vulkan_types::ImageAspectFlags| vulkan_bindings::VkImageSubresourceRange {
+        aspectMask: aspect.as_raw(),
+        baseMipLevel: 0,
+        levelCount: vulkan_bindings::VK_REMAINING_MIP_LEVELS as u32,
+        baseArrayLayer: 0,
+        layerCount: vulkan_bindings::VK_REMAINING_ARRAY_LAYERS as u32,
+    };
+    unsafe
+    {
+        match logical_device.fns.vkCmdPipelineBarrier2.or(logical_device.fns.vkCmdPipelineBarrier2KHR) {
+            Some(fn_vkCmdPipelineBarrier2) if logical_device.supports_synchronization2() => {
+                let buffer_barriers : Vec<vulkan_bindings::VkBufferMemoryBarrier2> = buffer_transitions.iter().zip(buffer_families.iter()).map(|(transition, (src_family, dst_family))| vulkan_bindings::VkBufferMemoryBarrier2 {
+                    sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_BUFFER_MEMORY_BARRIER_2,
+                    pNext: std::ptr::null(),
+                    srcStageMask: transition.src_stage.as_raw(),
+                    srcAccessMask: transition.src_access.as_raw(),
+                    dstStageMask: transition.dst_stage.as_raw(),
+                    dstAccessMask: transition.dst_access.as_raw(),
+                    srcQueueFamilyIndex: *src_family,
+                    dstQueueFamilyIndex: *dst_family,
+                    buffer: transition.buffer,
+                    offset: 0,
+                    size: vulkan_bindings::VK_WHOLE_SIZE as u64
+                }).collect();
+                let image_barriers : Vec<vulkan_bindings::VkImageMemoryBarrier2> = image_transitions.iter().zip(image_families.iter()).map(|(transition, (src_family, dst_family))| vulkan_bindings::VkImageMemoryBarrier2 {
+                    sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_IMAGE_MEMORY_BARRIER_2,
+                    pNext: std::ptr::null(),
+                    srcStageMask: transition.src_stage.as_raw(),
+                    srcAccessMask: transition.src_access.as_raw(),
+                    dstStageMask: transition.dst_stage.as_raw(),
+                    dstAccessMask: transition.dst_access.as_raw(),
+                    oldLayout: transition.current_layout.as_raw(),
+                    newLayout: transition.new_layout.as_raw(),
+                    srcQueueFamilyIndex: *src_family,
+                    dstQueueFamilyIndex: *dst_family,
+                    image: transition.image,
+                    subresourceRange: whole_image(transition.aspect)
+                }).collect();
+                let dependency_info = vulkan_bindings::VkDependencyInfo {
+                    sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_DEPENDENCY_INFO,
+                    pNext: std::ptr::null(),
+                    dependencyFlags: 0,
+                    memoryBarrierCount: 0,
+                    pMemoryBarriers: std::ptr::null(),
+                    bufferMemoryBarrierCount: buffer_barriers.len() as u32,
+                    pBufferMemoryBarriers: buffer_barriers.as_ptr(),
+                    imageMemoryBarrierCount: image_barriers.len() as u32,
+                    pImageMemoryBarriers: image_barriers.as_ptr()
+                };
+                fn_vkCmdPipelineBarrier2(cmd_buffer, &dependency_info);
+            },
+            _ => {
+                let mut src_stages = vulkan_types::PipelineStageFlags2::empty();
+                let mut dst_stages = vulkan_types::PipelineStageFlags2::empty();
+                let buffer_barriers : Vec<vulkan_bindings::VkBufferMemoryBarrier> = buffer_transitions.iter().zip(buffer_families.iter()).map(|(transition, (src_family, dst_family))| {
+                    src_stages |= transition.src_stage;
+                    dst_stages |= transition.dst_stage;
+                    vulkan_bindings::VkBufferMemoryBarrier {
+                        sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_BUFFER_MEMORY_BARRIER,
+                        pNext: std::ptr::null(),
+                        srcAccessMask: vulkan_synchro::legacy_access_mask(transition.src_access).as_raw(),
+                        dstAccessMask: vulkan_synchro::legacy_access_mask(transition.dst_access).as_raw(),
+                        srcQueueFamilyIndex: *src_family,
+                        dstQueueFamilyIndex: *dst_family,
+                        buffer: transition.buffer,
+                        offset: 0,
+                        size: vulkan_bindings::VK_WHOLE_SIZE as u64
+                    }
+                }).collect();
+                let image_barriers : Vec<vulkan_bindings::VkImageMemoryBarrier> = image_transitions.iter().zip(image_families.iter()).map(|(transition, (src_family, dst_family))| {
+                    src_stages |= transition.src_stage;
+                    dst_stages |= transition.dst_stage;
+                    vulkan_bindings::VkImageMemoryBarrier {
+                        sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_IMAGE_MEMORY_BARRIER,
+                        pNext: std::ptr::null(),
+                        srcAccessMask: vulkan_synchro::legacy_access_mask(transition.src_access).as_raw(),
+                        dstAccessMask: vulkan_synchro::legacy_access_mask(transition.dst_access).as_raw(),
+                        oldLayout: transition.current_layout.as_raw(),
+                        newLayout: transition.new_layout.as_raw(),
+                        srcQueueFamilyIndex: *src_family,
+                        dstQueueFamilyIndex: *dst_family,
+                        image: transition.image,
+                        subresourceRange: whole_image(transition.aspect)
+                    }
+                }).collect();
+                let fn_vkCmdPipelineBarrier = logical_device.fns.vkCmdPipelineBarrier.unwrap();
+                fn_vkCmdPipelineBarrier(
+                    cmd_buffer,
+                    vulkan_synchro::legacy_stage_mask(src_stages, true).as_raw(),
+                    vulkan_synchro::legacy_stage_mask(dst_stages, false).as_raw(),
+                    0,
+                    0,
+                    std::ptr::null(),
+                    buffer_barriers.len() as u32,
+                    if !buffer_barriers.is_empty() { buffer_barriers.as_ptr() } else { std::ptr::null() },
+                    image_barriers.len() as u32,
+                    if !image_barriers.is_empty() { image_barriers.as_ptr() } else { std::ptr::null() }
+                );
+            }
+        }
+    }
+    Ok(())
 }
 
 pub struct VulkanDeviceMemory
@@ -63,7 +269,7 @@ pub struct VulkanDeviceMemory
     pub logical_device : *const vulkan_init::VulkanLogicalDevice,
     pub handle: vulkan_bindings::VkDeviceMemory,
     pub size: u64,
-    pub properties:vulkan_bindings::VkMemoryPropertyFlagBits,
+    pub properties: vulkan_types::MemoryPropertyFlags,
     pub data_region: *mut std::ffi::c_void,
     pub flushable_memory: Vec<vulkan_bindings::VkMappedMemoryRange>
 }
@@ -73,7 +279,7 @@ impl VulkanDeviceMemory
     pub fn new(
         logical_device: &vulkan_init::VulkanLogicalDevice,
         mem_req: &vulkan_bindings::VkMemoryRequirements,
-        mem_props: vulkan_bindings::VkMemoryPropertyFlagBits
+        mem_props: vulkan_types::MemoryPropertyFlags
     ) -> Result< Self, VulkanMemError>
     {
         unsafe
@@ -86,19 +292,19 @@ impl VulkanDeviceMemory
                 data_region: std::ptr::null_mut(),
                 flushable_memory: Vec::new()
             };
-            let ref ph_device = *logical_device.physical_device;
-            let ref memory_properties = ph_device.mem_properties;
+            let ph_device = &*logical_device.physical_device;
+            let memory_properties = &ph_device.mem_properties;
             let mut mem_type = 0;
             while mem_type < memory_properties.memoryTypeCount
             {
-                let has_property = memory_properties.memoryTypes[mem_type as usize].propertyFlags & (new_memory.properties as u32);
+                let has_property = vulkan_types::MemoryPropertyFlags::from_raw(memory_properties.memoryTypes[mem_type as usize].propertyFlags).contains(new_memory.properties);
                 // println!("values of first condition {} & {} ", mem_req.memoryTypeBits , 1 << mem_type);
                 // println!("first conditions {}", mem_req.memoryTypeBits & (1 << mem_type) != 0);
                 // println!("values of second condition {} & {}", memory_properties.memoryTypes[mem_type as usize].propertyFlags, new_memory.properties as u32);
                 // println!("second condition {}", (memory_properties.memoryTypes[mem_type as usize].propertyFlags & (new_memory.properties as u32)) == (new_memory.properties as u32));
-                if mem_req.memoryTypeBits & (1 << mem_type) != 0 && has_property == (new_memory.properties as u32)
+                if mem_req.memoryTypeBits & (1 << mem_type) != 0 && has_property
                 {
-                    let fn_vkAllocateMemory = vulkan_init::vkAllocateMemory.unwrap();
+                    let fn_vkAllocateMemory = logical_device.fns.vkAllocateMemory.unwrap();
                     let allocation_info = vulkan_bindings::VkMemoryAllocateInfo {
                         sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_INFO,
                         pNext: std::ptr::null(),
@@ -108,12 +314,12 @@ impl VulkanDeviceMemory
                     let result = fn_vkAllocateMemory(
                         logical_device.device,
                         &allocation_info,
-                        std::ptr::null(),
+                        logical_device.allocation_callbacks,
                         &mut new_memory.handle
                     );
                     if result != vulkan_bindings::VkResult_VK_SUCCESS
                     {
-                        return Err(VulkanMemError::COULDNT_ALLOCATE_DEVICE_MEMORY);
+                        return Err(VulkanMemError::COULDNT_ALLOCATE_DEVICE_MEMORY(vulkan_error::VulkanCallError::new("vkAllocateMemory", result)));
                     }
                     break;
                 }
@@ -121,7 +327,7 @@ impl VulkanDeviceMemory
             }
             if new_memory.handle == std::ptr::null_mut()
             {
-                return Err(VulkanMemError::COULDNT_ALLOCATE_DEVICE_MEMORY);
+                return Err(VulkanMemError::NO_COMPATIBLE_MEMORY_TYPE(mem_req.memoryTypeBits, new_memory.properties.as_raw()));
             }
             Ok(new_memory)
         }   
@@ -131,12 +337,12 @@ impl VulkanDeviceMemory
     {
         unsafe
         {
-            let fn_vkMapMemory = vulkan_init::vkMapMemory.unwrap();
+            let fn_vkMapMemory = (*self.logical_device).fns.vkMapMemory.unwrap();
             let logical_device = (*self.logical_device).device;
             let result = fn_vkMapMemory(logical_device, self.handle, 0, self.size, 0, &mut self.data_region);
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanMemError::FAILED_GETTING_MEMORY_POINTER);
+                return Err(VulkanMemError::FAILED_GETTING_MEMORY_POINTER(vulkan_error::VulkanCallError::new("vkMapMemory", result)));
             }
             Ok(())
         }
@@ -173,20 +379,42 @@ impl VulkanDeviceMemory
     {
         unsafe 
         {
-            if self.flushable_memory.len() <= 0
+            if self.flushable_memory.is_empty()
             {
                 println!("No maped memory");
                 return Ok(());
             }
-            let fn_vkFlushMappedMemoryRanges = vulkan_init::vkFlushMappedMemoryRanges.unwrap();
+            let fn_vkFlushMappedMemoryRanges = (*self.logical_device).fns.vkFlushMappedMemoryRanges.unwrap();
             let logical_device = (*self.logical_device).device;
             let result = fn_vkFlushMappedMemoryRanges(logical_device, self.flushable_memory.len() as u32, self.flushable_memory.as_ptr());
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanMemError::COULDNT_FLUSH_MEMORY);
+                return Err(VulkanMemError::COULDNT_FLUSH_MEMORY(vulkan_error::VulkanCallError::new("vkFlushMappedMemoryRanges", result)));
             }
             self.flushable_memory.clear();
-            return Ok(())
+            Ok(())
+        }
+    }
+
+    pub fn release(&mut self)
+    {
+        unsafe
+        {
+            if self.handle == std::ptr::null_mut()
+            {
+                return;
+            }
+            let logical_device = &*self.logical_device;
+            if self.data_region != std::ptr::null_mut()
+            {
+                let fn_vkUnmapMemory = logical_device.fns.vkUnmapMemory.unwrap();
+                fn_vkUnmapMemory(logical_device.device, self.handle);
+                self.data_region = std::ptr::null_mut();
+            }
+            let fn_vkFreeMemory = logical_device.fns.vkFreeMemory.unwrap();
+            fn_vkFreeMemory(logical_device.device, self.handle, logical_device.allocation_callbacks);
+            self.handle = std::ptr::null_mut();
+            self.flushable_memory.clear();
         }
     }
 
@@ -194,7 +422,7 @@ impl VulkanDeviceMemory
     {
         unsafe
         {
-            let fn_vkUnmapMemory = vulkan_init::vkUnmapMemory.unwrap();
+            let fn_vkUnmapMemory = (*self.logical_device).fns.vkUnmapMemory.unwrap();
             let logical_device = (*self.logical_device).device;
             fn_vkUnmapMemory(logical_device, self.handle);
         }
@@ -206,48 +434,53 @@ pub struct VulkanBufferMem
     pub logical_device: *const vulkan_init::VulkanLogicalDevice,
     pub handle : vulkan_bindings::VkBuffer,
     pub size: u64,
-    pub usage: vulkan_bindings::VkBufferUsageFlags,
+    pub usage: vulkan_types::BufferUsageFlags,
     pub device_memory: Option<VulkanDeviceMemory>,
+    pub memory_properties: Option<vulkan_types::MemoryPropertyFlags>,
     pub buffer_view: vulkan_bindings::VkBufferView,
+    pub view_format: Option<vulkan_bindings::VkFormat>,
     pub copied_regions: Vec<vulkan_bindings::VkBufferCopy>,
     pub img_copied_regions: Vec<vulkan_bindings::VkBufferImageCopy>
 }
 
 impl VulkanBufferMem
 {
-    pub fn new(logical_device : &vulkan_init::VulkanLogicalDevice, size:u64, usage:vulkan_bindings::VkBufferUsageFlags) -> Result<Self, VulkanMemError>
+    pub fn new(logical_device : &vulkan_init::VulkanLogicalDevice, size:u64, usage: vulkan_types::BufferUsageFlags) -> Result<Self, VulkanMemError>
     {
-        let buffer_create_info = vulkan_bindings::VkBufferCreateInfo {
-            sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_BUFFER_CREATE_INFO,
-            pNext: std::ptr::null(),
-            flags: 0,
+        let mut new_buffer = VulkanBufferMem {
+            logical_device,
+            handle: std::ptr::null_mut(),
             size,
             usage,
-            sharingMode: vulkan_bindings::VkSharingMode_VK_SHARING_MODE_EXCLUSIVE,
-            queueFamilyIndexCount: 0,
-            pQueueFamilyIndices: std::ptr::null()
+            device_memory: None,
+            memory_properties: None,
+            buffer_view: std::ptr::null_mut(),
+            view_format: None,
+            copied_regions: Vec::new(),
+            img_copied_regions : Vec::new()
         };
+        new_buffer.create_buffer()?;
+        let mem_req = new_buffer.load_memory_requirements();
+        new_buffer.allocate_memory(&mem_req, vulkan_types::MemoryPropertyFlags::HOST_VISIBLE)?;
+        Ok(new_buffer)
+    }
+
+    fn create_buffer(&mut self) -> Result<(), VulkanMemError>
+    {
+        let buffer_create_info = vulkan_structs::BufferCreateInfo::new()
+            .size(self.size)
+            .usage(self.usage)
+            .sharing_mode(vulkan_bindings::VkSharingMode_VK_SHARING_MODE_EXCLUSIVE);
         unsafe {
-            let mut new_buffer = VulkanBufferMem {
-                logical_device,
-                handle: std::ptr::null_mut(),
-                size,
-                usage,
-                device_memory: None,
-                buffer_view: std::ptr::null_mut(),
-                copied_regions: Vec::new(),
-                img_copied_regions : Vec::new()
-            };
-            let fn_vkCreateBuffer = vulkan_init::vkCreateBuffer.unwrap();
-            let result = fn_vkCreateBuffer(logical_device.device, &buffer_create_info, std::ptr::null(), &mut new_buffer.handle);
+            let logical_device = &*self.logical_device;
+            let fn_vkCreateBuffer = logical_device.fns.vkCreateBuffer.unwrap();
+            let result = fn_vkCreateBuffer(logical_device.device, &*buffer_create_info, logical_device.allocation_callbacks, &mut self.handle);
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanMemError::COULDNT_ALLOCATE_BUFFER);
+                return Err(VulkanMemError::COULDNT_ALLOCATE_BUFFER(vulkan_error::VulkanCallError::new("vkCreateBuffer", result)));
             }
-            let mem_req = new_buffer.load_memory_requirements();
-            new_buffer.allocate_memory(&mem_req, vulkan_bindings::VkMemoryPropertyFlagBits_VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT)?;
-            Ok(new_buffer)
         }
+        Ok(())
     }
 
     pub fn load_memory_requirements(&mut self) -> vulkan_bindings::VkMemoryRequirements
@@ -255,7 +488,7 @@ impl VulkanBufferMem
         unsafe
         {
             let mut mem_req = std::mem::zeroed();
-            let fn_vkGetBufferMemoryRequirements = vulkan_init::vkGetBufferMemoryRequirements.unwrap();
+            let fn_vkGetBufferMemoryRequirements = (*self.logical_device).fns.vkGetBufferMemoryRequirements.unwrap();
             let logical_device = (*self.logical_device).device;
             fn_vkGetBufferMemoryRequirements(logical_device, self.handle, &mut mem_req);
             mem_req
@@ -264,55 +497,58 @@ impl VulkanBufferMem
 
     pub fn allocate_memory(&mut self,
         mem_req: &vulkan_bindings::VkMemoryRequirements,
-        mem_props: vulkan_bindings::VkMemoryPropertyFlagBits
+        mem_props: vulkan_types::MemoryPropertyFlags
     ) -> Result<(), VulkanMemError>
     {
         unsafe
         {
-            let ref logical_device = *self.logical_device;
+            let logical_device = &*self.logical_device;
             let device_memory = VulkanDeviceMemory::new(logical_device, mem_req, mem_props)?;
-            let fn_vkBindBufferMemory = vulkan_init::vkBindBufferMemory.unwrap();
+            let fn_vkBindBufferMemory = (*self.logical_device).fns.vkBindBufferMemory.unwrap();
             let result = fn_vkBindBufferMemory(logical_device.device, self.handle, device_memory.handle, 0);
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanMemError::COULDNT_ALLOCATE_DEVICE_MEMORY);
+                return Err(VulkanMemError::COULDNT_BIND_BUFFER_MEMORY(vulkan_error::VulkanCallError::new("vkBindBufferMemory", result)));
             }
             self.device_memory = Some(device_memory);
+            self.memory_properties = Some(mem_props);
             Ok(())
         }
     }
 
     pub fn create_buffers_barriers(
+        logical_device: &vulkan_init::VulkanLogicalDevice,
         transitions: Vec<VulkanBufferTransition>,
         cmd_buffer: vulkan_bindings::VkCommandBuffer,
-        generating_stages: vulkan_bindings::VkPipelineStageFlags,
-        consuming_stages: vulkan_bindings::VkPipelineStageFlags
+        generating_stages: vulkan_types::PipelineStageFlags,
+        consuming_stages: vulkan_types::PipelineStageFlags
     ) -> Result<(), VulkanMemError>
     {
         let mut buffers_mem_barriers :Vec<vulkan_bindings::VkBufferMemoryBarrier>  = Vec::with_capacity(transitions.len());
         for transition in transitions.into_iter()
         {
+            let (src_family, dst_family) = queue_families(logical_device, transition.current_role, transition.new_role)?;
             buffers_mem_barriers.push(vulkan_bindings::VkBufferMemoryBarrier{
                 sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_BUFFER_MEMORY_BARRIER,
                 pNext: std::ptr::null(),
                 buffer: transition.buffer,
-                srcAccessMask: transition.current_access,
-                dstAccessMask: transition.new_access,
-                srcQueueFamilyIndex: transition.current_fam_queue,
-                dstQueueFamilyIndex: transition.new_fam_queue,
+                srcAccessMask: transition.current_access.as_raw(),
+                dstAccessMask: transition.new_access.as_raw(),
+                srcQueueFamilyIndex: src_family,
+                dstQueueFamilyIndex: dst_family,
                 offset: 0,
                 size: vulkan_bindings::VK_WHOLE_SIZE as u64
             });
         }
-        if buffers_mem_barriers.len() > 0
+        if !buffers_mem_barriers.is_empty()
         {
             unsafe
             {
-                let fn_vkCmdPipelineBarrier = vulkan_init::vkCmdPipelineBarrier.unwrap();
+                let fn_vkCmdPipelineBarrier = logical_device.fns.vkCmdPipelineBarrier.unwrap();
                 fn_vkCmdPipelineBarrier(
                     cmd_buffer,
-                    generating_stages,
-                    consuming_stages, 
+                    generating_stages.as_raw(),
+                    consuming_stages.as_raw(),
                     0, 
                     0, 
                     std::ptr::null(),
@@ -326,12 +562,31 @@ impl VulkanBufferMem
         Ok(())
     }
 
+    pub fn create_buffers_barriers2(
+        logical_device: &vulkan_init::VulkanLogicalDevice,
+        transitions: &[VulkanBufferTransition2],
+        cmd_buffer: vulkan_bindings::VkCommandBuffer
+    ) -> Result<(), VulkanMemError>
+    {
+        record_barriers2(logical_device, cmd_buffer, transitions, &[])
+    }
+
     pub fn create_buffer_view(&mut self, format: vulkan_bindings::VkFormat) -> Result<(), VulkanMemError>
     {
         unsafe
         {
-            let fn_vkCreateBufferView = vulkan_init::vkCreateBufferView.unwrap();
-            let ref logical_device = *self.logical_device;
+            let fn_vkCreateBufferView = (*self.logical_device).fns.vkCreateBufferView.unwrap();
+            let logical_device = &*self.logical_device;
+            let mut required_features = vulkan_types::FormatFeatureFlags::empty();
+            if self.usage.contains(vulkan_types::BufferUsageFlags::UNIFORM_TEXEL_BUFFER)
+            {
+                required_features |= vulkan_types::FormatFeatureFlags::UNIFORM_TEXEL_BUFFER;
+            }
+            if self.usage.contains(vulkan_types::BufferUsageFlags::STORAGE_TEXEL_BUFFER)
+            {
+                required_features |= vulkan_types::FormatFeatureFlags::STORAGE_TEXEL_BUFFER;
+            }
+            (*logical_device.physical_device).choose_buffer_format(&[format], required_features)?;
             let view_create_info = vulkan_bindings::VkBufferViewCreateInfo
             {
                 sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_BUFFER_VIEW_CREATE_INFO,
@@ -342,29 +597,28 @@ impl VulkanBufferMem
                 offset: 0,
                 range: self.size
             };
-            let result = fn_vkCreateBufferView(logical_device.device, &view_create_info, std::ptr::null(), &mut self.buffer_view);
+            let result = fn_vkCreateBufferView(logical_device.device, &view_create_info, logical_device.allocation_callbacks, &mut self.buffer_view);
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             || self.buffer_view == std::ptr::null_mut()
             {
-                return Err(VulkanMemError::FAILED_CREATING_BUFFER_VIEW);
+                return Err(VulkanMemError::FAILED_CREATING_BUFFER_VIEW(vulkan_error::VulkanCallError::new("vkCreateBufferView", result)));
             }
+            self.view_format = Some(format);
             Ok(())
         }
     }
 
     pub fn copy_buffer(&mut self, src: &VulkanBufferMem) -> Result<(), VulkanMemError>
     {
-        let dst_bit = vulkan_bindings::VkBufferUsageFlagBits_VK_BUFFER_USAGE_TRANSFER_DST_BIT as u32;
-        if self.usage & dst_bit != dst_bit
+        if !self.usage.contains(vulkan_types::BufferUsageFlags::TRANSFER_DST)
         {
             return Err(VulkanMemError::CANT_WRITE_TO_DST);
         }
-        let src_bit = vulkan_bindings::VkBufferUsageFlagBits_VK_BUFFER_USAGE_TRANSFER_SRC_BIT as u32;
-        if src.usage & src_bit != src_bit
+        if !src.usage.contains(vulkan_types::BufferUsageFlags::TRANSFER_SRC)
         {
             return Err(VulkanMemError::CANT_COPY_FROM_SRC);
         }
-        self.copied_regions.push(vulkan_bindings::VkBufferCopy { srcOffset: 0, dstOffset: 0, size: src.size as u64});
+        self.copied_regions.push(vulkan_bindings::VkBufferCopy { srcOffset: 0, dstOffset: 0, size: src.size});
         Ok(())
     }
 
@@ -372,11 +626,11 @@ impl VulkanBufferMem
     {
         unsafe
         {
-            if self.copied_regions.len() <= 0
+            if self.copied_regions.is_empty()
             {
-                return ();
+                return ;
             }
-            let fn_vkCmdCopyBuffer = vulkan_init::vkCmdCopyBuffer.unwrap();
+            let fn_vkCmdCopyBuffer = (*self.logical_device).fns.vkCmdCopyBuffer.unwrap();
             fn_vkCmdCopyBuffer(cmd_buffer, src.handle, self.handle, self.copied_regions.len() as u32, self.copied_regions.as_ptr());
             self.copied_regions.clear();
         }
@@ -384,14 +638,11 @@ impl VulkanBufferMem
 
     pub fn copy_image(&mut self, src_image:&VulkanImageMem) -> Result<(), VulkanMemError>
     {
-        let dst_usage_bit = vulkan_bindings::VkBufferUsageFlagBits_VK_BUFFER_USAGE_TRANSFER_DST_BIT as u32;
-        if self.usage & dst_usage_bit != dst_usage_bit
+        if !self.usage.contains(vulkan_types::BufferUsageFlags::TRANSFER_DST)
         {
             return Err(VulkanMemError::CANT_WRITE_TO_DST);
         }
-        let src_layout_bit = vulkan_bindings::VkImageLayout_VK_IMAGE_LAYOUT_TRANSFER_SRC_OPTIMAL;
-        let src_usage_bit = vulkan_bindings::VkImageUsageFlagBits_VK_IMAGE_USAGE_TRANSFER_SRC_BIT as u32;
-        if src_image.layout & src_layout_bit != src_layout_bit || src_image.usage & src_usage_bit != src_usage_bit
+        if src_image.layout != vulkan_types::ImageLayout::TRANSFER_SRC_OPTIMAL || !src_image.usage.contains(vulkan_types::ImageUsageFlags::TRANSFER_SRC)
         {
             return Err(VulkanMemError::CANT_COPY_FROM_SRC);
         }
@@ -415,40 +666,81 @@ impl VulkanBufferMem
     {
         unsafe
         {
-            if self.img_copied_regions.len() <= 0
+            if self.img_copied_regions.is_empty()
             {
-                return ()
+                return 
             }
-            let fn_vkCmdCopyImageToBuffer = vulkan_init::vkCmdCopyImageToBuffer.unwrap();
-            fn_vkCmdCopyImageToBuffer(cmd_buffer, src.handle, src.layout, self.handle, self.img_copied_regions.len() as u32, self.img_copied_regions.as_ptr());
+            let fn_vkCmdCopyImageToBuffer = (*self.logical_device).fns.vkCmdCopyImageToBuffer.unwrap();
+            fn_vkCmdCopyImageToBuffer(cmd_buffer, src.handle, src.layout.as_raw(), self.handle, self.img_copied_regions.len() as u32, self.img_copied_regions.as_ptr());
             self.img_copied_regions.clear();
         }
     }
 
-    pub fn destroy(mut self)
+    // the creation parameters are kept so the buffer can be rebuilt after a device loss, its content isn't
+    pub fn release(&mut self)
     {
-        match self.device_memory.take()
+        unsafe
         {
-            Some(d) => d.destroy(),
-            None => ()
+            let logical_device = &*self.logical_device;
+            if self.buffer_view != std::ptr::null_mut()
+            {
+                let fn_vkDestroyBufferView = logical_device.fns.vkDestroyBufferView.unwrap();
+                fn_vkDestroyBufferView(logical_device.device, self.buffer_view, logical_device.allocation_callbacks);
+                self.buffer_view = std::ptr::null_mut();
+            }
+            if self.handle != std::ptr::null_mut()
+            {
+                let fn_vkDestroyBuffer = logical_device.fns.vkDestroyBuffer.unwrap();
+                fn_vkDestroyBuffer(logical_device.device, self.handle, logical_device.allocation_callbacks);
+                self.handle = std::ptr::null_mut();
+            }
+            if let Some(device_memory) = self.device_memory.as_mut()
+            {
+                device_memory.release();
+            }
+            self.device_memory = None;
+            self.copied_regions.clear();
+            self.img_copied_regions.clear();
         }
     }
+
+    pub fn recreate(&mut self) -> Result<(), VulkanMemError>
+    {
+        self.create_buffer()?;
+        if let Some(mem_props) = self.memory_properties
+        {
+            let mem_req = self.load_memory_requirements();
+            self.allocate_memory(&mem_req, mem_props)?;
+        }
+        if let Some(format) = self.view_format
+        {
+            self.create_buffer_view(format)?;
+        }
+        Ok(())
+    }
+
+    pub fn destroy(mut self)
+    {
+        if let Some(d) = self.device_memory.take() { d.destroy() }
+    }
 }
 
 pub struct VulkanImageMem
 {
     logical_device: *const vulkan_init::VulkanLogicalDevice,
     pub handle : vulkan_bindings::VkImage,
-    pub img_type : vulkan_bindings::VkImageType,
+    pub img_type : vulkan_types::ImageType,
     pub format : vulkan_bindings::VkFormat,
     pub dimensions: vulkan_bindings::VkExtent3D,
     pub mipmap_lvl : u32,
     pub layer_num : u32,
-    pub sample_count: vulkan_bindings::VkSampleCountFlagBits,
-    pub usage: vulkan_bindings::VkImageUsageFlags,
-    pub layout: vulkan_bindings::VkImageLayout,
+    pub sample_count: vulkan_types::SampleCountFlags,
+    pub usage: vulkan_types::ImageUsageFlags,
+    pub layout: vulkan_types::ImageLayout,
     pub device_memory: Option<VulkanDeviceMemory>,
+    pub memory_properties: Option<vulkan_types::MemoryPropertyFlags>,
     pub view: vulkan_bindings::VkImageView,
+    pub view_info: Option<(vulkan_types::ImageViewType, vulkan_types::ImageAspectFlags)>,
     pub copy_regions: Vec<vulkan_bindings::VkBufferImageCopy>
 }
 
@@ -456,19 +748,27 @@ impl VulkanImageMem
 {
     pub fn new(
         logical_device: &vulkan_init::VulkanLogicalDevice,
-        img_type : vulkan_bindings::VkImageType,
+        img_type : vulkan_types::ImageType,
         format : vulkan_bindings::VkFormat,
         dimensions: vulkan_bindings::VkExtent3D,
         mipmap_lvl : u32,
         layer_num : u32,
-        sample_count: vulkan_bindings::VkSampleCountFlagBits,
-        usage: vulkan_bindings::VkImageUsageFlags,
-        layout: vulkan_bindings::VkImageLayout
+        sample_count: vulkan_types::SampleCountFlags,
+        usage: vulkan_types::ImageUsageFlags,
+        layout: vulkan_types::ImageLayout
     ) -> Result<Self, VulkanMemError>
     {
         unsafe
         {
-            let fn_vkCreateImage = vulkan_init::vkCreateImage.unwrap();
+            (*logical_device.physical_device).check_image_support(format,
+                img_type,
+                vulkan_types::ImageTiling::OPTIMAL,
+                usage,
+                &dimensions,
+                mipmap_lvl,
+                layer_num,
+                sample_count
+            )?;
             let mut new_image = VulkanImageMem {
                 logical_device,
                 handle: std::ptr::null_mut(),
@@ -481,41 +781,48 @@ impl VulkanImageMem
                 usage,
                 layout,
                 device_memory:None,
+                memory_properties: None,
                 view: std::ptr::null_mut(),
+                view_info: None,
                 copy_regions: Vec::new()
             };
-            let image_creation_info = vulkan_bindings::VkImageCreateInfo {
-                sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_IMAGE_CREATE_INFO,
-                pNext: std::ptr::null(),
-                flags: 0,
-                imageType: new_image.img_type,
-                format: new_image.format,
-                extent: new_image.dimensions,
-                mipLevels: new_image.mipmap_lvl,
-                arrayLayers: new_image.layer_num,
-                samples: new_image.sample_count,
-                tiling: vulkan_bindings::VkImageTiling_VK_IMAGE_TILING_OPTIMAL,
-                usage: new_image.usage,
-                sharingMode: vulkan_bindings::VkSharingMode_VK_SHARING_MODE_EXCLUSIVE,
-                queueFamilyIndexCount: 0,
-                pQueueFamilyIndices: std::ptr::null(),
-                initialLayout: new_image.layout
-            };
-            let result = fn_vkCreateImage(logical_device.device, &image_creation_info, std::ptr::null(), &mut new_image.handle);
+            new_image.create_image()?;
+            let mem_req = new_image.load_memory_requirements();
+            new_image.allocate_memory(mem_req, vulkan_types::MemoryPropertyFlags::empty())?;
+            Ok(new_image)
+        }
+    }
+
+    fn create_image(&mut self) -> Result<(), VulkanMemError>
+    {
+        unsafe
+        {
+            let logical_device = &*self.logical_device;
+            let fn_vkCreateImage = logical_device.fns.vkCreateImage.unwrap();
+            let image_creation_info = vulkan_structs::ImageCreateInfo::new()
+                .image_type(self.img_type)
+                .format(self.format)
+                .extent(self.dimensions)
+                .mip_levels(self.mipmap_lvl)
+                .array_layers(self.layer_num)
+                .samples(self.sample_count)
+                .tiling(vulkan_types::ImageTiling::OPTIMAL)
+                .usage(self.usage)
+                .sharing_mode(vulkan_bindings::VkSharingMode_VK_SHARING_MODE_EXCLUSIVE)
+                .initial_layout(self.layout);
+            let result = fn_vkCreateImage(logical_device.device, &*image_creation_info, logical_device.allocation_callbacks, &mut self.handle);
             if result !=  vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanMemError::COULDNT_ALLOCATE_IMAGE);
+                return Err(VulkanMemError::COULDNT_ALLOCATE_IMAGE(vulkan_error::VulkanCallError::new("vkCreateImage", result)));
             }
-            let mem_req = new_image.load_memory_requirements();
-            new_image.allocate_memory(mem_req, 0)?;
-            Ok(new_image)
         }
+        Ok(())
     }
 
     pub fn load_memory_requirements(&self) -> vulkan_bindings::VkMemoryRequirements
     {
         unsafe{
-            let fn_vkGetImageMemoryRequirements = vulkan_init::vkGetImageMemoryRequirements.unwrap();
+            let fn_vkGetImageMemoryRequirements = (*self.logical_device).fns.vkGetImageMemoryRequirements.unwrap();
             let mut mem_reqs : vulkan_bindings::VkMemoryRequirements= std::mem::zeroed();
             let logical_device = (*self.logical_device).device;
             fn_vkGetImageMemoryRequirements(logical_device, self.handle, &mut mem_reqs);
@@ -525,20 +832,21 @@ impl VulkanImageMem
 
     pub fn allocate_memory(&mut self,
         mem_req : vulkan_bindings::VkMemoryRequirements,
-        mem_props: vulkan_bindings::VkMemoryPropertyFlagBits
+        mem_props: vulkan_types::MemoryPropertyFlags
     ) -> Result<(), VulkanMemError>
     {
         unsafe
         {
-            let ref logical_device = *self.logical_device;
+            let logical_device = &*self.logical_device;
             let device_memory = VulkanDeviceMemory::new(logical_device, &mem_req, mem_props)?;
-            let fn_vkBindImageMemory = vulkan_init::vkBindImageMemory.unwrap();
+            let fn_vkBindImageMemory = (*self.logical_device).fns.vkBindImageMemory.unwrap();
             let result = fn_vkBindImageMemory(logical_device.device, self.handle, device_memory.handle, 0);
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanMemError::COULDNT_BIND_IMAGE_MEMORY);
+                return Err(VulkanMemError::COULDNT_BIND_IMAGE_MEMORY(vulkan_error::VulkanCallError::new("vkBindImageMemory", result)));
             }
             self.device_memory = Some(device_memory);
+            self.memory_properties = Some(mem_props);
             Ok(())
         }
     }
@@ -546,25 +854,27 @@ impl VulkanImageMem
     pub fn create_image_barrier(&mut self,
         transitions : Vec<VulkanImageTransition>,
         cmd_buffer: vulkan_bindings::VkCommandBuffer,
-        generating_stages: vulkan_bindings::VkPipelineStageFlags,
-        consuming_stages: vulkan_bindings::VkPipelineStageFlags
-    )
+        generating_stages: vulkan_types::PipelineStageFlags,
+        consuming_stages: vulkan_types::PipelineStageFlags
+    ) -> Result<(), VulkanMemError>
     {
+        let logical_device = unsafe { &*self.logical_device };
         let mut image_barriers : Vec<vulkan_bindings::VkImageMemoryBarrier> = Vec::with_capacity(transitions.len());
         for transition in transitions
         {
+            let (src_family, dst_family) = queue_families(logical_device, transition.current_role, transition.new_role)?;
             image_barriers.push(vulkan_bindings::VkImageMemoryBarrier {
                 sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_IMAGE_MEMORY_BARRIER,
                 pNext: std::ptr::null(),
-                srcAccessMask: transition.current_access,
-                dstAccessMask: transition.new_access,
-                oldLayout: transition.current_layout,
-                newLayout: transition.new_layout,
-                srcQueueFamilyIndex: transition.current_fam_queue,
-                dstQueueFamilyIndex: transition.new_fam_queue,
+                srcAccessMask: transition.current_access.as_raw(),
+                dstAccessMask: transition.new_access.as_raw(),
+                oldLayout: transition.current_layout.as_raw(),
+                newLayout: transition.new_layout.as_raw(),
+                srcQueueFamilyIndex: src_family,
+                dstQueueFamilyIndex: dst_family,
                 image: transition.image,
                 subresourceRange: vulkan_bindings::VkImageSubresourceRange{
-                    aspectMask: transition.aspect,
+                    aspectMask: transition.aspect.as_raw(),
                     baseMipLevel: 0,
                     levelCount: vulkan_bindings::VK_REMAINING_MIP_LEVELS as u32,
                     baseArrayLayer: 0,
@@ -572,15 +882,15 @@ impl VulkanImageMem
                 }
             });
         }
-        if image_barriers.len() > 0
+        if !image_barriers.is_empty()
         {
             unsafe
             {
-                let fn_vkCmdPipelineBarrier = vulkan_init::vkCmdPipelineBarrier.unwrap();
+                let fn_vkCmdPipelineBarrier = logical_device.fns.vkCmdPipelineBarrier.unwrap();
                 fn_vkCmdPipelineBarrier(
                     cmd_buffer, 
-                    generating_stages,
-                    consuming_stages,
+                    generating_stages.as_raw(),
+                    consuming_stages.as_raw(),
                     0,
                     0,
                     std::ptr::null(),
@@ -591,49 +901,55 @@ impl VulkanImageMem
                 );
             }
         }
+        Ok(())
     }
 
-    pub fn create_image_view(&mut self, view_type:  vulkan_bindings::VkImageViewType, aspect: vulkan_bindings::VkImageAspectFlags) -> Result<(), VulkanMemError>
+    pub fn create_image_barrier2(&mut self,
+        transitions : &[VulkanImageTransition2],
+        cmd_buffer: vulkan_bindings::VkCommandBuffer
+    ) -> Result<(), VulkanMemError>
     {
         unsafe
         {
-            let fn_vkCreateImageView = vulkan_init::vkCreateImageView.unwrap();
-            let image_view_create_info = vulkan_bindings::VkImageViewCreateInfo{
-                sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_IMAGE_VIEW_CREATE_INFO,
-                pNext: std::ptr::null(),
-                flags: 0,
-                image: self.handle,
-                viewType: view_type,
-                format: self.format,
-                components: std::mem::zeroed(),
-                subresourceRange : vulkan_bindings::VkImageSubresourceRange { 
-                    aspectMask: aspect,
+            record_barriers2(&*self.logical_device, cmd_buffer, &[], transitions)
+        }
+    }
+
+    pub fn create_image_view(&mut self, view_type: vulkan_types::ImageViewType, aspect: vulkan_types::ImageAspectFlags) -> Result<(), VulkanMemError>
+    {
+        unsafe
+        {
+            let fn_vkCreateImageView = (*self.logical_device).fns.vkCreateImageView.unwrap();
+            let image_view_create_info = vulkan_structs::ImageViewCreateInfo::new()
+                .image(self.handle)
+                .view_type(view_type)
+                .format(self.format)
+                .subresource_range(vulkan_bindings::VkImageSubresourceRange {
+                    aspectMask: aspect.as_raw(),
                     baseMipLevel: 0,
                     levelCount: vulkan_bindings::VK_REMAINING_MIP_LEVELS as u32,
                     baseArrayLayer: 0,
                     layerCount: vulkan_bindings::VK_REMAINING_ARRAY_LAYERS as u32
-                }
-            };
+                });
+            let allocator = (*self.logical_device).allocation_callbacks;
             let logical_device = (*self.logical_device).device;
-            let result = fn_vkCreateImageView(logical_device, &image_view_create_info, std::ptr::null(), &mut self.view);
+            let result = fn_vkCreateImageView(logical_device, &*image_view_create_info, allocator, &mut self.view);
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanMemError::FAILED_CREATING_IMAGE_VIEW);
+                return Err(VulkanMemError::FAILED_CREATING_IMAGE_VIEW(vulkan_error::VulkanCallError::new("vkCreateImageView", result)));
             }
+            self.view_info = Some((view_type, aspect));
             Ok(())
         }
     }
 
     pub fn copy_buffer(&mut self, src: &VulkanBufferMem) -> Result<(), VulkanMemError>
     {
-        let dst_layout_bit = vulkan_bindings::VkImageLayout_VK_IMAGE_LAYOUT_TRANSFER_DST_OPTIMAL;
-        let dst_usage_bit = vulkan_bindings::VkImageUsageFlagBits_VK_IMAGE_USAGE_TRANSFER_DST_BIT as u32;
-        if self.layout & dst_layout_bit != dst_layout_bit || self.usage & dst_usage_bit != dst_usage_bit
+        if self.layout != vulkan_types::ImageLayout::TRANSFER_DST_OPTIMAL || !self.usage.contains(vulkan_types::ImageUsageFlags::TRANSFER_DST)
         {
             return Err(VulkanMemError::CANT_WRITE_TO_DST);
         }
-        let src_usage_bit = vulkan_bindings::VkBufferUsageFlagBits_VK_BUFFER_USAGE_TRANSFER_SRC_BIT as u32;
-        if src.usage & src_usage_bit != src_usage_bit
+        if !src.usage.contains(vulkan_types::BufferUsageFlags::TRANSFER_SRC)
         {
             return Err(VulkanMemError::CANT_COPY_FROM_SRC);
         }
@@ -657,12 +973,12 @@ impl VulkanImageMem
     {
         unsafe
         {
-            if self.copy_regions.len() <= 0
+            if self.copy_regions.is_empty()
             {
-                return ();
+                return ;
             }
-            let fn_vkCmdCopyBufferToImage = vulkan_init::vkCmdCopyBufferToImage.unwrap();
-            fn_vkCmdCopyBufferToImage(cmd_buffer, src.handle, self.handle, self.layout, self.copy_regions.len() as u32, self.copy_regions.as_ptr());
+            let fn_vkCmdCopyBufferToImage = (*self.logical_device).fns.vkCmdCopyBufferToImage.unwrap();
+            fn_vkCmdCopyBufferToImage(cmd_buffer, src.handle, self.handle, self.layout.as_raw(), self.copy_regions.len() as u32, self.copy_regions.as_ptr());
             self.copy_regions.clear();
         }
     }
@@ -675,25 +991,59 @@ impl VulkanImageMem
             {
                 return;
             }
-            let fn_vkDestroyImageView = vulkan_init::vkDestroyImageView.unwrap();
+            let fn_vkDestroyImageView = (*self.logical_device).fns.vkDestroyImageView.unwrap();
+            let allocator = (*self.logical_device).allocation_callbacks;
             let logical_device = (*self.logical_device).device;
-            fn_vkDestroyImageView(logical_device, self.view, std::ptr::null());
+            fn_vkDestroyImageView(logical_device, self.view, allocator);
+            self.view = std::ptr::null_mut();
         }
     }
 
+    // same as the buffers, the image is rebuilt from its creation parameters but its content is lost
+    pub fn release(&mut self)
+    {
+        self.destroy_view();
+        unsafe
+        {
+            if self.handle != std::ptr::null_mut()
+            {
+                let fn_vkDestroyImage = (*self.logical_device).fns.vkDestroyImage.unwrap();
+                fn_vkDestroyImage((*self.logical_device).device, self.handle, (*self.logical_device).allocation_callbacks);
+                self.handle = std::ptr::null_mut();
+            }
+        }
+        if let Some(device_memory) = self.device_memory.as_mut()
+        {
+            device_memory.release();
+        }
+        self.device_memory = None;
+        self.copy_regions.clear();
+    }
 
-    pub fn destroy(mut self)
+    pub fn recreate(&mut self) -> Result<(), VulkanMemError>
     {
-        match self.device_memory.take()
+        self.create_image()?;
+        if let Some(mem_props) = self.memory_properties
         {
-            Some(d) => d.destroy(),
-            None => ()
+            let mem_req = self.load_memory_requirements();
+            self.allocate_memory(mem_req, mem_props)?;
         }
+        if let Some((view_type, aspect)) = self.view_info
+        {
+            self.create_image_view(view_type, aspect)?;
+        }
+        Ok(())
+    }
+
+    pub fn destroy(mut self)
+    {
+        if let Some(d) = self.device_memory.take() { d.destroy() }
         unsafe
         {
-            let fn_vkDestroyImage = vulkan_init::vkDestroyImage.unwrap();
+            let fn_vkDestroyImage = (*self.logical_device).fns.vkDestroyImage.unwrap();
+            let allocator = (*self.logical_device).allocation_callbacks;
             let logical_device = (*self.logical_device).device;
-            fn_vkDestroyImage(logical_device, self.handle, std::ptr::null())
+            fn_vkDestroyImage(logical_device, self.handle, allocator)
         }
     }
 }
@@ -0,0 +1,356 @@
+use std::path::{Path, PathBuf};
+use crate::vulkan_bindings;
+use crate::vulkan_init;
+use crate::vulkan_error;
+
+// headerSize, headerVersion, vendorID, deviceID and the 16 bytes pipelineCacheUUID
+pub const PIPELINE_CACHE_HEADER_SIZE: usize = 32;
+
+#[derive(Debug)]
+pub enum VulkanPipelineCacheError {
+    UNREADABLE_CACHE_FILE(PathBuf, String),
+    UNWRITABLE_CACHE_FILE(PathBuf, String),
+    FAILED_CREATING_PIPELINE_CACHE(vulkan_error::VulkanCallError),
+    FAILED_READING_PIPELINE_CACHE_DATA(vulkan_error::VulkanCallError),
+    NO_PIPELINE_CACHE_DIRECTORY
+}
+
+impl VulkanPipelineCacheError {
+    pub fn call_error(& self) -> Option<&vulkan_error::VulkanCallError>
+    {
+        match self {
+            VulkanPipelineCacheError::FAILED_CREATING_PIPELINE_CACHE(e)
+            | VulkanPipelineCacheError::FAILED_READING_PIPELINE_CACHE_DATA(e) => Some(e),
+            _ => None
+        }
+    }
+}
+
+impl std::fmt::Display for VulkanPipelineCacheError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VulkanPipelineCacheError::UNREADABLE_CACHE_FILE(path, reason) => write!(f, "Couldn't read pipeline cache {}: {}", path.display(), reason),
+            VulkanPipelineCacheError::UNWRITABLE_CACHE_FILE(path, reason) => write!(f, "Couldn't write pipeline cache {}: {}", path.display(), reason),
+            VulkanPipelineCacheError::FAILED_CREATING_PIPELINE_CACHE(_) => write!(f, "Couldn't create pipeline cache"),
+            VulkanPipelineCacheError::FAILED_READING_PIPELINE_CACHE_DATA(_) => write!(f, "Couldn't read pipeline cache data"),
+            VulkanPipelineCacheError::NO_PIPELINE_CACHE_DIRECTORY => write!(f, "No pipeline cache directory was given")
+        }
+    }
+}
+
+impl std::error::Error for VulkanPipelineCacheError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.call_error().map(|e| e as &(dyn std::error::Error + 'static))
+    }
+}
+
+// why a cache file on disk wasn't handed to the driver
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VulkanPipelineCacheRejection {
+    TRUNCATED(usize),
+    WRONG_HEADER_SIZE(u32),
+    WRONG_HEADER_VERSION(u32),
+    VENDOR_MISMATCH(u32),
+    DEVICE_MISMATCH(u32),
+    UUID_MISMATCH
+}
+
+impl std::fmt::Display for VulkanPipelineCacheRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VulkanPipelineCacheRejection::TRUNCATED(size) => write!(f, "cache file is truncated ({} bytes)", size),
+            VulkanPipelineCacheRejection::WRONG_HEADER_SIZE(size) => write!(f, "cache header has an invalid size {}", size),
+            VulkanPipelineCacheRejection::WRONG_HEADER_VERSION(version) => write!(f, "cache header version {} isn't supported", version),
+            VulkanPipelineCacheRejection::VENDOR_MISMATCH(vendor) => write!(f, "cache was created by vendor {:#x}", vendor),
+            VulkanPipelineCacheRejection::DEVICE_MISMATCH(device) => write!(f, "cache was created by device {:#x}", device),
+            VulkanPipelineCacheRejection::UUID_MISMATCH => write!(f, "cache uuid doesn't match the driver's")
+        }
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32
+{
+    u32::from_le_bytes([data[offset], data[offset + 1], data[offset + 2], data[offset + 3]])
+}
+
+// the header fields are always stored least significant byte first whatever the host is
+pub fn validate_header(data: &[u8], properties: &vulkan_bindings::VkPhysicalDeviceProperties) -> Result<(), VulkanPipelineCacheRejection>
+{
+    if data.len() < PIPELINE_CACHE_HEADER_SIZE
+    {
+        return Err(VulkanPipelineCacheRejection::TRUNCATED(data.len()));
+    }
+    let header_size = read_u32(data, 0);
+    if (header_size as usize) < PIPELINE_CACHE_HEADER_SIZE
+    {
+        return Err(VulkanPipelineCacheRejection::WRONG_HEADER_SIZE(header_size));
+    }
+    if data.len() < header_size as usize
+    {
+        return Err(VulkanPipelineCacheRejection::TRUNCATED(data.len()));
+    }
+    let header_version = read_u32(data, 4);
+    if header_version != vulkan_bindings::VkPipelineCacheHeaderVersion_VK_PIPELINE_CACHE_HEADER_VERSION_ONE
+    {
+        return Err(VulkanPipelineCacheRejection::WRONG_HEADER_VERSION(header_version));
+    }
+    let vendor_id = read_u32(data, 8);
+    if vendor_id != properties.vendorID
+    {
+        return Err(VulkanPipelineCacheRejection::VENDOR_MISMATCH(vendor_id));
+    }
+    let device_id = read_u32(data, 12);
+    if device_id != properties.deviceID
+    {
+        return Err(VulkanPipelineCacheRejection::DEVICE_MISMATCH(device_id));
+    }
+    if data[16..PIPELINE_CACHE_HEADER_SIZE] != properties.pipelineCacheUUID[..]
+    {
+        return Err(VulkanPipelineCacheRejection::UUID_MISMATCH);
+    }
+    Ok(())
+}
+
+pub const PIPELINE_CACHE_DIR_ENV: &str = "ANVIL_PIPELINE_CACHE_DIR";
+
+pub fn default_cache_directory() -> PathBuf
+{
+    match std::env::var(PIPELINE_CACHE_DIR_ENV) {
+        Ok(directory) if !directory.is_empty() => PathBuf::from(directory),
+        _ => std::env::temp_dir().join("anvil")
+    }
+}
+
+pub fn cache_file_name(properties: &vulkan_bindings::VkPhysicalDeviceProperties) -> String
+{
+    format!("pipeline_cache_{:04x}_{:04x}.bin", properties.vendorID, properties.deviceID)
+}
+
+pub struct VulkanPipelineCache {
+    pub handle: vulkan_bindings::VkPipelineCache,
+    pub path: Option<PathBuf>,
+    pub rejection: Option<VulkanPipelineCacheRejection>
+}
+
+impl VulkanPipelineCache {
+    pub fn new() -> Self
+    {
+        VulkanPipelineCache {
+            handle: std::ptr::null_mut(),
+            path: None,
+            rejection: None
+        }
+    }
+
+    // a missing file just means a cold start, stale or corrupted files are dropped and an empty cache is created
+    pub fn load(&mut self,
+        fns: &vulkan_init::DeviceFns,
+        device: vulkan_bindings::VkDevice,
+        allocator: *const vulkan_bindings::VkAllocationCallbacks,
+        properties: &vulkan_bindings::VkPhysicalDeviceProperties,
+        directory: &Path
+    ) -> Result<(), VulkanPipelineCacheError>
+    {
+        let path = directory.join(cache_file_name(properties));
+        let mut data = match std::fs::read(&path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(VulkanPipelineCacheError::UNREADABLE_CACHE_FILE(path, e.to_string()))
+        };
+        self.rejection = None;
+        if !data.is_empty()
+        {
+            if let Err(rejection) = validate_header(&data, properties)
+            {
+                self.rejection = Some(rejection);
+                data.clear();
+            }
+        }
+        self.path = Some(path);
+        self.create(fns, device, allocator, &data)
+    }
+
+    pub fn create(&mut self,
+        fns: &vulkan_init::DeviceFns,
+        device: vulkan_bindings::VkDevice,
+        allocator: *const vulkan_bindings::VkAllocationCallbacks,
+        initial_data: &[u8]
+    ) -> Result<(), VulkanPipelineCacheError>
+    {
+        self.destroy(fns, device, allocator);
+        let create_info = vulkan_bindings::VkPipelineCacheCreateInfo {
+            sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_PIPELINE_CACHE_CREATE_INFO,
+            pNext: std::ptr::null(),
+            flags: 0,
+            initialDataSize: initial_data.len(),
+            pInitialData: if initial_data.is_empty() { std::ptr::null() } else { initial_data.as_ptr() as *const std::ffi::c_void }
+        };
+        unsafe {
+            let fn_vkCreatePipelineCache = match fns.vkCreatePipelineCache {
+                Some(f) => f,
+                None => return Err(VulkanPipelineCacheError::FAILED_CREATING_PIPELINE_CACHE(vulkan_error::VulkanCallError::missing("vkCreatePipelineCache")))
+            };
+            let result = fn_vkCreatePipelineCache(device, &create_info, allocator, &mut self.handle);
+            if result != vulkan_bindings::VkResult_VK_SUCCESS
+            {
+                self.handle = std::ptr::null_mut();
+                return Err(VulkanPipelineCacheError::FAILED_CREATING_PIPELINE_CACHE(vulkan_error::VulkanCallError::new("vkCreatePipelineCache", result)));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn data(& self, fns: &vulkan_init::DeviceFns, device: vulkan_bindings::VkDevice) -> Result<Vec<u8>, VulkanPipelineCacheError>
+    {
+        unsafe {
+            let fn_vkGetPipelineCacheData = match fns.vkGetPipelineCacheData {
+                Some(f) => f,
+                None => return Err(VulkanPipelineCacheError::FAILED_READING_PIPELINE_CACHE_DATA(vulkan_error::VulkanCallError::missing("vkGetPipelineCacheData")))
+            };
+            let mut size : usize = 0;
+            let result = fn_vkGetPipelineCacheData(device, self.handle, &mut size, std::ptr::null_mut());
+            if result != vulkan_bindings::VkResult_VK_SUCCESS
+            {
+                return Err(VulkanPipelineCacheError::FAILED_READING_PIPELINE_CACHE_DATA(vulkan_error::VulkanCallError::new("vkGetPipelineCacheData", result)));
+            }
+            let mut data = vec![0u8; size];
+            let result = fn_vkGetPipelineCacheData(device, self.handle, &mut size, data.as_mut_ptr() as *mut std::ffi::c_void);
+            if result != vulkan_bindings::VkResult_VK_SUCCESS
+            {
+                return Err(VulkanPipelineCacheError::FAILED_READING_PIPELINE_CACHE_DATA(vulkan_error::VulkanCallError::new("vkGetPipelineCacheData", result)));
+            }
+            data.truncate(size);
+            Ok(data)
+        }
+    }
+
+    // written next to the target then renamed so a crash mid-write never leaves a half cache behind
+    pub fn save(& self, fns: &vulkan_init::DeviceFns, device: vulkan_bindings::VkDevice) -> Result<(), VulkanPipelineCacheError>
+    {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Err(VulkanPipelineCacheError::NO_PIPELINE_CACHE_DIRECTORY)
+        };
+        let data = self.data(fns, device)?;
+        if let Some(directory) = path.parent()
+        {
+            std::fs::create_dir_all(directory)
+                .map_err(|e| VulkanPipelineCacheError::UNWRITABLE_CACHE_FILE(directory.to_path_buf(), e.to_string()))?;
+        }
+        let tmp_path = path.with_extension("tmp");
+        std::fs::write(&tmp_path, &data)
+            .map_err(|e| VulkanPipelineCacheError::UNWRITABLE_CACHE_FILE(tmp_path.clone(), e.to_string()))?;
+        std::fs::rename(&tmp_path, path)
+            .map_err(|e| VulkanPipelineCacheError::UNWRITABLE_CACHE_FILE(path.clone(), e.to_string()))?;
+        Ok(())
+    }
+
+    pub fn destroy(&mut self, fns: &vulkan_init::DeviceFns, device: vulkan_bindings::VkDevice, allocator: *const vulkan_bindings::VkAllocationCallbacks)
+    {
+        if self.handle == std::ptr::null_mut()
+        {
+            return;
+        }
+        unsafe {
+            if let Some(fn_vkDestroyPipelineCache) = fns.vkDestroyPipelineCache
+            {
+                fn_vkDestroyPipelineCache(device, self.handle, allocator);
+            }
+        }
+        self.handle = std::ptr::null_mut();
+    }
+}
+
+impl Default for VulkanPipelineCache {
+    fn default() -> Self {
+        VulkanPipelineCache::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn properties() -> vulkan_bindings::VkPhysicalDeviceProperties
+    {
+        let mut properties: vulkan_bindings::VkPhysicalDeviceProperties = unsafe { std::mem::zeroed() };
+        properties.vendorID = 0x10de;
+        properties.deviceID = 0x2684;
+        properties.pipelineCacheUUID = [7; 16];
+        properties
+    }
+
+    // a header the properties above accept followed by some driver data
+    fn cache_data(properties: &vulkan_bindings::VkPhysicalDeviceProperties) -> Vec<u8>
+    {
+        let mut data = Vec::new();
+        data.extend_from_slice(&(PIPELINE_CACHE_HEADER_SIZE as u32).to_le_bytes());
+        data.extend_from_slice(&vulkan_bindings::VkPipelineCacheHeaderVersion_VK_PIPELINE_CACHE_HEADER_VERSION_ONE.to_le_bytes());
+        data.extend_from_slice(&properties.vendorID.to_le_bytes());
+        data.extend_from_slice(&properties.deviceID.to_le_bytes());
+        data.extend_from_slice(&properties.pipelineCacheUUID);
+        data.extend_from_slice(&[0xab; 64]);
+        data
+    }
+
+    #[test]
+    fn matching_headers_are_accepted()
+    {
+        let properties = properties();
+        assert!(validate_header(&cache_data(&properties), &properties).is_ok());
+        assert!(validate_header(&cache_data(&properties)[..PIPELINE_CACHE_HEADER_SIZE], &properties).is_ok());
+    }
+
+    #[test]
+    fn short_data_is_truncated()
+    {
+        let properties = properties();
+        let data = cache_data(&properties);
+        assert_eq!(validate_header(&[], &properties), Err(VulkanPipelineCacheRejection::TRUNCATED(0)));
+        assert_eq!(validate_header(&data[..31], &properties), Err(VulkanPipelineCacheRejection::TRUNCATED(31)));
+    }
+
+    #[test]
+    fn header_size_has_to_fit_the_header_and_the_data()
+    {
+        let properties = properties();
+        let mut data = cache_data(&properties);
+        data[0..4].copy_from_slice(&16u32.to_le_bytes());
+        assert_eq!(validate_header(&data, &properties), Err(VulkanPipelineCacheRejection::WRONG_HEADER_SIZE(16)));
+        let length = data.len();
+        data[0..4].copy_from_slice(&(length as u32 + 1).to_le_bytes());
+        assert_eq!(validate_header(&data, &properties), Err(VulkanPipelineCacheRejection::TRUNCATED(length)));
+        // a bigger header than the one version one describes is fine as long as the data holds it
+        data[0..4].copy_from_slice(&(length as u32).to_le_bytes());
+        assert!(validate_header(&data, &properties).is_ok());
+    }
+
+    #[test]
+    fn other_header_versions_are_rejected()
+    {
+        let properties = properties();
+        let mut data = cache_data(&properties);
+        data[4..8].copy_from_slice(&2u32.to_le_bytes());
+        assert_eq!(validate_header(&data, &properties), Err(VulkanPipelineCacheRejection::WRONG_HEADER_VERSION(2)));
+    }
+
+    #[test]
+    fn caches_from_another_device_are_rejected()
+    {
+        let properties = properties();
+        let data = cache_data(&properties);
+
+        let mut other_vendor = properties;
+        other_vendor.vendorID = 0x1002;
+        assert_eq!(validate_header(&data, &other_vendor), Err(VulkanPipelineCacheRejection::VENDOR_MISMATCH(0x10de)));
+
+        let mut other_device = properties;
+        other_device.deviceID = 0x2204;
+        assert_eq!(validate_header(&data, &other_device), Err(VulkanPipelineCacheRejection::DEVICE_MISMATCH(0x2684)));
+
+        // same device after a driver update
+        let mut other_driver = properties;
+        other_driver.pipelineCacheUUID[15] = 8;
+        assert_eq!(validate_header(&data, &other_driver), Err(VulkanPipelineCacheRejection::UUID_MISMATCH));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::vulkan_init;
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
+use crate::vulkan_window;
+use crate::vulkan_synchro;
+use crate::vulkan_mem;
+use crate::vulkan_error;
+
+// anything holding handles created from the logical device, release drops them without touching the
+// (lost) content and recreate rebuilds them once the device is back
+pub trait VulkanRecoverable {
+    fn release(&mut self);
+    fn recreate(&mut self) -> Result<(), vulkan_error::VulkanError>;
+}
+
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
+impl VulkanRecoverable for vulkan_window::VulkanSurface {
+    fn release(&mut self)
+    {
+        self.release_swapchain();
+    }
+
+    fn recreate(&mut self) -> Result<(), vulkan_error::VulkanError>
+    {
+        Ok(self.recreate_swapchain()?)
+    }
+}
+
+impl VulkanRecoverable for vulkan_synchro::VulkanCmdPool {
+    fn release(&mut self)
+    {
+        vulkan_synchro::VulkanCmdPool::release(self);
+    }
+
+    fn recreate(&mut self) -> Result<(), vulkan_error::VulkanError>
+    {
+        Ok(vulkan_synchro::VulkanCmdPool::recreate(self)?)
+    }
+}
+
+impl VulkanRecoverable for vulkan_mem::VulkanBufferMem {
+    fn release(&mut self)
+    {
+        vulkan_mem::VulkanBufferMem::release(self);
+    }
+
+    fn recreate(&mut self) -> Result<(), vulkan_error::VulkanError>
+    {
+        Ok(vulkan_mem::VulkanBufferMem::recreate(self)?)
+    }
+}
+
+impl VulkanRecoverable for vulkan_mem::VulkanImageMem {
+    fn release(&mut self)
+    {
+        vulkan_mem::VulkanImageMem::release(self);
+    }
+
+    fn recreate(&mut self) -> Result<(), vulkan_error::VulkanError>
+    {
+        Ok(vulkan_mem::VulkanImageMem::recreate(self)?)
+    }
+}
+
+// registered resources are kept as raw pointers like the rest of the engine,
+// they must outlive the recovery or be unregistered before being dropped
+pub struct VulkanDeviceRecovery {
+    logical_device: *mut vulkan_init::VulkanLogicalDevice,
+    resources: Vec<*mut dyn VulkanRecoverable>
+}
+
+impl VulkanDeviceRecovery {
+    pub fn new(logical_device: &mut vulkan_init::VulkanLogicalDevice) -> Self
+    {
+        VulkanDeviceRecovery {
+            logical_device,
+            resources: Vec::new()
+        }
+    }
+
+    pub fn register<T: VulkanRecoverable + 'static>(&mut self, resource: &mut T)
+    {
+        self.resources.push(resource as *mut dyn VulkanRecoverable);
+    }
+
+    pub fn unregister<T: VulkanRecoverable + 'static>(&mut self, resource: &T)
+    {
+        let resource = resource as *const T as *const ();
+        self.resources.retain(|r| *r as *const () != resource);
+    }
+
+    pub fn is_needed(& self) -> bool
+    {
+        unsafe {
+            (*self.logical_device).is_lost()
+        }
+    }
+
+    // resources are released in reverse registration order so dependent objects go first,
+    // then rebuilt in registration order on the new device
+    pub fn recover(&mut self) -> Result<(), vulkan_error::VulkanError>
+    {
+        unsafe {
+            for resource in self.resources.iter().rev()
+            {
+                (**resource).release();
+            }
+            (*self.logical_device).recreate()?;
+            for resource in self.resources.iter()
+            {
+                (**resource).recreate()?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+// Generated by headersgen from vk.xml, regenerate instead of editing
+VULKAN_STRUCT!(BufferCreateInfo: VkBufferCreateInfo = VkStructureType_VK_STRUCTURE_TYPE_BUFFER_CREATE_INFO {
+    value flags => flags: vulkan_bindings::VkBufferCreateFlags;
+    value size => size: vulkan_bindings::VkDeviceSize;
+    value sharing_mode => sharingMode: vulkan_bindings::VkSharingMode;
+    typed usage => usage: vulkan_types::BufferUsageFlags;
+    slice queue_family_indices => queueFamilyIndexCount: u32 { pQueueFamilyIndices: u32; }
+    extends [];
+});
+
+VULKAN_STRUCT!(ImageCreateInfo: VkImageCreateInfo = VkStructureType_VK_STRUCTURE_TYPE_IMAGE_CREATE_INFO {
+    value flags => flags: vulkan_bindings::VkImageCreateFlags;
+    value format => format: vulkan_bindings::VkFormat;
+    value extent => extent: vulkan_bindings::VkExtent3D;
+    value mip_levels => mipLevels: u32;
+    value array_layers => arrayLayers: u32;
+    value sharing_mode => sharingMode: vulkan_bindings::VkSharingMode;
+    typed image_type => imageType: vulkan_types::ImageType;
+    typed samples => samples: vulkan_types::SampleCountFlags;
+    typed tiling => tiling: vulkan_types::ImageTiling;
+    typed usage => usage: vulkan_types::ImageUsageFlags;
+    typed initial_layout => initialLayout: vulkan_types::ImageLayout;
+    slice queue_family_indices => queueFamilyIndexCount: u32 { pQueueFamilyIndices: u32; }
+    extends [];
+});
+
+VULKAN_STRUCT!(ImageViewCreateInfo: VkImageViewCreateInfo = VkStructureType_VK_STRUCTURE_TYPE_IMAGE_VIEW_CREATE_INFO {
+    value flags => flags: vulkan_bindings::VkImageViewCreateFlags;
+    value image => image: vulkan_bindings::VkImage;
+    value format => format: vulkan_bindings::VkFormat;
+    value components => components: vulkan_bindings::VkComponentMapping;
+    value subresource_range => subresourceRange: vulkan_bindings::VkImageSubresourceRange;
+    typed view_type => viewType: vulkan_types::ImageViewType;
+    extends [];
+});
+
+VULKAN_STRUCT!(SemaphoreCreateInfo: VkSemaphoreCreateInfo = VkStructureType_VK_STRUCTURE_TYPE_SEMAPHORE_CREATE_INFO {
+    value flags => flags: vulkan_bindings::VkSemaphoreCreateFlags;
+    extends [];
+});
+
+VULKAN_STRUCT!(SemaphoreTypeCreateInfo: VkSemaphoreTypeCreateInfo = VkStructureType_VK_STRUCTURE_TYPE_SEMAPHORE_TYPE_CREATE_INFO {
+    value semaphore_type => semaphoreType: vulkan_bindings::VkSemaphoreType;
+    value initial_value => initialValue: u64;
+    extends [SemaphoreCreateInfo];
+});
+
+VULKAN_STRUCT!(SubmitInfo: VkSubmitInfo = VkStructureType_VK_STRUCTURE_TYPE_SUBMIT_INFO {
+    slice wait_semaphores => waitSemaphoreCount: u32 { pWaitSemaphores: vulkan_bindings::VkSemaphore; pWaitDstStageMask: vulkan_bindings::VkPipelineStageFlags; }
+    slice command_buffers => commandBufferCount: u32 { pCommandBuffers: vulkan_bindings::VkCommandBuffer; }
+    slice signal_semaphores => signalSemaphoreCount: u32 { pSignalSemaphores: vulkan_bindings::VkSemaphore; }
+    extends [];
+});
+
+VULKAN_STRUCT!(TimelineSemaphoreSubmitInfo: VkTimelineSemaphoreSubmitInfo = VkStructureType_VK_STRUCTURE_TYPE_TIMELINE_SEMAPHORE_SUBMIT_INFO {
+    slice wait_semaphore_values => waitSemaphoreValueCount: u32 { pWaitSemaphoreValues: u64; }
+    slice signal_semaphore_values => signalSemaphoreValueCount: u32 { pSignalSemaphoreValues: u64; }
+    extends [SubmitInfo];
+});
+
+VULKAN_STRUCT!(ProtectedSubmitInfo: VkProtectedSubmitInfo = VkStructureType_VK_STRUCTURE_TYPE_PROTECTED_SUBMIT_INFO {
+    value protected_submit => protectedSubmit: vulkan_bindings::VkBool32;
+    extends [SubmitInfo];
+});
+
+VULKAN_STRUCT!(SwapchainCreateInfoKHR: VkSwapchainCreateInfoKHR = VkStructureType_VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR {
+    value flags => flags: vulkan_bindings::VkSwapchainCreateFlagsKHR;
+    value surface => surface: vulkan_bindings::VkSurfaceKHR;
+    value min_image_count => minImageCount: u32;
+    value image_format => imageFormat: vulkan_bindings::VkFormat;
+    value image_color_space => imageColorSpace: vulkan_bindings::VkColorSpaceKHR;
+    value image_extent => imageExtent: vulkan_bindings::VkExtent2D;
+    value image_array_layers => imageArrayLayers: u32;
+    value image_sharing_mode => imageSharingMode: vulkan_bindings::VkSharingMode;
+    value composite_alpha => compositeAlpha: vulkan_bindings::VkCompositeAlphaFlagBitsKHR;
+    value clipped => clipped: vulkan_bindings::VkBool32;
+    value old_swapchain => oldSwapchain: vulkan_bindings::VkSwapchainKHR;
+    typed image_usage => imageUsage: vulkan_types::ImageUsageFlags;
+    typed pre_transform => preTransform: vulkan_types::SurfaceTransformFlagsKHR;
+    typed present_mode => presentMode: vulkan_types::PresentModeKHR;
+    slice queue_family_indices => queueFamilyIndexCount: u32 { pQueueFamilyIndices: u32; }
+    extends [];
+});
+
+VULKAN_STRUCT!(PresentInfoKHR: VkPresentInfoKHR = VkStructureType_VK_STRUCTURE_TYPE_PRESENT_INFO_KHR {
+    value results => pResults: *mut vulkan_bindings::VkResult;
+    slice wait_semaphores => waitSemaphoreCount: u32 { pWaitSemaphores: vulkan_bindings::VkSemaphore; }
+    slice swapchains => swapchainCount: u32 { pSwapchains: vulkan_bindings::VkSwapchainKHR; pImageIndices: u32; }
+    extends [];
+});
@@ -0,0 +1,144 @@
+use paste::paste;
+use std::marker::PhantomData;
+use crate::vulkan_bindings;
+use crate::vulkan_types;
+
+// builders over the raw create infos, sType is filled in by new() and every borrowed slice or struct has to outlive
+// the builder. vulkan_struct_builders.rs is generated by `headersgen structs <vk.xml>`
+
+macro_rules! VULKAN_STRUCT {
+    ($name: ident : $raw: ident = $stype: ident {
+        $(value $value_setter: ident => $value_field: ident : $value_type: ty;)*
+        $(typed $typed_setter: ident => $typed_field: ident : $typed_type: ty;)*
+        $(cstr $cstr_setter: ident => $cstr_field: ident;)*
+        $(slice $slice_setter: ident => $count: ident : $count_type: ty { $($slice_field: ident : $slice_type: ty;)+ })*
+        extends [$($parent: ident),*];
+    }) => {
+        paste! {
+            // only structs the registry lists as extending $raw implement it, so push_next rejects anything else
+            pub unsafe trait [<Extends $name>] {}
+
+            #[repr(transparent)]
+            pub struct $name<'a> {
+                raw: vulkan_bindings::$raw,
+                marker: PhantomData<&'a ()>
+            }
+
+            impl<'a> $name<'a> {
+                pub fn new() -> Self
+                {
+                    let mut raw: vulkan_bindings::$raw = unsafe { std::mem::zeroed() };
+                    raw.sType = vulkan_bindings::$stype;
+                    $name { raw, marker: PhantomData }
+                }
+
+                $(pub fn $value_setter(mut self, value: $value_type) -> Self
+                {
+                    self.raw.$value_field = value;
+                    self
+                })*
+
+                $(pub fn $typed_setter(mut self, value: $typed_type) -> Self
+                {
+                    self.raw.$typed_field = value.as_raw() as _;
+                    self
+                })*
+
+                $(pub fn $cstr_setter(mut self, value: &'a std::ffi::CStr) -> Self
+                {
+                    self.raw.$cstr_field = value.as_ptr();
+                    self
+                })*
+
+                // arrays sharing a count are set together and have to be the same length
+                $(pub fn $slice_setter(mut self, $($slice_field: &'a [$slice_type]),+) -> Self
+                {
+                    let lengths = [$($slice_field.len()),+];
+                    assert!(lengths.iter().all(|length| *length == lengths[0]), "{} arrays sharing {} have different lengths", stringify!($raw), stringify!($count));
+                    self.raw.$count = lengths[0] as $count_type;
+                    $(self.raw.$slice_field = if $slice_field.is_empty() { std::ptr::null() } else { $slice_field.as_ptr() };)+
+                    self
+                })*
+
+                // next and anything already chained to it go in front of the current chain, a struct that is already in
+                // the chain would link the chain back onto itself so it panics instead
+                pub fn push_next<T: [<Extends $name>]>(mut self, next: &'a mut T) -> Self
+                {
+                    unsafe {
+                        let mut chained = Vec::new();
+                        let mut current = self.raw.pNext as *mut vulkan_bindings::VkBaseOutStructure;
+                        while !current.is_null()
+                        {
+                            chained.push(current);
+                            current = (*current).pNext;
+                        }
+                        let next = next as *mut T as *mut vulkan_bindings::VkBaseOutStructure;
+                        let mut last = next;
+                        loop
+                        {
+                            assert!(!chained.contains(&last), "struct pushed to {} is already in its pNext chain", stringify!($raw));
+                            chained.push(last);
+                            if (*last).pNext.is_null()
+                            {
+                                break;
+                            }
+                            last = (*last).pNext;
+                        }
+                        (*last).pNext = self.raw.pNext as *mut vulkan_bindings::VkBaseOutStructure;
+                        self.raw.pNext = next as _;
+                    }
+                    self
+                }
+            }
+
+            impl<'a> Default for $name<'a> {
+                fn default() -> Self {
+                    $name::new()
+                }
+            }
+
+            impl<'a> std::ops::Deref for $name<'a> {
+                type Target = vulkan_bindings::$raw;
+                fn deref(&self) -> &Self::Target {
+                    &self.raw
+                }
+            }
+
+            $(unsafe impl<'a> [<Extends $parent>] for $name<'a> {})*
+        }
+    };
+}
+
+include!("./vulkan_struct_builders.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pushed_structs_go_in_front_of_the_chain()
+    {
+        let mut first = TimelineSemaphoreSubmitInfo::new();
+        let mut second = ProtectedSubmitInfo::new();
+        let first_pointer = &mut first as *mut TimelineSemaphoreSubmitInfo as *mut std::ffi::c_void;
+        let second_pointer = &mut second as *mut ProtectedSubmitInfo as *mut std::ffi::c_void;
+        let info = SubmitInfo::new().push_next(&mut first).push_next(&mut second);
+        assert_eq!(info.pNext as *mut std::ffi::c_void, second_pointer);
+        unsafe {
+            let second = &*(second_pointer as *const vulkan_bindings::VkBaseOutStructure);
+            assert_eq!(second.pNext as *mut std::ffi::c_void, first_pointer);
+            assert!((*second.pNext).pNext.is_null());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "already in its pNext chain")]
+    fn pushing_a_chained_struct_again_panics()
+    {
+        let mut first = TimelineSemaphoreSubmitInfo::new();
+        let mut second = ProtectedSubmitInfo::new();
+        // second already carries first, so pushing it after first would link first to itself
+        second.raw.pNext = &mut first as *mut TimelineSemaphoreSubmitInfo as *mut std::ffi::c_void;
+        let _ = SubmitInfo::new().push_next(&mut first).push_next(&mut second);
+    }
+}
@@ -1,21 +1,31 @@
 use crate::vulkan_init;
 use crate::vulkan_bindings;
+use crate::vulkan_error;
+use crate::vulkan_types;
+use crate::vulkan_structs;
 
 #[derive(Debug)]
 pub enum VulkanSynchroError
 {
-    COULDNT_CREATE_CMD_POOL,
-    COULDNT_CREATE_CMD_BUFFER,
-    FAILED_STARTING_PRIMARY_BUFFER_RECORDING,
+    COULDNT_CREATE_CMD_POOL(vulkan_error::VulkanCallError),
+    COULDNT_CREATE_CMD_BUFFER(vulkan_error::VulkanCallError),
+    FAILED_STARTING_PRIMARY_BUFFER_RECORDING(vulkan_error::VulkanCallError),
     FAILED_STARTING_SECONDARY_BUFFER_RECORDING,
-    FAILED_ENDING_PRIMARY_BUFFER_RECORDING,
-    FAILED_RESETING_PRIMARY_BUFFER,
-    FAILED_RESETING_POOL,
-    FAILED_CREATING_SEMAPHORE,
-    FAILED_CREATING_FENCE,
-    COULDNT_WAIT_FOR_FENCES,
-    COULDNT_RESET_FENCES,
-    FAILED_SUBMITING_BUFFERS
+    FAILED_ENDING_PRIMARY_BUFFER_RECORDING(vulkan_error::VulkanCallError),
+    FAILED_RESETING_PRIMARY_BUFFER(vulkan_error::VulkanCallError),
+    FAILED_RESETING_POOL(vulkan_error::VulkanCallError),
+    FAILED_CREATING_SEMAPHORE(vulkan_error::VulkanCallError),
+    FAILED_CREATING_FENCE(vulkan_error::VulkanCallError),
+    COULDNT_WAIT_FOR_FENCES(vulkan_error::VulkanCallError),
+    COULDNT_RESET_FENCES(vulkan_error::VulkanCallError),
+    FAILED_SUBMITING_BUFFERS(vulkan_error::VulkanCallError),
+    UNAVAILABLE_QUEUE_ROLE(vulkan_init::VulkanQueueRole),
+    INVALID_BUFFER_INDEX(usize),
+    UNSUPPORTED_TIMELINE_SEMAPHORES,
+    FAILED_READING_SEMAPHORE_VALUE(vulkan_error::VulkanCallError),
+    FAILED_SIGNALING_SEMAPHORE(vulkan_error::VulkanCallError),
+    COULDNT_WAIT_FOR_SEMAPHORES(vulkan_error::VulkanCallError),
+    MISMATCHED_SEMAPHORE_VALUES(usize, usize)
 }
 
 impl std::fmt::Display for  VulkanSynchroError
@@ -23,58 +33,116 @@ impl std::fmt::Display for  VulkanSynchroError
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self
         {
-            VulkanSynchroError::COULDNT_CREATE_CMD_POOL => write!(f, "Couldnt create a Command pool"),
-            VulkanSynchroError::COULDNT_CREATE_CMD_BUFFER => write!(f, "Couldn't create a Command Buffer"),
-            VulkanSynchroError::FAILED_STARTING_PRIMARY_BUFFER_RECORDING => write!(f, "Failed Starting primary buffer recording"),
+            VulkanSynchroError::COULDNT_CREATE_CMD_POOL(_) => write!(f, "Couldnt create a Command pool"),
+            VulkanSynchroError::COULDNT_CREATE_CMD_BUFFER(_) => write!(f, "Couldn't create a Command Buffer"),
+            VulkanSynchroError::FAILED_STARTING_PRIMARY_BUFFER_RECORDING(_) => write!(f, "Failed Starting primary buffer recording"),
             VulkanSynchroError::FAILED_STARTING_SECONDARY_BUFFER_RECORDING => write!(f, "Failed Starting secondary buffer recording"),
-            VulkanSynchroError::FAILED_ENDING_PRIMARY_BUFFER_RECORDING => write!(f, "Failed Closing primary buffer recording"),
-            VulkanSynchroError::FAILED_RESETING_PRIMARY_BUFFER => write!(f, "Failed resetting primary buffer"),
-            VulkanSynchroError::FAILED_RESETING_POOL => write!(f, "Failed resetting pool"),
-            VulkanSynchroError::FAILED_CREATING_SEMAPHORE => write!(f, "Failed creating semaphore"),
-            VulkanSynchroError::FAILED_CREATING_FENCE => write!(f, "Failed creating fence"),
-            VulkanSynchroError::COULDNT_WAIT_FOR_FENCES => write!(f, "Couldnt wait for fences"),
-            VulkanSynchroError::COULDNT_RESET_FENCES => write!(f, "Couldn't reset fences"),
-            VulkanSynchroError::FAILED_SUBMITING_BUFFERS => write!(f, "Failed Submiting buffers"),
+            VulkanSynchroError::FAILED_ENDING_PRIMARY_BUFFER_RECORDING(_) => write!(f, "Failed Closing primary buffer recording"),
+            VulkanSynchroError::FAILED_RESETING_PRIMARY_BUFFER(_) => write!(f, "Failed resetting primary buffer"),
+            VulkanSynchroError::FAILED_RESETING_POOL(_) => write!(f, "Failed resetting pool"),
+            VulkanSynchroError::FAILED_CREATING_SEMAPHORE(_) => write!(f, "Failed creating semaphore"),
+            VulkanSynchroError::FAILED_CREATING_FENCE(_) => write!(f, "Failed creating fence"),
+            VulkanSynchroError::COULDNT_WAIT_FOR_FENCES(_) => write!(f, "Couldnt wait for fences"),
+            VulkanSynchroError::COULDNT_RESET_FENCES(_) => write!(f, "Couldn't reset fences"),
+            VulkanSynchroError::FAILED_SUBMITING_BUFFERS(_) => write!(f, "Failed Submiting buffers"),
+            VulkanSynchroError::UNAVAILABLE_QUEUE_ROLE(role) => write!(f, "The logical device has no {} queue", role),
+            VulkanSynchroError::INVALID_BUFFER_INDEX(idx) => write!(f, "No command buffer at index {}", idx),
+            VulkanSynchroError::UNSUPPORTED_TIMELINE_SEMAPHORES => write!(f, "Timeline semaphores aren't enabled on this logical device"),
+            VulkanSynchroError::FAILED_READING_SEMAPHORE_VALUE(_) => write!(f, "Failed reading semaphore counter value"),
+            VulkanSynchroError::FAILED_SIGNALING_SEMAPHORE(_) => write!(f, "Failed signaling semaphore"),
+            VulkanSynchroError::COULDNT_WAIT_FOR_SEMAPHORES(_) => write!(f, "Couldn't wait for semaphores"),
+            VulkanSynchroError::MISMATCHED_SEMAPHORE_VALUES(semaphores, values) => write!(f, "{} semaphores were given {} values", semaphores, values),
         }
     }
 }
 
-impl std::error::Error for VulkanSynchroError
-{}
+impl VulkanSynchroError {
+    pub fn call_error(& self) -> Option<&vulkan_error::VulkanCallError>
+    {
+        match self
+        {
+            VulkanSynchroError::COULDNT_CREATE_CMD_POOL(e)
+            | VulkanSynchroError::COULDNT_CREATE_CMD_BUFFER(e)
+            | VulkanSynchroError::FAILED_STARTING_PRIMARY_BUFFER_RECORDING(e)
+            | VulkanSynchroError::FAILED_ENDING_PRIMARY_BUFFER_RECORDING(e)
+            | VulkanSynchroError::FAILED_RESETING_PRIMARY_BUFFER(e)
+            | VulkanSynchroError::FAILED_RESETING_POOL(e)
+            | VulkanSynchroError::FAILED_CREATING_SEMAPHORE(e)
+            | VulkanSynchroError::FAILED_CREATING_FENCE(e)
+            | VulkanSynchroError::COULDNT_WAIT_FOR_FENCES(e)
+            | VulkanSynchroError::COULDNT_RESET_FENCES(e)
+            | VulkanSynchroError::FAILED_SUBMITING_BUFFERS(e)
+            | VulkanSynchroError::FAILED_READING_SEMAPHORE_VALUE(e)
+            | VulkanSynchroError::FAILED_SIGNALING_SEMAPHORE(e)
+            | VulkanSynchroError::COULDNT_WAIT_FOR_SEMAPHORES(e) => Some(e),
+            _ => None
+        }
+    }
+}
+
+impl VulkanSynchroError {
+    pub fn is_device_lost(& self) -> bool
+    {
+        self.call_error().is_some_and(|e| e.is_device_lost())
+    }
+}
+
+impl std::error::Error for VulkanSynchroError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.call_error().map(|e| e as &(dyn std::error::Error + 'static))
+    }
+}
 
 pub struct VulkanCmdPool
 {
     cmd_pool_handle: vulkan_bindings::VkCommandPool,
+    pub queue_family_index : u32,
     logical_device : *const vulkan_init::VulkanLogicalDevice,
     cmd_buffers : Option<VulkanCmdBuffer>,
 }
 
 impl VulkanCmdPool
 {
-    pub fn new(logical_device : &vulkan_init::VulkanLogicalDevice) -> Result<Self, VulkanSynchroError>
+    pub fn new(logical_device : &vulkan_init::VulkanLogicalDevice, queue_family_index: u32) -> Result<Self, VulkanSynchroError>
     {
         let mut vk_cmd_pool = VulkanCmdPool {
             cmd_pool_handle : std::ptr::null_mut(),
+            queue_family_index,
             logical_device,
             cmd_buffers : None,
         };
+        vk_cmd_pool.create_pool()?;
+        Ok(vk_cmd_pool)
+    }
+
+    fn create_pool(&mut self) -> Result<(), VulkanSynchroError>
+    {
         unsafe
         {
-            let fn_vkCreateCommandPool = vulkan_init::vkCreateCommandPool.unwrap();
+            let logical_device = &*self.logical_device;
+            let fn_vkCreateCommandPool = logical_device.fns.vkCreateCommandPool.unwrap();
             let buffer_params = vulkan_bindings::VkCommandPoolCreateFlagBits_VK_COMMAND_POOL_CREATE_TRANSIENT_BIT | vulkan_bindings::VkCommandPoolCreateFlagBits_VK_COMMAND_POOL_CREATE_RESET_COMMAND_BUFFER_BIT;
             let cmd_pool_create_info = vulkan_bindings::VkCommandPoolCreateInfo {
                 sType : vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_COMMAND_POOL_CREATE_INFO,
                 pNext: std::ptr::null(),
-                flags: buffer_params as u32,
-                queueFamilyIndex: 0
+                flags: buffer_params,
+                queueFamilyIndex: self.queue_family_index
             };
-            let result = fn_vkCreateCommandPool(logical_device.device, &cmd_pool_create_info, std::ptr::null(), &mut vk_cmd_pool.cmd_pool_handle);
+            let result = fn_vkCreateCommandPool(logical_device.device, &cmd_pool_create_info, logical_device.allocation_callbacks, &mut self.cmd_pool_handle);
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanSynchroError::COULDNT_CREATE_CMD_POOL);
+                return Err(VulkanSynchroError::COULDNT_CREATE_CMD_POOL(vulkan_error::VulkanCallError::new("vkCreateCommandPool", result)));
             }
         }
-        Ok(vk_cmd_pool)
+        Ok(())
+    }
+
+    pub fn for_role(logical_device : &vulkan_init::VulkanLogicalDevice, role: vulkan_init::VulkanQueueRole) -> Result<Self, VulkanSynchroError>
+    {
+        match logical_device.queue_family_index(role) {
+            Some(family_index) => VulkanCmdPool::new(logical_device, family_index),
+            None => Err(VulkanSynchroError::UNAVAILABLE_QUEUE_ROLE(role))
+        }
     }
 
     pub fn create_buffers(&mut self, primary_count: u32,  secondary_count: u32) -> Result<&mut VulkanCmdBuffer, VulkanSynchroError>
@@ -83,66 +151,182 @@ impl VulkanCmdPool
         Ok(self.cmd_buffers.as_mut().unwrap())
     }
 
+    pub fn buffers(&mut self) -> Option<&mut VulkanCmdBuffer>
+    {
+        self.cmd_buffers.as_mut()
+    }
+
     pub fn reset_pool(&mut self, release_mem: bool) -> Result<(), VulkanSynchroError>
     {
         unsafe
         {
-            let fn_vkResetCommandPool = vulkan_init::vkResetCommandPool.unwrap();
+            let fn_vkResetCommandPool = (*self.logical_device).fns.vkResetCommandPool.unwrap();
             let logical_device = (*self.logical_device).device;
             let result = fn_vkResetCommandPool(
                 logical_device, 
                 self.cmd_pool_handle,
-                if release_mem { vulkan_bindings::VkCommandPoolResetFlagBits_VK_COMMAND_POOL_RESET_RELEASE_RESOURCES_BIT as u32 } else { 0 } 
+                if release_mem { vulkan_bindings::VkCommandPoolResetFlagBits_VK_COMMAND_POOL_RESET_RELEASE_RESOURCES_BIT } else { 0 } 
             );
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanSynchroError::FAILED_RESETING_POOL);
+                return Err(VulkanSynchroError::FAILED_RESETING_POOL(vulkan_error::VulkanCallError::new("vkResetCommandPool", result)));
             }
         }
         Ok(())
     }
 
-    pub fn submit_buffers(&mut self,queue: vulkan_bindings::VkQueue,  wait_sems: &VulkanWaitSemaphoresInfo)-> Result<(), VulkanSynchroError>
+    // timeline values are only chained when one of the semaphores waits on or signals a value,
+    // binary semaphores mixed in the same submission just have their value ignored
+    pub fn submit_buffers(&mut self,
+        queue: vulkan_bindings::VkQueue,
+        wait_sems: &VulkanWaitSemaphoresInfo,
+        signal_sems: &VulkanSignalSemaphoresInfo,
+        fence: vulkan_bindings::VkFence
+    )-> Result<(), VulkanSynchroError>
     {
         let buffer = self.cmd_buffers.as_ref().unwrap();
+        wait_sems.check_values()?;
+        signal_sems.check_values()?;
         unsafe
         {
-            let ref logical_device = *self.logical_device;
-            let sig_sem = init_semaphore(logical_device)?;
-            let buffer_submit_info =  vulkan_bindings::VkSubmitInfo
+            let logical_device = &*self.logical_device;
+            let uses_timeline = !wait_sems.values.is_empty() || !signal_sems.values.is_empty();
+            if uses_timeline && !logical_device.supports_timeline_semaphores()
             {
-                sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_SUBMIT_INFO,
-                pNext: std::ptr::null(),
-                waitSemaphoreCount: wait_sems.semaphores.len() as u32,
-                pWaitSemaphores: wait_sems.semaphores.as_ptr(),
-                pWaitDstStageMask: wait_sems.waiting_stage.as_ptr(),
-                commandBufferCount: buffer.primary_buffers.len() as u32,
-                pCommandBuffers: buffer.primary_buffers.as_ptr(),
-                signalSemaphoreCount: 1,
-                pSignalSemaphores: &sig_sem,
-            };
-            let fn_vkQueueSubmit = vulkan_init::vkQueueSubmit.unwrap();
-            let result = fn_vkQueueSubmit(queue, 1, &buffer_submit_info, std::ptr::null_mut());
+                return Err(VulkanSynchroError::UNSUPPORTED_TIMELINE_SEMAPHORES);
+            }
+            let wait_values = if wait_sems.values.is_empty() { vec![0; wait_sems.semaphores.len()] } else { wait_sems.values.clone() };
+            let signal_values = if signal_sems.values.is_empty() { vec![0; signal_sems.semaphores.len()] } else { signal_sems.values.clone() };
+            let mut timeline_submit_info = vulkan_structs::TimelineSemaphoreSubmitInfo::new()
+                .wait_semaphore_values(&wait_values)
+                .signal_semaphore_values(&signal_values);
+            let mut buffer_submit_info = vulkan_structs::SubmitInfo::new()
+                .wait_semaphores(&wait_sems.semaphores, &wait_sems.waiting_stage)
+                .command_buffers(&buffer.primary_buffers)
+                .signal_semaphores(&signal_sems.semaphores);
+            if uses_timeline
+            {
+                buffer_submit_info = buffer_submit_info.push_next(&mut timeline_submit_info);
+            }
+            let fn_vkQueueSubmit = logical_device.fns.vkQueueSubmit.unwrap();
+            let result = fn_vkQueueSubmit(queue, 1, &*buffer_submit_info, fence);
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanSynchroError::FAILED_SUBMITING_BUFFERS);
+                return Err(VulkanSynchroError::FAILED_SUBMITING_BUFFERS(logical_device.track_call_error(vulkan_error::VulkanCallError::new("vkQueueSubmit", result))));
             }
         }
         Ok(())
     }
 
-    pub fn destroy(mut self)
+    // each semaphore carries its own stage mask, devices without synchronization2 go through submit_buffers
+    // with the stages folded back into legacy masks
+    pub fn submit_buffers2(&mut self,
+        queue: vulkan_bindings::VkQueue,
+        wait_sems: &[VulkanSemaphoreSubmit],
+        signal_sems: &[VulkanSemaphoreSubmit],
+        fence: vulkan_bindings::VkFence
+    ) -> Result<(), VulkanSynchroError>
     {
-        match self.cmd_buffers.take()
+        let logical_device = unsafe { &*self.logical_device };
+        let uses_timeline = wait_sems.iter().chain(signal_sems.iter()).any(|sem| sem.value.is_some());
+        if uses_timeline && !logical_device.supports_timeline_semaphores()
         {
-            Some(b) => b.destroy(),
-            None => ()
+            return Err(VulkanSynchroError::UNSUPPORTED_TIMELINE_SEMAPHORES);
+        }
+        let fn_vkQueueSubmit2 = match logical_device.fns.vkQueueSubmit2.or(logical_device.fns.vkQueueSubmit2KHR) {
+            Some(f) if logical_device.supports_synchronization2() => f,
+            _ => {
+                let mut legacy_wait = VulkanWaitSemaphoresInfo::new();
+                let mut legacy_signal = VulkanSignalSemaphoresInfo::new();
+                for sem in wait_sems
+                {
+                    legacy_wait = legacy_wait.wait(sem.semaphore, legacy_stage_mask(sem.stage, false));
+                }
+                for sem in signal_sems
+                {
+                    legacy_signal = legacy_signal.signal(sem.semaphore);
+                }
+                if uses_timeline
+                {
+                    legacy_wait.values = wait_sems.iter().map(|sem| sem.value.unwrap_or(0)).collect();
+                    legacy_signal.values = signal_sems.iter().map(|sem| sem.value.unwrap_or(0)).collect();
+                }
+                return self.submit_buffers(queue, &legacy_wait, &legacy_signal, fence);
+            }
         };
+        let buffer = self.cmd_buffers.as_ref().unwrap();
+        let to_submit_info = |sem: &VulkanSemaphoreSubmit| vulkan_bindings::VkSemaphoreSubmitInfo {
+            sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_SEMAPHORE_SUBMIT_INFO,
+            pNext: std::ptr::null(),
+            semaphore: sem.semaphore,
+            value: sem.value.unwrap_or(0),
+            stageMask: sem.stage.as_raw(),
+            deviceIndex: 0
+        };
+        let wait_infos : Vec<vulkan_bindings::VkSemaphoreSubmitInfo> = wait_sems.iter().map(to_submit_info).collect();
+        let signal_infos : Vec<vulkan_bindings::VkSemaphoreSubmitInfo> = signal_sems.iter().map(to_submit_info).collect();
+        let cmd_buffer_infos : Vec<vulkan_bindings::VkCommandBufferSubmitInfo> = buffer.primary_buffers.iter().map(|cmd_buffer| vulkan_bindings::VkCommandBufferSubmitInfo {
+            sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_COMMAND_BUFFER_SUBMIT_INFO,
+            pNext: std::ptr::null(),
+            commandBuffer: *cmd_buffer,
+            deviceMask: 0
+        }).collect();
+        let submit_info = vulkan_bindings::VkSubmitInfo2 {
+            sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_SUBMIT_INFO_2,
+            pNext: std::ptr::null(),
+            flags: 0,
+            waitSemaphoreInfoCount: wait_infos.len() as u32,
+            pWaitSemaphoreInfos: wait_infos.as_ptr(),
+            commandBufferInfoCount: cmd_buffer_infos.len() as u32,
+            pCommandBufferInfos: cmd_buffer_infos.as_ptr(),
+            signalSemaphoreInfoCount: signal_infos.len() as u32,
+            pSignalSemaphoreInfos: signal_infos.as_ptr()
+        };
+        unsafe
+        {
+            let result = fn_vkQueueSubmit2(queue, 1, &submit_info, fence);
+            if result != vulkan_bindings::VkResult_VK_SUCCESS
+            {
+                return Err(VulkanSynchroError::FAILED_SUBMITING_BUFFERS(logical_device.track_call_error(vulkan_error::VulkanCallError::new("vkQueueSubmit2", result))));
+            }
+        }
+        Ok(())
+    }
+
+    // destroying the pool frees its command buffers, their counts are kept for recreate
+    pub fn release(&mut self)
+    {
+        unsafe
+        {
+            if self.cmd_pool_handle == std::ptr::null_mut()
+            {
+                return;
+            }
+            let fn_vkDestroyCommandPool = (*self.logical_device).fns.vkDestroyCommandPool.unwrap();
+            fn_vkDestroyCommandPool((*self.logical_device).device, self.cmd_pool_handle, (*self.logical_device).allocation_callbacks);
+            self.cmd_pool_handle = std::ptr::null_mut();
+        }
+    }
+
+    pub fn recreate(&mut self) -> Result<(), VulkanSynchroError>
+    {
+        self.create_pool()?;
+        let counts = self.cmd_buffers.as_ref().map(|buffers| (buffers.primary_buffers.len() as u32, buffers.secondary_buffers.len() as u32));
+        if let Some((primary_count, secondary_count)) = counts
+        {
+            self.create_buffers(primary_count, secondary_count)?;
+        }
+        Ok(())
+    }
+
+    pub fn destroy(mut self)
+    {
+        if let Some(b) = self.cmd_buffers.take() { b.destroy() };
         unsafe
         {
             let logical_device =  (*self.logical_device).device;
-            let fn_vkDestroyCommandPool = vulkan_init::vkDestroyCommandPool.unwrap();
-            fn_vkDestroyCommandPool(logical_device, self.cmd_pool_handle, std::ptr::null());
+            let fn_vkDestroyCommandPool = (*self.logical_device).fns.vkDestroyCommandPool.unwrap();
+            fn_vkDestroyCommandPool(logical_device, self.cmd_pool_handle, (*self.logical_device).allocation_callbacks);
         }
     }
 
@@ -154,9 +338,11 @@ pub enum VulkanBufferType
     SECONDARY
 }
 
+// keeps the pool handle rather than a pointer to the pool, the pool is free to move after allocating its buffers
 pub struct VulkanCmdBuffer
 {
-    cmd_pool : *const VulkanCmdPool,
+    cmd_pool_handle : vulkan_bindings::VkCommandPool,
+    logical_device : *const vulkan_init::VulkanLogicalDevice,
     pub primary_buffers : Vec<vulkan_bindings::VkCommandBuffer>,
     pub secondary_buffers: Vec<vulkan_bindings::VkCommandBuffer>,
     started_buffers : Vec<(VulkanBufferType, usize)>
@@ -167,7 +353,8 @@ impl VulkanCmdBuffer
     pub fn new(cmd_pool: &VulkanCmdPool, primary_count: u32 , secondary_count: u32) -> Result<Self, VulkanSynchroError>
     {
         let mut cmd_buffer = VulkanCmdBuffer {
-            cmd_pool,
+            cmd_pool_handle: cmd_pool.cmd_pool_handle,
+            logical_device: cmd_pool.logical_device,
             primary_buffers: Vec::new(),
             secondary_buffers : Vec::new(),
             started_buffers: Vec::new()
@@ -186,41 +373,41 @@ impl VulkanCmdBuffer
             let buffer_create_info = vulkan_bindings::VkCommandBufferAllocateInfo {
                 sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_COMMAND_BUFFER_ALLOCATE_INFO,
                 pNext: std::ptr::null(),
-                commandPool: (*self.cmd_pool).cmd_pool_handle,
+                commandPool: self.cmd_pool_handle,
                 level,
                 commandBufferCount: count
             };
-            let fn_vkAllocateCommandBuffers = vulkan_init::vkAllocateCommandBuffers.unwrap();
-            let logical_device = (*(*self.cmd_pool).logical_device).device;
+            let fn_vkAllocateCommandBuffers = (*self.logical_device).fns.vkAllocateCommandBuffers.unwrap();
+            let logical_device = (*self.logical_device).device;
             let mut cmd_buffers : Vec<vulkan_bindings::VkCommandBuffer> = vec![std::ptr::null_mut(); count as usize];
             let result = fn_vkAllocateCommandBuffers(logical_device, &buffer_create_info, cmd_buffers.as_mut_ptr());
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanSynchroError::COULDNT_CREATE_CMD_BUFFER);
+                return Err(VulkanSynchroError::COULDNT_CREATE_CMD_BUFFER(vulkan_error::VulkanCallError::new("vkAllocateCommandBuffers", result)));
             }
             Ok(cmd_buffers)
         }
     }
     
-    pub fn begin_primary_buffer(&mut self, buffer_idx: usize, usage: vulkan_bindings::VkBufferUsageFlagBits) -> Result<(), VulkanSynchroError>
+    pub fn begin_primary_buffer(&mut self, buffer_idx: usize, usage: vulkan_types::CommandBufferUsageFlags) -> Result<(), VulkanSynchroError>
     {
         if buffer_idx >= self.primary_buffers.len()
         {
-            return Err(VulkanSynchroError::FAILED_STARTING_PRIMARY_BUFFER_RECORDING);
+            return Err(VulkanSynchroError::INVALID_BUFFER_INDEX(buffer_idx));
         }
         let buffer_begin_info = vulkan_bindings::VkCommandBufferBeginInfo {
             sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_COMMAND_BUFFER_BEGIN_INFO,
             pNext : std::ptr::null(),
-            flags: usage as u32,
+            flags: usage.as_raw(),
             pInheritanceInfo: std::ptr::null()
         };
         unsafe 
         {
-            let fn_vkBeginCommandBuffer = vulkan_init::vkBeginCommandBuffer.unwrap();
+            let fn_vkBeginCommandBuffer = (*self.logical_device).fns.vkBeginCommandBuffer.unwrap();
             let result = fn_vkBeginCommandBuffer(self.primary_buffers[buffer_idx], &buffer_begin_info);
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanSynchroError::FAILED_STARTING_PRIMARY_BUFFER_RECORDING);
+                return Err(VulkanSynchroError::FAILED_STARTING_PRIMARY_BUFFER_RECORDING(vulkan_error::VulkanCallError::new("vkBeginCommandBuffer", result)));
             }
         }
         self.started_buffers.push((VulkanBufferType::PRIMARY, buffer_idx));
@@ -232,17 +419,17 @@ impl VulkanCmdBuffer
         let mut i = 0;
         while i < self.started_buffers.len()
         {
-            let ref started =  self.started_buffers[i];
+            let started = &self.started_buffers[i];
             if let VulkanBufferType::PRIMARY = started.0 
             {
                 if started.1 == buffer_idx
                 {
                     unsafe{
-                        let fn_vkEndCommandBuffer = vulkan_init::vkEndCommandBuffer.unwrap();
+                        let fn_vkEndCommandBuffer = (*self.logical_device).fns.vkEndCommandBuffer.unwrap();
                         let result = fn_vkEndCommandBuffer(self.primary_buffers[buffer_idx]);
                         if result != vulkan_bindings::VkResult_VK_SUCCESS
                         {
-                            return Err(VulkanSynchroError::FAILED_ENDING_PRIMARY_BUFFER_RECORDING);
+                            return Err(VulkanSynchroError::FAILED_ENDING_PRIMARY_BUFFER_RECORDING(vulkan_error::VulkanCallError::new("vkEndCommandBuffer", result)));
                         }
                     }
                     self.started_buffers.remove(i);
@@ -258,17 +445,17 @@ impl VulkanCmdBuffer
     {
         if idx >= self.primary_buffers.len()
         {
-            return Err(VulkanSynchroError::FAILED_RESETING_PRIMARY_BUFFER);
+            return Err(VulkanSynchroError::INVALID_BUFFER_INDEX(idx));
         }
         unsafe
         {
-            let fn_vkResetCommandBuffer = vulkan_init::vkResetCommandBuffer.unwrap();
+            let fn_vkResetCommandBuffer = (*self.logical_device).fns.vkResetCommandBuffer.unwrap();
             let result = fn_vkResetCommandBuffer(self.primary_buffers[idx],
-                if release_mem { vulkan_bindings::VkCommandBufferResetFlagBits_VK_COMMAND_BUFFER_RESET_RELEASE_RESOURCES_BIT as u32 } else { 0 }
+                if release_mem { vulkan_bindings::VkCommandBufferResetFlagBits_VK_COMMAND_BUFFER_RESET_RELEASE_RESOURCES_BIT } else { 0 }
             );
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return  Err(VulkanSynchroError::FAILED_RESETING_PRIMARY_BUFFER);
+                return  Err(VulkanSynchroError::FAILED_RESETING_PRIMARY_BUFFER(vulkan_error::VulkanCallError::new("vkResetCommandBuffer", result)));
             }
         }
         Ok(())
@@ -279,18 +466,17 @@ impl VulkanCmdBuffer
     {
         unsafe
         {
-            let ref cmd_pool = *self.cmd_pool;
-            let ref logical_device = *cmd_pool.logical_device;
-            let fn_vkFreeCommandBuffers = vulkan_init::vkFreeCommandBuffers.unwrap();
+            let logical_device = &*self.logical_device;
+            let fn_vkFreeCommandBuffers = logical_device.fns.vkFreeCommandBuffers.unwrap();
             let primary_buffer_len  = self.primary_buffers.len() as u32;
             let secondary_buffer_len = self.secondary_buffers.len() as u32;
             fn_vkFreeCommandBuffers(logical_device.device,
-                cmd_pool.cmd_pool_handle,
+                self.cmd_pool_handle,
                 primary_buffer_len,
                 if primary_buffer_len > 0 { self.primary_buffers.as_ptr() } else { std::ptr::null() }
             );
             fn_vkFreeCommandBuffers(logical_device.device,
-                cmd_pool.cmd_pool_handle,
+                self.cmd_pool_handle,
                 secondary_buffer_len,
                 if secondary_buffer_len > 0 { self.secondary_buffers.as_ptr() } else { std::ptr::null() }
             );
@@ -298,10 +484,163 @@ impl VulkanCmdBuffer
     }
 }
 
+// binary semaphores have no value, a timeline semaphore always carries one even when it's 0
+#[derive(Clone, Copy)]
+pub struct VulkanSemaphoreSubmit
+{
+    pub semaphore: vulkan_bindings::VkSemaphore,
+    pub value: Option<u64>,
+    pub stage: vulkan_types::PipelineStageFlags2
+}
+
+impl VulkanSemaphoreSubmit
+{
+    pub fn binary(semaphore: vulkan_bindings::VkSemaphore, stage: vulkan_types::PipelineStageFlags2) -> Self
+    {
+        VulkanSemaphoreSubmit { semaphore, value: None, stage }
+    }
+
+    pub fn timeline(semaphore: &VulkanTimelineSemaphore, value: u64, stage: vulkan_types::PipelineStageFlags2) -> Self
+    {
+        VulkanSemaphoreSubmit { semaphore: semaphore.handle, value: Some(value), stage }
+    }
+}
+
+// the synchronization2 stages that don't exist in the legacy 32 bits masks map to the stages that contain them,
+// an empty mask becomes top of pipe on the source side and bottom of pipe on the destination side
+pub fn legacy_stage_mask(stages: vulkan_types::PipelineStageFlags2, is_src: bool) -> vulkan_types::PipelineStageFlags
+{
+    let mut legacy = vulkan_types::PipelineStageFlags::from_raw((stages.as_raw() & 0xFFFF_FFFF) as vulkan_bindings::VkPipelineStageFlags);
+    let transfer_stages = vulkan_types::PipelineStageFlags2::COPY | vulkan_types::PipelineStageFlags2::RESOLVE
+        | vulkan_types::PipelineStageFlags2::BLIT | vulkan_types::PipelineStageFlags2::CLEAR;
+    if stages.intersects(transfer_stages)
+    {
+        legacy |= vulkan_types::PipelineStageFlags::TRANSFER;
+    }
+    if stages.intersects(vulkan_types::PipelineStageFlags2::INDEX_INPUT | vulkan_types::PipelineStageFlags2::VERTEX_ATTRIBUTE_INPUT)
+    {
+        legacy |= vulkan_types::PipelineStageFlags::VERTEX_INPUT;
+    }
+    if stages.intersects(vulkan_types::PipelineStageFlags2::PRE_RASTERIZATION_SHADERS)
+    {
+        legacy |= vulkan_types::PipelineStageFlags::VERTEX_SHADER
+            | vulkan_types::PipelineStageFlags::TESSELLATION_CONTROL_SHADER
+            | vulkan_types::PipelineStageFlags::TESSELLATION_EVALUATION_SHADER
+            | vulkan_types::PipelineStageFlags::GEOMETRY_SHADER;
+    }
+    if legacy.is_empty()
+    {
+        legacy = if is_src { vulkan_types::PipelineStageFlags::TOP_OF_PIPE } else { vulkan_types::PipelineStageFlags::BOTTOM_OF_PIPE };
+    }
+    legacy
+}
+
+pub fn legacy_access_mask(access: vulkan_types::AccessFlags2) -> vulkan_types::AccessFlags
+{
+    let mut legacy = vulkan_types::AccessFlags::from_raw((access.as_raw() & 0xFFFF_FFFF) as vulkan_bindings::VkAccessFlags);
+    if access.intersects(vulkan_types::AccessFlags2::SHADER_SAMPLED_READ | vulkan_types::AccessFlags2::SHADER_STORAGE_READ)
+    {
+        legacy |= vulkan_types::AccessFlags::SHADER_READ;
+    }
+    if access.intersects(vulkan_types::AccessFlags2::SHADER_STORAGE_WRITE)
+    {
+        legacy |= vulkan_types::AccessFlags::SHADER_WRITE;
+    }
+    legacy
+}
+
 pub struct VulkanWaitSemaphoresInfo
 {
     pub semaphores : Vec<vulkan_bindings::VkSemaphore>,
-    pub waiting_stage: Vec<vulkan_bindings::VkPipelineStageFlags>
+    pub waiting_stage: Vec<vulkan_bindings::VkPipelineStageFlags>,
+    // either empty or one value per semaphore
+    pub values: Vec<u64>
+}
+
+impl VulkanWaitSemaphoresInfo
+{
+    pub fn new() -> Self
+    {
+        VulkanWaitSemaphoresInfo {
+            semaphores: Vec::new(),
+            waiting_stage: Vec::new(),
+            values: Vec::new()
+        }
+    }
+
+    pub fn wait(mut self, semaphore: vulkan_bindings::VkSemaphore, stage: vulkan_types::PipelineStageFlags) -> Self
+    {
+        self.semaphores.push(semaphore);
+        self.waiting_stage.push(stage.as_raw());
+        if !self.values.is_empty()
+        {
+            self.values.push(0);
+        }
+        self
+    }
+
+    pub fn wait_value(mut self, semaphore: &VulkanTimelineSemaphore, value: u64, stage: vulkan_types::PipelineStageFlags) -> Self
+    {
+        self.values.resize(self.semaphores.len(), 0);
+        self.semaphores.push(semaphore.handle);
+        self.waiting_stage.push(stage.as_raw());
+        self.values.push(value);
+        self
+    }
+
+    fn check_values(& self) -> Result<(), VulkanSynchroError>
+    {
+        if !self.values.is_empty() && self.values.len() != self.semaphores.len()
+        {
+            return Err(VulkanSynchroError::MISMATCHED_SEMAPHORE_VALUES(self.semaphores.len(), self.values.len()));
+        }
+        Ok(())
+    }
+}
+
+pub struct VulkanSignalSemaphoresInfo
+{
+    pub semaphores : Vec<vulkan_bindings::VkSemaphore>,
+    // either empty or one value per semaphore
+    pub values: Vec<u64>
+}
+
+impl VulkanSignalSemaphoresInfo
+{
+    pub fn new() -> Self
+    {
+        VulkanSignalSemaphoresInfo {
+            semaphores: Vec::new(),
+            values: Vec::new()
+        }
+    }
+
+    pub fn signal(mut self, semaphore: vulkan_bindings::VkSemaphore) -> Self
+    {
+        self.semaphores.push(semaphore);
+        if !self.values.is_empty()
+        {
+            self.values.push(0);
+        }
+        self
+    }
+
+    pub fn signal_value(mut self, semaphore: &VulkanTimelineSemaphore, value: u64) -> Self
+    {
+        self.values.resize(self.semaphores.len(), 0);
+        self.semaphores.push(semaphore.handle);
+        self.values.push(value);
+        self
+    }
+
+    fn check_values(& self) -> Result<(), VulkanSynchroError>
+    {
+        if !self.values.is_empty() && self.values.len() != self.semaphores.len()
+        {
+            return Err(VulkanSynchroError::MISMATCHED_SEMAPHORE_VALUES(self.semaphores.len(), self.values.len()));
+        }
+        Ok(())
+    }
 }
 
 pub fn init_semaphore(logical_device: &vulkan_init::VulkanLogicalDevice) -> Result<vulkan_bindings::VkSemaphore, VulkanSynchroError>
@@ -312,13 +651,14 @@ pub fn init_semaphore(logical_device: &vulkan_init::VulkanLogicalDevice) -> Resu
         flags: 0
     };
     unsafe{
-        let fn_vkCreateSemaphore = vulkan_init::vkCreateSemaphore.unwrap();
+        let fn_vkCreateSemaphore = logical_device.fns.vkCreateSemaphore.unwrap();
+        let allocator = logical_device.allocation_callbacks;
         let logical_device = logical_device.device;
         let mut sem : vulkan_bindings::VkSemaphore = std::ptr::null_mut();
-        let result = fn_vkCreateSemaphore(logical_device, &sem_create_info, std::ptr::null(), &mut sem);
+        let result = fn_vkCreateSemaphore(logical_device, &sem_create_info, allocator, &mut sem);
         if result != vulkan_bindings::VkResult_VK_SUCCESS
         {
-            return Err(VulkanSynchroError::FAILED_CREATING_SEMAPHORE);
+            return Err(VulkanSynchroError::FAILED_CREATING_SEMAPHORE(vulkan_error::VulkanCallError::new("vkCreateSemaphore", result)));
         }
         Ok(sem)
     }
@@ -328,8 +668,8 @@ pub fn destroy_semaphore(logical_device: &vulkan_init::VulkanLogicalDevice, sema
 {
     unsafe
     {
-        let fn_vkDestroySemaphore = vulkan_init::vkDestroySemaphore.unwrap();
-        fn_vkDestroySemaphore(logical_device.device, semaphore, std::ptr::null());   
+        let fn_vkDestroySemaphore = logical_device.fns.vkDestroySemaphore.unwrap();
+        fn_vkDestroySemaphore(logical_device.device, semaphore, logical_device.allocation_callbacks);   
     }
 }
 
@@ -341,26 +681,27 @@ pub fn init_fence(logical_device: &vulkan_init::VulkanLogicalDevice) -> Result<v
         flags:0
     };
     unsafe {
-        let fn_vkCreateFence = vulkan_init::vkCreateFence.unwrap();
+        let fn_vkCreateFence = logical_device.fns.vkCreateFence.unwrap();
+        let allocator = logical_device.allocation_callbacks;
         let logical_device = logical_device.device;
         let mut fence: vulkan_bindings::VkFence = std::ptr::null_mut();
-        let result = fn_vkCreateFence(logical_device, &fence_create_info, std::ptr::null(), &mut fence);
+        let result = fn_vkCreateFence(logical_device, &fence_create_info, allocator, &mut fence);
         if result != vulkan_bindings::VkResult_VK_SUCCESS
         {
-            return Err(VulkanSynchroError::FAILED_CREATING_FENCE);
+            return Err(VulkanSynchroError::FAILED_CREATING_FENCE(vulkan_error::VulkanCallError::new("vkCreateFence", result)));
         }
         Ok(fence)
     }
 }
 
-pub fn wait_fences(logical_device: &vulkan_init::VulkanLogicalDevice, fences: &Vec<vulkan_bindings::VkFence>, wait_all: vulkan_bindings::VkBool32, timeout: u64) -> Result<(), VulkanSynchroError>
+pub fn wait_fences(logical_device: &vulkan_init::VulkanLogicalDevice, fences: &[vulkan_bindings::VkFence], wait_all: vulkan_bindings::VkBool32, timeout: u64) -> Result<(), VulkanSynchroError>
 {
     unsafe
     {
-        let logical_device = logical_device.device;
-        let fn_vkWaitForFences = vulkan_init::vkWaitForFences.unwrap();
+        let fn_vkWaitForFences = logical_device.fns.vkWaitForFences.unwrap();
+        let device = logical_device.device;
         let result = fn_vkWaitForFences(
-            logical_device,
+            device,
             fences.len() as u32,
             fences.as_ptr(),
             wait_all,
@@ -368,22 +709,22 @@ pub fn wait_fences(logical_device: &vulkan_init::VulkanLogicalDevice, fences: &V
         );
         if result !=  vulkan_bindings::VkResult_VK_SUCCESS
         {
-            return Err(VulkanSynchroError::COULDNT_WAIT_FOR_FENCES);
+            return Err(VulkanSynchroError::COULDNT_WAIT_FOR_FENCES(logical_device.track_call_error(vulkan_error::VulkanCallError::new("vkWaitForFences", result))));
         }
     }
     Ok(())
 }
 
-pub fn reset_fences(logical_device: &vulkan_init::VulkanLogicalDevice, fences: &Vec<vulkan_bindings::VkFence>) -> Result<(), VulkanSynchroError>
+pub fn reset_fences(logical_device: &vulkan_init::VulkanLogicalDevice, fences: &[vulkan_bindings::VkFence]) -> Result<(), VulkanSynchroError>
 {
     unsafe
     {
+        let fn_vkResetFences = logical_device.fns.vkResetFences.unwrap();
         let logical_device = logical_device.device;
-        let fn_vkResetFences = vulkan_init::vkResetFences.unwrap();
         let result = fn_vkResetFences(logical_device, fences.len() as u32, fences.as_ptr());
         if result != vulkan_bindings::VkResult_VK_SUCCESS
         {
-            return Err(VulkanSynchroError::COULDNT_RESET_FENCES);
+            return Err(VulkanSynchroError::COULDNT_RESET_FENCES(vulkan_error::VulkanCallError::new("vkResetFences", result)));
         }
     }
     Ok(())
@@ -393,22 +734,150 @@ pub fn destroy_fence(logical_device: &vulkan_init::VulkanLogicalDevice, fence: v
 {
     unsafe
     {
-        let logical_device = logical_device.device;
-        let fn_vkDestroyFence = vulkan_init::vkDestroyFence.unwrap();
-        fn_vkDestroyFence(logical_device, fence, std::ptr::null());
+        let fn_vkDestroyFence = logical_device.fns.vkDestroyFence.unwrap();
+        fn_vkDestroyFence(logical_device.device, fence, logical_device.allocation_callbacks);
     }
 }
 
-pub fn check_queue_idle(queue: vulkan_bindings::VkQueue) -> bool
+pub fn check_queue_idle(logical_device: &vulkan_init::VulkanLogicalDevice, queue: vulkan_bindings::VkQueue) -> bool
 {
     unsafe
     {
-        let fn_vkQueueWaitIdle = vulkan_init::vkQueueWaitIdle.unwrap();
+        let fn_vkQueueWaitIdle = logical_device.fns.vkQueueWaitIdle.unwrap();
         let result = fn_vkQueueWaitIdle(queue);
         if result != vulkan_bindings::VkResult_VK_SUCCESS
         {
             return false;
         }
-        return true
+        true
+    }
+}
+
+// a single counter the gpu and the host both signal and wait on, replaces most per-frame fences
+pub struct VulkanTimelineSemaphore
+{
+    pub handle: vulkan_bindings::VkSemaphore,
+    logical_device: *const vulkan_init::VulkanLogicalDevice
+}
+
+impl VulkanTimelineSemaphore
+{
+    pub fn new(logical_device: &vulkan_init::VulkanLogicalDevice, initial_value: u64) -> Result<Self, VulkanSynchroError>
+    {
+        if !logical_device.supports_timeline_semaphores()
+        {
+            return Err(VulkanSynchroError::UNSUPPORTED_TIMELINE_SEMAPHORES);
+        }
+        let mut type_create_info = vulkan_structs::SemaphoreTypeCreateInfo::new()
+            .semaphore_type(vulkan_bindings::VkSemaphoreType_VK_SEMAPHORE_TYPE_TIMELINE)
+            .initial_value(initial_value);
+        let sem_create_info = vulkan_structs::SemaphoreCreateInfo::new()
+            .push_next(&mut type_create_info);
+        unsafe
+        {
+            let fn_vkCreateSemaphore = logical_device.fns.vkCreateSemaphore.unwrap();
+            let mut handle : vulkan_bindings::VkSemaphore = std::ptr::null_mut();
+            let result = fn_vkCreateSemaphore(logical_device.device, &*sem_create_info, logical_device.allocation_callbacks, &mut handle);
+            if result != vulkan_bindings::VkResult_VK_SUCCESS
+            {
+                return Err(VulkanSynchroError::FAILED_CREATING_SEMAPHORE(vulkan_error::VulkanCallError::new("vkCreateSemaphore", result)));
+            }
+            Ok(VulkanTimelineSemaphore {
+                handle,
+                logical_device
+            })
+        }
+    }
+
+    pub fn current_value(& self) -> Result<u64, VulkanSynchroError>
+    {
+        unsafe
+        {
+            let logical_device = &*self.logical_device;
+            let fn_vkGetSemaphoreCounterValue = match logical_device.fns.vkGetSemaphoreCounterValue.or(logical_device.fns.vkGetSemaphoreCounterValueKHR) {
+                Some(f) => f,
+                None => return Err(VulkanSynchroError::FAILED_READING_SEMAPHORE_VALUE(vulkan_error::VulkanCallError::missing("vkGetSemaphoreCounterValue")))
+            };
+            let mut value : u64 = 0;
+            let result = fn_vkGetSemaphoreCounterValue(logical_device.device, self.handle, &mut value);
+            if result != vulkan_bindings::VkResult_VK_SUCCESS
+            {
+                return Err(VulkanSynchroError::FAILED_READING_SEMAPHORE_VALUE(logical_device.track_call_error(vulkan_error::VulkanCallError::new("vkGetSemaphoreCounterValue", result))));
+            }
+            Ok(value)
+        }
+    }
+
+    pub fn signal(& self, value: u64) -> Result<(), VulkanSynchroError>
+    {
+        let signal_info = vulkan_bindings::VkSemaphoreSignalInfo {
+            sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_SEMAPHORE_SIGNAL_INFO,
+            pNext: std::ptr::null(),
+            semaphore: self.handle,
+            value
+        };
+        unsafe
+        {
+            let logical_device = &*self.logical_device;
+            let fn_vkSignalSemaphore = match logical_device.fns.vkSignalSemaphore.or(logical_device.fns.vkSignalSemaphoreKHR) {
+                Some(f) => f,
+                None => return Err(VulkanSynchroError::FAILED_SIGNALING_SEMAPHORE(vulkan_error::VulkanCallError::missing("vkSignalSemaphore")))
+            };
+            let result = fn_vkSignalSemaphore(logical_device.device, &signal_info);
+            if result != vulkan_bindings::VkResult_VK_SUCCESS
+            {
+                return Err(VulkanSynchroError::FAILED_SIGNALING_SEMAPHORE(logical_device.track_call_error(vulkan_error::VulkanCallError::new("vkSignalSemaphore", result))));
+            }
+        }
+        Ok(())
+    }
+
+    // returns false when the timeout (in nanoseconds) expired before the counter reached value
+    pub fn wait(& self, value: u64, timeout: u64) -> Result<bool, VulkanSynchroError>
+    {
+        unsafe
+        {
+            wait_timeline_semaphores(&*self.logical_device, &[(self, value)], true, timeout)
+        }
+    }
+
+    pub fn destroy(self)
+    {
+        unsafe
+        {
+            destroy_semaphore(&*self.logical_device, self.handle);
+        }
+    }
+}
+
+pub fn wait_timeline_semaphores(logical_device: &vulkan_init::VulkanLogicalDevice,
+    semaphores: &[(&VulkanTimelineSemaphore, u64)],
+    wait_all: bool,
+    timeout: u64
+) -> Result<bool, VulkanSynchroError>
+{
+    let handles : Vec<vulkan_bindings::VkSemaphore> = semaphores.iter().map(|(semaphore, _)| semaphore.handle).collect();
+    let values : Vec<u64> = semaphores.iter().map(|(_, value)| *value).collect();
+    let wait_info = vulkan_bindings::VkSemaphoreWaitInfo {
+        sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_SEMAPHORE_WAIT_INFO,
+        pNext: std::ptr::null(),
+        flags: if wait_all { 0 } else { vulkan_bindings::VkSemaphoreWaitFlagBits_VK_SEMAPHORE_WAIT_ANY_BIT },
+        semaphoreCount: handles.len() as u32,
+        pSemaphores: handles.as_ptr(),
+        pValues: values.as_ptr()
+    };
+    unsafe
+    {
+        let fn_vkWaitSemaphores = match logical_device.fns.vkWaitSemaphores.or(logical_device.fns.vkWaitSemaphoresKHR) {
+            Some(f) => f,
+            None => return Err(VulkanSynchroError::COULDNT_WAIT_FOR_SEMAPHORES(vulkan_error::VulkanCallError::missing("vkWaitSemaphores")))
+        };
+        let result = fn_vkWaitSemaphores(logical_device.device, &wait_info, timeout);
+        match result
+        {
+            vulkan_bindings::VkResult_VK_SUCCESS => Ok(true),
+            vulkan_bindings::VkResult_VK_TIMEOUT => Ok(false),
+            _ => Err(VulkanSynchroError::COULDNT_WAIT_FOR_SEMAPHORES(logical_device.track_call_error(vulkan_error::VulkanCallError::new("vkWaitSemaphores", result))))
+        }
     }
 }
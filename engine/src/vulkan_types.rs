@@ -0,0 +1,204 @@
+use crate::vulkan_bindings;
+
+// typed wrappers around bindgen's flat constants, vulkan_enums.rs is generated by `headersgen enums <vk.xml>`
+// and only lists the enums and bitmasks the engine exposes
+
+macro_rules! VULKAN_ENUM {
+    ($name: ident : $raw: ident { $($variant: ident = $value: ident),* }) => {
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name(vulkan_bindings::$raw);
+
+        impl $name {
+            $(pub const $variant: $name = $name(vulkan_bindings::$value as vulkan_bindings::$raw);)*
+
+            // every value the registry lists, in its order
+            pub const VALUES: &'static [$name] = &[$($name::$variant),*];
+
+            pub const fn from_raw(raw: vulkan_bindings::$raw) -> Self
+            {
+                $name(raw)
+            }
+
+            pub const fn as_raw(self) -> vulkan_bindings::$raw
+            {
+                self.0
+            }
+
+            pub fn name(self) -> Option<&'static str>
+            {
+                $(if self == $name::$variant
+                {
+                    return Some(stringify!($variant));
+                })*
+                None
+            }
+        }
+
+        impl std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self.name() {
+                    Some(name) => write!(f, "{}", name),
+                    None => write!(f, "{}({})", stringify!($name), self.0)
+                }
+            }
+        }
+
+        impl From<$name> for vulkan_bindings::$raw {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+    };
+}
+
+macro_rules! VULKAN_FLAGS {
+    ($name: ident : $raw: ident { $($flag: ident = $value: ident),* }) => {
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name(vulkan_bindings::$raw);
+
+        impl $name {
+            $(pub const $flag: $name = $name(vulkan_bindings::$value as vulkan_bindings::$raw);)*
+
+            const NAMES: &'static [(&'static str, $name)] = &[$((stringify!($flag), $name::$flag)),*];
+
+            pub const fn empty() -> Self
+            {
+                $name(0)
+            }
+
+            pub const fn from_raw(raw: vulkan_bindings::$raw) -> Self
+            {
+                $name(raw)
+            }
+
+            pub const fn as_raw(self) -> vulkan_bindings::$raw
+            {
+                self.0
+            }
+
+            pub const fn is_empty(self) -> bool
+            {
+                self.0 == 0
+            }
+
+            pub const fn contains(self, other: $name) -> bool
+            {
+                self.0 & other.0 == other.0
+            }
+
+            pub const fn intersects(self, other: $name) -> bool
+            {
+                self.0 & other.0 != 0
+            }
+        }
+
+        impl std::ops::BitOr for $name {
+            type Output = $name;
+            fn bitor(self, rhs: $name) -> $name {
+                $name(self.0 | rhs.0)
+            }
+        }
+
+        impl std::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: $name) {
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl std::ops::BitAnd for $name {
+            type Output = $name;
+            fn bitand(self, rhs: $name) -> $name {
+                $name(self.0 & rhs.0)
+            }
+        }
+
+        impl std::ops::BitAndAssign for $name {
+            fn bitand_assign(&mut self, rhs: $name) {
+                self.0 &= rhs.0;
+            }
+        }
+
+        // set flags are printed by name, bits the engine doesn't know about are kept as hex
+        impl std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                if self.0 == 0
+                {
+                    return write!(f, "{}(empty)", stringify!($name));
+                }
+                let mut remaining = self.0;
+                let mut separator = "";
+                for (name, flag) in $name::NAMES
+                {
+                    if flag.0 != 0 && remaining & flag.0 == flag.0
+                    {
+                        write!(f, "{}{}", separator, name)?;
+                        separator = " | ";
+                        remaining &= !flag.0;
+                    }
+                }
+                if remaining != 0
+                {
+                    write!(f, "{}{:#x}", separator, remaining)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl From<$name> for vulkan_bindings::$raw {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+    };
+}
+
+include!("./vulkan_enums.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_print_their_names()
+    {
+        assert_eq!(format!("{:?}", QueueFlags::empty()), "QueueFlags(empty)");
+        assert_eq!(format!("{:?}", QueueFlags::GRAPHICS), "GRAPHICS");
+        assert_eq!(format!("{:?}", QueueFlags::COMPUTE | QueueFlags::GRAPHICS), "GRAPHICS | COMPUTE");
+    }
+
+    #[test]
+    fn unknown_bits_are_printed_as_hex()
+    {
+        assert_eq!(format!("{:?}", QueueFlags::from_raw(0x100)), "0x100");
+        assert_eq!(format!("{:?}", QueueFlags::TRANSFER | QueueFlags::from_raw(0x300)), "TRANSFER | 0x300");
+    }
+
+    #[test]
+    fn wide_flags_print_bits_above_32()
+    {
+        let stages = PipelineStageFlags2::COPY | PipelineStageFlags2::BLIT | PipelineStageFlags2::from_raw(1 << 60);
+        assert_eq!(format!("{:?}", stages), "COPY | BLIT | 0x1000000000000000");
+        // NONE is zero and never matches a set bit
+        assert_eq!(format!("{:?}", PipelineStageFlags2::NONE), "PipelineStageFlags2(empty)");
+    }
+
+    #[test]
+    fn enums_print_their_variant()
+    {
+        assert_eq!(format!("{:?}", ImageType::TYPE_2D), "TYPE_2D");
+        assert_eq!(format!("{:?}", ImageType::from_raw(42)), "ImageType(42)");
+        assert_eq!(ImageLayout::PRESENT_SRC_KHR.name(), Some("PRESENT_SRC_KHR"));
+        assert_eq!(format!("{:?}", Format::from_raw(vulkan_bindings::VkFormat_VK_FORMAT_ASTC_4x4_SFLOAT_BLOCK)), "ASTC_4x4_SFLOAT_BLOCK");
+    }
+
+    #[test]
+    fn enum_values_include_promoted_extension_values()
+    {
+        assert_eq!(Format::VALUES[0], Format::UNDEFINED);
+        assert!(Format::VALUES.contains(&Format::G8B8G8R8_422_UNORM));
+        assert!(Format::VALUES.contains(&Format::A4B4G4R4_UNORM_PACK16));
+        assert!(Format::VALUES.iter().all(|format| format.name().is_some()));
+    }
+}
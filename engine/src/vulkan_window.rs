@@ -2,34 +2,43 @@ use crate::vulkan_init;
 use crate::vulkan_bindings;
 use crate::system_window;
 use crate::vulkan_synchro;
+use crate::vulkan_error;
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
+use crate::vulkan_types;
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
+use crate::vulkan_structs;
 
 #[derive(Debug)]
 pub enum VulkanWindowError
 {
-    DEFAULT_ERROR(String),
-    CANT_LOAD_VULKAN_SURFACE,
-    CANT_LOAD_SURFACE_CAPABILITIES,
+    INIT_ERROR(vulkan_init::VulkanInitError),
+    SYNCHRO_ERROR(vulkan_synchro::VulkanSynchroError),
+    CANT_CREATE_WINDOW(system_window::SystemWindowError),
+    CANT_LOAD_VULKAN_SURFACE(vulkan_error::VulkanCallError),
+    CANT_LOAD_SURFACE_CAPABILITIES(vulkan_error::VulkanCallError),
     UNSUPPORTED_IMAGE_USAGE,
-    CANT_LOAD_SURFACE_FORMATS,
-    FAILED_CREATING_SWAPCHAIN,
-    CANT_LOAD_SWAPCHAIN_IMAGE,
-    UNUSABLE_SWAPCHAIN,
-    COULDNT_PRESENT_IMAGE
+    CANT_LOAD_SURFACE_FORMATS(vulkan_error::VulkanCallError),
+    FAILED_CREATING_SWAPCHAIN(vulkan_error::VulkanCallError),
+    CANT_LOAD_SWAPCHAIN_IMAGE(vulkan_error::VulkanCallError),
+    UNUSABLE_SWAPCHAIN(vulkan_error::VulkanCallError),
+    COULDNT_PRESENT_IMAGE(vulkan_error::VulkanCallError)
 }
 
 impl std::fmt::Display for VulkanWindowError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self
         {
-            VulkanWindowError::DEFAULT_ERROR(s) => write!(f, "{}", s),
-            VulkanWindowError::CANT_LOAD_VULKAN_SURFACE => write!(f, "Couldn't a vulkan surface"),
-            VulkanWindowError::CANT_LOAD_SURFACE_CAPABILITIES => write!(f, "Couldn't load surface Capabilities"),
+            VulkanWindowError::INIT_ERROR(_) => write!(f, "Couldn't set up the surface"),
+            VulkanWindowError::SYNCHRO_ERROR(_) => write!(f, "Couldn't synchronize the swapchain"),
+            VulkanWindowError::CANT_CREATE_WINDOW(_) => write!(f, "Couldn't create the window"),
+            VulkanWindowError::CANT_LOAD_VULKAN_SURFACE(_) => write!(f, "Couldn't a vulkan surface"),
+            VulkanWindowError::CANT_LOAD_SURFACE_CAPABILITIES(_) => write!(f, "Couldn't load surface Capabilities"),
             VulkanWindowError::UNSUPPORTED_IMAGE_USAGE => write!(f, "Unsupported swapchain image usage"),
-            VulkanWindowError::CANT_LOAD_SURFACE_FORMATS => write!(f, "Couldn't load surface formats"),
-            VulkanWindowError::FAILED_CREATING_SWAPCHAIN => write!(f, "Couldn't create swapchain"),
-            VulkanWindowError::CANT_LOAD_SWAPCHAIN_IMAGE => write!(f, "Couldn't load swapchain image"),
-            VulkanWindowError::COULDNT_PRESENT_IMAGE => write!(f, "Couldn't present image"),
-            VulkanWindowError::UNUSABLE_SWAPCHAIN => write!(f, "")
+            VulkanWindowError::CANT_LOAD_SURFACE_FORMATS(_) => write!(f, "Couldn't load surface formats"),
+            VulkanWindowError::FAILED_CREATING_SWAPCHAIN(_) => write!(f, "Couldn't create swapchain"),
+            VulkanWindowError::CANT_LOAD_SWAPCHAIN_IMAGE(_) => write!(f, "Couldn't load swapchain image"),
+            VulkanWindowError::COULDNT_PRESENT_IMAGE(_) => write!(f, "Couldn't present image"),
+            VulkanWindowError::UNUSABLE_SWAPCHAIN(_) => write!(f, "Swapchain is unusable")
         }
     }
 }
@@ -37,76 +46,204 @@ impl std::fmt::Display for VulkanWindowError {
 impl From<vulkan_init::VulkanInitError> for VulkanWindowError
 {
     fn from(value: vulkan_init::VulkanInitError) -> Self {
-        VulkanWindowError::DEFAULT_ERROR(value.to_string())
+        VulkanWindowError::INIT_ERROR(value)
     }
 }
 
 impl From<vulkan_synchro::VulkanSynchroError> for VulkanWindowError
 {
     fn from(value: vulkan_synchro::VulkanSynchroError) -> Self {
-        VulkanWindowError::DEFAULT_ERROR(value.to_string())
+        VulkanWindowError::SYNCHRO_ERROR(value)
     }
 }
 
-impl std::error::Error for VulkanWindowError {}
+impl From<system_window::SystemWindowError> for VulkanWindowError
+{
+    fn from(value: system_window::SystemWindowError) -> Self {
+        VulkanWindowError::CANT_CREATE_WINDOW(value)
+    }
+}
+
+impl VulkanWindowError {
+    pub fn call_error(& self) -> Option<&vulkan_error::VulkanCallError>
+    {
+        match self
+        {
+            VulkanWindowError::CANT_LOAD_VULKAN_SURFACE(e)
+            | VulkanWindowError::CANT_LOAD_SURFACE_CAPABILITIES(e)
+            | VulkanWindowError::CANT_LOAD_SURFACE_FORMATS(e)
+            | VulkanWindowError::FAILED_CREATING_SWAPCHAIN(e)
+            | VulkanWindowError::CANT_LOAD_SWAPCHAIN_IMAGE(e)
+            | VulkanWindowError::UNUSABLE_SWAPCHAIN(e)
+            | VulkanWindowError::COULDNT_PRESENT_IMAGE(e) => Some(e),
+            VulkanWindowError::INIT_ERROR(e) => e.call_error(),
+            VulkanWindowError::SYNCHRO_ERROR(e) => e.call_error(),
+            VulkanWindowError::UNSUPPORTED_IMAGE_USAGE
+            | VulkanWindowError::CANT_CREATE_WINDOW(_) => None
+        }
+    }
+}
 
+impl VulkanWindowError {
+    pub fn is_device_lost(& self) -> bool
+    {
+        self.call_error().is_some_and(|e| e.is_device_lost())
+    }
+}
+
+impl std::error::Error for VulkanWindowError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self
+        {
+            VulkanWindowError::INIT_ERROR(e) => Some(e),
+            VulkanWindowError::SYNCHRO_ERROR(e) => Some(e),
+            VulkanWindowError::CANT_CREATE_WINDOW(e) => Some(e),
+            _ => self.call_error().map(|e| e as &(dyn std::error::Error + 'static))
+        }
+    }
+}
+
+// vkCreate*SurfaceKHR for whichever window system the handle comes from
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
+unsafe fn create_platform_surface(vk_instance: &vulkan_init::VulkanInstance, handle: &system_window::WindowHandle) -> Result<vulkan_bindings::VkSurfaceKHR, VulkanWindowError>
+{
+    let mut surface : vulkan_bindings::VkSurfaceKHR = std::ptr::null_mut();
+    let (call, result) = match *handle {
+        #[cfg(feature = "win32")]
+        system_window::WindowHandle::WIN32 { hinstance, hwnd } => {
+            let fn_vkCreateWin32SurfaceKHR = vk_instance.fns.vkCreateWin32SurfaceKHR
+                .ok_or(VulkanWindowError::CANT_LOAD_VULKAN_SURFACE(vulkan_error::VulkanCallError::missing("vkCreateWin32SurfaceKHR")))?;
+            let surface_create_info = vulkan_bindings::VkWin32SurfaceCreateInfoKHR {
+                sType : vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_WIN32_SURFACE_CREATE_INFO_KHR,
+                pNext : std::ptr::null(),
+                flags: 0,
+                hinstance,
+                hwnd
+            };
+            ("vkCreateWin32SurfaceKHR", fn_vkCreateWin32SurfaceKHR(vk_instance.instance, &surface_create_info, vk_instance.allocation_callbacks(), &mut surface))
+        },
+        #[cfg(feature = "xlib")]
+        system_window::WindowHandle::XLIB { display, window } => {
+            let fn_vkCreateXlibSurfaceKHR = vk_instance.fns.vkCreateXlibSurfaceKHR
+                .ok_or(VulkanWindowError::CANT_LOAD_VULKAN_SURFACE(vulkan_error::VulkanCallError::missing("vkCreateXlibSurfaceKHR")))?;
+            let surface_create_info = vulkan_bindings::VkXlibSurfaceCreateInfoKHR {
+                sType : vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_XLIB_SURFACE_CREATE_INFO_KHR,
+                pNext : std::ptr::null(),
+                flags: 0,
+                dpy: display,
+                window
+            };
+            ("vkCreateXlibSurfaceKHR", fn_vkCreateXlibSurfaceKHR(vk_instance.instance, &surface_create_info, vk_instance.allocation_callbacks(), &mut surface))
+        },
+        #[cfg(feature = "xcb")]
+        system_window::WindowHandle::XCB { connection, window } => {
+            let fn_vkCreateXcbSurfaceKHR = vk_instance.fns.vkCreateXcbSurfaceKHR
+                .ok_or(VulkanWindowError::CANT_LOAD_VULKAN_SURFACE(vulkan_error::VulkanCallError::missing("vkCreateXcbSurfaceKHR")))?;
+            let surface_create_info = vulkan_bindings::VkXcbSurfaceCreateInfoKHR {
+                sType : vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_XCB_SURFACE_CREATE_INFO_KHR,
+                pNext : std::ptr::null(),
+                flags: 0,
+                connection,
+                window
+            };
+            ("vkCreateXcbSurfaceKHR", fn_vkCreateXcbSurfaceKHR(vk_instance.instance, &surface_create_info, vk_instance.allocation_callbacks(), &mut surface))
+        },
+        #[cfg(feature = "wayland")]
+        system_window::WindowHandle::WAYLAND { display, surface: wl_surface } => {
+            let fn_vkCreateWaylandSurfaceKHR = vk_instance.fns.vkCreateWaylandSurfaceKHR
+                .ok_or(VulkanWindowError::CANT_LOAD_VULKAN_SURFACE(vulkan_error::VulkanCallError::missing("vkCreateWaylandSurfaceKHR")))?;
+            let surface_create_info = vulkan_bindings::VkWaylandSurfaceCreateInfoKHR {
+                sType : vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_WAYLAND_SURFACE_CREATE_INFO_KHR,
+                pNext : std::ptr::null(),
+                flags: 0,
+                display,
+                surface: wl_surface
+            };
+            ("vkCreateWaylandSurfaceKHR", fn_vkCreateWaylandSurfaceKHR(vk_instance.instance, &surface_create_info, vk_instance.allocation_callbacks(), &mut surface))
+        }
+    };
+    if result != vulkan_bindings::VkResult_VK_SUCCESS || surface == std::ptr::null_mut()
+    {
+        return Err(VulkanWindowError::CANT_LOAD_VULKAN_SURFACE(vulkan_error::VulkanCallError::new(call, result)));
+    }
+    Ok(surface)
+}
+
+// surfaces and swapchains only exist in builds with a window backend
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
 pub struct VulkanSurface {
     pub window : system_window::WindowParameters,
     pub surface : vulkan_bindings::VkSurfaceKHR,
+    pub instance: *const vulkan_init::VulkanInstance,
     pub logical_device: *const vulkan_init::VulkanLogicalDevice,
     pub capabilites : vulkan_bindings::VkSurfaceCapabilitiesKHR,
     pub swapchain_images_count : u32,
     pub swapchain_image_size: vulkan_bindings::VkExtent2D,
-    pub swapchain_image_usage: vulkan_bindings::VkImageUsageFlags,
-    pub swapchain_image_transform: vulkan_bindings::VkSurfaceTransformFlagsKHR,
+    pub swapchain_image_usage: vulkan_types::ImageUsageFlags,
+    pub swapchain_image_transform: vulkan_types::SurfaceTransformFlagsKHR,
     pub surface_format: vulkan_bindings::VkSurfaceFormatKHR,
     pub swapchain: Option<VulkanSwapchain>
 }
 
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
 impl VulkanSurface {
-    pub fn new(vk_instance: &vulkan_init::VulkanInstance) -> Result<Self, VulkanWindowError>
+    pub fn new(vk_instance: &vulkan_init::VulkanInstance, descriptor: &system_window::WindowDescriptor) -> Result<Self, VulkanWindowError>
     {
-        unsafe {
-            let mut vk_surface = VulkanSurface {
-                window : system_window::WindowParameters::new("Anvil".to_string()),
-                surface: std::ptr::null_mut(),
-                logical_device: std::ptr::null(),
-                capabilites: std::mem::zeroed(),
-                swapchain_images_count : 0,
-                swapchain_image_size: std::mem::zeroed(),
-                swapchain_image_usage: 0,
-                swapchain_image_transform : 0,
-                surface_format: std::mem::zeroed(),
-                swapchain: None
-            };
-            let vk_surface_create_info = vulkan_bindings::VkWin32SurfaceCreateInfoKHR {
-                sType : vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_WIN32_SURFACE_CREATE_INFO_KHR,
-                pNext : std::ptr::null(),
-                flags: 0,
-                hinstance: vk_surface.window.Hinstance,
-                hwnd: vk_surface.window.Hwnd
-            };
-            let fn_vkCreateWin32SurfaceKHR = vulkan_init::vkCreateWin32SurfaceKHR.unwrap();
-            let result = fn_vkCreateWin32SurfaceKHR(vk_instance.instance, &vk_surface_create_info, std::ptr::null(), &mut vk_surface.surface);
-            if result != vulkan_bindings::VkResult_VK_SUCCESS || vk_surface.surface == std::ptr::null_mut()
-            {
-                return Err(VulkanWindowError::CANT_LOAD_VULKAN_SURFACE);
+        let backend = system_window::WindowBackend::default_backend().ok_or(system_window::SystemWindowError::NO_WINDOW_BACKEND)?;
+        VulkanSurface::with_backend(vk_instance, backend, descriptor)
+    }
+
+    // the instance needs backend.surface_extension_name() enabled
+    pub fn with_backend(vk_instance: &vulkan_init::VulkanInstance, backend: system_window::WindowBackend, descriptor: &system_window::WindowDescriptor) -> Result<Self, VulkanWindowError>
+    {
+        let window = system_window::WindowParameters::with_backend(backend, descriptor)?;
+        unsafe { VulkanSurface::with_window(vk_instance, window) }
+    }
+
+    // renders into a window the host application created, WindowHandle::from_window takes raw-window-handle types.
+    // the instance needs handle.backend().surface_extension_name() enabled, the handles have to outlive the surface
+    // and the host passes resizes on through window.set_size
+    pub unsafe fn from_handle(vk_instance: &vulkan_init::VulkanInstance, handle: system_window::WindowHandle, width: u32, height: u32) -> Result<Self, VulkanWindowError>
+    {
+        VulkanSurface::with_window(vk_instance, system_window::WindowParameters::from_handle(handle, width, height))
+    }
+
+    unsafe fn with_window(vk_instance: &vulkan_init::VulkanInstance, window: system_window::WindowParameters) -> Result<Self, VulkanWindowError>
+    {
+        let mut vk_surface = VulkanSurface {
+            window,
+            surface: std::ptr::null_mut(),
+            instance: vk_instance,
+            logical_device: std::ptr::null(),
+            capabilites: std::mem::zeroed(),
+            swapchain_images_count : 0,
+            swapchain_image_size: std::mem::zeroed(),
+            swapchain_image_usage: vulkan_types::ImageUsageFlags::empty(),
+            swapchain_image_transform : vulkan_types::SurfaceTransformFlagsKHR::empty(),
+            surface_format: std::mem::zeroed(),
+            swapchain: None
+        };
+        vk_surface.surface = match create_platform_surface(vk_instance, &vk_surface.window.handle()) {
+            Ok(surface) => surface,
+            Err(e) => {
+                vk_surface.window.destroy();
+                return Err(e);
             }
-            Ok(vk_surface)
-        }
+        };
+        Ok(vk_surface)
     }
 
     pub fn load_surface_capabilities(&mut self) -> Result<(), VulkanWindowError>
     {
         unsafe
         {
-            let fn_vkGetPhysicalDeviceSurfaceCapabilitiesKHR = vulkan_init::vkGetPhysicalDeviceSurfaceCapabilitiesKHR.unwrap();
-            let ref physical_device =  *(*self.logical_device).physical_device;
+            let fn_vkGetPhysicalDeviceSurfaceCapabilitiesKHR = (*(*self.logical_device).physical_device).fns.vkGetPhysicalDeviceSurfaceCapabilitiesKHR.unwrap();
+            let physical_device = &*(*self.logical_device).physical_device;
             let physical_device = physical_device.ph_device;
             let result = fn_vkGetPhysicalDeviceSurfaceCapabilitiesKHR(physical_device, self.surface, &mut self.capabilites);
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanWindowError::CANT_LOAD_SURFACE_CAPABILITIES)
+                return Err(VulkanWindowError::CANT_LOAD_SURFACE_CAPABILITIES(vulkan_error::VulkanCallError::new("vkGetPhysicalDeviceSurfaceCapabilitiesKHR", result)))
             }
         }
         Ok(())
@@ -127,10 +264,10 @@ impl VulkanSurface {
 
     pub fn set_swapchain_image_size(&mut self)
     {
-        let ref capabilities = self.capabilites;
-        if capabilities.currentExtent.width == (0xFFFFFFFF as u32)
+        let capabilities = &self.capabilites;
+        if capabilities.currentExtent.width == 0xFFFFFFFF_u32
         {
-            let (width, height) = (system_window::DISPLAY_WIDTH as u32, system_window::DISPLAY_HEIGHT as u32);
+            let (width, height) = self.window.size();
             self.swapchain_image_size.width = match width
             {
                 w if w < capabilities.minImageExtent.width => capabilities.minImageExtent.width,
@@ -151,9 +288,9 @@ impl VulkanSurface {
     }
 
 
-    pub fn set_swapchain_image_usage(&mut self, desired_usage: vulkan_bindings::VkImageUsageFlags) -> Result<(), VulkanWindowError>
+    pub fn set_swapchain_image_usage(&mut self, desired_usage: vulkan_types::ImageUsageFlags) -> Result<(), VulkanWindowError>
     {
-        match desired_usage & self.capabilites.supportedUsageFlags
+        match desired_usage & vulkan_types::ImageUsageFlags::from_raw(self.capabilites.supportedUsageFlags)
         {
             usage if usage == desired_usage => 
             {
@@ -164,15 +301,15 @@ impl VulkanSurface {
         }
     }
 
-    pub fn set_swapchain_image_transform(&mut self, transform : vulkan_bindings::VkSurfaceTransformFlagsKHR)
+    pub fn set_swapchain_image_transform(&mut self, transform : vulkan_types::SurfaceTransformFlagsKHR)
     {
-        if (transform & self.capabilites.supportedTransforms) == transform 
+        if vulkan_types::SurfaceTransformFlagsKHR::from_raw(self.capabilites.supportedTransforms).contains(transform)
         {
             self.swapchain_image_transform = transform;
         }
         else
         {
-            self.swapchain_image_transform = self.capabilites.currentTransform as  u32;
+            self.swapchain_image_transform = vulkan_types::SurfaceTransformFlagsKHR::from_raw(self.capabilites.currentTransform as vulkan_bindings::VkSurfaceTransformFlagsKHR);
         }
     }
 
@@ -181,18 +318,18 @@ impl VulkanSurface {
         unsafe
         {
             let mut formats_count:u32 = 0;
-            let fn_vkGetPhysicalDeviceSurfaceFormatsKHR = vulkan_init::vkGetPhysicalDeviceSurfaceFormatsKHR.unwrap();
+            let fn_vkGetPhysicalDeviceSurfaceFormatsKHR = (*(*self.logical_device).physical_device).fns.vkGetPhysicalDeviceSurfaceFormatsKHR.unwrap();
             let physical_device = (*(*self.logical_device).physical_device).ph_device;
             let result = fn_vkGetPhysicalDeviceSurfaceFormatsKHR(physical_device, self.surface, &mut formats_count, std::ptr::null_mut());
             if result != vulkan_bindings::VkResult_VK_SUCCESS || formats_count == 0
             {
-                return Err(VulkanWindowError::CANT_LOAD_SURFACE_FORMATS);
+                return Err(VulkanWindowError::CANT_LOAD_SURFACE_FORMATS(vulkan_error::VulkanCallError::new("vkGetPhysicalDeviceSurfaceFormatsKHR", result)));
             }
             let mut available_surface_format: Vec<vulkan_bindings::VkSurfaceFormatKHR> = vec![std::mem::zeroed(); formats_count as usize]; 
             let result = fn_vkGetPhysicalDeviceSurfaceFormatsKHR(physical_device, self.surface, &mut formats_count , available_surface_format.as_mut_ptr());
             if result != vulkan_bindings::VkResult_VK_SUCCESS || formats_count == 0
             {
-                return Err(VulkanWindowError::CANT_LOAD_SURFACE_FORMATS);
+                return Err(VulkanWindowError::CANT_LOAD_SURFACE_FORMATS(vulkan_error::VulkanCallError::new("vkGetPhysicalDeviceSurfaceFormatsKHR", result)));
             }
             Ok(available_surface_format)
         }
@@ -234,8 +371,8 @@ impl VulkanSurface {
     pub fn configure_swapchain(&mut self,
         logical_device: &vulkan_init::VulkanLogicalDevice,
         image_count : u32,
-        image_usage: vulkan_bindings::VkImageUsageFlags,
-        image_transform : vulkan_bindings::VkSurfaceTransformFlagsKHR,
+        image_usage: vulkan_types::ImageUsageFlags,
+        image_transform : vulkan_types::SurfaceTransformFlagsKHR,
         image_format: &vulkan_bindings::VkSurfaceFormatKHR,
     ) -> Result<(), VulkanWindowError>
     {
@@ -273,6 +410,7 @@ impl VulkanSurface {
             match swapchain_ref.get_next_image()
             {
                 Ok(_) => return Ok(()),
+                Err(e) if e.is_device_lost() => return Err(e),
                 Err(_) => ()
             };
         }
@@ -284,46 +422,48 @@ impl VulkanSurface {
     {
         self.acquire_next_image()?;
         let swapchain = self.swapchain.as_ref().unwrap();
-        let present_info = vulkan_bindings::VkPresentInfoKHR
-        {
-            sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_PRESENT_INFO_KHR,
-            pNext: std::ptr::null(),
-            waitSemaphoreCount: 1,
-            pWaitSemaphores: &swapchain.images_sem,
-            swapchainCount: 1,
-            pSwapchains: &swapchain.swapchain_handle,
-            pImageIndices: &swapchain.presentable_img_idx,
-            pResults: std::ptr::null_mut()
-        };
+        let present_info = vulkan_structs::PresentInfoKHR::new()
+            .wait_semaphores(std::slice::from_ref(&swapchain.images_sem))
+            .swapchains(std::slice::from_ref(&swapchain.swapchain_handle), std::slice::from_ref(&swapchain.presentable_img_idx));
         unsafe {
-            let queue = match (*self.logical_device).get_device_queue(vulkan_bindings::VkQueueFlagBits_VK_QUEUE_GRAPHICS_BIT as u32 , 0)
+            let queue = match (*self.logical_device).queue(vulkan_init::VulkanQueueRole::PRESENT)
             {
-                Some(q) => q,
-                None => return Err(VulkanWindowError::COULDNT_PRESENT_IMAGE)
+                Some(q) => q.handle,
+                None => return Err(VulkanWindowError::SYNCHRO_ERROR(vulkan_synchro::VulkanSynchroError::UNAVAILABLE_QUEUE_ROLE(vulkan_init::VulkanQueueRole::PRESENT)))
             };
-            let fn_vkQueuePresentKHR = vulkan_init::vkQueuePresentKHR.unwrap();
-            let result = fn_vkQueuePresentKHR(queue, &present_info);
+            let fn_vkQueuePresentKHR = (*self.logical_device).fns.vkQueuePresentKHR.unwrap();
+            let result = fn_vkQueuePresentKHR(queue, &*present_info);
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanWindowError::COULDNT_PRESENT_IMAGE);
+                return Err(VulkanWindowError::COULDNT_PRESENT_IMAGE((*self.logical_device).track_call_error(vulkan_error::VulkanCallError::new("vkQueuePresentKHR", result))));
             }
         }
         Ok(())
     }
 
+    // the swapchain belongs to the logical device, the surface itself survives a device loss
+    pub fn release_swapchain(&mut self)
+    {
+        if let Some(s) = self.swapchain.take() { s.destroy() }
+    }
+
+    pub fn recreate_swapchain(&mut self) -> Result<(), VulkanWindowError>
+    {
+        self.load_surface_capabilities()?;
+        self.set_swapchain_image_size();
+        self.create_swapchain()
+    }
+
+    // the surface has to go before the native window it was created from
     pub fn destroy(mut self)
     {
-        self.window.destroy();
-        match self.swapchain.take()
-        {
-            Some(s) => s.destroy(),
-            None => {}
-        }
+        if let Some(s) = self.swapchain.take() { s.destroy() }
         unsafe{
-            let fn_vkDestroySurfaceKHR = vulkan_init::vkDestroySurfaceKHR.unwrap();
-            let vk_instance = vulkan_init::VULKAN_INSTANCE.as_ref().unwrap();
-            fn_vkDestroySurfaceKHR( vk_instance.instance, self.surface, std::ptr::null());
+            let vk_instance = &*self.instance;
+            let fn_vkDestroySurfaceKHR = vk_instance.fns.vkDestroySurfaceKHR.unwrap();
+            fn_vkDestroySurfaceKHR( vk_instance.instance, self.surface, vk_instance.allocation_callbacks());
         }
+        self.window.destroy();
     }
 }
 
@@ -333,6 +473,7 @@ pub struct VulkanSemaphore
 
 }
 //swapchain instance
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
 pub struct VulkanSwapchain
 {
     pub surface: *const VulkanSurface,
@@ -343,6 +484,7 @@ pub struct VulkanSwapchain
     pub presentable_img_idx: u32,
 }
 
+#[cfg(any(feature = "win32", feature = "xlib", feature = "xcb", feature = "wayland"))]
 impl VulkanSwapchain
 {
     pub fn new(vk_surface:& VulkanSurface) -> Result<Self, VulkanWindowError>
@@ -357,32 +499,26 @@ impl VulkanSwapchain
         };
         unsafe
         {
-            let swapchain_create_info = vulkan_bindings::VkSwapchainCreateInfoKHR {
-                sType: vulkan_bindings::VkStructureType_VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR,
-                pNext: std::ptr::null(),
-                flags:0,
-                surface: vk_surface.surface,
-                minImageCount: vk_surface.swapchain_images_count,
-                imageFormat:  vk_surface.surface_format.format,
-                imageColorSpace : vk_surface.surface_format.colorSpace,
-                imageExtent: vk_surface.swapchain_image_size,
-                imageArrayLayers: 1,
-                imageUsage: vk_surface.swapchain_image_usage,
-                imageSharingMode: vulkan_bindings::VkSharingMode_VK_SHARING_MODE_EXCLUSIVE,
-                queueFamilyIndexCount: 0,
-                pQueueFamilyIndices: std::ptr::null(),
-                preTransform: vk_surface.swapchain_image_transform as i32,
-                compositeAlpha: vulkan_bindings::VkCompositeAlphaFlagBitsKHR_VK_COMPOSITE_ALPHA_OPAQUE_BIT_KHR,
-                presentMode: (*vk_surface.logical_device).presentation_mode,
-                clipped: vulkan_bindings::VK_TRUE,
-                oldSwapchain: match &vk_surface.swapchain { Some(s) => s.swapchain_handle , None => std::ptr::null_mut()}
-            };
-            let fn_vkCreateSwapchainKHR =  vulkan_init::vkCreateSwapchainKHR.unwrap();
+            let swapchain_create_info = vulkan_structs::SwapchainCreateInfoKHR::new()
+                .surface(vk_surface.surface)
+                .min_image_count(vk_surface.swapchain_images_count)
+                .image_format(vk_surface.surface_format.format)
+                .image_color_space(vk_surface.surface_format.colorSpace)
+                .image_extent(vk_surface.swapchain_image_size)
+                .image_array_layers(1)
+                .image_usage(vk_surface.swapchain_image_usage)
+                .image_sharing_mode(vulkan_bindings::VkSharingMode_VK_SHARING_MODE_EXCLUSIVE)
+                .pre_transform(vk_surface.swapchain_image_transform)
+                .composite_alpha(vulkan_bindings::VkCompositeAlphaFlagBitsKHR_VK_COMPOSITE_ALPHA_OPAQUE_BIT_KHR)
+                .present_mode((*vk_surface.logical_device).presentation_mode)
+                .clipped(vulkan_bindings::VK_TRUE)
+                .old_swapchain(match &vk_surface.swapchain { Some(s) => s.swapchain_handle , None => std::ptr::null_mut()});
+            let fn_vkCreateSwapchainKHR =  (*vk_surface.logical_device).fns.vkCreateSwapchainKHR.unwrap();
             let result = fn_vkCreateSwapchainKHR((*vk_surface.logical_device).device,
-                &swapchain_create_info, std::ptr::null(), &mut swapchain.swapchain_handle);
+                &*swapchain_create_info, (*vk_surface.logical_device).allocation_callbacks, &mut swapchain.swapchain_handle);
             if result != vulkan_bindings::VkResult_VK_SUCCESS
             {
-                return Err(VulkanWindowError::FAILED_CREATING_SWAPCHAIN);
+                return Err(VulkanWindowError::FAILED_CREATING_SWAPCHAIN(vulkan_error::VulkanCallError::new("vkCreateSwapchainKHR", result)));
             }
             swapchain.load_swap_chain_images()?;
         }
@@ -393,20 +529,20 @@ impl VulkanSwapchain
     {
         unsafe
         {
-            let fn_vkGetSwapchainImagesKHR =  vulkan_init::vkGetSwapchainImagesKHR.unwrap();
+            let fn_vkGetSwapchainImagesKHR =  (*(*self.surface).logical_device).fns.vkGetSwapchainImagesKHR.unwrap();
             let mut image_count = 0;
-            let ref logical_device = *(*self.surface).logical_device;
+            let logical_device = &*(*self.surface).logical_device;
             let result = fn_vkGetSwapchainImagesKHR(logical_device.device, self.swapchain_handle,&mut image_count, std::ptr::null_mut());
             if result != vulkan_bindings::VkResult_VK_SUCCESS || image_count == 0
             {
-                return Err(VulkanWindowError::CANT_LOAD_SWAPCHAIN_IMAGE);
+                return Err(VulkanWindowError::CANT_LOAD_SWAPCHAIN_IMAGE(vulkan_error::VulkanCallError::new("vkGetSwapchainImagesKHR", result)));
             }
             self.swapchain_images.resize(image_count as usize, std::mem::zeroed());
             let result = fn_vkGetSwapchainImagesKHR(logical_device.device,
                 self.swapchain_handle, &mut image_count, self.swapchain_images.as_mut_ptr());
             if result != vulkan_bindings::VkResult_VK_SUCCESS || image_count == 0
             {
-                return Err(VulkanWindowError::CANT_LOAD_SWAPCHAIN_IMAGE);
+                return Err(VulkanWindowError::CANT_LOAD_SWAPCHAIN_IMAGE(vulkan_error::VulkanCallError::new("vkGetSwapchainImagesKHR", result)));
             }
         }
         Ok(())
@@ -415,16 +551,15 @@ impl VulkanSwapchain
     pub fn get_next_image(&mut self) -> Result<(), VulkanWindowError>
     {
         unsafe {
-            let ref logical_device = *(*self.surface).logical_device;
+            let logical_device = &*(*self.surface).logical_device;
             self.images_sem = vulkan_synchro::init_semaphore(logical_device)?;
             self.images_fence = vulkan_synchro::init_fence(logical_device)?;
-            let fn_vkAcquireNextImageKHR = vulkan_init::vkAcquireNextImageKHR.unwrap();
-            let logical_device = (*(*self.surface).logical_device).device;
-            let result = fn_vkAcquireNextImageKHR(logical_device, self.swapchain_handle, 2000000000, self.images_sem, self.images_fence, &mut self.presentable_img_idx);
+            let fn_vkAcquireNextImageKHR = logical_device.fns.vkAcquireNextImageKHR.unwrap();
+            let result = fn_vkAcquireNextImageKHR(logical_device.device, self.swapchain_handle, 2000000000, self.images_sem, self.images_fence, &mut self.presentable_img_idx);
             match result
             {
                 vulkan_bindings::VkResult_VK_SUCCESS | vulkan_bindings::VkResult_VK_SUBOPTIMAL_KHR => (),
-                _ => return Err(VulkanWindowError::UNUSABLE_SWAPCHAIN)
+                _ => return Err(VulkanWindowError::UNUSABLE_SWAPCHAIN(logical_device.track_call_error(vulkan_error::VulkanCallError::new("vkAcquireNextImageKHR", result))))
             };
         }
         Ok(())
@@ -434,16 +569,16 @@ impl VulkanSwapchain
     {
         unsafe
         {
-            let fn_vkDestroySwapchainKHR = vulkan_init::vkDestroySwapchainKHR.unwrap();
-            let ref logical_device = *(*self.surface).logical_device;
-            fn_vkDestroySwapchainKHR(logical_device.device, self.swapchain_handle, std::ptr::null());
+            let logical_device = &*(*self.surface).logical_device;
+            let fn_vkDestroySwapchainKHR = logical_device.fns.vkDestroySwapchainKHR.unwrap();
+            fn_vkDestroySwapchainKHR(logical_device.device, self.swapchain_handle, logical_device.allocation_callbacks);
         }
     }
 }
 
 pub fn vulkan_init_window()
 {
-    // let mut vk_surface = VulkanSurface::new(vk_instance).unwrap_or_else(|e| {
+    // let mut vk_surface = VulkanSurface::new(vk_instance, &system_window::WindowDescriptor::default()).unwrap_or_else(|e| {
     //     eprintln!("{}",e);
     //     std::process::exit(1);
     // });
@@ -452,9 +587,9 @@ pub fn vulkan_init_window()
     // logical_device = vulkan_init::VulkanLogicalDevice::new(
     //     &mut vk_instance,
     //     device_exts, 
-    //     &[(vulkan_bindings::VkQueueFlagBits_VK_QUEUE_GRAPHICS_BIT | vulkan_bindings::VkQueueFlagBits_VK_QUEUE_COMPUTE_BIT) as u32],
+    //     &[vulkan_types::QueueFlags::GRAPHICS | vulkan_types::QueueFlags::COMPUTE],
     //     &vk_surface.surface,
-    //     vulkan_bindings::VkPresentModeKHR_VK_PRESENT_MODE_MAILBOX_KHR
+    //     vulkan_types::PresentModeKHR::MAILBOX
     // ).unwrap_or_else(|e| {
     //     eprintln!("{}",e);
     //     std::process::exit(1);
@@ -465,8 +600,8 @@ pub fn vulkan_init_window()
     // };
     // vk_surface.configure_swapchain(&logical_device, 
     //     3,
-    //     (vulkan_bindings::VkImageUsageFlagBits_VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT) as u32,
-    //     vulkan_bindings::VkSurfaceTransformFlagBitsKHR_VK_SURFACE_TRANSFORM_IDENTITY_BIT_KHR as u32,
+    //     vulkan_types::ImageUsageFlags::COLOR_ATTACHMENT,
+    //     vulkan_types::SurfaceTransformFlagsKHR::IDENTITY,
     //     &desired_surface_format
     // ).unwrap_or_else(|e| {
     //         eprintln!("{}",e);
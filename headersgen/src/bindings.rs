@@ -0,0 +1,196 @@
+use bindgen::Builder;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum BindingsError {
+    MISSING_HEADERS(String),
+    UNKNOWN_PLATFORM(String),
+    GENERATION_FAILED(String, String),
+    UNWRITABLE_OUTPUT(String)
+}
+
+impl std::fmt::Display for BindingsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindingsError::MISSING_HEADERS(path) => write!(f, "{} doesn't contain include/vulkan/vulkan_core.h", path),
+            BindingsError::UNKNOWN_PLATFORM(name) => write!(f, "Unknown platform {}, expected one of win32, xlib, xcb or wayland", name),
+            BindingsError::GENERATION_FAILED(file, reason) => write!(f, "Couldn't generate {}: {}", file, reason),
+            BindingsError::UNWRITABLE_OUTPUT(reason) => write!(f, "Couldn't write the generated bindings: {}", reason)
+        }
+    }
+}
+
+impl std::error::Error for BindingsError {}
+
+// window system integrations the engine can be built with, each one is a cargo feature of the engine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    WIN32,
+    XLIB,
+    XCB,
+    WAYLAND
+}
+
+impl Platform {
+    pub const ALL: [Platform; 4] = [Platform::WIN32, Platform::XLIB, Platform::XCB, Platform::WAYLAND];
+
+    pub fn name(& self) -> &'static str
+    {
+        match self {
+            Platform::WIN32 => "win32",
+            Platform::XLIB => "xlib",
+            Platform::XCB => "xcb",
+            Platform::WAYLAND => "wayland"
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Platform, BindingsError>
+    {
+        Platform::ALL.iter()
+            .find(|platform| platform.name() == name)
+            .copied()
+            .ok_or(BindingsError::UNKNOWN_PLATFORM(name.to_string()))
+    }
+
+    fn define(& self) -> &'static str
+    {
+        match self {
+            Platform::WIN32 => "VK_USE_PLATFORM_WIN32_KHR",
+            Platform::XLIB => "VK_USE_PLATFORM_XLIB_KHR",
+            Platform::XCB => "VK_USE_PLATFORM_XCB_KHR",
+            Platform::WAYLAND => "VK_USE_PLATFORM_WAYLAND_KHR"
+        }
+    }
+
+    fn header(& self) -> &'static str
+    {
+        match self {
+            Platform::WIN32 => "vulkan_win32.h",
+            Platform::XLIB => "vulkan_xlib.h",
+            Platform::XCB => "vulkan_xcb.h",
+            Platform::WAYLAND => "vulkan_wayland.h"
+        }
+    }
+
+    // window system headers vulkan.h doesn't pull in itself
+    fn extra_includes(& self) -> &'static str
+    {
+        match self {
+            Platform::XLIB => "#include <X11/Xutil.h>\n#include <X11/extensions/Xrandr.h>\n",
+            Platform::XCB => "#include <xcb/randr.h>\n",
+            Platform::WIN32 | Platform::WAYLAND => ""
+        }
+    }
+
+    // the window system entry points the engine's window backends call on top of the surface extension
+    fn allow_window_system(& self, builder: Builder) -> Builder
+    {
+        match self {
+            Platform::WIN32 => builder
+                .allowlist_function("GetModuleHandleW|RegisterClassW|UnregisterClassW|CreateWindowExW|DestroyWindow|DefWindowProcW")
+                .allowlist_function("LoadIconW|LoadCursorW|AdjustWindowRect|ShowWindow")
+                .allowlist_function("PeekMessageW|GetMessageW|TranslateMessage|DispatchMessageW|SetWindowLongPtrW|GetWindowLongPtrW")
+                .allowlist_function("SetWindowTextW|SetWindowPos|GetWindowRect|EnumDisplayMonitors|GetMonitorInfoW|ChangeDisplaySettingsExW")
+                .allowlist_var("WM_.*|WS_.*|SW_.*|PM_.*|GWLP_.*|SIZE_.*|VK_.*|XBUTTON.*|WHEEL_DELTA")
+                .allowlist_var("GWL_STYLE|SWP_.*|CDS_FULLSCREEN|DISP_CHANGE_SUCCESSFUL|DM_PELS.*|MONITORINFOF_PRIMARY")
+                .allowlist_type("MONITORINFO"),
+            // randr lists the monitors, the core protocol only knows screens
+            Platform::XLIB => builder
+                .allowlist_file(".*X11/Xlib\\.h")
+                .allowlist_file(".*X11/Xutil\\.h")
+                .allowlist_file(".*X11/X\\.h")
+                .allowlist_function("XRRGetMonitors|XRRFreeMonitors"),
+            // xcb.h and X.h both define the protocol version, keep it out so xlib.rs and xcb.rs can be included together
+            Platform::XCB => builder
+                .allowlist_file(".*xcb/xcb\\.h")
+                .allowlist_file(".*xcb/xproto\\.h")
+                .allowlist_function("xcb_randr_get_monitors.*|xcb_randr_monitor_info_.*")
+                .blocklist_item("X_PROTOCOL|X_PROTOCOL_REVISION"),
+            // the protocol requests are static inline in wayland-client-protocol.h, the engine marshals them itself
+            Platform::WAYLAND => builder
+                .allowlist_file(".*wayland-client-core\\.h")
+                .allowlist_file(".*wayland-client-protocol\\.h")
+                .allowlist_file(".*wayland-util\\.h")
+        }
+    }
+
+    pub fn file_name(& self) -> String
+    {
+        format!("{}.rs", self.name())
+    }
+}
+
+pub const CORE_BINDINGS: &str = "core.rs";
+
+const GENERATED_HEADER: &str = "// Generated by headersgen from the Vulkan-Headers, regenerate instead of editing";
+
+// accepts a Vulkan-Headers checkout as well as an sdk root, both keep the headers under include/
+fn include_directory(headers_directory: &Path) -> Result<PathBuf, BindingsError>
+{
+    let include = headers_directory.join("include");
+    if !include.join("vulkan").join("vulkan_core.h").is_file()
+    {
+        return Err(BindingsError::MISSING_HEADERS(headers_directory.display().to_string()));
+    }
+    Ok(include)
+}
+
+fn builder(include: &Path, wrapper: String) -> Builder
+{
+    Builder::default()
+        .header_contents("wrapper.h", &wrapper)
+        .clang_arg(format!("-I{}", include.display()))
+        .raw_line(GENERATED_HEADER)
+        .generate_comments(false)
+        .layout_tests(false)
+}
+
+fn generate(file: String, builder: Builder, output_directory: &Path) -> Result<PathBuf, BindingsError>
+{
+    let bindings = builder
+        .generate()
+        .map_err(|e| BindingsError::GENERATION_FAILED(file.clone(), e.to_string()))?;
+    let path = output_directory.join(file);
+    bindings
+        .write_to_file(&path)
+        .map_err(|e| BindingsError::UNWRITABLE_OUTPUT(format!("{}: {}", path.display(), e)))?;
+    Ok(path)
+}
+
+// everything from vulkan_core.h, shared by every platform
+pub fn generate_core_bindings(headers_directory: &Path, output_directory: &Path) -> Result<PathBuf, BindingsError>
+{
+    let include = include_directory(headers_directory)?;
+    let wrapper = String::from("#define VK_NO_PROTOTYPES\n#include \"vulkan/vulkan_core.h\"\n");
+    let builder = builder(&include, wrapper)
+        .allowlist_file(".*vulkan_core\\.h")
+        .allowlist_file(".*vk_platform\\.h");
+    generate(CORE_BINDINGS.to_string(), builder, output_directory)
+}
+
+// only the platform header's items, the window system types they pull in and the window system calls the engine
+// makes, the core items come from core.rs so the engine can include several platforms side by side
+pub fn generate_platform_bindings(headers_directory: &Path, platform: Platform, output_directory: &Path) -> Result<PathBuf, BindingsError>
+{
+    let include = include_directory(headers_directory)?;
+    let wrapper = format!("#define VK_NO_PROTOTYPES\n#define {}\n#include \"vulkan/vulkan.h\"\n{}", platform.define(), platform.extra_includes());
+    let builder = platform.allow_window_system(builder(&include, wrapper))
+        .allowlist_file(format!(".*{}", platform.header().replace('.', "\\.")))
+        .blocklist_file(".*vulkan_core\\.h")
+        .blocklist_file(".*vk_platform\\.h")
+        .blocklist_file(".*vk_video/.*");
+    generate(platform.file_name(), builder, output_directory)
+}
+
+// regenerates core.rs and one file per requested platform in output_directory
+pub fn generate_bindings(headers_directory: &Path, platforms: &[Platform], output_directory: &Path) -> Result<Vec<PathBuf>, BindingsError>
+{
+    std::fs::create_dir_all(output_directory)
+        .map_err(|e| BindingsError::UNWRITABLE_OUTPUT(format!("{}: {}", output_directory.display(), e)))?;
+    let mut written = vec![generate_core_bindings(headers_directory, output_directory)?];
+    for platform in platforms
+    {
+        written.push(generate_platform_bindings(headers_directory, *platform, output_directory)?);
+    }
+    Ok(written)
+}
@@ -0,0 +1,255 @@
+use std::fmt::Write;
+use crate::loader::LoaderSelection;
+use crate::registry::{EnumKind, EnumType, EnumValue, Registry, RegistryError};
+
+// KHR, EXT, NV... the registry tags types and values of vendor extensions with
+fn vendor_suffix(name: &str) -> &str
+{
+    let tag_len = name.chars().rev().take_while(|c| c.is_ascii_uppercase()).count();
+    if tag_len >= 2 && tag_len < name.len() { &name[name.len() - tag_len..] } else { "" }
+}
+
+fn screaming_case(name: &str) -> String
+{
+    let mut out = String::new();
+    let mut previous: Option<char> = None;
+    for c in name.chars()
+    {
+        if c.is_ascii_uppercase() && previous.is_some_and(|previous| previous.is_ascii_lowercase() || previous.is_ascii_digit())
+        {
+            out.push('_');
+        }
+        out.push(c.to_ascii_uppercase());
+        previous = Some(c);
+    }
+    out
+}
+
+// VkImageUsageFlagBits -> VK_IMAGE_USAGE_, VkPipelineStageFlagBits2 -> VK_PIPELINE_STAGE_2_
+fn value_prefix(enum_type: &EnumType) -> String
+{
+    let name = enum_type.name.trim_start_matches("Vk");
+    let name = &name[..name.len() - vendor_suffix(name).len()];
+    match name.split_once("FlagBits") {
+        Some((base, "")) => format!("VK_{}_", screaming_case(base)),
+        Some((base, version)) => format!("VK_{}_{}_", screaming_case(base), version),
+        None => format!("VK_{}_", screaming_case(name))
+    }
+}
+
+// VK_IMAGE_USAGE_TRANSFER_DST_BIT in VkImageUsageFlagBits -> TRANSFER_DST
+fn variant_name(enum_type: &EnumType, prefix: &str, value: &str) -> String
+{
+    let mut name = value.strip_prefix(prefix).or(value.strip_prefix("VK_")).unwrap_or(value).to_string();
+    let vendor = vendor_suffix(&enum_type.name);
+    if !vendor.is_empty()
+    {
+        if let Some(stripped) = name.strip_suffix(&format!("_{}", vendor))
+        {
+            name = stripped.to_string();
+        }
+    }
+    if enum_type.kind == EnumKind::BITMASK
+    {
+        if let Some(stripped) = name.strip_suffix("_BIT")
+        {
+            name = stripped.to_string();
+        }
+        else if let Some(idx) = name.rfind("_BIT_")
+        {
+            name = format!("{}{}", &name[..idx], &name[idx + 4..]);
+        }
+    }
+    // VK_IMAGE_TYPE_2D would give 2D which isn't an identifier
+    if name.starts_with(|c: char| c.is_ascii_digit())
+    {
+        let last_word = prefix.trim_end_matches('_').rsplit('_').next().unwrap_or("");
+        name = format!("{}_{}", last_word, name);
+    }
+    name
+}
+
+pub(crate) fn rust_type_name(enum_type: &EnumType) -> String
+{
+    match (&enum_type.kind, &enum_type.flags) {
+        (EnumKind::BITMASK, Some(flags)) => flags.trim_start_matches("Vk").to_string(),
+        (EnumKind::BITMASK, None) => enum_type.name.trim_start_matches("Vk").replacen("FlagBits", "Flags", 1),
+        (EnumKind::ENUM, _) => enum_type.name.trim_start_matches("Vk").to_string()
+    }
+}
+
+// the type bindgen stores values of this enum in
+fn raw_type_name(enum_type: &EnumType) -> String
+{
+    match (&enum_type.kind, &enum_type.flags) {
+        (EnumKind::BITMASK, Some(flags)) => flags.clone(),
+        (EnumKind::BITMASK, None) => enum_type.name.replacen("FlagBits", "Flags", 1),
+        (EnumKind::ENUM, _) => enum_type.name.clone()
+    }
+}
+
+// the constant bindgen emits for a value, 64 bit flags are static consts in the headers and keep their plain name
+fn binding_constant(enum_type: &EnumType, value: &EnumValue) -> String
+{
+    if enum_type.bitwidth == 64 { value.name.clone() } else { format!("{}_{}", enum_type.name, value.name) }
+}
+
+fn is_selected(value: &EnumValue, selection: &LoaderSelection) -> bool
+{
+    if value.alias.is_some()
+    {
+        return false;
+    }
+    if value.version.is_none() && value.extensions.is_empty()
+    {
+        return true;
+    }
+    value.version.is_some_and(|version| version <= selection.max_version)
+        || value.extensions.iter().any(|extension| selection.extensions.contains(extension))
+}
+
+fn generate_enum_type(out: &mut String, enum_type: &EnumType, selection: &LoaderSelection)
+{
+    let macro_name = match enum_type.kind {
+        EnumKind::ENUM => "VULKAN_ENUM",
+        EnumKind::BITMASK => "VULKAN_FLAGS"
+    };
+    let prefix = value_prefix(enum_type);
+    let _ = writeln!(out, "{}!({}: {} {{", macro_name, rust_type_name(enum_type), raw_type_name(enum_type));
+    let values: Vec<&EnumValue> = enum_type.values.iter().filter(|value| is_selected(value, selection)).collect();
+    for (idx, value) in values.iter().enumerate()
+    {
+        let separator = if idx + 1 == values.len() { "" } else { "," };
+        let _ = writeln!(out, "    {} = {}{}", variant_name(enum_type, &prefix, &value.name), binding_constant(enum_type, value), separator);
+    }
+    out.push_str("});\n");
+}
+
+// typed wrappers over bindgen's flat constants, expanded by the VULKAN_ENUM and VULKAN_FLAGS macros of the engine
+pub fn generate_enum_types(registry: &Registry, selection: &LoaderSelection, type_names: &[&str]) -> Result<String, RegistryError>
+{
+    let mut out = String::from("// Generated by headersgen from vk.xml, regenerate instead of editing\n");
+    for (idx, name) in type_names.iter().enumerate()
+    {
+        let enum_type = registry.enum_type(name).ok_or(RegistryError::UNKNOWN_TYPE(name.to_string()))?;
+        if idx != 0
+        {
+            out.push('\n');
+        }
+        generate_enum_type(&mut out, enum_type, selection);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enum_type(name: &str, kind: EnumKind, bitwidth: u32) -> EnumType
+    {
+        EnumType {
+            name: name.to_string(),
+            kind,
+            bitwidth,
+            flags: None,
+            values: Vec::new()
+        }
+    }
+
+    fn variant(enum_type: &EnumType, value: &str) -> String
+    {
+        variant_name(enum_type, &value_prefix(enum_type), value)
+    }
+
+    #[test]
+    fn prefixes_drop_the_vendor_and_keep_the_flags_version()
+    {
+        assert_eq!(value_prefix(&enum_type("VkImageLayout", EnumKind::ENUM, 32)), "VK_IMAGE_LAYOUT_");
+        assert_eq!(value_prefix(&enum_type("VkPresentModeKHR", EnumKind::ENUM, 32)), "VK_PRESENT_MODE_");
+        assert_eq!(value_prefix(&enum_type("VkImageUsageFlagBits", EnumKind::BITMASK, 32)), "VK_IMAGE_USAGE_");
+        assert_eq!(value_prefix(&enum_type("VkDebugUtilsMessageSeverityFlagBitsEXT", EnumKind::BITMASK, 32)), "VK_DEBUG_UTILS_MESSAGE_SEVERITY_");
+        assert_eq!(value_prefix(&enum_type("VkPipelineStageFlagBits2", EnumKind::BITMASK, 64)), "VK_PIPELINE_STAGE_2_");
+    }
+
+    #[test]
+    fn vendor_suffix_of_the_type_is_stripped_from_values()
+    {
+        let present_mode = enum_type("VkPresentModeKHR", EnumKind::ENUM, 32);
+        assert_eq!(variant(&present_mode, "VK_PRESENT_MODE_FIFO_RELAXED_KHR"), "FIFO_RELAXED");
+        // a core enum keeps the suffix of a value an extension added
+        let layout = enum_type("VkImageLayout", EnumKind::ENUM, 32);
+        assert_eq!(variant(&layout, "VK_IMAGE_LAYOUT_PRESENT_SRC_KHR"), "PRESENT_SRC_KHR");
+    }
+
+    #[test]
+    fn bit_is_removed_from_bitmask_values()
+    {
+        let usage = enum_type("VkImageUsageFlagBits", EnumKind::BITMASK, 32);
+        assert_eq!(variant(&usage, "VK_IMAGE_USAGE_TRANSFER_DST_BIT"), "TRANSFER_DST");
+        let severity = enum_type("VkDebugUtilsMessageSeverityFlagBitsEXT", EnumKind::BITMASK, 32);
+        assert_eq!(variant(&severity, "VK_DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT"), "WARNING");
+        let stages = enum_type("VkPipelineStageFlagBits2", EnumKind::BITMASK, 64);
+        assert_eq!(variant(&stages, "VK_PIPELINE_STAGE_2_ACCELERATION_STRUCTURE_BUILD_BIT_KHR"), "ACCELERATION_STRUCTURE_BUILD_KHR");
+        assert_eq!(variant(&stages, "VK_PIPELINE_STAGE_2_NONE"), "NONE");
+        // enums only lose the prefix
+        let tiling = enum_type("VkImageTiling", EnumKind::ENUM, 32);
+        assert_eq!(variant(&tiling, "VK_IMAGE_TILING_OPTIMAL"), "OPTIMAL");
+    }
+
+    #[test]
+    fn leading_digits_take_the_last_prefix_word()
+    {
+        let image_type = enum_type("VkImageType", EnumKind::ENUM, 32);
+        assert_eq!(variant(&image_type, "VK_IMAGE_TYPE_1D"), "TYPE_1D");
+        let view_type = enum_type("VkImageViewType", EnumKind::ENUM, 32);
+        assert_eq!(variant(&view_type, "VK_IMAGE_VIEW_TYPE_2D_ARRAY"), "TYPE_2D_ARRAY");
+        let samples = enum_type("VkSampleCountFlagBits", EnumKind::BITMASK, 32);
+        assert_eq!(variant(&samples, "VK_SAMPLE_COUNT_4_BIT"), "COUNT_4");
+    }
+
+    const REGISTRY: &str = r#"<registry>
+    <types>
+        <type requires="VkQueueFlagBits" category="bitmask">typedef <type>VkFlags</type> <name>VkQueueFlags</name>;</type>
+        <type bitvalues="VkPipelineStageFlagBits2" category="bitmask">typedef <type>VkFlags64</type> <name>VkPipelineStageFlags2</name>;</type>
+    </types>
+    <enums name="VkQueueFlagBits" type="bitmask">
+        <enum bitpos="0" name="VK_QUEUE_GRAPHICS_BIT"/>
+        <enum bitpos="1" name="VK_QUEUE_COMPUTE_BIT"/>
+    </enums>
+    <enums name="VkPipelineStageFlagBits2" type="bitmask" bitwidth="64">
+        <enum value="0" name="VK_PIPELINE_STAGE_2_NONE"/>
+        <enum value="0" name="VK_PIPELINE_STAGE_2_NONE_KHR" alias="VK_PIPELINE_STAGE_2_NONE"/>
+        <enum bitpos="32" name="VK_PIPELINE_STAGE_2_COPY_BIT"/>
+    </enums>
+    <feature api="vulkan" name="VK_VERSION_1_1" number="1.1">
+        <require>
+            <enum bitpos="4" extends="VkQueueFlagBits" name="VK_QUEUE_PROTECTED_BIT"/>
+        </require>
+    </feature>
+</registry>"#;
+
+    #[test]
+    fn bitmasks_are_generated_over_their_flags_type()
+    {
+        let registry = Registry::parse(REGISTRY).unwrap();
+        let generated = generate_enum_types(&registry, &LoaderSelection::new((1, 0)), &["VkQueueFlagBits", "VkPipelineStageFlagBits2"]).unwrap();
+        assert_eq!(generated, "// Generated by headersgen from vk.xml, regenerate instead of editing\n\
+            VULKAN_FLAGS!(QueueFlags: VkQueueFlags {\n    \
+            GRAPHICS = VkQueueFlagBits_VK_QUEUE_GRAPHICS_BIT,\n    \
+            COMPUTE = VkQueueFlagBits_VK_QUEUE_COMPUTE_BIT\n\
+            });\n\n\
+            VULKAN_FLAGS!(PipelineStageFlags2: VkPipelineStageFlags2 {\n    \
+            NONE = VK_PIPELINE_STAGE_2_NONE,\n    \
+            COPY = VK_PIPELINE_STAGE_2_COPY_BIT\n\
+            });\n");
+    }
+
+    #[test]
+    fn values_from_later_versions_need_the_version()
+    {
+        let registry = Registry::parse(REGISTRY).unwrap();
+        let generated = generate_enum_types(&registry, &LoaderSelection::new((1, 1)), &["VkQueueFlagBits"]).unwrap();
+        assert!(generated.contains("    PROTECTED = VkQueueFlagBits_VK_QUEUE_PROTECTED_BIT\n"));
+        assert!(matches!(generate_enum_types(&registry, &LoaderSelection::new((1, 1)), &["VkMissing"]), Err(RegistryError::UNKNOWN_TYPE(_))));
+    }
+}
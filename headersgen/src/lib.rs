@@ -1,19 +1,103 @@
-use bindgen::Builder;
-use std::path::PathBuf;
+#![allow(non_camel_case_types)]
+#![allow(clippy::upper_case_acronyms)]
 
-pub fn generate_headers_bindings()
+use std::path::{Path, PathBuf};
+
+pub mod registry;
+pub mod loader;
+pub mod enums;
+pub mod structs;
+pub mod bindings;
+
+// the core version and extensions the engine loads, the platform surface extensions are cfg'd on their feature
+pub fn engine_loader_selection() -> loader::LoaderSelection
+{
+    loader::LoaderSelection::new((1, 3)).extensions(&[
+        "VK_KHR_surface",
+        "VK_KHR_win32_surface",
+        "VK_KHR_xlib_surface",
+        "VK_KHR_xcb_surface",
+        "VK_KHR_wayland_surface",
+        "VK_EXT_headless_surface",
+        "VK_EXT_debug_utils",
+        "VK_KHR_swapchain",
+        "VK_KHR_timeline_semaphore",
+        "VK_KHR_synchronization2"
+    ])
+}
+
+// regenerates imported_functions.rs, loaded_functions.rs and extension_commands.rs in output_directory from a vk.xml
+pub fn generate_loader_tables(registry_path: &Path, selection: &loader::LoaderSelection, output_directory: &Path) -> Result<(), registry::RegistryError>
+{
+    let registry = registry::Registry::load(registry_path)?;
+    loader::generate_loader_tables(&registry, selection)?.write_to(output_directory)
+}
+
+pub const ENUM_TYPES_FILE: &str = "vulkan_enums.rs";
+
+// the enums and bitmasks the engine wraps in vulkan_types, in the order vulkan_enums.rs lists them
+pub fn engine_enum_types() -> &'static [&'static str]
+{
+    &[
+        "VkImageType",
+        "VkImageViewType",
+        "VkImageTiling",
+        "VkImageLayout",
+        "VkFormat",
+        "VkPresentModeKHR",
+        "VkQueueFlagBits",
+        "VkCommandBufferUsageFlagBits",
+        "VkBufferUsageFlagBits",
+        "VkImageUsageFlagBits",
+        "VkImageAspectFlagBits",
+        "VkMemoryPropertyFlagBits",
+        "VkSampleCountFlagBits",
+        "VkFormatFeatureFlagBits",
+        "VkSurfaceTransformFlagBitsKHR",
+        "VkAccessFlagBits",
+        "VkPipelineStageFlagBits",
+        "VkAccessFlagBits2",
+        "VkPipelineStageFlagBits2"
+    ]
+}
+
+// regenerates vulkan_enums.rs in output_directory, only the listed enums and bitmasks are emitted
+pub fn generate_enum_types(registry_path: &Path, selection: &loader::LoaderSelection, type_names: &[&str], output_directory: &Path) -> Result<PathBuf, registry::RegistryError>
+{
+    let registry = registry::Registry::load(registry_path)?;
+    let types = enums::generate_enum_types(&registry, selection, type_names)?;
+    let path = output_directory.join(ENUM_TYPES_FILE);
+    std::fs::write(&path, types)
+        .map_err(|e| registry::RegistryError::UNWRITABLE_OUTPUT(format!("{}: {}", path.display(), e)))?;
+    Ok(path)
+}
+
+pub const STRUCT_BUILDERS_FILE: &str = "vulkan_struct_builders.rs";
+
+// the create infos the engine builds through vulkan_structs, their typed members use engine_enum_types()
+pub fn engine_struct_builders() -> &'static [&'static str]
+{
+    &[
+        "VkBufferCreateInfo",
+        "VkImageCreateInfo",
+        "VkImageViewCreateInfo",
+        "VkSemaphoreCreateInfo",
+        "VkSemaphoreTypeCreateInfo",
+        "VkSubmitInfo",
+        "VkTimelineSemaphoreSubmitInfo",
+        "VkProtectedSubmitInfo",
+        "VkSwapchainCreateInfoKHR",
+        "VkPresentInfoKHR"
+    ]
+}
+
+// regenerates vulkan_struct_builders.rs in output_directory, members typed with one of typed_enums take the vulkan_types wrapper
+pub fn generate_struct_builders(registry_path: &Path, struct_names: &[&str], typed_enums: &[&str], output_directory: &Path) -> Result<PathBuf, registry::RegistryError>
 {
-    let include_path = String::from("-I") + &std::env::var("VULKAN_SDK").unwrap() + "/include";
-
-    let bindings = Builder::default()
-        .header("wrapper.h")
-        .clang_arg(include_path)
-        .generate_comments(true)
-        .generate()
-        .expect("Couldn't generate the bindings");
-
-    let output_buff = PathBuf::from("./engine/bindings");
-    bindings
-        .write_to_file(output_buff.join("binding.rs"))
-        .expect("Good Bindings happened");
+    let registry = registry::Registry::load(registry_path)?;
+    let builders = structs::generate_struct_builders(&registry, struct_names, typed_enums)?;
+    let path = output_directory.join(STRUCT_BUILDERS_FILE);
+    std::fs::write(&path, builders)
+        .map_err(|e| registry::RegistryError::UNWRITABLE_OUTPUT(format!("{}: {}", path.display(), e)))?;
+    Ok(path)
 }
@@ -0,0 +1,371 @@
+use std::fmt::Write;
+use std::path::Path;
+use crate::registry::{Command, CommandLevel, Registry, RegistryError};
+
+// the core version and extensions the engine is built against, everything else is left out of the tables
+#[derive(Debug, Clone)]
+pub struct LoaderSelection {
+    pub max_version: (u32, u32),
+    pub extensions: Vec<String>
+}
+
+impl LoaderSelection {
+    pub fn new(max_version: (u32, u32)) -> Self
+    {
+        LoaderSelection {
+            max_version,
+            extensions: Vec::new()
+        }
+    }
+
+    pub fn extension(mut self, name: &str) -> Self
+    {
+        if !self.extensions.iter().any(|extension| extension == name)
+        {
+            self.extensions.push(name.to_string());
+        }
+        self
+    }
+
+    pub fn extensions(mut self, names: &[&str]) -> Self
+    {
+        for name in names
+        {
+            self = self.extension(name);
+        }
+        self
+    }
+}
+
+// how a selected command gets loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CommandSource {
+    CORE,
+    VERSION(u32, u32),
+    EXTENSION(String)
+}
+
+struct SelectedCommand<'a> {
+    command: &'a Command,
+    source: CommandSource,
+    // window system extensions only exist when the engine is built with the matching feature
+    platform: Option<&'a str>
+}
+
+// the files write_to puts in the output directory
+pub const LOADER_TABLES: [&str; 3] = ["imported_functions.rs", "loaded_functions.rs", "extension_commands.rs"];
+
+pub struct LoaderTables {
+    pub imported_functions: String,
+    pub loaded_functions: String,
+    pub extension_commands: String
+}
+
+impl LoaderTables {
+    pub fn write_to(& self, directory: &Path) -> Result<(), RegistryError>
+    {
+        for (file, content) in LOADER_TABLES.iter().zip([&self.imported_functions, &self.loaded_functions, &self.extension_commands])
+        {
+            let path = directory.join(file);
+            std::fs::write(&path, content)
+                .map_err(|e| RegistryError::UNWRITABLE_OUTPUT(format!("{}: {}", path.display(), e)))?;
+        }
+        Ok(())
+    }
+}
+
+fn select_commands<'a>(registry: &'a Registry, selection: &LoaderSelection) -> Result<Vec<SelectedCommand<'a>>, RegistryError>
+{
+    let mut selected: Vec<SelectedCommand> = Vec::new();
+    for command in registry.core_commands(selection.max_version)
+    {
+        let source = match command.version {
+            Some((1, 0)) | None => CommandSource::CORE,
+            Some((major, minor)) => CommandSource::VERSION(major, minor)
+        };
+        selected.push(SelectedCommand { command, source, platform: None });
+    }
+    for name in &selection.extensions
+    {
+        let extension = registry.extension(name).ok_or(RegistryError::UNKNOWN_EXTENSION(name.clone()))?;
+        for command in registry.extension_commands(name)
+        {
+            if selected.iter().any(|selected| selected.command.name == command.name)
+            {
+                continue;
+            }
+            selected.push(SelectedCommand { command, source: CommandSource::EXTENSION(extension.name_constant.clone()), platform: extension.platform.as_deref() });
+        }
+    }
+    Ok(selected)
+}
+
+fn macro_name(level: CommandLevel, source: &CommandSource) -> &'static str
+{
+    match (level, source) {
+        (CommandLevel::EXPORTED, _) => "LOAD_EXPORTED_VULKAN_FUNCTION",
+        (CommandLevel::GLOBAL, CommandSource::CORE) => "LOAD_GLOBAL_LEVEL_VULKAN_FUNCTION",
+        // global commands added after 1.0 are missing from 1.0 loaders, the engine checks them for null
+        (CommandLevel::GLOBAL, _) => "LOAD_OPTIONAL_GLOBAL_LEVEL_VULKAN_FUNCTION",
+        (CommandLevel::INSTANCE, CommandSource::CORE) => "LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION",
+        (CommandLevel::INSTANCE, CommandSource::VERSION(_, _)) => "LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION",
+        (CommandLevel::INSTANCE, CommandSource::EXTENSION(_)) => "LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS",
+        (CommandLevel::DEVICE, CommandSource::CORE) => "LOAD_DEVICE_LEVEL_VULKAN_FUNCTION",
+        (CommandLevel::DEVICE, CommandSource::VERSION(_, _)) => "LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION",
+        (CommandLevel::DEVICE, CommandSource::EXTENSION(_)) => "LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION"
+    }
+}
+
+fn cfg_attribute(selected: &SelectedCommand) -> String
+{
+    selected.platform.map_or(String::new(), |platform| format!("#[cfg(feature = \"{}\")] ", platform))
+}
+
+fn load_line(selected: &SelectedCommand) -> String
+{
+    let macro_name = macro_name(selected.command.level, &selected.source);
+    let cfg = cfg_attribute(selected);
+    match (selected.command.level, &selected.source) {
+        (CommandLevel::EXPORTED, _) | (CommandLevel::GLOBAL, _) | (_, CommandSource::CORE) =>
+            format!("    {}{}!({});", cfg, macro_name, selected.command.name),
+        (_, CommandSource::VERSION(major, minor)) =>
+            format!("    {}{}!({}, {}, {});", cfg, macro_name, selected.command.name, major, minor),
+        (_, CommandSource::EXTENSION(constant)) =>
+            format!("    {}{}!({}, {});", cfg, macro_name, selected.command.name, constant)
+    }
+}
+
+fn is_extension(selected: &SelectedCommand) -> bool
+{
+    matches!(selected.source, CommandSource::EXTENSION(_))
+}
+
+// groups are separated by a blank line like the hand written tables were
+fn write_groups(out: &mut String, groups: &[(&str, Vec<String>)])
+{
+    let mut first = true;
+    for (comment, names) in groups.iter().filter(|(_, names)| !names.is_empty())
+    {
+        if !first
+        {
+            out.push('\n');
+        }
+        first = false;
+        let _ = writeln!(out, "    // {}", comment);
+        for name in names
+        {
+            let _ = writeln!(out, "    {},", name);
+        }
+    }
+}
+
+fn generate_imported_functions(selected: &[SelectedCommand]) -> String
+{
+    let names = |level: CommandLevel, extension: bool| -> Vec<String> {
+        selected.iter()
+            .filter(|command| command.command.level == level && is_extension(command) == extension)
+            .map(|command| format!("{}{}", cfg_attribute(command), command.command.name))
+            .collect()
+    };
+    let mut out = String::from("// Generated by headersgen from vk.xml, regenerate instead of editing\n");
+    out.push_str("VULKAN_DISPATCH_TABLE!(InstanceFns {\n");
+    write_groups(&mut out, &[
+        ("Exported", names(CommandLevel::EXPORTED, false)),
+        ("Global Vulkan Functions", names(CommandLevel::GLOBAL, false)),
+        ("Instance Level Vulkan Functions", names(CommandLevel::INSTANCE, false)),
+        ("Instance Level Vulkan Extensions Functions", names(CommandLevel::INSTANCE, true))
+    ]);
+    out.push_str("});\n\nVULKAN_DISPATCH_TABLE!(DeviceFns {\n");
+    write_groups(&mut out, &[
+        ("Device Level Vulkan Function", names(CommandLevel::DEVICE, false)),
+        ("Device Level Vulkan Extensions Functions", names(CommandLevel::DEVICE, true))
+    ]);
+    out.push_str("});\n");
+    out
+}
+
+fn generate_loaded_functions(selected: &[SelectedCommand]) -> String
+{
+    let mut out = String::from("{\n    // Generated by headersgen from vk.xml, regenerate instead of editing\n");
+    let mut previous: Option<(CommandLevel, std::mem::Discriminant<CommandSource>)> = None;
+    let mut ordered: Vec<&SelectedCommand> = selected.iter().collect();
+    // stable so commands keep their registry order inside a group
+    ordered.sort_by_key(|command| (command.command.level, match command.source {
+        CommandSource::CORE => 0,
+        CommandSource::VERSION(_, _) => 1,
+        CommandSource::EXTENSION(_) => 2
+    }));
+    for command in ordered
+    {
+        let group = (command.command.level, std::mem::discriminant(&command.source));
+        if previous.is_some() && previous != Some(group)
+        {
+            out.push('\n');
+        }
+        previous = Some(group);
+        out.push_str(&load_line(command));
+        out.push('\n');
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn generate_extension_commands(registry: &Registry, selection: &LoaderSelection) -> String
+{
+    let mut out = String::from("// Generated by headersgen from vk.xml, regenerate instead of editing\n");
+    out.push_str("pub const EXTENSION_COMMANDS: &[(&str, &[&str])] = &[\n");
+    for name in &selection.extensions
+    {
+        let commands: Vec<String> = registry.extension_commands(name).iter().map(|command| format!("\"{}\"", command.name)).collect();
+        let _ = writeln!(out, "    (\"{}\", &[{}]),", name, commands.join(", "));
+    }
+    out.push_str("];\n\n");
+    out.push_str("pub fn extension_commands(extension: &str) -> &'static [&'static str]\n{\n");
+    out.push_str("    EXTENSION_COMMANDS.iter().find(|(name, _)| *name == extension).map_or(&[], |(_, commands)| *commands)\n}\n");
+    out
+}
+
+// builds the dispatch tables and LOAD_* lines the engine includes in vulkan_init.rs
+pub fn generate_loader_tables(registry: &Registry, selection: &LoaderSelection) -> Result<LoaderTables, RegistryError>
+{
+    let selected = select_commands(registry, selection)?;
+    Ok(LoaderTables {
+        imported_functions: generate_imported_functions(&selected),
+        loaded_functions: generate_loaded_functions(&selected),
+        extension_commands: generate_extension_commands(registry, selection)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = r#"<registry>
+    <commands>
+        <command><proto><type>PFN_vkVoidFunction</type> <name>vkGetInstanceProcAddr</name></proto><param><type>VkInstance</type> <name>instance</name></param></command>
+        <command><proto><type>VkResult</type> <name>vkCreateInstance</name></proto><param>const <type>VkInstanceCreateInfo</type>* <name>pCreateInfo</name></param></command>
+        <command><proto><type>VkResult</type> <name>vkEnumerateInstanceVersion</name></proto><param><type>uint32_t</type>* <name>pApiVersion</name></param></command>
+        <command><proto><type>void</type> <name>vkGetPhysicalDeviceFeatures2</name></proto><param><type>VkPhysicalDevice</type> <name>physicalDevice</name></param></command>
+        <command><proto><type>VkResult</type> <name>vkCreateCommandPool</name></proto><param><type>VkDevice</type> <name>device</name></param></command>
+        <command><proto><type>VkResult</type> <name>vkWaitSemaphores</name></proto><param><type>VkDevice</type> <name>device</name></param></command>
+        <command><proto><type>VkResult</type> <name>vkQueueSubmit2</name></proto><param><type>VkQueue</type> <name>queue</name></param></command>
+        <command name="vkWaitSemaphoresKHR" alias="vkWaitSemaphores"/>
+        <command><proto><type>VkResult</type> <name>vkCreateXcbSurfaceKHR</name></proto><param><type>VkInstance</type> <name>instance</name></param></command>
+    </commands>
+    <feature api="vulkan" name="VK_VERSION_1_0" number="1.0">
+        <require>
+            <command name="vkGetInstanceProcAddr"/>
+            <command name="vkCreateInstance"/>
+            <command name="vkCreateCommandPool"/>
+        </require>
+    </feature>
+    <feature api="vulkan" name="VK_VERSION_1_1" number="1.1">
+        <require>
+            <command name="vkEnumerateInstanceVersion"/>
+            <command name="vkGetPhysicalDeviceFeatures2"/>
+        </require>
+    </feature>
+    <feature api="vulkan" name="VK_VERSION_1_2" number="1.2">
+        <require>
+            <command name="vkWaitSemaphores"/>
+        </require>
+    </feature>
+    <feature api="vulkan" name="VK_VERSION_1_3" number="1.3">
+        <require>
+            <command name="vkQueueSubmit2"/>
+        </require>
+    </feature>
+    <extensions>
+        <extension name="VK_KHR_xcb_surface" number="6" type="instance" platform="xcb" supported="vulkan">
+            <require>
+                <enum value="&quot;VK_KHR_xcb_surface&quot;" name="VK_KHR_XCB_SURFACE_EXTENSION_NAME"/>
+                <command name="vkCreateXcbSurfaceKHR"/>
+            </require>
+        </extension>
+        <extension name="VK_KHR_timeline_semaphore" number="208" type="device" supported="vulkan">
+            <require>
+                <enum value="&quot;VK_KHR_timeline_semaphore&quot;" name="VK_KHR_TIMELINE_SEMAPHORE_EXTENSION_NAME"/>
+                <command name="vkWaitSemaphoresKHR"/>
+            </require>
+        </extension>
+    </extensions>
+</registry>"#;
+
+    fn tables(selection: &LoaderSelection) -> LoaderTables
+    {
+        let registry = Registry::parse(REGISTRY).unwrap();
+        generate_loader_tables(&registry, selection).unwrap()
+    }
+
+    #[test]
+    fn every_level_gets_its_load_macro()
+    {
+        let selection = LoaderSelection::new((1, 3)).extensions(&["VK_KHR_xcb_surface", "VK_KHR_timeline_semaphore"]);
+        let loaded = tables(&selection).loaded_functions;
+        for line in [
+            "    LOAD_EXPORTED_VULKAN_FUNCTION!(vkGetInstanceProcAddr);",
+            "    LOAD_GLOBAL_LEVEL_VULKAN_FUNCTION!(vkCreateInstance);",
+            "    LOAD_OPTIONAL_GLOBAL_LEVEL_VULKAN_FUNCTION!(vkEnumerateInstanceVersion);",
+            "    LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkGetPhysicalDeviceFeatures2, 1, 1);",
+            "    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateCommandPool);",
+            "    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkWaitSemaphores, 1, 2);",
+            "    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkQueueSubmit2, 1, 3);",
+            "    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkWaitSemaphoresKHR, VK_KHR_TIMELINE_SEMAPHORE_EXTENSION_NAME);",
+            "    #[cfg(feature = \"xcb\")] LOAD_INSTANCE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSIONS!(vkCreateXcbSurfaceKHR, VK_KHR_XCB_SURFACE_EXTENSION_NAME);"
+        ]
+        {
+            assert!(loaded.lines().any(|loaded_line| loaded_line == line), "missing {}", line);
+        }
+    }
+
+    #[test]
+    fn versioned_commands_are_grouped_after_the_core_ones()
+    {
+        let loaded = tables(&LoaderSelection::new((1, 3)).extension("VK_KHR_timeline_semaphore")).loaded_functions;
+        let device: Vec<&str> = loaded.lines().skip_while(|line| !line.contains("LOAD_DEVICE_LEVEL")).collect();
+        assert_eq!(device, vec![
+            "    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION!(vkCreateCommandPool);",
+            "",
+            "    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkWaitSemaphores, 1, 2);",
+            "    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_VERSION!(vkQueueSubmit2, 1, 3);",
+            "",
+            "    LOAD_DEVICE_LEVEL_VULKAN_FUNCTION_FROM_EXTENSION!(vkWaitSemaphoresKHR, VK_KHR_TIMELINE_SEMAPHORE_EXTENSION_NAME);",
+            "}"
+        ]);
+    }
+
+    #[test]
+    fn max_version_leaves_out_later_commands()
+    {
+        let tables = tables(&LoaderSelection::new((1, 1)));
+        assert!(tables.loaded_functions.contains("vkGetPhysicalDeviceFeatures2"));
+        assert!(!tables.loaded_functions.contains("vkWaitSemaphores"));
+        assert!(!tables.imported_functions.contains("vkQueueSubmit2"));
+    }
+
+    #[test]
+    fn dispatch_tables_split_instance_and_device_commands()
+    {
+        let imported = tables(&LoaderSelection::new((1, 3)).extensions(&["VK_KHR_xcb_surface", "VK_KHR_timeline_semaphore"])).imported_functions;
+        let (instance, device) = imported.split_once("VULKAN_DISPATCH_TABLE!(DeviceFns").unwrap();
+        assert!(instance.contains("    #[cfg(feature = \"xcb\")] vkCreateXcbSurfaceKHR,\n"));
+        assert!(instance.contains("    vkGetPhysicalDeviceFeatures2,\n"));
+        assert!(!instance.contains("vkCreateCommandPool"));
+        assert!(device.contains("    // Device Level Vulkan Extensions Functions\n    vkWaitSemaphoresKHR,\n"));
+    }
+
+    #[test]
+    fn unknown_extensions_are_rejected()
+    {
+        let registry = Registry::parse(REGISTRY).unwrap();
+        let selection = LoaderSelection::new((1, 3)).extension("VK_KHR_missing");
+        assert!(matches!(generate_loader_tables(&registry, &selection), Err(RegistryError::UNKNOWN_EXTENSION(_))));
+    }
+
+    #[test]
+    fn extension_commands_follow_the_selection()
+    {
+        let commands = tables(&LoaderSelection::new((1, 3)).extensions(&["VK_KHR_timeline_semaphore", "VK_KHR_xcb_surface"])).extension_commands;
+        assert!(commands.contains("    (\"VK_KHR_timeline_semaphore\", &[\"vkWaitSemaphoresKHR\"]),\n    (\"VK_KHR_xcb_surface\", &[\"vkCreateXcbSurfaceKHR\"]),\n"));
+    }
+}
@@ -0,0 +1,128 @@
+use std::path::PathBuf;
+use headersgen::bindings::{self, Platform};
+use headersgen::loader::LOADER_TABLES;
+
+fn usage() -> !
+{
+    eprintln!("usage: headersgen <Vulkan-Headers directory> [--platforms win32,xlib,xcb,wayland] [--out <directory>]");
+    eprintln!("       regenerates core.rs and one bindings file per platform, every platform and engine/bindings by default");
+    eprintln!("       headersgen loader <vk.xml> [--out <directory>]");
+    eprintln!("       regenerates the engine's loader tables, engine/src by default");
+    eprintln!("       headersgen enums <vk.xml> [--out <directory>]");
+    eprintln!("       regenerates the engine's vulkan_enums.rs, engine/src by default");
+    eprintln!("       headersgen structs <vk.xml> [--out <directory>]");
+    eprintln!("       regenerates the engine's vulkan_struct_builders.rs, engine/src by default");
+    std::process::exit(2);
+}
+
+fn parse_platforms(names: &str) -> Vec<Platform>
+{
+    let mut platforms: Vec<Platform> = Vec::new();
+    for name in names.split(',').filter(|name| !name.is_empty())
+    {
+        let platform = Platform::from_name(name).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        });
+        if !platforms.contains(&platform)
+        {
+            platforms.push(platform);
+        }
+    }
+    platforms
+}
+
+// <vk.xml> [--out <directory>], the arguments every registry subcommand takes
+fn registry_args(args: &[String]) -> (PathBuf, PathBuf)
+{
+    let mut registry_path: Option<PathBuf> = None;
+    let mut output_directory = PathBuf::from("engine/src");
+    let mut idx = 0;
+    while idx < args.len()
+    {
+        match args[idx].as_str() {
+            "--out" => {
+                idx += 1;
+                output_directory = PathBuf::from(args.get(idx).unwrap_or_else(|| usage()));
+            },
+            "-h" | "--help" => usage(),
+            path if registry_path.is_none() && !path.starts_with("--") => registry_path = Some(PathBuf::from(path)),
+            _ => usage()
+        }
+        idx += 1;
+    }
+    (registry_path.unwrap_or_else(|| usage()), output_directory)
+}
+
+fn loader(args: &[String])
+{
+    let (registry_path, output_directory) = registry_args(args);
+    headersgen::generate_loader_tables(&registry_path, &headersgen::engine_loader_selection(), &output_directory).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+    for file in LOADER_TABLES
+    {
+        println!("{}", output_directory.join(file).display());
+    }
+}
+
+fn enums(args: &[String])
+{
+    let (registry_path, output_directory) = registry_args(args);
+    let written = headersgen::generate_enum_types(&registry_path, &headersgen::engine_loader_selection(), headersgen::engine_enum_types(), &output_directory).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+    println!("{}", written.display());
+}
+
+fn structs(args: &[String])
+{
+    let (registry_path, output_directory) = registry_args(args);
+    let written = headersgen::generate_struct_builders(&registry_path, headersgen::engine_struct_builders(), headersgen::engine_enum_types(), &output_directory).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+    println!("{}", written.display());
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(|arg| arg.as_str()) {
+        Some("loader") => return loader(&args[1..]),
+        Some("enums") => return enums(&args[1..]),
+        Some("structs") => return structs(&args[1..]),
+        _ => ()
+    }
+    let mut headers_directory: Option<PathBuf> = None;
+    let mut platforms = Platform::ALL.to_vec();
+    let mut output_directory = PathBuf::from("engine/bindings");
+    let mut idx = 0;
+    while idx < args.len()
+    {
+        match args[idx].as_str() {
+            "--platforms" => {
+                idx += 1;
+                platforms = parse_platforms(args.get(idx).unwrap_or_else(|| usage()));
+            },
+            "--out" => {
+                idx += 1;
+                output_directory = PathBuf::from(args.get(idx).unwrap_or_else(|| usage()));
+            },
+            "-h" | "--help" => usage(),
+            path if headers_directory.is_none() && !path.starts_with("--") => headers_directory = Some(PathBuf::from(path)),
+            _ => usage()
+        }
+        idx += 1;
+    }
+    let headers_directory = headers_directory.unwrap_or_else(|| usage());
+    let written = bindings::generate_bindings(&headers_directory, &platforms, &output_directory).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+    for path in written
+    {
+        println!("{}", path.display());
+    }
+}
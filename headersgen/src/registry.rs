@@ -0,0 +1,655 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Debug)]
+pub enum RegistryError {
+    UNREADABLE_REGISTRY(String),
+    MALFORMED_REGISTRY(String),
+    UNKNOWN_ALIAS(String, String),
+    UNKNOWN_EXTENSION(String),
+    UNKNOWN_TYPE(String),
+    UNWRITABLE_OUTPUT(String)
+}
+
+impl std::fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegistryError::UNREADABLE_REGISTRY(reason) => write!(f, "Couldn't read the vulkan registry: {}", reason),
+            RegistryError::MALFORMED_REGISTRY(reason) => write!(f, "The vulkan registry is malformed: {}", reason),
+            RegistryError::UNKNOWN_ALIAS(command, alias) => write!(f, "{} is an alias of the unknown command {}", command, alias),
+            RegistryError::UNKNOWN_EXTENSION(extension) => write!(f, "The vulkan registry has no extension named {}", extension),
+            RegistryError::UNKNOWN_TYPE(name) => write!(f, "The vulkan registry has no enum, bitmask or struct named {}", name),
+            RegistryError::UNWRITABLE_OUTPUT(reason) => write!(f, "Couldn't write the generated file: {}", reason)
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+// which loader entry point a command has to be fetched from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CommandLevel {
+    EXPORTED,
+    GLOBAL,
+    INSTANCE,
+    DEVICE
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtensionKind {
+    INSTANCE,
+    DEVICE
+}
+
+#[derive(Debug, Clone)]
+pub struct Command {
+    pub name: String,
+    pub level: CommandLevel,
+    pub alias: Option<String>,
+    // the core version that provides it, None for extension only commands
+    pub version: Option<(u32, u32)>,
+    // every extension that requires it, a promoted command keeps its extensions here
+    pub extensions: Vec<String>
+}
+
+#[derive(Debug, Clone)]
+pub struct Extension {
+    pub name: String,
+    // the *_EXTENSION_NAME constant bindgen emits for it
+    pub name_constant: String,
+    pub kind: ExtensionKind,
+    pub platform: Option<String>,
+    pub supported: bool,
+    pub commands: Vec<String>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnumKind {
+    ENUM,
+    BITMASK
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumValue {
+    pub name: String,
+    pub alias: Option<String>,
+    // where the value comes from when it was added by a feature or an extension,
+    // values listed in the <enums> block itself have neither
+    pub version: Option<(u32, u32)>,
+    pub extensions: Vec<String>
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumType {
+    pub name: String,
+    pub kind: EnumKind,
+    pub bitwidth: u32,
+    // the VkFlags typedef a FlagBits enum is stored in
+    pub flags: Option<String>,
+    pub values: Vec<EnumValue>
+}
+
+#[derive(Debug, Clone)]
+pub struct StructMember {
+    pub name: String,
+    pub type_name: String,
+    // "const char* const*" is two pointers, each entry telling whether that level is const, innermost first
+    pub pointers: Vec<bool>,
+    // fixed size array dimensions, numbers or API constants
+    pub array: Vec<String>,
+    pub len: Option<String>,
+    pub optional: bool,
+    // the VkStructureType an sType member has to hold
+    pub values: Option<String>
+}
+
+#[derive(Debug, Clone)]
+pub struct StructType {
+    pub name: String,
+    pub members: Vec<StructMember>,
+    // the structs this one can be chained to through pNext
+    pub extends: Vec<String>,
+    pub returned_only: bool
+}
+
+#[derive(Debug, Clone)]
+pub struct Registry {
+    pub commands: Vec<Command>,
+    pub extensions: Vec<Extension>,
+    pub enums: Vec<EnumType>,
+    pub structs: Vec<StructType>,
+    command_idx: HashMap<String, usize>
+}
+
+// the api attribute is a comma separated list since vulkansc landed in the same registry
+fn is_vulkan_api(node: &roxmltree::Node) -> bool
+{
+    match node.attribute("api") {
+        Some(apis) => apis.split(',').any(|api| api == "vulkan"),
+        None => true
+    }
+}
+
+fn child_text<'a>(node: &roxmltree::Node<'a, 'a>, tag: &str) -> Option<&'a str>
+{
+    node.children().find(|child| child.has_tag_name(tag)).and_then(|child| child.text())
+}
+
+fn command_level(name: &str, first_param_type: &str) -> CommandLevel
+{
+    match name {
+        "vkGetInstanceProcAddr" => CommandLevel::EXPORTED,
+        // fetched through vkGetInstanceProcAddr even though its first parameter is a device
+        "vkGetDeviceProcAddr" => CommandLevel::INSTANCE,
+        _ => match first_param_type {
+            "VkDevice" | "VkQueue" | "VkCommandBuffer" | "VkExternalComputeQueueNV" => CommandLevel::DEVICE,
+            "VkInstance" | "VkPhysicalDevice" => CommandLevel::INSTANCE,
+            _ => CommandLevel::GLOBAL
+        }
+    }
+}
+
+fn parse_version(number: &str) -> Option<(u32, u32)>
+{
+    let mut parts = number.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+// <member>const <type>uint32_t</type>* <name>pQueueFamilyIndices</name></member>, the text around the
+// type and the name carries the constness, the pointers and a fixed array size
+fn parse_struct_member(member: &roxmltree::Node) -> Option<StructMember>
+{
+    let mut type_name: Option<&str> = None;
+    let mut name: Option<&str> = None;
+    let mut before_type = String::new();
+    let mut after_type = String::new();
+    let mut after_name = String::new();
+    for child in member.children()
+    {
+        if child.has_tag_name("type")
+        {
+            type_name = child.text();
+        }
+        else if child.has_tag_name("name")
+        {
+            name = child.text();
+        }
+        else if child.is_text() || child.has_tag_name("enum")
+        {
+            let text = child.text().unwrap_or("");
+            match (type_name, name) {
+                (None, _) => before_type.push_str(text),
+                (Some(_), None) => after_type.push_str(text),
+                (Some(_), Some(_)) => after_name.push_str(text)
+            }
+        }
+    }
+    let segments: Vec<&str> = after_type.split('*').collect();
+    let pointers = (0..segments.len() - 1)
+        .map(|level| if level == 0 { before_type.contains("const") } else { segments[level].contains("const") })
+        .collect();
+    let array = after_name.split('[').skip(1)
+        .filter_map(|dimension| dimension.split(']').next())
+        .map(|dimension| dimension.trim().to_string())
+        .collect();
+    Some(StructMember {
+        name: name?.to_string(),
+        type_name: type_name?.to_string(),
+        pointers,
+        array,
+        len: member.attribute("len").map(|len| len.to_string()),
+        optional: member.attribute("optional").is_some_and(|optional| optional.starts_with("true")),
+        values: member.attribute("values").map(|values| values.to_string())
+    })
+}
+
+impl Registry {
+    pub fn load(path: &Path) -> Result<Registry, RegistryError>
+    {
+        let xml = std::fs::read_to_string(path)
+            .map_err(|e| RegistryError::UNREADABLE_REGISTRY(format!("{}: {}", path.display(), e)))?;
+        Registry::parse(&xml)
+    }
+
+    pub fn parse(xml: &str) -> Result<Registry, RegistryError>
+    {
+        let document = roxmltree::Document::parse(xml)
+            .map_err(|e| RegistryError::MALFORMED_REGISTRY(e.to_string()))?;
+        let root = document.root_element();
+        let mut registry = Registry {
+            commands: Vec::new(),
+            extensions: Vec::new(),
+            enums: Vec::new(),
+            structs: Vec::new(),
+            command_idx: HashMap::new()
+        };
+        let mut aliases: Vec<(String, String)> = Vec::new();
+        for commands in root.children().filter(|node| node.has_tag_name("commands"))
+        {
+            for command in commands.children().filter(|node| node.has_tag_name("command") && is_vulkan_api(node))
+            {
+                if let (Some(name), Some(alias)) = (command.attribute("name"), command.attribute("alias"))
+                {
+                    aliases.push((name.to_string(), alias.to_string()));
+                    continue;
+                }
+                let proto = command.children().find(|child| child.has_tag_name("proto"))
+                    .ok_or(RegistryError::MALFORMED_REGISTRY(String::from("command without proto")))?;
+                let name = child_text(&proto, "name")
+                    .ok_or(RegistryError::MALFORMED_REGISTRY(String::from("command proto without name")))?;
+                let first_param_type = command.children()
+                    .find(|child| child.has_tag_name("param") && is_vulkan_api(child))
+                    .and_then(|param| child_text(&param, "type"))
+                    .unwrap_or("");
+                registry.push_command(Command {
+                    name: name.to_string(),
+                    level: command_level(name, first_param_type),
+                    alias: None,
+                    version: None,
+                    extensions: Vec::new()
+                });
+            }
+        }
+        for enums in root.children().filter(|node| node.has_tag_name("enums"))
+        {
+            registry.parse_enums(&enums);
+        }
+        for types in root.children().filter(|node| node.has_tag_name("types"))
+        {
+            registry.parse_bitmask_types(&types);
+            registry.parse_struct_types(&types);
+        }
+        for (name, alias) in aliases
+        {
+            let level = match registry.command(&alias) {
+                Some(command) => command.level,
+                None => return Err(RegistryError::UNKNOWN_ALIAS(name, alias))
+            };
+            registry.push_command(Command {
+                name,
+                level,
+                alias: Some(alias),
+                version: None,
+                extensions: Vec::new()
+            });
+        }
+        for feature in root.children().filter(|node| node.has_tag_name("feature") && is_vulkan_api(node))
+        {
+            let version = match feature.attribute("number").and_then(parse_version) {
+                Some(version) => version,
+                None => continue
+            };
+            for require in feature.children().filter(|node| node.has_tag_name("require") && is_vulkan_api(node))
+            {
+                for name in require.children().filter(|node| node.has_tag_name("command")).filter_map(|node| node.attribute("name"))
+                {
+                    if let Some(command) = registry.command_mut(name)
+                    {
+                        command.version = Some(command.version.map_or(version, |current| current.min(version)));
+                    }
+                }
+                for node in require.children().filter(|node| node.has_tag_name("enum") && is_vulkan_api(node))
+                {
+                    if let Some(value) = registry.extend_enum(&node)
+                    {
+                        value.version = Some(value.version.map_or(version, |current| current.min(version)));
+                    }
+                }
+            }
+        }
+        for extensions in root.children().filter(|node| node.has_tag_name("extensions"))
+        {
+            for extension in extensions.children().filter(|node| node.has_tag_name("extension"))
+            {
+                registry.parse_extension(&extension)?;
+            }
+        }
+        Ok(registry)
+    }
+
+    fn parse_extension(&mut self, extension: &roxmltree::Node) -> Result<(), RegistryError>
+    {
+        let name = extension.attribute("name")
+            .ok_or(RegistryError::MALFORMED_REGISTRY(String::from("extension without name")))?;
+        let kind = match extension.attribute("type") {
+            Some("instance") => ExtensionKind::INSTANCE,
+            _ => ExtensionKind::DEVICE
+        };
+        let supported = extension.attribute("supported")
+            .is_some_and(|supported| supported.split(',').any(|api| api == "vulkan"));
+        let mut new_extension = Extension {
+            name: name.to_string(),
+            name_constant: format!("{}_EXTENSION_NAME", name.to_uppercase()),
+            kind,
+            platform: extension.attribute("platform").map(|platform| platform.to_string()),
+            supported,
+            commands: Vec::new()
+        };
+        for require in extension.children().filter(|node| node.has_tag_name("require") && is_vulkan_api(node))
+        {
+            for node in require.children()
+            {
+                if node.has_tag_name("enum")
+                {
+                    if let Some(constant) = node.attribute("name").filter(|constant| constant.ends_with("_EXTENSION_NAME"))
+                    {
+                        new_extension.name_constant = constant.to_string();
+                    }
+                    if !supported || !is_vulkan_api(&node)
+                    {
+                        continue;
+                    }
+                    if let Some(value) = self.extend_enum(&node)
+                    {
+                        if !value.extensions.iter().any(|extension| extension == name)
+                        {
+                            value.extensions.push(name.to_string());
+                        }
+                    }
+                }
+                else if node.has_tag_name("command")
+                {
+                    let command_name = match node.attribute("name") {
+                        Some(command_name) => command_name,
+                        None => continue
+                    };
+                    if new_extension.commands.iter().any(|command| command == command_name)
+                    {
+                        continue;
+                    }
+                    new_extension.commands.push(command_name.to_string());
+                    if supported
+                    {
+                        if let Some(command) = self.command_mut(command_name)
+                        {
+                            command.extensions.push(name.to_string());
+                        }
+                    }
+                }
+            }
+        }
+        self.extensions.push(new_extension);
+        Ok(())
+    }
+
+    fn parse_enums(&mut self, enums: &roxmltree::Node)
+    {
+        let kind = match enums.attribute("type") {
+            Some("enum") => EnumKind::ENUM,
+            Some("bitmask") => EnumKind::BITMASK,
+            _ => return
+        };
+        let name = match enums.attribute("name") {
+            Some(name) => name,
+            None => return
+        };
+        let values = enums.children()
+            .filter(|node| node.has_tag_name("enum") && is_vulkan_api(node))
+            .filter_map(|node| node.attribute("name").map(|value| EnumValue {
+                name: value.to_string(),
+                alias: node.attribute("alias").map(|alias| alias.to_string()),
+                version: None,
+                extensions: Vec::new()
+            }))
+            .collect();
+        self.enums.push(EnumType {
+            name: name.to_string(),
+            kind,
+            bitwidth: enums.attribute("bitwidth").and_then(|bitwidth| bitwidth.parse().ok()).unwrap_or(32),
+            flags: None,
+            values
+        });
+    }
+
+    // <type category="bitmask"> ties a VkFooFlags typedef to its VkFooFlagBits enum
+    fn parse_bitmask_types(&mut self, types: &roxmltree::Node)
+    {
+        for node in types.children().filter(|node| node.has_tag_name("type") && node.attribute("category") == Some("bitmask") && is_vulkan_api(node))
+        {
+            let bits = match node.attribute("requires").or(node.attribute("bitvalues")) {
+                Some(bits) => bits,
+                None => continue
+            };
+            if let Some(flags) = child_text(&node, "name")
+            {
+                if let Some(enum_type) = self.enums.iter_mut().find(|enum_type| enum_type.name == bits)
+                {
+                    enum_type.flags = Some(flags.to_string());
+                }
+            }
+        }
+    }
+
+    fn parse_struct_types(&mut self, types: &roxmltree::Node)
+    {
+        for node in types.children().filter(|node| node.has_tag_name("type") && node.attribute("category") == Some("struct") && is_vulkan_api(node))
+        {
+            let name = match node.attribute("name") {
+                Some(name) if node.attribute("alias").is_none() => name,
+                _ => continue
+            };
+            let members = node.children()
+                .filter(|child| child.has_tag_name("member") && is_vulkan_api(child))
+                .filter_map(|member| parse_struct_member(&member))
+                .collect();
+            self.structs.push(StructType {
+                name: name.to_string(),
+                members,
+                extends: node.attribute("structextends")
+                    .map_or(Vec::new(), |extends| extends.split(',').map(|parent| parent.to_string()).collect()),
+                returned_only: node.attribute("returnedonly") == Some("true")
+            });
+        }
+    }
+
+    // a <require><enum extends=".."> adds a value to an existing enum, returns it so the caller can tag where it came from
+    fn extend_enum(&mut self, node: &roxmltree::Node) -> Option<&mut EnumValue>
+    {
+        let extends = node.attribute("extends")?;
+        let name = node.attribute("name")?;
+        let enum_type = self.enums.iter_mut().find(|enum_type| enum_type.name == extends)?;
+        let idx = match enum_type.values.iter().position(|value| value.name == name) {
+            Some(idx) => idx,
+            None => {
+                enum_type.values.push(EnumValue {
+                    name: name.to_string(),
+                    alias: node.attribute("alias").map(|alias| alias.to_string()),
+                    version: None,
+                    extensions: Vec::new()
+                });
+                enum_type.values.len() - 1
+            }
+        };
+        Some(&mut enum_type.values[idx])
+    }
+
+    pub fn enum_type(& self, name: &str) -> Option<&EnumType>
+    {
+        self.enums.iter().find(|enum_type| enum_type.name == name)
+    }
+
+    pub fn struct_type(& self, name: &str) -> Option<&StructType>
+    {
+        self.structs.iter().find(|struct_type| struct_type.name == name)
+    }
+
+    fn push_command(&mut self, command: Command)
+    {
+        self.command_idx.insert(command.name.clone(), self.commands.len());
+        self.commands.push(command);
+    }
+
+    pub fn command(& self, name: &str) -> Option<&Command>
+    {
+        self.command_idx.get(name).map(|idx| &self.commands[*idx])
+    }
+
+    fn command_mut(&mut self, name: &str) -> Option<&mut Command>
+    {
+        match self.command_idx.get(name) {
+            Some(idx) => Some(&mut self.commands[*idx]),
+            None => None
+        }
+    }
+
+    pub fn extension(& self, name: &str) -> Option<&Extension>
+    {
+        self.extensions.iter().find(|extension| extension.name == name)
+    }
+
+    // every command an extension brings, in registry order
+    pub fn extension_commands(& self, name: &str) -> Vec<&Command>
+    {
+        match self.extension(name) {
+            Some(extension) => extension.commands.iter().filter_map(|command| self.command(command)).collect(),
+            None => Vec::new()
+        }
+    }
+
+    // core commands up to and including the given version
+    pub fn core_commands(& self, max_version: (u32, u32)) -> Vec<&Command>
+    {
+        self.commands.iter().filter(|command| command.version.is_some_and(|version| version <= max_version)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = r#"<registry>
+    <commands>
+        <command><proto><type>PFN_vkVoidFunction</type> <name>vkGetInstanceProcAddr</name></proto><param><type>VkInstance</type> <name>instance</name></param></command>
+        <command><proto><type>PFN_vkVoidFunction</type> <name>vkGetDeviceProcAddr</name></proto><param><type>VkDevice</type> <name>device</name></param></command>
+        <command><proto><type>VkResult</type> <name>vkCreateInstance</name></proto><param>const <type>VkInstanceCreateInfo</type>* <name>pCreateInfo</name></param></command>
+        <command><proto><type>VkResult</type> <name>vkEnumerateInstanceVersion</name></proto><param><type>uint32_t</type>* <name>pApiVersion</name></param></command>
+        <command><proto><type>VkResult</type> <name>vkEnumeratePhysicalDevices</name></proto><param><type>VkInstance</type> <name>instance</name></param></command>
+        <command><proto><type>void</type> <name>vkGetPhysicalDeviceFeatures2</name></proto><param><type>VkPhysicalDevice</type> <name>physicalDevice</name></param></command>
+        <command><proto><type>VkResult</type> <name>vkCreateCommandPool</name></proto><param><type>VkDevice</type> <name>device</name></param></command>
+        <command><proto><type>VkResult</type> <name>vkQueueSubmit</name></proto><param><type>VkQueue</type> <name>queue</name></param></command>
+        <command><proto><type>void</type> <name>vkCmdPipelineBarrier</name></proto><param><type>VkCommandBuffer</type> <name>commandBuffer</name></param></command>
+        <command><proto><type>VkResult</type> <name>vkWaitSemaphores</name></proto><param><type>VkDevice</type> <name>device</name></param></command>
+        <command name="vkWaitSemaphoresKHR" alias="vkWaitSemaphores"/>
+        <command><proto><type>VkResult</type> <name>vkCreateSwapchainKHR</name></proto><param><type>VkDevice</type> <name>device</name></param></command>
+        <command><proto><type>VkResult</type> <name>vkCreateXcbSurfaceKHR</name></proto><param><type>VkInstance</type> <name>instance</name></param></command>
+        <command api="vulkansc"><proto><type>VkResult</type> <name>vkGetFaultData</name></proto><param><type>VkDevice</type> <name>device</name></param></command>
+    </commands>
+    <feature api="vulkan" name="VK_VERSION_1_0" number="1.0">
+        <require>
+            <command name="vkGetInstanceProcAddr"/>
+            <command name="vkGetDeviceProcAddr"/>
+            <command name="vkCreateInstance"/>
+            <command name="vkEnumeratePhysicalDevices"/>
+            <command name="vkCreateCommandPool"/>
+            <command name="vkQueueSubmit"/>
+            <command name="vkCmdPipelineBarrier"/>
+        </require>
+    </feature>
+    <feature api="vulkan" name="VK_VERSION_1_1" number="1.1">
+        <require>
+            <command name="vkEnumerateInstanceVersion"/>
+            <command name="vkGetPhysicalDeviceFeatures2"/>
+        </require>
+    </feature>
+    <feature api="vulkan" name="VK_VERSION_1_2" number="1.2">
+        <require>
+            <command name="vkWaitSemaphores"/>
+        </require>
+    </feature>
+    <extensions>
+        <extension name="VK_KHR_swapchain" number="2" type="device" supported="vulkan">
+            <require>
+                <enum value="&quot;VK_KHR_swapchain&quot;" name="VK_KHR_SWAPCHAIN_EXTENSION_NAME"/>
+                <command name="vkCreateSwapchainKHR"/>
+            </require>
+        </extension>
+        <extension name="VK_KHR_xcb_surface" number="6" type="instance" platform="xcb" supported="vulkan">
+            <require>
+                <enum value="&quot;VK_KHR_xcb_surface&quot;" name="VK_KHR_XCB_SURFACE_EXTENSION_NAME"/>
+                <command name="vkCreateXcbSurfaceKHR"/>
+            </require>
+        </extension>
+        <extension name="VK_KHR_timeline_semaphore" number="208" type="device" supported="vulkan">
+            <require>
+                <enum value="&quot;VK_KHR_timeline_semaphore&quot;" name="VK_KHR_TIMELINE_SEMAPHORE_EXTENSION_NAME"/>
+                <command name="vkWaitSemaphoresKHR"/>
+            </require>
+        </extension>
+        <extension name="VK_KHR_disabled" number="999" type="device" supported="disabled">
+            <require>
+                <command name="vkWaitSemaphores"/>
+            </require>
+        </extension>
+    </extensions>
+</registry>"#;
+
+    fn level(registry: &Registry, name: &str) -> CommandLevel
+    {
+        registry.command(name).unwrap().level
+    }
+
+    #[test]
+    fn commands_are_classified_by_their_first_parameter()
+    {
+        let registry = Registry::parse(REGISTRY).unwrap();
+        assert_eq!(level(&registry, "vkGetInstanceProcAddr"), CommandLevel::EXPORTED);
+        assert_eq!(level(&registry, "vkCreateInstance"), CommandLevel::GLOBAL);
+        assert_eq!(level(&registry, "vkEnumerateInstanceVersion"), CommandLevel::GLOBAL);
+        assert_eq!(level(&registry, "vkEnumeratePhysicalDevices"), CommandLevel::INSTANCE);
+        assert_eq!(level(&registry, "vkGetPhysicalDeviceFeatures2"), CommandLevel::INSTANCE);
+        assert_eq!(level(&registry, "vkGetDeviceProcAddr"), CommandLevel::INSTANCE);
+        assert_eq!(level(&registry, "vkCreateCommandPool"), CommandLevel::DEVICE);
+        assert_eq!(level(&registry, "vkQueueSubmit"), CommandLevel::DEVICE);
+        assert_eq!(level(&registry, "vkCmdPipelineBarrier"), CommandLevel::DEVICE);
+    }
+
+    #[test]
+    fn aliases_take_the_level_of_their_target()
+    {
+        let registry = Registry::parse(REGISTRY).unwrap();
+        let alias = registry.command("vkWaitSemaphoresKHR").unwrap();
+        assert_eq!(alias.level, CommandLevel::DEVICE);
+        assert_eq!(alias.alias.as_deref(), Some("vkWaitSemaphores"));
+        assert_eq!(alias.version, None);
+    }
+
+    #[test]
+    fn other_apis_are_skipped()
+    {
+        let registry = Registry::parse(REGISTRY).unwrap();
+        assert!(registry.command("vkGetFaultData").is_none());
+    }
+
+    #[test]
+    fn features_set_the_lowest_version()
+    {
+        let registry = Registry::parse(REGISTRY).unwrap();
+        assert_eq!(registry.command("vkCreateCommandPool").unwrap().version, Some((1, 0)));
+        assert_eq!(registry.command("vkGetPhysicalDeviceFeatures2").unwrap().version, Some((1, 1)));
+        assert_eq!(registry.command("vkWaitSemaphores").unwrap().version, Some((1, 2)));
+        assert_eq!(registry.command("vkCreateSwapchainKHR").unwrap().version, None);
+        let core: Vec<&str> = registry.core_commands((1, 1)).iter().map(|command| command.name.as_str()).collect();
+        assert!(core.contains(&"vkEnumerateInstanceVersion"));
+        assert!(!core.contains(&"vkWaitSemaphores"));
+    }
+
+    #[test]
+    fn extensions_own_their_commands()
+    {
+        let registry = Registry::parse(REGISTRY).unwrap();
+        assert_eq!(registry.command("vkCreateSwapchainKHR").unwrap().extensions, vec!["VK_KHR_swapchain"]);
+        assert_eq!(registry.command("vkWaitSemaphoresKHR").unwrap().extensions, vec!["VK_KHR_timeline_semaphore"]);
+        // an unsupported extension lists the command but doesn't claim it
+        assert!(registry.command("vkWaitSemaphores").unwrap().extensions.is_empty());
+        assert!(!registry.extension("VK_KHR_disabled").unwrap().supported);
+
+        let xcb = registry.extension("VK_KHR_xcb_surface").unwrap();
+        assert_eq!(xcb.kind, ExtensionKind::INSTANCE);
+        assert_eq!(xcb.platform.as_deref(), Some("xcb"));
+        assert_eq!(xcb.name_constant, "VK_KHR_XCB_SURFACE_EXTENSION_NAME");
+        let commands: Vec<&str> = registry.extension_commands("VK_KHR_xcb_surface").iter().map(|command| command.name.as_str()).collect();
+        assert_eq!(commands, vec!["vkCreateXcbSurfaceKHR"]);
+    }
+}
@@ -0,0 +1,274 @@
+use std::fmt::Write;
+use crate::enums;
+use crate::registry::{Registry, RegistryError, StructMember, StructType};
+
+const RUST_KEYWORDS: &[&str] = &["type", "match", "ref", "move", "loop", "use", "mod", "fn", "impl", "where", "self", "struct", "enum", "const", "static", "in", "for", "if", "else"];
+
+fn snake_case(name: &str) -> String
+{
+    let mut out = String::new();
+    for c in name.chars()
+    {
+        if c.is_ascii_uppercase() && !out.is_empty()
+        {
+            out.push('_');
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    if RUST_KEYWORDS.contains(&out.as_str()) { format!("r#{}", out) } else { out }
+}
+
+// bindgen appends an underscore to fields named after a keyword
+fn field_name(member: &StructMember) -> String
+{
+    if RUST_KEYWORDS.contains(&member.name.as_str()) { format!("{}_", member.name) } else { member.name.clone() }
+}
+
+// pQueueFamilyIndices -> queue_family_indices, ppEnabledLayerNames -> enabled_layer_names
+fn setter_name(member: &StructMember) -> String
+{
+    let stripped = member.name.get(member.pointers.len()..)
+        .filter(|rest| member.name.starts_with(&"p".repeat(member.pointers.len())) && rest.starts_with(|c: char| c.is_ascii_uppercase()));
+    snake_case(stripped.unwrap_or(&member.name))
+}
+
+fn base_type(type_name: &str) -> String
+{
+    match type_name {
+        "void" => String::from("std::ffi::c_void"),
+        "char" => String::from("std::os::raw::c_char"),
+        "int" => String::from("std::os::raw::c_int"),
+        "float" => String::from("f32"),
+        "double" => String::from("f64"),
+        "uint8_t" => String::from("u8"),
+        "uint16_t" => String::from("u16"),
+        "uint32_t" => String::from("u32"),
+        "uint64_t" => String::from("u64"),
+        "int32_t" => String::from("i32"),
+        "int64_t" => String::from("i64"),
+        "size_t" => String::from("usize"),
+        other => format!("vulkan_bindings::{}", other)
+    }
+}
+
+// the type bindgen gives the member, keeping only the first `levels` pointers
+fn rust_type(member: &StructMember, levels: usize) -> String
+{
+    let mut rust_type = base_type(&member.type_name);
+    for is_const in member.pointers.iter().take(levels)
+    {
+        rust_type = format!("*{} {}", if *is_const { "const" } else { "mut" }, rust_type);
+    }
+    for dimension in member.array.iter().rev()
+    {
+        let length = if dimension.chars().all(|c| c.is_ascii_digit()) { dimension.clone() } else { format!("vulkan_bindings::{} as usize", dimension) };
+        rust_type = format!("[{}; {}]", rust_type, length);
+    }
+    rust_type
+}
+
+// the sibling member a len attribute counts with, "enabledExtensionCount,null-terminated" -> enabledExtensionCount
+fn count_member<'a>(struct_type: &'a StructType, member: &StructMember) -> Option<&'a StructMember>
+{
+    let count = member.len.as_ref()?.split(',').next()?;
+    struct_type.members.iter().find(|sibling| sibling.name == count && sibling.pointers.is_empty())
+}
+
+fn is_slice(struct_type: &StructType, member: &StructMember) -> bool
+{
+    member.pointers.last() == Some(&true) && member.array.is_empty() && count_member(struct_type, member).is_some()
+}
+
+fn is_cstr(member: &StructMember) -> bool
+{
+    member.type_name == "char" && member.pointers == [true] && member.len.as_deref() == Some("null-terminated")
+}
+
+// members whose type has a vulkan_types wrapper get a typed setter
+fn typed_name(registry: &Registry, member: &StructMember, typed_enums: &[&str]) -> Option<String>
+{
+    if !member.pointers.is_empty() || !member.array.is_empty()
+    {
+        return None;
+    }
+    registry.enums.iter()
+        .filter(|enum_type| typed_enums.contains(&enum_type.name.as_str()))
+        .find(|enum_type| enum_type.name == member.type_name || enum_type.flags.as_deref() == Some(member.type_name.as_str()))
+        .map(|enum_type| format!("vulkan_types::{}", enums::rust_type_name(enum_type)))
+}
+
+fn generate_struct_builder(out: &mut String, registry: &Registry, struct_type: &StructType, struct_names: &[&str], typed_enums: &[&str]) -> Result<(), RegistryError>
+{
+    let stype = struct_type.members.iter().find_map(|member| member.values.as_ref())
+        .ok_or(RegistryError::MALFORMED_REGISTRY(format!("{} has no sType to build", struct_type.name)))?;
+    let mut slices: Vec<(&StructMember, Vec<&StructMember>)> = Vec::new();
+    for member in struct_type.members.iter().filter(|member| is_slice(struct_type, member))
+    {
+        let count = count_member(struct_type, member).unwrap();
+        match slices.iter_mut().find(|(slice_count, _)| slice_count.name == count.name) {
+            Some((_, fields)) => fields.push(member),
+            None => slices.push((count, vec![member]))
+        }
+    }
+    let mut values: Vec<String> = Vec::new();
+    let mut typed: Vec<String> = Vec::new();
+    let mut cstrs: Vec<String> = Vec::new();
+    for member in struct_type.members.iter()
+    {
+        // counts are only ever written from the slices they describe
+        if member.name == "sType" || member.name == "pNext" || is_slice(struct_type, member)
+            || slices.iter().any(|(count, _)| count.name == member.name)
+        {
+            continue;
+        }
+        if is_cstr(member)
+        {
+            cstrs.push(format!("    cstr {} => {};", setter_name(member), field_name(member)));
+        }
+        else if let Some(typed_type) = typed_name(registry, member, typed_enums)
+        {
+            typed.push(format!("    typed {} => {}: {};", setter_name(member), field_name(member), typed_type));
+        }
+        // a single struct behind a const pointer is borrowed for as long as the builder
+        else if member.pointers == [true] && member.len.is_none() && member.type_name.starts_with("Vk")
+        {
+            values.push(format!("    value {} => {}: &'a {};", setter_name(member), field_name(member), base_type(&member.type_name)));
+        }
+        else
+        {
+            values.push(format!("    value {} => {}: {};", setter_name(member), field_name(member), rust_type(member, member.pointers.len())));
+        }
+    }
+    let _ = writeln!(out, "VULKAN_STRUCT!({}: {} = VkStructureType_{} {{", struct_type.name.trim_start_matches("Vk"), struct_type.name, stype);
+    for line in values.iter().chain(typed.iter()).chain(cstrs.iter())
+    {
+        let _ = writeln!(out, "{}", line);
+    }
+    for (count, fields) in &slices
+    {
+        let setter = match fields.as_slice() {
+            [field] => setter_name(field),
+            _ => format!("{}s", snake_case(count.name.trim_end_matches("Count")))
+        };
+        let fields: Vec<String> = fields.iter().map(|field| format!("{}: {};", field_name(field), rust_type(field, field.pointers.len() - 1))).collect();
+        let _ = writeln!(out, "    slice {} => {}: {} {{ {} }}", setter, count.name, rust_type(count, 0), fields.join(" "));
+    }
+    let parents: Vec<&str> = struct_type.extends.iter()
+        .filter(|parent| struct_names.contains(&parent.as_str()))
+        .map(|parent| parent.trim_start_matches("Vk"))
+        .collect();
+    let _ = writeln!(out, "    extends [{}];", parents.join(", "));
+    out.push_str("});\n");
+    Ok(())
+}
+
+// create-info builders expanded by the engine's VULKAN_STRUCT macro, pNext chains only accept the listed
+// structs that declare themselves as extending the builder's struct
+pub fn generate_struct_builders(registry: &Registry, struct_names: &[&str], typed_enums: &[&str]) -> Result<String, RegistryError>
+{
+    let mut out = String::from("// Generated by headersgen from vk.xml, regenerate instead of editing\n");
+    for (idx, name) in struct_names.iter().enumerate()
+    {
+        let struct_type = registry.struct_type(name).ok_or(RegistryError::UNKNOWN_TYPE(name.to_string()))?;
+        if idx != 0
+        {
+            out.push('\n');
+        }
+        generate_struct_builder(&mut out, registry, struct_type, struct_names, typed_enums)?;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = r#"<registry>
+    <types>
+        <type category="struct" name="VkApplicationInfo">
+            <member values="VK_STRUCTURE_TYPE_APPLICATION_INFO"><type>VkStructureType</type> <name>sType</name></member>
+            <member optional="true">const <type>void</type>* <name>pNext</name></member>
+            <member optional="true" len="null-terminated">const <type>char</type>* <name>pApplicationName</name></member>
+            <member><type>uint32_t</type> <name>applicationVersion</name></member>
+            <member><type>uint32_t</type> <name>apiVersion</name></member>
+        </type>
+        <type category="struct" name="VkSubmitInfo">
+            <member values="VK_STRUCTURE_TYPE_SUBMIT_INFO"><type>VkStructureType</type> <name>sType</name></member>
+            <member optional="true">const <type>void</type>* <name>pNext</name></member>
+            <member optional="true"><type>uint32_t</type> <name>waitSemaphoreCount</name></member>
+            <member len="waitSemaphoreCount">const <type>VkSemaphore</type>* <name>pWaitSemaphores</name></member>
+            <member len="waitSemaphoreCount">const <type>VkPipelineStageFlags</type>* <name>pWaitDstStageMask</name></member>
+            <member optional="true"><type>uint32_t</type> <name>commandBufferCount</name></member>
+            <member len="commandBufferCount">const <type>VkCommandBuffer</type>* <name>pCommandBuffers</name></member>
+        </type>
+        <type category="struct" name="VkTimelineSemaphoreSubmitInfo" structextends="VkSubmitInfo,VkBindSparseInfo">
+            <member values="VK_STRUCTURE_TYPE_TIMELINE_SEMAPHORE_SUBMIT_INFO"><type>VkStructureType</type> <name>sType</name></member>
+            <member optional="true">const <type>void</type>* <name>pNext</name></member>
+            <member optional="true"><type>uint32_t</type> <name>waitSemaphoreValueCount</name></member>
+            <member optional="true" len="waitSemaphoreValueCount">const <type>uint64_t</type>* <name>pWaitSemaphoreValues</name></member>
+        </type>
+        <type category="struct" name="VkAccelerationStructureCreateInfoKHR">
+            <member values="VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_CREATE_INFO_KHR"><type>VkStructureType</type> <name>sType</name></member>
+            <member optional="true">const <type>void</type>* <name>pNext</name></member>
+            <member><type>VkAccelerationStructureTypeKHR</type> <name>type</name></member>
+        </type>
+        <type category="struct" name="VkExtent2D">
+            <member><type>uint32_t</type> <name>width</name></member>
+            <member><type>uint32_t</type> <name>height</name></member>
+        </type>
+    </types>
+</registry>"#;
+
+    fn builder(name: &str, struct_names: &[&str]) -> String
+    {
+        let registry = Registry::parse(REGISTRY).unwrap();
+        let mut out = String::new();
+        generate_struct_builder(&mut out, &registry, registry.struct_type(name).unwrap(), struct_names, &[]).unwrap();
+        out
+    }
+
+    #[test]
+    fn arrays_sharing_a_count_are_set_together()
+    {
+        let out = builder("VkSubmitInfo", &["VkSubmitInfo"]);
+        assert!(out.contains("    slice wait_semaphores => waitSemaphoreCount: u32 { pWaitSemaphores: vulkan_bindings::VkSemaphore; pWaitDstStageMask: vulkan_bindings::VkPipelineStageFlags; }\n"));
+        assert!(out.contains("    slice command_buffers => commandBufferCount: u32 { pCommandBuffers: vulkan_bindings::VkCommandBuffer; }\n"));
+        // counts are never set on their own
+        assert!(!out.contains("value wait_semaphore_count"));
+        assert!(!out.contains("value command_buffer_count"));
+    }
+
+    #[test]
+    fn null_terminated_chars_are_cstrs()
+    {
+        let out = builder("VkApplicationInfo", &["VkApplicationInfo"]);
+        assert!(out.starts_with("VULKAN_STRUCT!(ApplicationInfo: VkApplicationInfo = VkStructureType_VK_STRUCTURE_TYPE_APPLICATION_INFO {\n"));
+        assert!(out.contains("    cstr application_name => pApplicationName;\n"));
+        assert!(out.contains("    value api_version => apiVersion: u32;\n"));
+    }
+
+    #[test]
+    fn extends_only_lists_generated_parents()
+    {
+        let out = builder("VkTimelineSemaphoreSubmitInfo", &["VkSubmitInfo", "VkTimelineSemaphoreSubmitInfo"]);
+        assert!(out.contains("    slice wait_semaphore_values => waitSemaphoreValueCount: u32 { pWaitSemaphoreValues: u64; }\n"));
+        assert!(out.ends_with("    extends [SubmitInfo];\n});\n"));
+        let out = builder("VkTimelineSemaphoreSubmitInfo", &["VkTimelineSemaphoreSubmitInfo"]);
+        assert!(out.ends_with("    extends [];\n});\n"));
+    }
+
+    #[test]
+    fn keywords_are_escaped_like_bindgen_does()
+    {
+        let out = builder("VkAccelerationStructureCreateInfoKHR", &["VkAccelerationStructureCreateInfoKHR"]);
+        assert!(out.contains("    value r#type => type_: vulkan_bindings::VkAccelerationStructureTypeKHR;\n"));
+    }
+
+    #[test]
+    fn structs_without_stype_are_rejected()
+    {
+        let registry = Registry::parse(REGISTRY).unwrap();
+        assert!(matches!(generate_struct_builders(&registry, &["VkExtent2D"], &[]), Err(RegistryError::MALFORMED_REGISTRY(_))));
+        assert!(matches!(generate_struct_builders(&registry, &["VkUnknown"], &[]), Err(RegistryError::UNKNOWN_TYPE(_))));
+    }
+}
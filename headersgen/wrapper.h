@@ -1,2 +0,0 @@
-#define VK_NO_PROTOTYPES
-#include "vulkan/vulkan.h"
\ No newline at end of file
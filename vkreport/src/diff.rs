@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+use serde_json::Value;
+use crate::report;
+
+fn flatten(prefix: String, value: &Value, out: &mut BTreeMap<String, String>)
+{
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map
+            {
+                let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                flatten(path, child, out);
+            }
+        },
+        Value::Array(items) if items.iter().any(|item| item.is_object() || item.is_array()) => {
+            for (idx, item) in items.iter().enumerate()
+            {
+                flatten(format!("{}[{}]", prefix, idx), item, out);
+            }
+        },
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(report::scalar_to_string).collect();
+            out.insert(prefix, items.join(", "));
+        },
+        scalar => {
+            out.insert(prefix, report::scalar_to_string(scalar));
+        }
+    }
+}
+
+pub fn diff(old: &Value, new: &Value) -> Vec<String>
+{
+    let mut old_values = BTreeMap::new();
+    let mut new_values = BTreeMap::new();
+    flatten(String::new(), old, &mut old_values);
+    flatten(String::new(), new, &mut new_values);
+    let mut differences = Vec::new();
+    for (path, old_value) in &old_values
+    {
+        match new_values.get(path) {
+            None => differences.push(format!("- {}: {}", path, old_value)),
+            Some(new_value) if new_value != old_value => differences.push(format!("~ {}: {} -> {}", path, old_value, new_value)),
+            _ => ()
+        }
+    }
+    for (path, new_value) in &new_values
+    {
+        if !old_values.contains_key(path)
+        {
+            differences.push(format!("+ {}: {}", path, new_value));
+        }
+    }
+    differences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn old_report() -> Value
+    {
+        json!({
+            "report_version": 1,
+            "instance": { "api_version": "1.3.250", "layers": {} },
+            "devices": [{
+                "name": "GPU",
+                "limits": { "maxImageDimension2D": 16384, "maxViewports": 16 },
+                "formats": { "R8G8B8A8_UNORM": { "linear": ["SAMPLED_IMAGE"], "optimal": ["SAMPLED_IMAGE", "BLIT_SRC"], "buffer": [] } },
+                "present_modes": null
+            }]
+        })
+    }
+
+    fn new_report() -> Value
+    {
+        json!({
+            "report_version": 1,
+            "instance": { "api_version": "1.3.275", "layers": { "VK_LAYER_KHRONOS_validation": { "spec_version": "1.3.275" } } },
+            "devices": [{
+                "name": "GPU",
+                "limits": { "maxImageDimension2D": 16384 },
+                "formats": { "R8G8B8A8_UNORM": { "linear": ["SAMPLED_IMAGE"], "optimal": ["SAMPLED_IMAGE", "BLIT_SRC", "BLIT_DST"], "buffer": [] } },
+                "present_modes": ["FIFO"]
+            }]
+        })
+    }
+
+    #[test]
+    fn identical_reports_have_no_differences()
+    {
+        assert!(diff(&old_report(), &old_report()).is_empty());
+    }
+
+    #[test]
+    fn changed_removed_and_added_values_are_listed_by_path()
+    {
+        assert_eq!(diff(&old_report(), &new_report()), vec![
+            "~ devices[0].formats.R8G8B8A8_UNORM.optimal: SAMPLED_IMAGE, BLIT_SRC -> SAMPLED_IMAGE, BLIT_SRC, BLIT_DST",
+            "- devices[0].limits.maxViewports: 16",
+            "~ devices[0].present_modes: unavailable -> FIFO",
+            "~ instance.api_version: 1.3.250 -> 1.3.275",
+            "+ instance.layers.VK_LAYER_KHRONOS_validation.spec_version: 1.3.275"
+        ]);
+    }
+}
@@ -0,0 +1,64 @@
+mod diff;
+mod report;
+use engine::vulkan_error;
+
+fn usage() -> !
+{
+    eprintln!("usage: vkreport [--json <output.json>]");
+    eprintln!("       vkreport --diff <old.json> <new.json>");
+    std::process::exit(2);
+}
+
+fn read_report(path: &str) -> serde_json::Value
+{
+    let content = std::fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("Couldn't read {}: {}", path, e);
+        std::process::exit(2);
+    });
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        eprintln!("{} isn't a valid report: {}", path, e);
+        std::process::exit(2);
+    })
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(|arg| arg.as_str()) {
+        Some("--diff") => {
+            if args.len() != 3
+            {
+                usage();
+            }
+            let differences = diff::diff(&read_report(&args[1]), &read_report(&args[2]));
+            for difference in &differences
+            {
+                println!("{}", difference);
+            }
+            if !differences.is_empty()
+            {
+                std::process::exit(1);
+            }
+        },
+        Some("--json") | None => {
+            let json_path = match args.len() {
+                0 => None,
+                2 => Some(&args[1]),
+                _ => usage()
+            };
+            let report = report::collect().unwrap_or_else(|e| {
+                eprintln!("{}", vulkan_error::describe(&*e));
+                std::process::exit(1);
+            });
+            print!("{}", report::to_text(&report));
+            if let Some(path) = json_path
+            {
+                let content = serde_json::to_string_pretty(&report).unwrap();
+                std::fs::write(path, content).unwrap_or_else(|e| {
+                    eprintln!("Couldn't write {}: {}", path, e);
+                    std::process::exit(1);
+                });
+            }
+        },
+        Some(_) => usage()
+    }
+}
@@ -0,0 +1,337 @@
+use engine::vulkan_bindings;
+use engine::vulkan_format;
+use engine::vulkan_init;
+use engine::vulkan_types;
+use serde_json::{json, Map, Value};
+
+pub const REPORT_VERSION: u32 = 1;
+
+macro_rules! LIMITS_JSON {
+    ($limits: expr, [$($field: ident),* $(,)?]) => {{
+        let mut limits = Map::new();
+        $(limits.insert(stringify!($field).to_string(), json!($limits.$field));)*
+        Value::Object(limits)
+    }};
+}
+
+const QUEUE_FLAGS: [(u32, &str); 5] = [
+    (vulkan_bindings::VkQueueFlagBits_VK_QUEUE_GRAPHICS_BIT, "GRAPHICS"),
+    (vulkan_bindings::VkQueueFlagBits_VK_QUEUE_COMPUTE_BIT, "COMPUTE"),
+    (vulkan_bindings::VkQueueFlagBits_VK_QUEUE_TRANSFER_BIT, "TRANSFER"),
+    (vulkan_bindings::VkQueueFlagBits_VK_QUEUE_SPARSE_BINDING_BIT, "SPARSE_BINDING"),
+    (vulkan_bindings::VkQueueFlagBits_VK_QUEUE_PROTECTED_BIT, "PROTECTED")
+];
+
+const MEMORY_PROPERTY_FLAGS: [(u32, &str); 6] = [
+    (vulkan_bindings::VkMemoryPropertyFlagBits_VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT, "DEVICE_LOCAL"),
+    (vulkan_bindings::VkMemoryPropertyFlagBits_VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT, "HOST_VISIBLE"),
+    (vulkan_bindings::VkMemoryPropertyFlagBits_VK_MEMORY_PROPERTY_HOST_COHERENT_BIT, "HOST_COHERENT"),
+    (vulkan_bindings::VkMemoryPropertyFlagBits_VK_MEMORY_PROPERTY_HOST_CACHED_BIT, "HOST_CACHED"),
+    (vulkan_bindings::VkMemoryPropertyFlagBits_VK_MEMORY_PROPERTY_LAZILY_ALLOCATED_BIT, "LAZILY_ALLOCATED"),
+    (vulkan_bindings::VkMemoryPropertyFlagBits_VK_MEMORY_PROPERTY_PROTECTED_BIT, "PROTECTED")
+];
+
+const MEMORY_HEAP_FLAGS: [(u32, &str); 2] = [
+    (vulkan_bindings::VkMemoryHeapFlagBits_VK_MEMORY_HEAP_DEVICE_LOCAL_BIT, "DEVICE_LOCAL"),
+    (vulkan_bindings::VkMemoryHeapFlagBits_VK_MEMORY_HEAP_MULTI_INSTANCE_BIT, "MULTI_INSTANCE")
+];
+
+const FORMAT_FEATURE_FLAGS: [(u32, &str); 15] = [
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT, "SAMPLED_IMAGE"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_STORAGE_IMAGE_BIT, "STORAGE_IMAGE"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_STORAGE_IMAGE_ATOMIC_BIT, "STORAGE_IMAGE_ATOMIC"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_UNIFORM_TEXEL_BUFFER_BIT, "UNIFORM_TEXEL_BUFFER"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_STORAGE_TEXEL_BUFFER_BIT, "STORAGE_TEXEL_BUFFER"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_STORAGE_TEXEL_BUFFER_ATOMIC_BIT, "STORAGE_TEXEL_BUFFER_ATOMIC"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_VERTEX_BUFFER_BIT, "VERTEX_BUFFER"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BIT, "COLOR_ATTACHMENT"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BLEND_BIT, "COLOR_ATTACHMENT_BLEND"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_DEPTH_STENCIL_ATTACHMENT_BIT, "DEPTH_STENCIL_ATTACHMENT"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_BLIT_SRC_BIT, "BLIT_SRC"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_BLIT_DST_BIT, "BLIT_DST"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_LINEAR_BIT, "SAMPLED_IMAGE_FILTER_LINEAR"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_TRANSFER_SRC_BIT, "TRANSFER_SRC"),
+    (vulkan_bindings::VkFormatFeatureFlagBits_VK_FORMAT_FEATURE_TRANSFER_DST_BIT, "TRANSFER_DST")
+];
+
+fn flag_names(flags: u32, names: &[(u32, &str)]) -> Value
+{
+    let mut set = Vec::new();
+    for (bit, name) in names
+    {
+        if flags & bit != 0
+        {
+            set.push(json!(name));
+        }
+    }
+    Value::Array(set)
+}
+
+fn device_type_name(device_type: vulkan_bindings::VkPhysicalDeviceType) -> &'static str
+{
+    match device_type {
+        vulkan_bindings::VkPhysicalDeviceType_VK_PHYSICAL_DEVICE_TYPE_DISCRETE_GPU => "DISCRETE_GPU",
+        vulkan_bindings::VkPhysicalDeviceType_VK_PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU => "INTEGRATED_GPU",
+        vulkan_bindings::VkPhysicalDeviceType_VK_PHYSICAL_DEVICE_TYPE_VIRTUAL_GPU => "VIRTUAL_GPU",
+        vulkan_bindings::VkPhysicalDeviceType_VK_PHYSICAL_DEVICE_TYPE_CPU => "CPU",
+        _ => "OTHER"
+    }
+}
+
+fn present_mode_name(mode: vulkan_bindings::VkPresentModeKHR) -> String
+{
+    match mode {
+        vulkan_bindings::VkPresentModeKHR_VK_PRESENT_MODE_IMMEDIATE_KHR => "IMMEDIATE".to_string(),
+        vulkan_bindings::VkPresentModeKHR_VK_PRESENT_MODE_MAILBOX_KHR => "MAILBOX".to_string(),
+        vulkan_bindings::VkPresentModeKHR_VK_PRESENT_MODE_FIFO_KHR => "FIFO".to_string(),
+        vulkan_bindings::VkPresentModeKHR_VK_PRESENT_MODE_FIFO_RELAXED_KHR => "FIFO_RELAXED".to_string(),
+        other => format!("VkPresentModeKHR({})", other)
+    }
+}
+
+fn extensions_json(extensions: &[vulkan_bindings::VkExtensionProperties]) -> Value
+{
+    let mut map = Map::new();
+    for extension in extensions
+    {
+        map.insert(vulkan_init::vk_chars_to_string(&extension.extensionName), json!(extension.specVersion));
+    }
+    Value::Object(map)
+}
+
+fn layers_json(layers: &[vulkan_bindings::VkLayerProperties]) -> Value
+{
+    let mut map = Map::new();
+    for layer in layers
+    {
+        map.insert(vulkan_init::vk_chars_to_string(&layer.layerName), json!({
+            "spec_version": vulkan_init::api_version_to_string(layer.specVersion),
+            "implementation_version": layer.implementationVersion,
+            "description": vulkan_init::vk_chars_to_string(&layer.description)
+        }));
+    }
+    Value::Object(map)
+}
+
+// null for the features the device's api version and extensions don't let it report
+fn features_json(ph_device: &vulkan_init::VulkanPhysicalDevice) -> Value
+{
+    let mut map = Map::new();
+    ph_device.features.zip(&ph_device.features, |name, supported, _| {
+        let supported = if ph_device.reports_feature(name) { json!(supported != vulkan_bindings::VK_FALSE) } else { Value::Null };
+        map.insert(name.to_string(), supported);
+    });
+    Value::Object(map)
+}
+
+fn limits_json(limits: &vulkan_bindings::VkPhysicalDeviceLimits) -> Value
+{
+    LIMITS_JSON!(limits, [
+        maxImageDimension1D, maxImageDimension2D, maxImageDimension3D, maxImageDimensionCube, maxImageArrayLayers,
+        maxTexelBufferElements, maxUniformBufferRange, maxStorageBufferRange, maxPushConstantsSize, maxMemoryAllocationCount,
+        maxSamplerAllocationCount, bufferImageGranularity, sparseAddressSpaceSize, maxBoundDescriptorSets, maxPerStageDescriptorSamplers,
+        maxPerStageDescriptorUniformBuffers, maxPerStageDescriptorStorageBuffers, maxPerStageDescriptorSampledImages, maxPerStageDescriptorStorageImages, maxPerStageDescriptorInputAttachments,
+        maxPerStageResources, maxDescriptorSetSamplers, maxDescriptorSetUniformBuffers, maxDescriptorSetUniformBuffersDynamic, maxDescriptorSetStorageBuffers,
+        maxDescriptorSetStorageBuffersDynamic, maxDescriptorSetSampledImages, maxDescriptorSetStorageImages, maxDescriptorSetInputAttachments, maxVertexInputAttributes,
+        maxVertexInputBindings, maxVertexInputAttributeOffset, maxVertexInputBindingStride, maxVertexOutputComponents, maxTessellationGenerationLevel,
+        maxTessellationPatchSize, maxTessellationControlPerVertexInputComponents, maxTessellationControlPerVertexOutputComponents, maxTessellationControlPerPatchOutputComponents, maxTessellationControlTotalOutputComponents,
+        maxTessellationEvaluationInputComponents, maxTessellationEvaluationOutputComponents, maxGeometryShaderInvocations, maxGeometryInputComponents, maxGeometryOutputComponents,
+        maxGeometryOutputVertices, maxGeometryTotalOutputComponents, maxFragmentInputComponents, maxFragmentOutputAttachments, maxFragmentDualSrcAttachments,
+        maxFragmentCombinedOutputResources, maxComputeSharedMemorySize, maxComputeWorkGroupCount, maxComputeWorkGroupInvocations, maxComputeWorkGroupSize,
+        subPixelPrecisionBits, subTexelPrecisionBits, mipmapPrecisionBits, maxDrawIndexedIndexValue, maxDrawIndirectCount,
+        maxSamplerLodBias, maxSamplerAnisotropy, maxViewports, maxViewportDimensions, viewportBoundsRange,
+        viewportSubPixelBits, minMemoryMapAlignment, minTexelBufferOffsetAlignment, minUniformBufferOffsetAlignment, minStorageBufferOffsetAlignment,
+        minTexelOffset, maxTexelOffset, minTexelGatherOffset, maxTexelGatherOffset, minInterpolationOffset,
+        maxInterpolationOffset, subPixelInterpolationOffsetBits, maxFramebufferWidth, maxFramebufferHeight, maxFramebufferLayers,
+        framebufferColorSampleCounts, framebufferDepthSampleCounts, framebufferStencilSampleCounts, framebufferNoAttachmentsSampleCounts, maxColorAttachments,
+        sampledImageColorSampleCounts, sampledImageIntegerSampleCounts, sampledImageDepthSampleCounts, sampledImageStencilSampleCounts, storageImageSampleCounts,
+        maxSampleMaskWords, timestampComputeAndGraphics, timestampPeriod, maxClipDistances, maxCullDistances,
+        maxCombinedClipAndCullDistances, discreteQueuePriorities, pointSizeRange, lineWidthRange, pointSizeGranularity,
+        lineWidthGranularity, strictLines, standardSampleLocations, optimalBufferCopyOffsetAlignment, optimalBufferCopyRowPitchAlignment,
+        nonCoherentAtomSize
+    ])
+}
+
+fn memory_json(mem_properties: &vulkan_bindings::VkPhysicalDeviceMemoryProperties) -> Value
+{
+    let heaps: Vec<Value> = mem_properties.memoryHeaps[..mem_properties.memoryHeapCount as usize].iter()
+        .map(|heap| json!({
+            "size": heap.size,
+            "flags": flag_names(heap.flags, &MEMORY_HEAP_FLAGS)
+        }))
+        .collect();
+    let types: Vec<Value> = mem_properties.memoryTypes[..mem_properties.memoryTypeCount as usize].iter()
+        .map(|mem_type| json!({
+            "heap": mem_type.heapIndex,
+            "flags": flag_names(mem_type.propertyFlags, &MEMORY_PROPERTY_FLAGS)
+        }))
+        .collect();
+    json!({ "heaps": heaps, "types": types })
+}
+
+fn queue_families_json(family_queues: &[vulkan_bindings::VkQueueFamilyProperties]) -> Value
+{
+    let families: Vec<Value> = family_queues.iter()
+        .map(|family| json!({
+            "count": family.queueCount,
+            "flags": flag_names(family.queueFlags, &QUEUE_FLAGS),
+            "timestamp_valid_bits": family.timestampValidBits,
+            "min_image_transfer_granularity": [
+                family.minImageTransferGranularity.width,
+                family.minImageTransferGranularity.height,
+                family.minImageTransferGranularity.depth
+            ]
+        }))
+        .collect();
+    Value::Array(families)
+}
+
+// the version that made the format core, the promoted ones can't be queried below it
+fn format_api_version(format: vulkan_bindings::VkFormat) -> u32
+{
+    match format {
+        vulkan_bindings::VkFormat_VK_FORMAT_UNDEFINED..=vulkan_bindings::VkFormat_VK_FORMAT_ASTC_12x12_SRGB_BLOCK => vulkan_init::make_api_version(0, 1, 0, 0),
+        vulkan_bindings::VkFormat_VK_FORMAT_G8B8G8R8_422_UNORM..=vulkan_bindings::VkFormat_VK_FORMAT_G16_B16_R16_3PLANE_444_UNORM => vulkan_init::make_api_version(0, 1, 1, 0),
+        _ => vulkan_init::make_api_version(0, 1, 3, 0)
+    }
+}
+
+fn formats_json(ph_device: &vulkan_init::VulkanPhysicalDevice) -> Value
+{
+    let mut map = Map::new();
+    let formats = vulkan_types::Format::VALUES.iter()
+        .map(|format| format.as_raw())
+        .filter(|format| *format != vulkan_bindings::VkFormat_VK_FORMAT_UNDEFINED && format_api_version(*format) <= ph_device.api_version());
+    for format in formats
+    {
+        let properties = ph_device.format_properties(format);
+        if properties.linearTilingFeatures == 0 && properties.optimalTilingFeatures == 0 && properties.bufferFeatures == 0
+        {
+            continue;
+        }
+        map.insert(vulkan_format::format_name(format), json!({
+            "linear": flag_names(properties.linearTilingFeatures, &FORMAT_FEATURE_FLAGS),
+            "optimal": flag_names(properties.optimalTilingFeatures, &FORMAT_FEATURE_FLAGS),
+            "buffer": flag_names(properties.bufferFeatures, &FORMAT_FEATURE_FLAGS)
+        }));
+    }
+    Value::Object(map)
+}
+
+fn device_json(ph_device: &vulkan_init::VulkanPhysicalDevice, present_modes: Option<&Vec<vulkan_bindings::VkPresentModeKHR>>) -> Value
+{
+    let properties = &ph_device.properties;
+    json!({
+        "name": ph_device.name(),
+        "type": device_type_name(properties.deviceType),
+        "vendor_id": format!("0x{:04x}", properties.vendorID),
+        "device_id": format!("0x{:04x}", properties.deviceID),
+        "driver_version": properties.driverVersion,
+        "api_version": vulkan_init::api_version_to_string(properties.apiVersion),
+        "uuid": ph_device.device_uuid.map(|uuid| uuid.iter().map(|b| format!("{:02x}", b)).collect::<String>()),
+        "extensions": extensions_json(&ph_device.extensions),
+        "features": features_json(ph_device),
+        "limits": limits_json(&properties.limits),
+        "memory": memory_json(&ph_device.mem_properties),
+        "queue_families": queue_families_json(&ph_device.family_queues),
+        "formats": formats_json(ph_device),
+        "present_modes": present_modes.map(|modes| modes.iter().map(|mode| present_mode_name(*mode)).collect::<Vec<String>>())
+    })
+}
+
+pub fn collect() -> Result<Value, Box<dyn std::error::Error>>
+{
+    let builder = || vulkan_init::VulkanInstance::builder()
+        .application("Anvil Report", vulkan_init::make_api_version(0, 0, 1, 0))
+        .engine("Anvil", vulkan_init::make_api_version(0, 0, 1, 0));
+    // present modes can only be queried against a surface, a headless one avoids needing a window
+    let surface_exts = vulkan_init::load_extension_names(&[vulkan_bindings::VK_KHR_SURFACE_EXTENSION_NAME, vulkan_bindings::VK_EXT_HEADLESS_SURFACE_EXTENSION_NAME]);
+    let mut probe = builder().build()?;
+    let mut instance = if surface_exts.iter().all(|ext| probe.has_extension(ext))
+    {
+        probe.destroy();
+        builder().extensions(surface_exts).build()?
+    }
+    else
+    {
+        probe
+    };
+    let surface = instance.create_headless_surface().ok();
+    let mut devices = Vec::new();
+    for ph_device in instance.physical_devices_mut().iter_mut()
+    {
+        let present_modes = match surface {
+            Some(surface) => ph_device.load_presentation_mode(&surface).ok().map(|_| &ph_device.supported_presentation_modes),
+            None => None
+        };
+        devices.push(device_json(ph_device, present_modes));
+    }
+    let report = json!({
+        "report_version": REPORT_VERSION,
+        "instance": {
+            "loader_version": vulkan_init::api_version_to_string(instance.loader_version),
+            "api_version": vulkan_init::api_version_to_string(instance.api_version),
+            "layers": layers_json(instance.available_layers()),
+            "extensions": extensions_json(instance.available_extensions())
+        },
+        "devices": devices
+    });
+    if let Some(surface) = surface
+    {
+        instance.destroy_surface(surface);
+    }
+    instance.destroy();
+    Ok(report)
+}
+
+fn write_value(out: &mut String, key: &str, value: &Value, depth: usize)
+{
+    let indent = "  ".repeat(depth);
+    match value {
+        Value::Object(map) => {
+            out.push_str(&format!("{}{}:\n", indent, key));
+            for (child_key, child) in map
+            {
+                write_value(out, child_key, child, depth + 1);
+            }
+        },
+        Value::Array(items) if items.iter().any(|item| item.is_object()) => {
+            out.push_str(&format!("{}{}:\n", indent, key));
+            for (idx, item) in items.iter().enumerate()
+            {
+                let label = match item.get("name").and_then(|name| name.as_str()) {
+                    Some(name) => format!("[{}] {}", idx, name),
+                    None => format!("[{}]", idx)
+                };
+                write_value(out, &label, item, depth + 1);
+            }
+        },
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(scalar_to_string).collect();
+            out.push_str(&format!("{}{}: {}\n", indent, key, items.join(", ")));
+        },
+        scalar => out.push_str(&format!("{}{}: {}\n", indent, key, scalar_to_string(scalar)))
+    }
+}
+
+pub fn scalar_to_string(value: &Value) -> String
+{
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "unavailable".to_string(),
+        other => other.to_string()
+    }
+}
+
+pub fn to_text(report: &Value) -> String
+{
+    let mut out = String::new();
+    if let Value::Object(map) = report
+    {
+        for (key, value) in map
+        {
+            write_value(&mut out, key, value, 0);
+        }
+    }
+    out
+}
@@ -1,3 +0,0 @@
-#define VK_NO_PROTOTYPES
-#define VK_USE_PLATFORM_WIN32_KHR
-#include "vulkan/vulkan.h"
\ No newline at end of file